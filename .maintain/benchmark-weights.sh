@@ -0,0 +1,41 @@
+#!/usr/bin/env bash
+# Regenerates the weights of the pallets benchmarked in the runtime
+#
+# Requires `frame-omni-bencher`:
+#   cargo install --git https://github.com/paritytech/polkadot-sdk --branch stable2412 frame-omni-bencher
+#
+# Usage: .maintain/benchmark-weights.sh [pallet...]
+#   Without arguments every pallet below is benchmarked.
+
+set -euo pipefail
+
+cd "$(dirname "$0")/.."
+
+RUNTIME=target/release/wbuild/hypertensor-runtime/hypertensor_runtime.compact.compressed.wasm
+TEMPLATE=./.maintain/frame-weight-template.hbs
+
+declare -A OUTPUTS=(
+	[pallet_network]=pallets/network/src/weights.rs
+	[pallet_author_subsidy]=pallets/author-subsidy/src/weights.rs
+	[pallet_randomness_beacon]=pallets/randomness-beacon/src/weights.rs
+)
+
+PALLETS=("$@")
+if [ ${#PALLETS[@]} -eq 0 ]; then
+	PALLETS=("${!OUTPUTS[@]}")
+fi
+
+cargo build --release -p hypertensor-runtime --features runtime-benchmarks
+
+for pallet in "${PALLETS[@]}"; do
+	output=${OUTPUTS[$pallet]:?"no weights file for $pallet"}
+	echo "Benchmarking $pallet into $output"
+	frame-omni-bencher v1 benchmark pallet \
+		--runtime "$RUNTIME" \
+		--extrinsic "" \
+		--pallet "$pallet" \
+		--steps 50 \
+		--repeat 20 \
+		--output "$output" \
+		--template "$TEMPLATE"
+done
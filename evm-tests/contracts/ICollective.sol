@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT
+
+pragma solidity ^0.8.0;
+
+interface ICollective  {
+  event Proposed(address indexed account, uint256 indexed proposalIndex, bytes32 indexed proposalHash, uint256 threshold);
+  event Voted(address indexed account, bytes32 indexed proposalHash, bool voted);
+  event Closed(bytes32 indexed proposalHash);
+
+  // `proposal` is a SCALE encoded runtime call, returns the proposal index
+  // `threshold` must be at least 2, lower thresholds revert
+  function propose(uint256 threshold, bytes calldata proposal) external returns (uint256);
+  function vote(bytes32 proposalHash, uint256 proposalIndex, bool approve) external;
+  function close(
+    bytes32 proposalHash,
+    uint256 proposalIndex,
+    uint256 weightBoundRefTime,
+    uint256 weightBoundProofSize,
+    uint256 lengthBound
+  ) external;
+  function proposalOf(bytes32 proposalHash) external view returns (bytes memory);
+  function members() external view returns (address[] memory);
+  function voting(bytes32 proposalHash) external view returns (
+    uint256 index,
+    uint256 threshold,
+    address[] memory ayes,
+    address[] memory nays,
+    uint256 end
+  );
+}
@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT
+
+pragma solidity ^0.8.0;
+
+interface IConsensus  {
+  struct SubnetNodeConsensusData {
+    uint256 subnetNodeId;
+    uint256 score;
+  }
+
+  event ValidatorSubmission(uint256 indexed subnetId, address indexed hotkey, uint256 epoch);
+  // Emitted instead of `ValidatorSubmission` when the backup validator submits
+  event BackupValidatorSubmission(uint256 indexed subnetId, address indexed hotkey, uint256 epoch);
+  event Attestation(uint256 indexed subnetId, address indexed hotkey, uint256 epoch);
+
+  // `prioritizeQueueNodeId` and `removeQueueNodeId` are unset when `0`, `args` and `attestData` when empty
+  function proposeAttestation(
+    uint256 subnetId,
+    SubnetNodeConsensusData[] calldata data,
+    uint256 prioritizeQueueNodeId,
+    uint256 removeQueueNodeId,
+    bytes calldata args,
+    bytes calldata attestData
+  ) external;
+  function attest(uint256 subnetId, bytes calldata attestData) external;
+  function getElectedValidator(uint256 subnetId, uint256 subnetEpoch) external view returns (uint256);
+  function getCurrentSubnetEpoch(uint256 subnetId) external view returns (uint256);
+  function getConsensusData(uint256 subnetId, uint256 subnetEpoch) external view returns (
+    uint256 validatorId,
+    uint256 submittedBlock,
+    SubnetNodeConsensusData[] memory data,
+    uint256[] memory attestors,
+    uint256 prioritizeQueueNodeId,
+    uint256 removeQueueNodeId,
+    bytes memory args
+  );
+}
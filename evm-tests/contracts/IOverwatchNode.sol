@@ -12,6 +12,15 @@ interface IOverwatchNode  {
     uint256 weight;
     uint8[] salt;
   }
+
+  event OverwatchNodeRegistered(address indexed coldkey, address indexed hotkey, uint256 stake);
+  event OverwatchNodeRemoved(uint256 indexed overwatchNodeId, address indexed account);
+  event OverwatchNodePeerIdSet(uint256 indexed subnetId, uint256 indexed overwatchNodeId, string peerId);
+  event OverwatchWeightsCommitted(uint256 indexed overwatchNodeId, address indexed hotkey, uint256[] subnetIds);
+  event OverwatchWeightsRevealed(uint256 indexed overwatchNodeId, address indexed hotkey, uint256[] subnetIds, uint256[] weights);
+  event OverwatchStakeAdded(uint256 indexed overwatchNodeId, address indexed coldkey, address indexed hotkey, uint256 amount);
+  event OverwatchStakeRemoved(address indexed coldkey, address indexed hotkey, uint256 amount);
+
   function registerOverwatchNode(address hotkey, uint256 stakeToBeAdded) external payable;
   function removeOverwatchNode(uint256 overwatchNodeId) external;
   function anyoneRemoveOverwatchNode(uint256 overwatchNodeId) external;
@@ -3,6 +3,24 @@
 pragma solidity ^0.8.0;
 
 interface Staking  {
+  event StakeAdded(uint256 indexed subnetId, address indexed coldkey, address indexed hotkey, uint256 amount);
+  event StakeRemoved(uint256 indexed subnetId, address indexed coldkey, address indexed hotkey, uint256 amount);
+  event UnbondingsClaimed(address indexed coldkey);
+  event SubnetDelegateStakeAdded(uint256 indexed subnetId, address indexed account, uint256 amount);
+  event SubnetDelegateStakeSwapped(uint256 indexed fromSubnetId, uint256 indexed toSubnetId, address indexed account, uint256 shares);
+  event SubnetDelegateStakeTransferred(uint256 indexed subnetId, address indexed from, address indexed to, uint256 shares);
+  event SubnetDelegateStakeRemoved(uint256 indexed subnetId, address indexed account, uint256 shares);
+  event SubnetDelegateStakeIncreased(uint256 indexed subnetId, address indexed account, uint256 amount);
+  event DelegateNodeStakeAdded(uint256 indexed subnetId, uint256 indexed subnetNodeId, address indexed account, uint256 amount);
+  event DelegateNodeStakeSwapped(uint256 indexed fromSubnetId, uint256 indexed toSubnetId, address indexed account, uint256 fromSubnetNodeId, uint256 toSubnetNodeId, uint256 shares);
+  event DelegateNodeStakeTransferred(uint256 indexed subnetId, address indexed from, address indexed to, uint256 subnetNodeId, uint256 shares);
+  event DelegateNodeStakeRemoved(uint256 indexed subnetId, uint256 indexed subnetNodeId, address indexed account, uint256 shares);
+  event DelegateNodeStakeIncreased(uint256 indexed subnetId, uint256 indexed subnetNodeId, address indexed account, uint256 amount);
+  event DelegateNodeToSubnetDelegateStakeSwapped(uint256 indexed fromSubnetId, uint256 indexed toSubnetId, address indexed account, uint256 fromSubnetNodeId, uint256 shares);
+  event SubnetDelegateToNodeDelegateStakeSwapped(uint256 indexed fromSubnetId, uint256 indexed toSubnetId, address indexed account, uint256 toSubnetNodeId, uint256 shares);
+  event SwapCallQueueUpdated(uint256 indexed id, address indexed account, uint256 callType, uint256 toSubnetId, uint256 toSubnetNodeId);
+  event SwapCallQueueCancelled(uint256 indexed id, address indexed account, uint256 balance);
+
   function addToStake(
     uint256 subnetId,
     uint256 subnetNodeId,
@@ -99,6 +117,8 @@ interface Staking  {
     uint256 toSubnetNodeId
   ) external;
 
+  function cancelSwapQueue(uint256 id) external;
+
   struct QueuedSwapData {
     uint32 id;
     address accountId;
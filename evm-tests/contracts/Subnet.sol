@@ -8,6 +8,59 @@ interface Subnet {
         uint256 count;
     }
 
+    event SubnetRegistered(uint256 indexed subnetId, address indexed owner, string name);
+    event SubnetNodeRegistered(uint256 indexed subnetId, uint256 indexed subnetNodeId, address indexed coldkey, address hotkey);
+    event SubnetActivated(uint256 indexed subnetId);
+    event SubnetNodeRemoved(uint256 indexed subnetId, uint256 indexed subnetNodeId);
+    event SubnetNodeUpdateDelegateRewardRate(uint256 indexed subnetId, uint256 indexed subnetNodeId, uint256 delegateRewardRate);
+    event SubnetNodeUpdateUnique(uint256 indexed subnetId, uint256 indexed subnetNodeId, string unique);
+    event SubnetNodeUpdateNonUnique(uint256 indexed subnetId, uint256 indexed subnetNodeId, string nonUnique);
+    event UpdateColdkey(address indexed coldkey, address indexed newColdkey);
+    event UpdateHotkey(address indexed hotkey, address indexed newHotkey);
+    event SubnetNodeUpdatePeerId(uint256 indexed subnetId, uint256 indexed subnetNodeId, string peerId);
+    event SubnetNodeUpdateBootnode(uint256 indexed subnetId, uint256 indexed subnetNodeId, string bootnode);
+    event SubnetNodeUpdateBootnodePeerId(uint256 indexed subnetId, uint256 indexed subnetNodeId, string bootnodePeerId);
+    event SubnetNodeUpdateClientPeerId(uint256 indexed subnetId, uint256 indexed subnetNodeId, string clientPeerId);
+    event IdentityRegistered(address indexed coldkey, address indexed hotkey, string name);
+    event IdentityRemoved(address indexed coldkey);
+    event SubnetPaused(uint256 indexed subnetId, address indexed owner);
+    event SubnetUnpaused(uint256 indexed subnetId, address indexed owner);
+    event SubnetForked(uint256 indexed subnetId, address indexed owner, uint256[] subnetNodeIds);
+    event SubnetForkRevert(uint256 indexed subnetId, address indexed owner);
+    event SubnetDeactivated(uint256 indexed subnetId, address indexed owner);
+    event SubnetNameUpdate(uint256 indexed subnetId, address indexed owner, string value);
+    event SubnetRepoUpdate(uint256 indexed subnetId, address indexed owner, string value);
+    event SubnetDescriptionUpdate(uint256 indexed subnetId, address indexed owner, string value);
+    event SubnetMiscUpdate(uint256 indexed subnetId, address indexed owner, string value);
+    event ChurnLimitUpdate(uint256 indexed subnetId, address indexed owner, uint256 value);
+    event RegistrationQueueEpochsUpdate(uint256 indexed subnetId, address indexed owner, uint256 value);
+    event IdleClassificationEpochsUpdate(uint256 indexed subnetId, address indexed owner, uint256 value);
+    event IncludedClassificationEpochsUpdate(uint256 indexed subnetId, address indexed owner, uint256 value);
+    event AddSubnetRegistrationInitialColdkeys(uint256 indexed subnetId, address indexed owner, InitialColdkeys[] coldkeys);
+    event RemoveSubnetRegistrationInitialColdkeys(uint256 indexed subnetId, address indexed owner, address[] coldkeys);
+    event SubnetKeyTypesUpdate(uint256 indexed subnetId, address indexed owner, uint256[] value);
+    event SubnetMinMaxStakeBalanceUpdate(uint256 indexed subnetId, address indexed owner, uint256 min, uint256 max);
+    event SubnetDelegateStakeRewardsPercentageUpdate(uint256 indexed subnetId, address indexed owner, uint256 value);
+    event MaxRegisteredNodesUpdate(uint256 indexed subnetId, address indexed owner, uint256 value);
+    event TransferPendingSubnetOwner(uint256 indexed subnetId, address indexed owner, address indexed newOwner);
+    event AcceptPendingSubnetOwner(uint256 indexed subnetId, address indexed newOwner);
+    event TargetNodeRegistrationsPerEpochUpdate(uint256 indexed subnetId, address indexed owner, uint256 value);
+    event NodeBurnRateAlphaUpdate(uint256 indexed subnetId, address indexed owner, uint256 value);
+    event QueueImmunityEpochsUpdate(uint256 indexed subnetId, address indexed owner, uint256 value);
+    event MinSubnetNodeReputationUpdate(uint256 indexed subnetId, address indexed owner, uint256 value);
+    event SubnetNodeMinWeightDecreaseReputationThresholdUpdate(uint256 indexed subnetId, address indexed owner, uint256 value);
+    event AbsentDecreaseReputationFactorUpdate(uint256 indexed subnetId, address indexed owner, uint256 value);
+    event IncludedIncreaseReputationFactorUpdate(uint256 indexed subnetId, address indexed owner, uint256 value);
+    event BelowMinWeightDecreaseReputationFactorUpdate(uint256 indexed subnetId, address indexed owner, uint256 value);
+    event NonAttestorDecreaseReputationFactorUpdate(uint256 indexed subnetId, address indexed owner, uint256 value);
+    event NonConsensusAttestorDecreaseReputationFactorUpdate(uint256 indexed subnetId, address indexed owner, uint256 value);
+    event ValidatorAbsentSubnetNodeReputationFactorUpdate(uint256 indexed subnetId, address indexed owner, uint256 value);
+    event ValidatorNonConsensusSubnetNodeReputationFactorUpdate(uint256 indexed subnetId, address indexed owner, uint256 value);
+    event BootnodesUpdated(uint256 indexed subnetId, string[] added, string[] removed);
+    event AddSubnetBootnodeAccess(uint256 indexed subnetId, address indexed owner, address indexed newAccount);
+    event RemoveSubnetBootnodeAccess(uint256 indexed subnetId, address indexed owner, address indexed removeAccount);
+    event SubnetElectionModeUpdate(uint256 indexed subnetId, address indexed owner, uint256 value);
+
     function registerSubnet(
         uint256 maxCost,
         string memory name,
@@ -259,6 +312,8 @@ interface Subnet {
 
     function ownerRemoveBootnodeAccess(uint256 subnetId, address) external;
 
+    function ownerUpdateElectionMode(uint256 subnetId, uint256 value) external;
+
     function updateBootnodes(
         uint256 subnetId,
         string[] memory add,
@@ -8,7 +8,8 @@ import { cryptoWaitReady, decodeAddress } from '@polkadot/util-crypto';
 import { hexToU8a, u8aToHex } from '@polkadot/util';
 import { KeyringPair } from "@polkadot/keyring/types";
 import { ApiPromise, Keyring } from "@polkadot/api";
-import { Contract, JsonRpcProvider } from "ethers";
+import { Contract, ContractFactory, JsonRpcProvider, Wallet, concat, zeroPadValue } from "ethers";
+import { DELEGATE_CALLER_BYTECODE } from "./utils";
 import { expect } from "chai";
 
 export async function transferBalanceFromSudo(
@@ -717,6 +718,17 @@ export async function updateSwapQueue(
   await tx.wait();
 }
 
+export async function cancelSwapQueue(
+  contract: Contract, 
+  id: string,
+) {
+  const tx = await contract.cancelSwapQueue(
+    id
+  );
+
+  await tx.wait();
+}
+
 export async function ownerPauseSubnet(
   contract: Contract, 
   subnetId: string,
@@ -934,6 +946,19 @@ export async function ownerUpdateMaxRegisteredNodes(
   await tx.wait();
 }
 
+export async function ownerUpdateElectionMode(
+  contract: Contract, 
+  subnetId: string,
+  value: string,
+) {
+  const tx = await contract.ownerUpdateElectionMode(
+    subnetId,
+    value
+  );
+
+  await tx.wait();
+}
+
 export async function transferSubnetOwnership(
   contract: Contract, 
   subnetId: string,
@@ -1230,6 +1255,132 @@ export async function revealOverwatchSubnetWeights(
   }
 }
 
+export async function setCouncilMembersFromSudo(
+  api: ApiPromise,
+  members: string[],
+) {
+  const keyring = new Keyring({ type: 'ethereum' });
+  const sudoPair: KeyringPair = keyring.addFromUri("0x5fb92d6e98884f76de468fa3f6278f8807c48bebc13595d45af5bdc4da702133");
+
+  const oldCount = (await api.query.collective.members()).length
+
+  await new Promise<void>((resolve, reject) => {
+    api.tx.sudo
+      .sudo(api.tx.collective.setMembers(members, null, oldCount))
+      .signAndSend(sudoPair, async (result) => {
+        if (result.status.isFinalized) {
+          if (typeof unsub === "function") unsub();
+          resolve();
+        } else if (result.isError) {
+          if (typeof unsub === "function") unsub();
+          reject(new Error("Transaction failed"));
+        }
+      })
+      .then((u) => {
+        unsub = u;
+      })
+      .catch((err) => {
+        reject(err);
+      });
+
+    let unsub: () => void;
+  });
+}
+
+export async function collectivePropose(
+  contract: Contract, 
+  threshold: string,
+  proposal: string,
+) {
+  const tx = await contract.propose(threshold, proposal);
+
+  return await tx.wait();
+}
+
+export async function collectiveVote(
+  contract: Contract, 
+  proposalHash: string,
+  proposalIndex: string,
+  approve: boolean,
+) {
+  const tx = await contract.vote(proposalHash, proposalIndex, approve);
+
+  return await tx.wait();
+}
+
+export async function collectiveClose(
+  contract: Contract, 
+  proposalHash: string,
+  proposalIndex: string,
+  weightBoundRefTime: string,
+  weightBoundProofSize: string,
+  lengthBound: string,
+) {
+  const tx = await contract.close(
+    proposalHash,
+    proposalIndex,
+    weightBoundRefTime,
+    weightBoundProofSize,
+    lengthBound
+  );
+
+  return await tx.wait();
+}
+
+export async function erc20Transfer(
+  contract: Contract, 
+  to: string,
+  value: bigint,
+) {
+  const tx = await contract["transfer(address,uint256)"](to, value);
+
+  return await tx.wait();
+}
+
+export async function erc20Approve(
+  contract: Contract, 
+  spender: string,
+  value: bigint,
+) {
+  const tx = await contract.approve(spender, value);
+
+  return await tx.wait();
+}
+
+export async function erc20TransferFrom(
+  contract: Contract, 
+  from: string,
+  to: string,
+  value: bigint,
+) {
+  const tx = await contract.transferFrom(from, to, value);
+
+  return await tx.wait();
+}
+
+export async function deployDelegateCaller(wallet: Wallet) {
+  const factory = new ContractFactory([], DELEGATE_CALLER_BYTECODE, wallet);
+  const contract = await factory.deploy();
+  await contract.waitForDeployment();
+
+  return await contract.getAddress();
+}
+
+// Sends `data` to `target` through a `DELEGATE_CALLER_BYTECODE` contract
+export async function delegateCall(
+  wallet: Wallet,
+  delegateCaller: string,
+  target: string,
+  data: string,
+) {
+  const tx = await wallet.sendTransaction({
+    to: delegateCaller,
+    data: concat([zeroPadValue(target, 32), data]),
+  });
+
+  return await tx.wait();
+}
+
 /**
  * Waits for the next finalized block and returns the finalized free balance for an account.
  * @param papiApi The polkadot-api instance
@@ -22,6 +22,56 @@ export const SUBNET_CONTRACT_ADDRESS = hash(2049);
 export const STAKING_CONTRACT_ABI = Staking.abi;
 export const STAKING_CONTRACT_ADDRESS = hash(2048);
 
+// Mirrors `contracts/IConsensus.sol`
+export const CONSENSUS_CONTRACT_ABI = [
+    "event ValidatorSubmission(uint256 indexed subnetId, address indexed hotkey, uint256 epoch)",
+    "event BackupValidatorSubmission(uint256 indexed subnetId, address indexed hotkey, uint256 epoch)",
+    "event Attestation(uint256 indexed subnetId, address indexed hotkey, uint256 epoch)",
+    "function proposeAttestation(uint256 subnetId, (uint256 subnetNodeId, uint256 score)[] data, uint256 prioritizeQueueNodeId, uint256 removeQueueNodeId, bytes args, bytes attestData)",
+    "function attest(uint256 subnetId, bytes attestData)",
+    "function getElectedValidator(uint256 subnetId, uint256 subnetEpoch) view returns (uint256)",
+    "function getCurrentSubnetEpoch(uint256 subnetId) view returns (uint256)",
+    "function getConsensusData(uint256 subnetId, uint256 subnetEpoch) view returns (uint256 validatorId, uint256 submittedBlock, (uint256 subnetNodeId, uint256 score)[] data, uint256[] attestors, uint256 prioritizeQueueNodeId, uint256 removeQueueNodeId, bytes args)",
+];
+export const CONSENSUS_CONTRACT_ADDRESS = hash(2051);
+
+// Mirrors `contracts/ICollective.sol`
+export const COLLECTIVE_CONTRACT_ABI = [
+    "event Proposed(address indexed account, uint256 indexed proposalIndex, bytes32 indexed proposalHash, uint256 threshold)",
+    "event Voted(address indexed account, bytes32 indexed proposalHash, bool voted)",
+    "event Closed(bytes32 indexed proposalHash)",
+    "function propose(uint256 threshold, bytes proposal) returns (uint256)",
+    "function vote(bytes32 proposalHash, uint256 proposalIndex, bool approve)",
+    "function close(bytes32 proposalHash, uint256 proposalIndex, uint256 weightBoundRefTime, uint256 weightBoundProofSize, uint256 lengthBound)",
+    "function proposalOf(bytes32 proposalHash) view returns (bytes)",
+    "function members() view returns (address[])",
+    "function voting(bytes32 proposalHash) view returns (uint256 index, uint256 threshold, address[] ayes, address[] nays, uint256 end)",
+];
+export const COLLECTIVE_CONTRACT_ADDRESS = hash(2052);
+
+// Mirrors `contracts/IERC20BalanceTransfer.sol`
+export const ERC20_BALANCE_CONTRACT_ABI = [
+    "event Transfer(address indexed from, address indexed to, uint256 value)",
+    "event Approval(address indexed owner, address indexed spender, uint256 value)",
+    "function name() view returns (string)",
+    "function symbol() view returns (string)",
+    "function decimals() view returns (uint8)",
+    "function totalSupply() view returns (uint256)",
+    "function balanceOf(address owner) view returns (uint256)",
+    "function allowance(address owner, address spender) view returns (uint256)",
+    "function approve(address spender, uint256 value) returns (bool)",
+    "function transfer(address to, uint256 value) returns (bool)",
+    "function transferFrom(address from, address to, uint256 value) returns (bool)",
+    "function transfer(bytes32 data) payable",
+];
+export const ERC20_BALANCE_CONTRACT_ADDRESS = hash(2053);
+
+// Creation code of a contract that delegatecalls the address in the first 32 bytes of the
+// calldata with the rest of it and returns or reverts with the result. Used to check that
+// precompiles dispatching calls as the caller reject `DELEGATECALL`
+export const DELEGATE_CALLER_BYTECODE =
+    "0x602780600b6000396000f3600060006020360380602060003760006000355af43d600060003e6022573d6000fd5b3d6000f3";
+
 
 export type ClientUrlType = 'http://localhost:9944';
 
@@ -112,3 +162,23 @@ export async function waitForBlocks(api: ApiPromise, blockCount = 1) {
         });
     })
 }
+
+/**
+ * Expects `promise` to fail with `reason` in its error, precompile reverts and errors surface
+ * in the `eth_estimateGas` error message
+ */
+export async function expectRevert(promise: Promise<any>, reason: string) {
+    let error: any = undefined;
+    try {
+        await promise;
+    } catch (e) {
+        error = e;
+    }
+
+    if (error === undefined) {
+        throw new Error(`Expected failure with "${reason}"`);
+    }
+    if (!String(error?.message ?? error).includes(reason)) {
+        throw new Error(`Expected failure with "${reason}", got: ${error?.message ?? error}`);
+    }
+}
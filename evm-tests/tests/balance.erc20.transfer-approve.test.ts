@@ -0,0 +1,160 @@
+import { getDevnetApi } from "../src/substrate"
+import { dev } from "@polkadot-api/descriptors"
+import { TypedApi } from "polkadot-api";
+import { ethers } from "ethers"
+import {
+    ERC20_BALANCE_CONTRACT_ABI,
+    ERC20_BALANCE_CONTRACT_ADDRESS,
+    expectRevert,
+    generateRandomEthersWallet,
+} from "../src/utils"
+import {
+    delegateCall,
+    deployDelegateCaller,
+    erc20Approve,
+    erc20Transfer,
+    erc20TransferFrom,
+    transferBalanceFromSudo,
+} from "../src/network"
+import { compareEthBalanceWithTxFee } from "../src/balance-math";
+import { SUB_LOCAL_URL } from "../src/config";
+import { ApiPromise, WsProvider } from "@polkadot/api";
+import { expect } from "chai";
+
+// Status: pending
+// npm test -- -g "test erc20 balance transfer approve-0xe2c20b4a"
+describe("test erc20 balance transfer approve-0xe2c20b4a", () => {
+    // init eth part
+    const owner = generateRandomEthersWallet();
+    const spender = generateRandomEthersWallet();
+    const recipient = generateRandomEthersWallet();
+
+    let papiApi: TypedApi<typeof dev>
+    let api: ApiPromise
+
+    const sudoTransferAmount = BigInt(10000e18)
+    const transferAmount = BigInt(100e18)
+    const allowanceAmount = BigInt(50e18)
+
+    before(async () => {
+        papiApi = await getDevnetApi()
+
+        const provider = new WsProvider(SUB_LOCAL_URL);
+
+        api = await ApiPromise.create({ provider });
+
+        for (const wallet of [owner, spender]) {
+            await transferBalanceFromSudo(
+                api,
+                papiApi,
+                SUB_LOCAL_URL,
+                wallet.address,
+                sudoTransferAmount,
+            )
+        }
+    })
+
+    // Status: pending
+    // npm test -- -g "testing transfer-0x91f3a6d2"
+    it("testing transfer-0x91f3a6d2", async () => {
+        const contract = new ethers.Contract(ERC20_BALANCE_CONTRACT_ADDRESS, ERC20_BALANCE_CONTRACT_ABI, owner);
+
+        const ownerBalanceBefore = await contract.balanceOf(owner.address);
+        const recipientBalanceBefore = await contract.balanceOf(recipient.address);
+
+        const receipt = await erc20Transfer(contract, recipient.address, transferAmount)
+
+        expect(receipt.logs.length).to.be.equal(1);
+        const transfer = contract.interface.parseLog(receipt.logs[0])!
+        expect(transfer.name).to.be.equal("Transfer");
+        expect(transfer.args.from).to.be.equal(owner.address);
+        expect(transfer.args.to).to.be.equal(recipient.address);
+        expect(transfer.args.value).to.be.equal(transferAmount);
+
+        const ownerBalanceAfter = await contract.balanceOf(owner.address);
+        const recipientBalanceAfter = await contract.balanceOf(recipient.address);
+
+        expect(recipientBalanceAfter).to.be.equal(recipientBalanceBefore + transferAmount);
+        compareEthBalanceWithTxFee(ownerBalanceBefore - transferAmount, ownerBalanceAfter)
+    })
+
+    // Status: pending
+    // npm test -- -g "testing approve transferFrom-0x5ad0c7e8"
+    it("testing approve transferFrom-0x5ad0c7e8", async () => {
+        const ownerContract = new ethers.Contract(ERC20_BALANCE_CONTRACT_ADDRESS, ERC20_BALANCE_CONTRACT_ABI, owner);
+        const spenderContract = new ethers.Contract(ERC20_BALANCE_CONTRACT_ADDRESS, ERC20_BALANCE_CONTRACT_ABI, spender);
+
+        // =======
+        // Approve
+        // =======
+        const approveReceipt = await erc20Approve(ownerContract, spender.address, allowanceAmount)
+
+        expect(approveReceipt.logs.length).to.be.equal(1);
+        const approval = ownerContract.interface.parseLog(approveReceipt.logs[0])!
+        expect(approval.name).to.be.equal("Approval");
+        expect(approval.args.owner).to.be.equal(owner.address);
+        expect(approval.args.spender).to.be.equal(spender.address);
+        expect(approval.args.value).to.be.equal(allowanceAmount);
+
+        expect(await ownerContract.allowance(owner.address, spender.address)).to.be.equal(allowanceAmount);
+
+        // ============
+        // TransferFrom
+        // ============
+        const spent = allowanceAmount / BigInt(2)
+        const recipientBalanceBefore = await spenderContract.balanceOf(recipient.address);
+
+        const transferReceipt = await erc20TransferFrom(spenderContract, owner.address, recipient.address, spent)
+
+        expect(transferReceipt.logs.length).to.be.equal(1);
+        const transfer = spenderContract.interface.parseLog(transferReceipt.logs[0])!
+        expect(transfer.name).to.be.equal("Transfer");
+        expect(transfer.args.from).to.be.equal(owner.address);
+        expect(transfer.args.to).to.be.equal(recipient.address);
+        expect(transfer.args.value).to.be.equal(spent);
+
+        // The allowance is decremented by the amount moved
+        expect(await ownerContract.allowance(owner.address, spender.address)).to.be.equal(allowanceAmount - spent);
+        expect(await spenderContract.balanceOf(recipient.address)).to.be.equal(recipientBalanceBefore + spent);
+
+        // ======================
+        // Insufficient allowance
+        // ======================
+        await expectRevert(
+            spenderContract.transferFrom(owner.address, recipient.address, allowanceAmount),
+            "Insufficient allowance"
+        )
+
+        // Nothing moved and the allowance is untouched
+        expect(await ownerContract.allowance(owner.address, spender.address)).to.be.equal(allowanceAmount - spent);
+        expect(await spenderContract.balanceOf(recipient.address)).to.be.equal(recipientBalanceBefore + spent);
+    })
+
+    // Status: pending
+    // npm test -- -g "testing delegatecall reverts-0x3b8e5f17"
+    it("testing delegatecall reverts-0x3b8e5f17", async () => {
+        const contract = new ethers.Contract(ERC20_BALANCE_CONTRACT_ADDRESS, ERC20_BALANCE_CONTRACT_ABI, owner);
+
+        // A contract the owner calls can't move the owner's balance or allowances
+        const delegateCaller = await deployDelegateCaller(owner)
+
+        const ownerBalanceBefore = await contract.balanceOf(owner.address);
+        const recipientBalanceBefore = await contract.balanceOf(recipient.address);
+        const allowanceBefore = await contract.allowance(owner.address, delegateCaller);
+
+        for (const data of [
+            contract.interface.encodeFunctionData("transfer(address,uint256)", [recipient.address, transferAmount]),
+            contract.interface.encodeFunctionData("approve", [delegateCaller, transferAmount]),
+        ]) {
+            await expectRevert(
+                delegateCall(owner, delegateCaller, ERC20_BALANCE_CONTRACT_ADDRESS, data),
+                "Cannot be called with DELEGATECALL or CALLCODE"
+            )
+        }
+
+        expect(await contract.allowance(owner.address, delegateCaller)).to.be.equal(allowanceBefore);
+        expect(await contract.balanceOf(recipient.address)).to.be.equal(recipientBalanceBefore);
+        // At most fees were spent, the transfer didn't go through
+        expect(await contract.balanceOf(owner.address) > ownerBalanceBefore - transferAmount).to.be.equal(true);
+    })
+})
@@ -0,0 +1,64 @@
+import { getDevnetApi } from "../src/substrate"
+import { dev } from "@polkadot-api/descriptors"
+import { TypedApi } from "polkadot-api";
+import { ethers } from "ethers"
+import {
+    CONSENSUS_CONTRACT_ABI,
+    CONSENSUS_CONTRACT_ADDRESS,
+    expectRevert,
+    generateRandomEthersWallet,
+} from "../src/utils"
+import {
+    delegateCall,
+    deployDelegateCaller,
+    transferBalanceFromSudo,
+} from "../src/network"
+import { SUB_LOCAL_URL } from "../src/config";
+import { ApiPromise, WsProvider } from "@polkadot/api";
+
+// Status: pending
+// npm test -- -g "test consensus delegatecall-0x6d2f8a93"
+describe("test consensus delegatecall-0x6d2f8a93", () => {
+    // init eth part
+    const wallet = generateRandomEthersWallet();
+
+    let papiApi: TypedApi<typeof dev>
+    let api: ApiPromise
+
+    const sudoTransferAmount = BigInt(10000e18)
+
+    before(async () => {
+        papiApi = await getDevnetApi()
+
+        const provider = new WsProvider(SUB_LOCAL_URL);
+
+        api = await ApiPromise.create({ provider });
+
+        await transferBalanceFromSudo(
+            api,
+            papiApi,
+            SUB_LOCAL_URL,
+            wallet.address,
+            sudoTransferAmount,
+        )
+    })
+
+    // Status: pending
+    // npm test -- -g "testing propose and attest through delegatecall revert-0x1c7e4b05"
+    it("testing propose and attest through delegatecall revert-0x1c7e4b05", async () => {
+        const iface = new ethers.Interface(CONSENSUS_CONTRACT_ABI)
+
+        // A contract a validator calls can't submit or attest as that validator
+        const delegateCaller = await deployDelegateCaller(wallet)
+
+        for (const data of [
+            iface.encodeFunctionData("proposeAttestation", [1, [[1, BigInt(1e18)]], 0, 0, "0x", "0x"]),
+            iface.encodeFunctionData("attest", [1, "0x"]),
+        ]) {
+            await expectRevert(
+                delegateCall(wallet, delegateCaller, CONSENSUS_CONTRACT_ADDRESS, data),
+                "Cannot be called with DELEGATECALL or CALLCODE"
+            )
+        }
+    })
+})
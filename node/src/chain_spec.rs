@@ -64,3 +64,21 @@ pub fn hoskinson_chain_spec() -> Result<ChainSpec, String> {
     .with_genesis_config_preset_name("HOSKINSON_RUNTIME_PRESET")
     .build())
 }
+
+/// Chain spec built from one of the runtime's named genesis presets
+pub fn preset_chain_spec(
+    name: &str,
+    preset: &str,
+    chain_type: ChainType,
+) -> Result<ChainSpec, String> {
+    Ok(ChainSpec::builder(
+        WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?,
+        None,
+    )
+    .with_name(name)
+    .with_id(preset)
+    .with_chain_type(chain_type)
+    .with_properties(properties())
+    .with_genesis_config_preset_name(preset)
+    .build())
+}
@@ -1,5 +1,6 @@
 use futures::TryFutureExt;
 // Substrate
+use sc_chain_spec::ChainType;
 use sc_cli::{ChainSpec, SubstrateCli};
 use sc_service::DatabaseSource;
 // Frontier
@@ -13,6 +14,9 @@ use crate::{
 
 #[cfg(feature = "runtime-benchmarks")]
 use hypertensor_runtime::genesis_config_presets::get_account_id_from_seed;
+use hypertensor_runtime::genesis_config_presets::{
+    BENCHMARK_PRESET, EVM_TESTS_PRESET, PRODUCTION_PRESET,
+};
 // use crate::chain_spec::get_account_id_from_seed;
 
 impl SubstrateCli for Cli {
@@ -51,6 +55,21 @@ impl SubstrateCli for Cli {
                 Box::new(chain_spec::eth_development_chain_spec(enable_manual_seal)?)
             }
             "hoskinson" => Box::new(chain_spec::hoskinson_chain_spec()?),
+            EVM_TESTS_PRESET => Box::new(chain_spec::preset_chain_spec(
+                "EVM Tests",
+                EVM_TESTS_PRESET,
+                ChainType::Development,
+            )?),
+            BENCHMARK_PRESET => Box::new(chain_spec::preset_chain_spec(
+                "Benchmark",
+                BENCHMARK_PRESET,
+                ChainType::Development,
+            )?),
+            PRODUCTION_PRESET => Box::new(chain_spec::preset_chain_spec(
+                "Production",
+                PRODUCTION_PRESET,
+                ChainType::Live,
+            )?),
             "" | "local" => Box::new(chain_spec::local_chain_spec()?),
             path => Box::new(chain_spec::ChainSpec::from_json_file(
                 std::path::PathBuf::from(path),
@@ -178,6 +178,33 @@ pub struct Votes<AccountId, BlockNumber> {
     end: BlockNumber,
 }
 
+impl<AccountId, BlockNumber: Copy> Votes<AccountId, BlockNumber> {
+    /// The proposal's unique index.
+    pub fn index(&self) -> ProposalIndex {
+        self.index
+    }
+
+    /// The number of approval votes that are needed to pass the motion.
+    pub fn threshold(&self) -> MemberCount {
+        self.threshold
+    }
+
+    /// The current set of voters that approved it.
+    pub fn ayes(&self) -> &[AccountId] {
+        &self.ayes
+    }
+
+    /// The current set of voters that rejected it.
+    pub fn nays(&self) -> &[AccountId] {
+        &self.nays
+    }
+
+    /// The hard end time of this vote.
+    pub fn end(&self) -> BlockNumber {
+        self.end
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
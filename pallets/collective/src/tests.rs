@@ -124,6 +124,8 @@ parameter_types! {
     pub const OverwatchEpochEmissions: u128 = OVERWATCH_EPOCH_EMISSIONS;
     pub MaximumHooksWeight: Weight = sp_runtime::Perbill::from_percent(50) * BlockWeights::get().max_block;
     pub const DesignatedEpochSlots: u32 = 3;
+    pub const PeerIdProofDeposit: u128 = 1_000_000_000_000_000_000;
+    pub const ReportHooksWeight: bool = true;
 }
 
 impl pallet_network::Config for Test {
@@ -143,6 +145,8 @@ impl pallet_network::Config for Test {
     type OverwatchEpochEmissions = OverwatchEpochEmissions;
     type MaximumHooksWeight = MaximumHooksWeight;
     type DesignatedEpochSlots = DesignatedEpochSlots;
+    type PeerIdProofDeposit = PeerIdProofDeposit;
+    type ReportHooksWeight = ReportHooksWeight;
 }
 
 pub type BlockNumber = u32;
@@ -8,11 +8,18 @@ use sp_blockchain::HeaderBackend;
 use sp_runtime::traits::Block as BlockT;
 use std::sync::Arc;
 
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 
 use fp_account::AccountId20;
 use frame_support::storage::bounded_vec::BoundedVec;
 pub use network_custom_rpc_runtime_api::NetworkRuntimeApi;
+use pallet_network::{
+    AccountRewardsInfo, AllSubnetBootnodes, ConsensusSubmissionInfo, DelegateStakeInfo,
+    NodeDelegateStakeInfo, QueuedSwapInfo, RewardsData, RpcPage, SubnetInfo, SubnetNodeFilter,
+    SubnetNodeInfo, SubnetNodeStakeInfo,
+};
+use sp_core::H256;
+use sp_rpc::number::NumberOrHex;
 
 #[rpc(client, server)]
 pub trait NetworkCustomApi<BlockHash> {
@@ -89,6 +96,181 @@ pub trait NetworkCustomApi<BlockHash> {
         subnet_id: u32,
         at: Option<BlockHash>,
     ) -> RpcResult<Vec<u8>>;
+    // Requires version 2 of `NetworkRuntimeApi`
+    #[method(name = "network_getAccountSwapQueue")]
+    fn get_account_swap_queue(
+        &self,
+        account_id: AccountId20,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    // Decoded versions of the methods above, these require version 2 of `NetworkRuntimeApi`
+    #[method(name = "network_getSubnetInfoV2")]
+    fn get_subnet_info_v2(
+        &self,
+        subnet_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<SubnetInfo<AccountId20>>>;
+    #[method(name = "network_getAllSubnetsInfoV2")]
+    fn get_all_subnets_info_v2(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<SubnetInfo<AccountId20>>>;
+    #[method(name = "network_getSubnetNodeInfoV2")]
+    fn get_subnet_node_info_v2(
+        &self,
+        subnet_id: u32,
+        subnet_node_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<SubnetNodeInfo<AccountId20>>>;
+    #[method(name = "network_getSubnetNodesInfoV2")]
+    fn get_subnet_nodes_info_v2(
+        &self,
+        subnet_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<SubnetNodeInfo<AccountId20>>>;
+    #[method(name = "network_getAllSubnetNodesInfoV2")]
+    fn get_all_subnet_nodes_info_v2(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<SubnetNodeInfo<AccountId20>>>;
+    #[method(name = "network_getBootnodesV2")]
+    fn get_bootnodes_v2(
+        &self,
+        subnet_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<AllSubnetBootnodes>;
+    #[method(name = "network_getColdkeySubnetNodesInfoV2")]
+    fn get_coldkey_subnet_nodes_info_v2(
+        &self,
+        coldkey: AccountId20,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<SubnetNodeInfo<AccountId20>>>;
+    #[method(name = "network_getColdkeyStakesV2")]
+    fn get_coldkey_stakes_v2(
+        &self,
+        coldkey: AccountId20,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<SubnetNodeStakeInfo<AccountId20>>>;
+    #[method(name = "network_getDelegateStakesV2")]
+    fn get_delegate_stakes_v2(
+        &self,
+        account_id: AccountId20,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<DelegateStakeInfo>>;
+    #[method(name = "network_getNodeDelegateStakesV2")]
+    fn get_node_delegate_stakes_v2(
+        &self,
+        account_id: AccountId20,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<NodeDelegateStakeInfo>>;
+    #[method(name = "network_getOverwatchCommitsForEpochAndNodeV2")]
+    fn get_overwatch_commits_for_epoch_and_node_v2(
+        &self,
+        epoch: u32,
+        overwatch_node_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(u32, H256)>>;
+    #[method(name = "network_getOverwatchRevealsForEpochAndNodeV2")]
+    fn get_overwatch_reveals_for_epoch_and_node_v2(
+        &self,
+        epoch: u32,
+        overwatch_node_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(u32, NumberOrHex)>>;
+    #[method(name = "network_getElectedValidatorInfoV2")]
+    fn get_elected_validator_info_v2(
+        &self,
+        subnet_id: u32,
+        subnet_epoch: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<SubnetNodeInfo<AccountId20>>>;
+    #[method(name = "network_getValidatorsAndAttestorsV2")]
+    fn get_validators_and_attestors_v2(
+        &self,
+        subnet_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<SubnetNodeInfo<AccountId20>>>;
+    #[method(name = "network_getAccountSwapQueueV2")]
+    fn get_account_swap_queue_v2(
+        &self,
+        account_id: AccountId20,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<QueuedSwapInfo<AccountId20>>>;
+    // Archived consensus submissions of a subnet, decoded, these require version 2 of
+    // `NetworkRuntimeApi`
+    #[method(name = "network_getConsensusSubmission")]
+    fn get_consensus_submission(
+        &self,
+        subnet_id: u32,
+        subnet_epoch: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<ConsensusSubmissionInfo<AccountId20>>>;
+    #[method(name = "network_getConsensusHistory")]
+    fn get_consensus_history(
+        &self,
+        subnet_id: u32,
+        from: u32,
+        to: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<ConsensusSubmissionInfo<AccountId20>>>;
+    // Paginated queries, these require version 3 of `NetworkRuntimeApi`. Pass the returned
+    // `next_cursor` as `start` to get the next page, `limit` is capped at `MAX_RPC_PAGE_LIMIT`.
+    // A page visits at most `MAX_RPC_PAGE_SCAN` IDs, only `next_cursor: None` is the last page
+    #[method(name = "network_getAllSubnetsInfoPaged")]
+    fn get_all_subnets_info_paged(
+        &self,
+        start: u32,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<RpcPage<SubnetInfo<AccountId20>, u32>>;
+    #[method(name = "network_getSubnetNodesInfoPaged")]
+    fn get_subnet_nodes_info_paged(
+        &self,
+        subnet_id: u32,
+        start: u32,
+        limit: u32,
+        filter: SubnetNodeFilter<AccountId20>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<RpcPage<SubnetNodeInfo<AccountId20>, u32>>;
+    #[method(name = "network_getAllSubnetNodesInfoPaged")]
+    fn get_all_subnet_nodes_info_paged(
+        &self,
+        start: (u32, u32),
+        limit: u32,
+        filter: SubnetNodeFilter<AccountId20>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<RpcPage<SubnetNodeInfo<AccountId20>, (u32, u32)>>;
+    // Reward estimates against the current state, these require version 4 of `NetworkRuntimeApi`.
+    // APRs are percentages where `1e18` is 100%
+    #[method(name = "network_estimateSubnetEpochRewards")]
+    fn estimate_subnet_epoch_rewards(
+        &self,
+        subnet_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<RewardsData>>;
+    #[method(name = "network_estimateDelegateApr")]
+    fn estimate_delegate_apr(
+        &self,
+        subnet_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<NumberOrHex>;
+    #[method(name = "network_estimateNodeDelegateApr")]
+    fn estimate_node_delegate_apr(
+        &self,
+        subnet_id: u32,
+        subnet_node_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<NumberOrHex>;
+    // Per epoch rewards of an account, requires version 5 of `NetworkRuntimeApi`. Only recorded
+    // while the `RewardHistoryDepth` of the chain is set
+    #[method(name = "network_getAccountRewards")]
+    fn get_account_rewards(
+        &self,
+        account_id: AccountId20,
+        from_epoch: u32,
+        to_epoch: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<AccountRewardsInfo>>;
 }
 
 /// A struct that implements the `NetworkCustomApi`.
@@ -131,6 +313,35 @@ impl From<Error> for i32 {
     }
 }
 
+impl<C, Block> NetworkCustom<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: NetworkRuntimeApi<Block>,
+{
+    /// Newer methods are not provided by older runtimes
+    fn ensure_api_version(&self, at: <Block as BlockT>::Hash, required: u32) -> RpcResult<()> {
+        let version = self
+            .client
+            .runtime_api()
+            .api_version::<dyn NetworkRuntimeApi<Block>>(at)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get runtime API version: {:?}", e))
+            })?
+            .unwrap_or_default();
+
+        if version < required {
+            return Err(Error::RuntimeError(format!(
+                "NetworkRuntimeApi version {} is required, runtime has version {}",
+                required, version
+            ))
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
 impl<C, Block> NetworkCustomApiServer<<Block as BlockT>::Hash> for NetworkCustom<C, Block>
 where
     Block: BlockT,
@@ -330,4 +541,374 @@ where
                 .into()
             })
     }
+
+    fn get_account_swap_queue(
+        &self,
+        account_id: AccountId20,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version(at, 2)?;
+        api.get_account_swap_queue(at, account_id).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get account swap queue: {:?}", e)).into()
+        })
+    }
+
+    fn get_subnet_info_v2(
+        &self,
+        subnet_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<SubnetInfo<AccountId20>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version(at, 2)?;
+        api.get_subnet_info_v2(at, subnet_id)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get subnet info: {:?}", e)).into())
+    }
+
+    fn get_all_subnets_info_v2(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<SubnetInfo<AccountId20>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version(at, 2)?;
+        api.get_all_subnets_info_v2(at).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get all subnets info: {:?}", e)).into()
+        })
+    }
+
+    fn get_subnet_node_info_v2(
+        &self,
+        subnet_id: u32,
+        subnet_node_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<SubnetNodeInfo<AccountId20>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version(at, 2)?;
+        api.get_subnet_node_info_v2(at, subnet_id, subnet_node_id)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get subnet node info: {:?}", e)).into()
+            })
+    }
+
+    fn get_subnet_nodes_info_v2(
+        &self,
+        subnet_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<SubnetNodeInfo<AccountId20>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version(at, 2)?;
+        api.get_subnet_nodes_info_v2(at, subnet_id).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get subnet node info: {:?}", e)).into()
+        })
+    }
+
+    fn get_all_subnet_nodes_info_v2(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<SubnetNodeInfo<AccountId20>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version(at, 2)?;
+        api.get_all_subnet_nodes_info_v2(at).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get all subnet node info: {:?}", e)).into()
+        })
+    }
+
+    fn get_bootnodes_v2(
+        &self,
+        subnet_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<AllSubnetBootnodes> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version(at, 2)?;
+        api.get_bootnodes_v2(at, subnet_id)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get bootnodes: {:?}", e)).into())
+    }
+
+    fn get_coldkey_subnet_nodes_info_v2(
+        &self,
+        coldkey: AccountId20,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<SubnetNodeInfo<AccountId20>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version(at, 2)?;
+        api.get_coldkey_subnet_nodes_info_v2(at, coldkey)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get coldkey subnet nodes info: {:?}", e))
+                    .into()
+            })
+    }
+
+    fn get_coldkey_stakes_v2(
+        &self,
+        coldkey: AccountId20,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<SubnetNodeStakeInfo<AccountId20>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version(at, 2)?;
+        api.get_coldkey_stakes_v2(at, coldkey).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get coldkey stakes: {:?}", e)).into()
+        })
+    }
+
+    fn get_delegate_stakes_v2(
+        &self,
+        account_id: AccountId20,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<DelegateStakeInfo>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version(at, 2)?;
+        api.get_delegate_stakes_v2(at, account_id).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get account delegate stakes: {:?}", e)).into()
+        })
+    }
+
+    fn get_node_delegate_stakes_v2(
+        &self,
+        account_id: AccountId20,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<NodeDelegateStakeInfo>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version(at, 2)?;
+        api.get_node_delegate_stakes_v2(at, account_id)
+            .map_err(|e| {
+                Error::RuntimeError(format!(
+                    "Unable to get account node delegate stakes: {:?}",
+                    e
+                ))
+                .into()
+            })
+    }
+
+    fn get_overwatch_commits_for_epoch_and_node_v2(
+        &self,
+        epoch: u32,
+        overwatch_node_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(u32, H256)>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version(at, 2)?;
+        api.get_overwatch_commits_for_epoch_and_node_v2(at, epoch, overwatch_node_id)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get overwatch node commits: {:?}", e)).into()
+            })
+    }
+
+    fn get_overwatch_reveals_for_epoch_and_node_v2(
+        &self,
+        epoch: u32,
+        overwatch_node_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(u32, NumberOrHex)>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version(at, 2)?;
+        api.get_overwatch_reveals_for_epoch_and_node_v2(at, epoch, overwatch_node_id)
+            .map(|reveals| {
+                reveals
+                    .into_iter()
+                    .map(|(subnet_id, weight)| (subnet_id, weight.into()))
+                    .collect()
+            })
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get overwatch node reveals: {:?}", e)).into()
+            })
+    }
+
+    fn get_elected_validator_info_v2(
+        &self,
+        subnet_id: u32,
+        subnet_epoch: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<SubnetNodeInfo<AccountId20>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version(at, 2)?;
+        api.get_elected_validator_info_v2(at, subnet_id, subnet_epoch)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get elected validator info: {:?}", e)).into()
+            })
+    }
+
+    fn get_validators_and_attestors_v2(
+        &self,
+        subnet_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<SubnetNodeInfo<AccountId20>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version(at, 2)?;
+        api.get_validators_and_attestors_v2(at, subnet_id)
+            .map_err(|e| {
+                Error::RuntimeError(format!(
+                    "Unable to get validators and attestor node info: {:?}",
+                    e
+                ))
+                .into()
+            })
+    }
+
+    fn get_account_swap_queue_v2(
+        &self,
+        account_id: AccountId20,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<QueuedSwapInfo<AccountId20>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version(at, 2)?;
+        api.get_account_swap_queue_v2(at, account_id).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get account swap queue: {:?}", e)).into()
+        })
+    }
+
+    fn get_consensus_submission(
+        &self,
+        subnet_id: u32,
+        subnet_epoch: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<ConsensusSubmissionInfo<AccountId20>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version(at, 2)?;
+        api.get_consensus_submission(at, subnet_id, subnet_epoch)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get consensus submission: {:?}", e)).into()
+            })
+    }
+
+    fn get_consensus_history(
+        &self,
+        subnet_id: u32,
+        from: u32,
+        to: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<ConsensusSubmissionInfo<AccountId20>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version(at, 2)?;
+        api.get_consensus_history(at, subnet_id, from, to)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get consensus history: {:?}", e)).into()
+            })
+    }
+
+    fn get_all_subnets_info_paged(
+        &self,
+        start: u32,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<RpcPage<SubnetInfo<AccountId20>, u32>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version(at, 3)?;
+        api.get_all_subnets_info_paged(at, start, limit)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get all subnets info: {:?}", e)).into()
+            })
+    }
+
+    fn get_subnet_nodes_info_paged(
+        &self,
+        subnet_id: u32,
+        start: u32,
+        limit: u32,
+        filter: SubnetNodeFilter<AccountId20>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<RpcPage<SubnetNodeInfo<AccountId20>, u32>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version(at, 3)?;
+        api.get_subnet_nodes_info_paged(at, subnet_id, start, limit, filter)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get subnet node info: {:?}", e)).into()
+            })
+    }
+
+    fn get_all_subnet_nodes_info_paged(
+        &self,
+        start: (u32, u32),
+        limit: u32,
+        filter: SubnetNodeFilter<AccountId20>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<RpcPage<SubnetNodeInfo<AccountId20>, (u32, u32)>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version(at, 3)?;
+        api.get_all_subnet_nodes_info_paged(at, start, limit, filter)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get all subnet node info: {:?}", e)).into()
+            })
+    }
+
+    fn estimate_subnet_epoch_rewards(
+        &self,
+        subnet_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<RewardsData>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version(at, 4)?;
+        api.estimate_subnet_epoch_rewards(at, subnet_id)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to estimate subnet rewards: {:?}", e)).into()
+            })
+    }
+
+    fn estimate_delegate_apr(
+        &self,
+        subnet_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<NumberOrHex> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version(at, 4)?;
+        api.estimate_delegate_apr(at, subnet_id)
+            .map(Into::into)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to estimate delegate APR: {:?}", e)).into()
+            })
+    }
+
+    fn estimate_node_delegate_apr(
+        &self,
+        subnet_id: u32,
+        subnet_node_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<NumberOrHex> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version(at, 4)?;
+        api.estimate_node_delegate_apr(at, subnet_id, subnet_node_id)
+            .map(Into::into)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to estimate node delegate APR: {:?}", e)).into()
+            })
+    }
+
+    fn get_account_rewards(
+        &self,
+        account_id: AccountId20,
+        from_epoch: u32,
+        to_epoch: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<AccountRewardsInfo>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.ensure_api_version(at, 5)?;
+        api.get_account_rewards(at, account_id, from_epoch, to_epoch)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get account rewards: {:?}", e)).into()
+            })
+    }
 }
@@ -19,9 +19,20 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 use fp_account::AccountId20;
+use pallet_network::{
+  AccountRewardsInfo, AllSubnetBootnodes, ConsensusSubmissionInfo, DelegateStakeInfo,
+  NodeDelegateStakeInfo, QueuedSwapInfo, RewardsData, RpcPage, SubnetInfo, SubnetNodeFilter,
+  SubnetNodeInfo, SubnetNodeStakeInfo,
+};
+use sp_core::H256;
 use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
+  /// Version 1 methods return SCALE encoded bytes and are kept for backwards compatibility,
+  /// version 2 methods return the decoded types and add the swap queue and consensus archive
+  /// queries, version 3 adds paginated queries, version 4 adds reward estimates and version 5
+  /// adds account reward history
+  #[api_version(5)]
   pub trait NetworkRuntimeApi {
     fn get_subnet_info(subnet_id: u32) -> Vec<u8>;
     fn get_all_subnets_info() -> Vec<u8>;
@@ -38,5 +49,55 @@ sp_api::decl_runtime_apis! {
     fn get_overwatch_reveals_for_epoch_and_node(epoch: u32,overwatch_node_id: u32) -> Vec<u8>;
     fn get_elected_validator_info(subnet_id: u32,subnet_epoch: u32) -> Vec<u8>;
     fn get_validators_and_attestors(subnet_id: u32) -> Vec<u8>;
+    #[api_version(2)]
+    fn get_subnet_info_v2(subnet_id: u32) -> Option<SubnetInfo<AccountId20>>;
+    #[api_version(2)]
+    fn get_all_subnets_info_v2() -> Vec<SubnetInfo<AccountId20>>;
+    #[api_version(2)]
+    fn get_subnet_node_info_v2(subnet_id: u32, subnet_node_id: u32) -> Option<SubnetNodeInfo<AccountId20>>;
+    #[api_version(2)]
+    fn get_subnet_nodes_info_v2(subnet_id: u32) -> Vec<SubnetNodeInfo<AccountId20>>;
+    #[api_version(2)]
+    fn get_all_subnet_nodes_info_v2() -> Vec<SubnetNodeInfo<AccountId20>>;
+    #[api_version(2)]
+    fn get_bootnodes_v2(subnet_id: u32) -> AllSubnetBootnodes;
+    #[api_version(2)]
+    fn get_coldkey_subnet_nodes_info_v2(coldkey: AccountId20) -> Vec<SubnetNodeInfo<AccountId20>>;
+    #[api_version(2)]
+    fn get_coldkey_stakes_v2(coldkey: AccountId20) -> Vec<SubnetNodeStakeInfo<AccountId20>>;
+    #[api_version(2)]
+    fn get_delegate_stakes_v2(account_id: AccountId20) -> Vec<DelegateStakeInfo>;
+    #[api_version(2)]
+    fn get_node_delegate_stakes_v2(account_id: AccountId20) -> Vec<NodeDelegateStakeInfo>;
+    #[api_version(2)]
+    fn get_overwatch_commits_for_epoch_and_node_v2(epoch: u32, overwatch_node_id: u32) -> Vec<(u32, H256)>;
+    #[api_version(2)]
+    fn get_overwatch_reveals_for_epoch_and_node_v2(epoch: u32, overwatch_node_id: u32) -> Vec<(u32, u128)>;
+    #[api_version(2)]
+    fn get_elected_validator_info_v2(subnet_id: u32, subnet_epoch: u32) -> Option<SubnetNodeInfo<AccountId20>>;
+    #[api_version(2)]
+    fn get_validators_and_attestors_v2(subnet_id: u32) -> Vec<SubnetNodeInfo<AccountId20>>;
+    #[api_version(2)]
+    fn get_account_swap_queue(account_id: AccountId20) -> Vec<u8>;
+    #[api_version(2)]
+    fn get_account_swap_queue_v2(account_id: AccountId20) -> Vec<QueuedSwapInfo<AccountId20>>;
+    #[api_version(2)]
+    fn get_consensus_submission(subnet_id: u32, subnet_epoch: u32) -> Option<ConsensusSubmissionInfo<AccountId20>>;
+    #[api_version(2)]
+    fn get_consensus_history(subnet_id: u32, from: u32, to: u32) -> Vec<ConsensusSubmissionInfo<AccountId20>>;
+    #[api_version(3)]
+    fn get_all_subnets_info_paged(start: u32, limit: u32) -> RpcPage<SubnetInfo<AccountId20>, u32>;
+    #[api_version(3)]
+    fn get_subnet_nodes_info_paged(subnet_id: u32, start: u32, limit: u32, filter: SubnetNodeFilter<AccountId20>) -> RpcPage<SubnetNodeInfo<AccountId20>, u32>;
+    #[api_version(3)]
+    fn get_all_subnet_nodes_info_paged(start: (u32, u32), limit: u32, filter: SubnetNodeFilter<AccountId20>) -> RpcPage<SubnetNodeInfo<AccountId20>, (u32, u32)>;
+    #[api_version(4)]
+    fn estimate_subnet_epoch_rewards(subnet_id: u32) -> Option<RewardsData>;
+    #[api_version(4)]
+    fn estimate_delegate_apr(subnet_id: u32) -> u128;
+    #[api_version(4)]
+    fn estimate_node_delegate_apr(subnet_id: u32, subnet_node_id: u32) -> u128;
+    #[api_version(5)]
+    fn get_account_rewards(account_id: AccountId20, from_epoch: u32, to_epoch: u32) -> Vec<AccountRewardsInfo>;
   }
 }
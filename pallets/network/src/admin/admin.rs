@@ -644,6 +644,13 @@ impl<T: Config> Pallet<T> {
 
         Ok(())
     }
+    pub fn do_set_max_pending_swaps_per_account(value: u32) -> DispatchResult {
+        MaxPendingSwapsPerAccount::<T>::put(value);
+
+        Self::deposit_event(Event::SetMaxPendingSwapsPerAccount(value));
+
+        Ok(())
+    }
     pub fn do_set_max_subnet_node_min_weight_decrease_reputation_threshold(
         value: u128,
     ) -> DispatchResult {
@@ -682,6 +689,56 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    pub fn do_set_backup_proposer_epoch_progression(value: u128) -> DispatchResult {
+        ensure!(
+            value <= Self::percentage_factor_as_u128(),
+            Error::<T>::InvalidPercent
+        );
+
+        BackupProposerEpochProgression::<T>::put(value);
+
+        Self::deposit_event(Event::SetBackupProposerEpochProgression(value));
+
+        Ok(())
+    }
+
+    pub fn do_set_backup_validator_reward_factor(value: u128) -> DispatchResult {
+        ensure!(
+            value <= Self::percentage_factor_as_u128(),
+            Error::<T>::InvalidPercent
+        );
+
+        BackupValidatorRewardFactor::<T>::put(value);
+
+        Self::deposit_event(Event::SetBackupValidatorRewardFactor(value));
+
+        Ok(())
+    }
+
+    pub fn do_set_consensus_archive_depth(value: u32) -> DispatchResult {
+        // The previous epochs submission is read when distributing rewards
+        ensure!(value >= 2, Error::<T>::InvalidConsensusArchiveDepth);
+
+        ConsensusArchiveDepth::<T>::put(value);
+
+        Self::deposit_event(Event::SetConsensusArchiveDepth(value));
+
+        Ok(())
+    }
+
+    pub fn do_set_reward_history_depth(value: u32) -> DispatchResult {
+        // Pruning starts from the epoch the history is first enabled in
+        if value != 0 && !RewardHistoryPruneEpoch::<T>::exists() {
+            RewardHistoryPruneEpoch::<T>::put(Self::get_current_epoch_as_u32());
+        }
+
+        RewardHistoryDepth::<T>::put(value);
+
+        Self::deposit_event(Event::SetRewardHistoryDepth(value));
+
+        Ok(())
+    }
+
     pub fn do_set_attestor_reward_exponent(value: u64) -> DispatchResult {
         ensure!(value > 0, Error::<T>::InvalidAttestorRewardExponent);
 
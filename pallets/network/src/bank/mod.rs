@@ -1,2 +1,3 @@
 use super::*;
+pub mod reward_history;
 pub mod rewards;
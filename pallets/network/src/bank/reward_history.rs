@@ -0,0 +1,223 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::pallet_prelude::Weight;
+
+impl<T: Config> Pallet<T> {
+    /// Max epochs of reward history queued for cleanup per epoch
+    pub const MAX_REWARD_HISTORY_PRUNE_EPOCHS: u32 = 8;
+
+    pub fn is_reward_history_enabled() -> bool {
+        RewardHistoryDepth::<T>::get() != 0
+    }
+
+    /// Add to the rewards of an account in `epoch`
+    pub fn record_account_rewards(
+        epoch: u32,
+        account_id: &T::AccountId,
+        f: impl FnOnce(&mut AccountEpochRewards),
+    ) -> Weight {
+        AccountRewardHistory::<T>::mutate(epoch, account_id, f);
+
+        // AccountRewardHistory
+        T::DbWeight::get().reads_writes(1, 1)
+    }
+
+    /// Record the rewards added to a subnet delegate stake pool in `epoch`
+    pub fn record_subnet_delegate_rewards(epoch: u32, subnet_id: u32, rewards: u128) -> Weight {
+        SubnetDelegateRewardHistory::<T>::insert(
+            epoch,
+            subnet_id,
+            DelegatePoolEpochRewards {
+                rewards,
+                total_shares: TotalSubnetDelegateStakeShares::<T>::get(subnet_id),
+            },
+        );
+
+        // Reads:
+        // - TotalSubnetDelegateStakeShares
+        // Writes:
+        // - SubnetDelegateRewardHistory
+        T::DbWeight::get().reads_writes(1, 1)
+    }
+
+    /// Record the rewards added to a node delegate stake pool in `epoch`
+    pub fn record_node_delegate_rewards(
+        epoch: u32,
+        subnet_id: u32,
+        subnet_node_id: u32,
+        rewards: u128,
+    ) -> Weight {
+        NodeDelegateRewardHistory::<T>::insert(
+            epoch,
+            (subnet_id, subnet_node_id),
+            DelegatePoolEpochRewards {
+                rewards,
+                total_shares: TotalNodeDelegateStakeShares::<T>::get(subnet_id, subnet_node_id),
+            },
+        );
+
+        // Reads:
+        // - TotalNodeDelegateStakeShares
+        // Writes:
+        // - NodeDelegateRewardHistory
+        T::DbWeight::get().reads_writes(1, 1)
+    }
+
+    /// Record the shares an account holds in a subnet delegate stake pool before they change
+    ///
+    /// Only the first change counting from an epoch is recorded. Changes after the pools rewards
+    /// of the current epoch were recorded count from the next epoch, the rewards used the shares
+    /// held before the change.
+    pub fn checkpoint_account_subnet_delegate_shares(
+        account_id: &T::AccountId,
+        subnet_id: u32,
+    ) -> Weight {
+        let db_weight = T::DbWeight::get();
+
+        // RewardHistoryDepth
+        if !Self::is_reward_history_enabled() {
+            return db_weight.reads(1);
+        }
+
+        let mut epoch = Self::get_current_epoch_as_u32();
+        if SubnetDelegateRewardHistory::<T>::contains_key(epoch, subnet_id) {
+            epoch = epoch.saturating_add(1);
+        }
+
+        if AccountSubnetDelegateSharesHistory::<T>::contains_key((epoch, account_id, subnet_id)) {
+            // Reads:
+            // - RewardHistoryDepth
+            // - SubnetDelegateRewardHistory
+            // - AccountSubnetDelegateSharesHistory
+            return db_weight.reads(3);
+        }
+
+        AccountSubnetDelegateSharesHistory::<T>::insert(
+            (epoch, account_id, subnet_id),
+            AccountSubnetDelegateStakeShares::<T>::get(account_id, subnet_id),
+        );
+
+        // Reads:
+        // - RewardHistoryDepth
+        // - SubnetDelegateRewardHistory
+        // - AccountSubnetDelegateSharesHistory
+        // - AccountSubnetDelegateStakeShares
+        // Writes:
+        // - AccountSubnetDelegateSharesHistory
+        db_weight.reads_writes(4, 1)
+    }
+
+    /// Record the shares an account holds in a node delegate stake pool before they change, see
+    /// `checkpoint_account_subnet_delegate_shares`
+    pub fn checkpoint_account_node_delegate_shares(
+        account_id: &T::AccountId,
+        subnet_id: u32,
+        subnet_node_id: u32,
+    ) -> Weight {
+        let db_weight = T::DbWeight::get();
+
+        // RewardHistoryDepth
+        if !Self::is_reward_history_enabled() {
+            return db_weight.reads(1);
+        }
+
+        let subnet_node = (subnet_id, subnet_node_id);
+        let mut epoch = Self::get_current_epoch_as_u32();
+        if NodeDelegateRewardHistory::<T>::contains_key(epoch, subnet_node) {
+            epoch = epoch.saturating_add(1);
+        }
+
+        if AccountNodeDelegateSharesHistory::<T>::contains_key((epoch, account_id, subnet_node)) {
+            // Reads:
+            // - RewardHistoryDepth
+            // - NodeDelegateRewardHistory
+            // - AccountNodeDelegateSharesHistory
+            return db_weight.reads(3);
+        }
+
+        AccountNodeDelegateSharesHistory::<T>::insert(
+            (epoch, account_id, subnet_node),
+            AccountNodeDelegateStakeShares::<T>::get((account_id, subnet_id, subnet_node_id)),
+        );
+
+        // Reads:
+        // - RewardHistoryDepth
+        // - NodeDelegateRewardHistory
+        // - AccountNodeDelegateSharesHistory
+        // - AccountNodeDelegateStakeShares
+        // Writes:
+        // - AccountNodeDelegateSharesHistory
+        db_weight.reads_writes(4, 1)
+    }
+
+    /// Queue the reward history older than `RewardHistoryDepth` epochs for cleanup
+    ///
+    /// At most `MAX_REWARD_HISTORY_PRUNE_EPOCHS` epochs are queued per call so lowering the depth
+    /// is spread over multiple epochs. Once disabled, all history is pruned and pruning stops.
+    /// Share checkpoints can count from the next epoch, so disabling prunes up to it.
+    pub fn enqueue_reward_history_cleanup(current_epoch: u32) -> Weight {
+        let db_weight = T::DbWeight::get();
+
+        // RewardHistoryPruneEpoch
+        let mut weight = db_weight.reads(1);
+
+        let Some(prune_epoch) = RewardHistoryPruneEpoch::<T>::get() else {
+            return weight;
+        };
+
+        // RewardHistoryDepth
+        weight = weight.saturating_add(db_weight.reads(1));
+        let depth = RewardHistoryDepth::<T>::get();
+
+        let cutoff = if depth == 0 {
+            current_epoch.saturating_add(2)
+        } else {
+            current_epoch.saturating_sub(depth)
+        };
+        let end = cutoff.min(prune_epoch.saturating_add(Self::MAX_REWARD_HISTORY_PRUNE_EPOCHS));
+
+        let mut epoch = prune_epoch;
+        while epoch < end {
+            weight = weight.saturating_add(Self::enqueue_cleanup_task(
+                CleanupTask::AccountRewardHistory(epoch),
+            ));
+            weight = weight.saturating_add(Self::enqueue_cleanup_task(
+                CleanupTask::SubnetDelegateRewardHistory(epoch),
+            ));
+            weight = weight.saturating_add(Self::enqueue_cleanup_task(
+                CleanupTask::NodeDelegateRewardHistory(epoch),
+            ));
+            weight = weight.saturating_add(Self::enqueue_cleanup_task(
+                CleanupTask::AccountSubnetDelegateSharesHistory(epoch),
+            ));
+            weight = weight.saturating_add(Self::enqueue_cleanup_task(
+                CleanupTask::AccountNodeDelegateSharesHistory(epoch),
+            ));
+            epoch += 1;
+        }
+
+        if depth == 0 && epoch >= cutoff {
+            RewardHistoryPruneEpoch::<T>::kill();
+            weight = weight.saturating_add(db_weight.writes(1));
+        } else if epoch != prune_epoch {
+            RewardHistoryPruneEpoch::<T>::put(epoch);
+            weight = weight.saturating_add(db_weight.writes(1));
+        }
+
+        weight
+    }
+}
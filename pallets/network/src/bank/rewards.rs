@@ -33,6 +33,9 @@ impl<T: Config> Pallet<T> {
         let db_weight = T::DbWeight::get();
 
         let percentage_factor = Self::percentage_factor_as_u128();
+        let reward_history = Self::is_reward_history_enabled();
+        // RewardHistoryDepth
+        weight_meter.consume(db_weight.reads(1));
         let min_validator_reputation = MinSubnetNodeReputation::<T>::get(subnet_id);
         let subnet_reputation = SubnetReputation::<T>::get(subnet_id);
         // MinSubnetNodeReputation | SubnetReputation
@@ -180,6 +183,18 @@ impl<T: Config> Pallet<T> {
             weight_meter.consume(db_weight.reads(1));
 
             Self::increase_account_stake(&hotkey, subnet_id, validator_reward);
+
+            if reward_history && validator_reward != 0 {
+                weight_meter.consume(Self::record_account_rewards(
+                    current_epoch,
+                    &hotkey,
+                    |rewards| {
+                        rewards
+                            .node_stake_rewards
+                            .saturating_accrue(validator_reward)
+                    },
+                ));
+            }
         } else {
             // Validator left subnet
 
@@ -310,6 +325,18 @@ impl<T: Config> Pallet<T> {
             if let Some(balance) = Self::u128_to_balance(rewards_data.subnet_owner_reward) {
                 Self::add_balance_to_coldkey_account(&owner, balance);
                 weight_meter.consume(T::WeightInfo::add_balance_to_coldkey_account());
+
+                if reward_history && rewards_data.subnet_owner_reward != 0 {
+                    weight_meter.consume(Self::record_account_rewards(
+                        current_epoch,
+                        &owner,
+                        |rewards| {
+                            rewards
+                                .subnet_owner_rewards
+                                .saturating_accrue(rewards_data.subnet_owner_reward)
+                        },
+                    ));
+                }
             }
         }
 
@@ -625,6 +652,15 @@ impl<T: Config> Pallet<T> {
                     // TotalNodeDelegateStakeShares | TotalNodeDelegateStakeBalance | TotalNodeDelegateStake
                     weight_meter.consume(db_weight.reads_writes(5, 3));
 
+                    if reward_history {
+                        weight_meter.consume(Self::record_node_delegate_rewards(
+                            current_epoch,
+                            subnet_id,
+                            subnet_node.id,
+                            node_delegate_reward,
+                        ));
+                    }
+
                     node_delegate_stake_rewards.push((subnet_node.id, node_delegate_reward));
                 }
             }
@@ -634,6 +670,14 @@ impl<T: Config> Pallet<T> {
             // AccountSubnetStake | TotalSubnetStake | TotalStake
             weight_meter.consume(db_weight.reads_writes(3, 3));
 
+            if reward_history {
+                weight_meter.consume(Self::record_account_rewards(
+                    current_epoch,
+                    &subnet_node.hotkey,
+                    |rewards| rewards.node_stake_rewards.saturating_accrue(account_reward),
+                ));
+            }
+
             node_rewards.push((subnet_node.id, account_reward));
         }
 
@@ -647,6 +691,14 @@ impl<T: Config> Pallet<T> {
             // TotalSubnetDelegateStakeBalance | | TotalSubnetDelegateStakeShares|
             // TotalSubnetDelegateStakeShares| TotalSubnetDelegateStakeBalance| TotalDelegateStake
             weight_meter.consume(db_weight.reads_writes(3, 5));
+
+            if reward_history {
+                weight_meter.consume(Self::record_subnet_delegate_rewards(
+                    current_epoch,
+                    subnet_id,
+                    rewards_data.delegate_stake_rewards,
+                ));
+            }
         }
 
         Self::deposit_event(Event::SubnetRewards {
@@ -24,7 +24,7 @@ use frame_benchmarking::v2::*;
 use frame_support::{
     assert_noop, assert_ok,
     pallet_prelude::{DispatchError, Zero},
-    traits::{EnsureOrigin, Get, OnInitialize, UnfilteredDispatchable},
+    traits::{EnsureOrigin, Get, OnInitialize, ReservableCurrency, UnfilteredDispatchable},
     weights::WeightMeter,
     Callable,
 };
@@ -55,9 +55,11 @@ const ALICE_EXPECTED_BALANCE: u128 = 1000000000000000000000000; // 1,000,000
 pub type BalanceOf<T> = <T as Config>::Currency;
 type TreasuryPallet<T> = pallet_treasury::Pallet<T, ()>;
 
-fn peer(id: u32) -> PeerId {
-    let peer_id = format!("QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx5N{id}");
-    PeerId(peer_id.into())
+fn peer<T: Config>(id: u32) -> PeerId {
+    // SHA-256 multihash peer ID, accepted by the `KeyType::Rsa` subnets registered here
+    let mut multihash = vec![0x12, 0x20];
+    multihash.extend_from_slice(&sp_core::blake2_256(&id.to_le_bytes()));
+    PeerId(Network::<T>::encode_base58(&multihash))
 }
 
 fn get_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
@@ -138,7 +140,7 @@ pub fn get_peer_id<T: Config>(
     max_subnets: u32,
     n: u32,
 ) -> PeerId {
-    peer(max_subnets * max_subnet_nodes + (subnets * max_subnet_nodes) + n)
+    peer::<T>(max_subnets * max_subnet_nodes + (subnets * max_subnet_nodes) + n)
 }
 
 pub fn get_bootnode_peer_id<T: Config>(
@@ -147,7 +149,7 @@ pub fn get_bootnode_peer_id<T: Config>(
     max_subnets: u32,
     n: u32,
 ) -> PeerId {
-    peer(
+    peer::<T>(
         (max_subnets * max_subnet_nodes * 2)
             + (max_subnets * max_subnet_nodes + (subnets * max_subnet_nodes) + n),
     )
@@ -159,7 +161,7 @@ pub fn get_client_peer_id<T: Config>(
     max_subnets: u32,
     n: u32,
 ) -> PeerId {
-    peer(
+    peer::<T>(
         (max_subnets * max_subnet_nodes * 3)
             + (max_subnets * max_subnet_nodes + (subnets * max_subnet_nodes) + n),
     )
@@ -217,6 +219,13 @@ pub fn increase_overwatch_epochs<T: Config>(n: u32) {
     frame_system::Pallet::<T>::set_block_number(new_block.into());
 }
 
+/// Enables reward history so delegate stake share changes are checkpointed
+///
+/// Call after setup, setup share changes would otherwise already be checkpointed this epoch.
+pub fn enable_reward_history<T: Config>() {
+    assert_ok!(Network::<T>::do_set_reward_history_depth(100));
+}
+
 pub fn set_overwatch_epoch<T: Config>(n: u32) {
     let multiplier = OverwatchEpochLengthMultiplier::<T>::get();
     frame_system::Pallet::<T>::set_block_number((n * multiplier * T::EpochLength::get()).into());
@@ -831,9 +840,9 @@ pub fn insert_subnet_node<T: Config>(
         SubnetNode {
             id: node_id,
             hotkey: get_account::<T>("subnet_node", hotkey_n),
-            peer_id: peer(peer_n),
-            bootnode_peer_id: peer(peer_n),
-            client_peer_id: peer(peer_n),
+            peer_id: peer::<T>(peer_n),
+            bootnode_peer_id: peer::<T>(peer_n),
+            client_peer_id: peer::<T>(peer_n),
             bootnode: None,
             delegate_reward_rate: 0,
             last_delegate_reward_rate_update: 0,
@@ -1055,6 +1064,32 @@ pub fn u128_to_balance<T: frame_system::Config + pallet::Config>(
     input.try_into().ok()
 }
 
+/// Store a proof of `hotkey` owning `peer_id`, reserving its deposit from `depositor`
+pub fn insert_peer_id_proof<T: Config>(
+    subnet_id: u32,
+    peer_id: &PeerId,
+    hotkey: &T::AccountId,
+    depositor: &T::AccountId,
+    block: u32,
+) {
+    let deposit = T::PeerIdProofDeposit::get();
+    assert_ok!(T::Currency::reserve(
+        depositor,
+        u128_to_balance::<T>(deposit).unwrap()
+    ));
+    PeerIdProofs::<T>::insert(
+        subnet_id,
+        peer_id,
+        PeerIdProof {
+            hotkey: hotkey.clone(),
+            block,
+            depositor: depositor.clone(),
+            deposit,
+        },
+    );
+    PeerIdProofsBySignedBlock::<T>::insert(block, (subnet_id, peer_id.clone()), ());
+}
+
 pub fn get_initial_coldkeys<T: Config>(
     subnets: u32,
     max_subnet_nodes: u32,
@@ -2005,7 +2040,7 @@ mod benchmarks {
     }
 
     #[benchmark]
-    fn owner_update_min_max_stake() {
+    fn owner_update_peer_id_proof_required() {
         let max_subnet_nodes = MaxSubnetNodes::<T>::get();
         build_activated_subnet::<T>(
             DEFAULT_SUBNET_NAME.into(),
@@ -2016,36 +2051,23 @@ mod benchmarks {
         );
         let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
 
-        let min_nodes = MinSubnetNodes::<T>::get();
         let max_subnets = MaxSubnets::<T>::get();
-        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
 
         let owner_coldkey =
             funded_initializer::<T>("subnet_owner", subnet_id * max_subnets * max_subnet_nodes);
 
-        let min = SubnetMinStakeBalance::<T>::get(subnet_id);
-        let new_min = min + 1;
-
-        let max = SubnetMaxStakeBalance::<T>::get(subnet_id);
-        let new_max = max - 1;
-
         #[extrinsic_call]
-        owner_update_min_max_stake(
+        owner_update_peer_id_proof_required(
             RawOrigin::Signed(owner_coldkey.clone()),
             subnet_id,
-            new_min,
-            new_max,
+            true,
         );
 
-        let value = SubnetMinStakeBalance::<T>::get(subnet_id);
-        assert_eq!(value, new_min);
-
-        let value = SubnetMaxStakeBalance::<T>::get(subnet_id);
-        assert_eq!(value, new_max);
+        assert!(SubnetPeerIdProofRequired::<T>::get(subnet_id));
     }
 
     #[benchmark]
-    fn owner_update_delegate_stake_percentage() {
+    fn owner_update_absent_decrease_reputation_factor() {
         let max_subnet_nodes = MaxSubnetNodes::<T>::get();
         build_activated_subnet::<T>(
             DEFAULT_SUBNET_NAME.into(),
@@ -2056,43 +2078,28 @@ mod benchmarks {
         );
         let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
 
-        let min_nodes = MinSubnetNodes::<T>::get();
         let max_subnets = MaxSubnets::<T>::get();
-        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
 
         let owner_coldkey =
             funded_initializer::<T>("subnet_owner", subnet_id * max_subnets * max_subnet_nodes);
 
-        let current_value = SubnetDelegateStakeRewardsPercentage::<T>::get(subnet_id);
-
-        let new_value = current_value + 1;
-
-        let block_number = get_current_block_as_u32::<T>();
-
-        let last_update = LastSubnetDelegateStakeRewardsUpdate::<T>::get(subnet_id);
-        let update_period = SubnetDelegateStakeRewardsUpdatePeriod::<T>::get();
-
-        let update_to_block = if block_number - last_update < update_period {
-            last_update + update_period
-        } else {
-            block_number
-        };
-
-        frame_system::Pallet::<T>::set_block_number(u32_to_block::<T>(update_to_block + 1));
+        let new_value = MinNodeReputationFactor::<T>::get();
 
         #[extrinsic_call]
-        owner_update_delegate_stake_percentage(
+        owner_update_absent_decrease_reputation_factor(
             RawOrigin::Signed(owner_coldkey.clone()),
             subnet_id,
             new_value,
         );
 
-        let value = SubnetDelegateStakeRewardsPercentage::<T>::get(subnet_id);
-        assert_eq!(value, new_value);
+        assert_eq!(
+            AbsentDecreaseReputationFactor::<T>::get(subnet_id),
+            new_value
+        );
     }
 
     #[benchmark]
-    fn owner_update_max_registered_nodes() {
+    fn owner_update_below_min_weight_decrease_reputation_factor() {
         let max_subnet_nodes = MaxSubnetNodes::<T>::get();
         build_activated_subnet::<T>(
             DEFAULT_SUBNET_NAME.into(),
@@ -2103,30 +2110,28 @@ mod benchmarks {
         );
         let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
 
-        let min_nodes = MinSubnetNodes::<T>::get();
         let max_subnets = MaxSubnets::<T>::get();
-        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
 
         let owner_coldkey =
             funded_initializer::<T>("subnet_owner", subnet_id * max_subnets * max_subnet_nodes);
 
-        let current_value = MaxRegisteredNodes::<T>::get(subnet_id);
-
-        let new_value = current_value - 1;
+        let new_value = MinNodeReputationFactor::<T>::get();
 
         #[extrinsic_call]
-        owner_update_max_registered_nodes(
+        owner_update_below_min_weight_decrease_reputation_factor(
             RawOrigin::Signed(owner_coldkey.clone()),
             subnet_id,
             new_value,
         );
 
-        let value = MaxRegisteredNodes::<T>::get(subnet_id);
-        assert_eq!(value, new_value);
+        assert_eq!(
+            BelowMinWeightDecreaseReputationFactor::<T>::get(subnet_id),
+            new_value
+        );
     }
 
     #[benchmark]
-    fn transfer_subnet_ownership() {
+    fn owner_update_included_increase_reputation_factor() {
         let max_subnet_nodes = MaxSubnetNodes::<T>::get();
         build_activated_subnet::<T>(
             DEFAULT_SUBNET_NAME.into(),
@@ -2137,30 +2142,28 @@ mod benchmarks {
         );
         let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
 
-        let min_nodes = MinSubnetNodes::<T>::get();
         let max_subnets = MaxSubnets::<T>::get();
-        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
 
         let owner_coldkey =
             funded_initializer::<T>("subnet_owner", subnet_id * max_subnets * max_subnet_nodes);
 
-        let current_value = MaxRegisteredNodes::<T>::get(subnet_id);
-
-        let new_owner = funded_initializer::<T>("new_subnet_owner", 0);
+        let new_value = MinNodeReputationFactor::<T>::get();
 
         #[extrinsic_call]
-        transfer_subnet_ownership(
+        owner_update_included_increase_reputation_factor(
             RawOrigin::Signed(owner_coldkey.clone()),
             subnet_id,
-            new_owner.clone(),
+            new_value,
         );
 
-        let pending_owner = PendingSubnetOwner::<T>::get(subnet_id).unwrap();
-        assert_eq!(new_owner.clone(), pending_owner.clone());
+        assert_eq!(
+            IncludedIncreaseReputationFactor::<T>::get(subnet_id),
+            new_value
+        );
     }
 
     #[benchmark]
-    fn accept_subnet_ownership() {
+    fn owner_update_non_attestor_decrease_reputation_factor() {
         let max_subnet_nodes = MaxSubnetNodes::<T>::get();
         build_activated_subnet::<T>(
             DEFAULT_SUBNET_NAME.into(),
@@ -2171,32 +2174,28 @@ mod benchmarks {
         );
         let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
 
-        let min_nodes = MinSubnetNodes::<T>::get();
         let max_subnets = MaxSubnets::<T>::get();
-        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
 
         let owner_coldkey =
             funded_initializer::<T>("subnet_owner", subnet_id * max_subnets * max_subnet_nodes);
 
-        let current_value = MaxRegisteredNodes::<T>::get(subnet_id);
-
-        let new_owner = funded_initializer::<T>("new_subnet_owner", 0);
-
-        assert_ok!(Network::<T>::transfer_subnet_ownership(
-            RawOrigin::Signed(owner_coldkey.clone()).into(),
-            subnet_id,
-            new_owner.clone(),
-        ));
+        let new_value = MinNodeReputationFactor::<T>::get();
 
         #[extrinsic_call]
-        accept_subnet_ownership(RawOrigin::Signed(new_owner.clone()), subnet_id);
+        owner_update_non_attestor_decrease_reputation_factor(
+            RawOrigin::Signed(owner_coldkey.clone()),
+            subnet_id,
+            new_value,
+        );
 
-        let owner = SubnetOwner::<T>::get(subnet_id).unwrap();
-        assert_eq!(new_owner.clone(), owner);
+        assert_eq!(
+            NonAttestorDecreaseReputationFactor::<T>::get(subnet_id),
+            new_value
+        );
     }
 
     #[benchmark]
-    fn owner_add_bootnode_access() {
+    fn owner_update_non_consensus_attestor_decrease_reputation_factor() {
         let max_subnet_nodes = MaxSubnetNodes::<T>::get();
         build_activated_subnet::<T>(
             DEFAULT_SUBNET_NAME.into(),
@@ -2207,33 +2206,28 @@ mod benchmarks {
         );
         let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
 
-        let min_nodes = MinSubnetNodes::<T>::get();
         let max_subnets = MaxSubnets::<T>::get();
-        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
 
         let owner_coldkey =
             funded_initializer::<T>("subnet_owner", subnet_id * max_subnets * max_subnet_nodes);
 
-        let new_access = funded_initializer::<T>("new", 0);
-
-        // sanity check
-        assert!(SubnetBootnodeAccess::<T>::get(subnet_id)
-            .get(&new_access.clone())
-            .is_none());
+        let new_value = MinNodeReputationFactor::<T>::get();
 
         #[extrinsic_call]
-        owner_add_bootnode_access(
+        owner_update_non_consensus_attestor_decrease_reputation_factor(
             RawOrigin::Signed(owner_coldkey.clone()),
             subnet_id,
-            new_access.clone(),
+            new_value,
         );
 
-        let new_access_set = SubnetBootnodeAccess::<T>::get(subnet_id);
-        assert!(new_access_set.get(&new_access.clone()).is_some());
+        assert_eq!(
+            NonConsensusAttestorDecreaseReputationFactor::<T>::get(subnet_id),
+            new_value
+        );
     }
 
     #[benchmark]
-    fn owner_remove_bootnode_access() {
+    fn owner_update_validator_absent_decrease_reputation_factor() {
         let max_subnet_nodes = MaxSubnetNodes::<T>::get();
         build_activated_subnet::<T>(
             DEFAULT_SUBNET_NAME.into(),
@@ -2244,39 +2238,28 @@ mod benchmarks {
         );
         let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
 
-        let min_nodes = MinSubnetNodes::<T>::get();
         let max_subnets = MaxSubnets::<T>::get();
-        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
 
         let owner_coldkey =
             funded_initializer::<T>("subnet_owner", subnet_id * max_subnets * max_subnet_nodes);
 
-        let new_access = funded_initializer::<T>("new", 0);
-
-        // sanity check
-        assert!(SubnetBootnodeAccess::<T>::get(subnet_id)
-            .get(&new_access.clone())
-            .is_none());
-
-        assert_ok!(Network::<T>::owner_add_bootnode_access(
-            RawOrigin::Signed(owner_coldkey.clone()).into(),
-            subnet_id,
-            new_access.clone()
-        ));
+        let new_value = MinNodeReputationFactor::<T>::get();
 
         #[extrinsic_call]
-        owner_remove_bootnode_access(
+        owner_update_validator_absent_decrease_reputation_factor(
             RawOrigin::Signed(owner_coldkey.clone()),
             subnet_id,
-            new_access.clone(),
+            new_value,
         );
 
-        let new_access_set = SubnetBootnodeAccess::<T>::get(subnet_id);
-        assert!(new_access_set.get(&new_access.clone()).into_iter().count() == 0);
+        assert_eq!(
+            ValidatorAbsentSubnetNodeReputationFactor::<T>::get(subnet_id),
+            new_value
+        );
     }
 
     #[benchmark]
-    fn owner_update_target_node_registrations_per_epoch() {
+    fn owner_update_validator_non_consensus_decrease_reputation_factor() {
         let max_subnet_nodes = MaxSubnetNodes::<T>::get();
         build_activated_subnet::<T>(
             DEFAULT_SUBNET_NAME.into(),
@@ -2287,30 +2270,28 @@ mod benchmarks {
         );
         let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
 
-        let min_nodes = MinSubnetNodes::<T>::get();
         let max_subnets = MaxSubnets::<T>::get();
-        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
 
         let owner_coldkey =
             funded_initializer::<T>("subnet_owner", subnet_id * max_subnets * max_subnet_nodes);
 
-        let new_value = TargetNodeRegistrationsPerEpoch::<T>::get(subnet_id) - 1;
+        let new_value = MinNodeReputationFactor::<T>::get();
 
         #[extrinsic_call]
-        owner_update_target_node_registrations_per_epoch(
+        owner_update_validator_non_consensus_decrease_reputation_factor(
             RawOrigin::Signed(owner_coldkey.clone()),
             subnet_id,
             new_value,
         );
 
         assert_eq!(
-            TargetNodeRegistrationsPerEpoch::<T>::get(subnet_id),
+            ValidatorNonConsensusSubnetNodeReputationFactor::<T>::get(subnet_id),
             new_value
         );
     }
 
     #[benchmark]
-    fn owner_update_node_burn_rate_alpha() {
+    fn owner_update_min_subnet_node_reputation() {
         let max_subnet_nodes = MaxSubnetNodes::<T>::get();
         build_activated_subnet::<T>(
             DEFAULT_SUBNET_NAME.into(),
@@ -2321,27 +2302,25 @@ mod benchmarks {
         );
         let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
 
-        let min_nodes = MinSubnetNodes::<T>::get();
         let max_subnets = MaxSubnets::<T>::get();
-        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
 
         let owner_coldkey =
             funded_initializer::<T>("subnet_owner", subnet_id * max_subnets * max_subnet_nodes);
 
-        let new_value = NodeBurnRateAlpha::<T>::get(subnet_id) - 1;
+        let new_value = MinMinSubnetNodeReputation::<T>::get();
 
         #[extrinsic_call]
-        owner_update_node_burn_rate_alpha(
+        owner_update_min_subnet_node_reputation(
             RawOrigin::Signed(owner_coldkey.clone()),
             subnet_id,
             new_value,
         );
 
-        assert_eq!(NodeBurnRateAlpha::<T>::get(subnet_id), new_value);
+        assert_eq!(MinSubnetNodeReputation::<T>::get(subnet_id), new_value);
     }
 
     #[benchmark]
-    fn owner_update_queue_immunity_epochs() {
+    fn owner_update_election_mode() {
         let max_subnet_nodes = MaxSubnetNodes::<T>::get();
         build_activated_subnet::<T>(
             DEFAULT_SUBNET_NAME.into(),
@@ -2352,64 +2331,69 @@ mod benchmarks {
         );
         let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
 
-        let min_nodes = MinSubnetNodes::<T>::get();
         let max_subnets = MaxSubnets::<T>::get();
-        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
 
         let owner_coldkey =
             funded_initializer::<T>("subnet_owner", subnet_id * max_subnets * max_subnet_nodes);
 
-        let new_value = QueueImmunityEpochs::<T>::get(subnet_id) - 1;
-
         #[extrinsic_call]
-        owner_update_queue_immunity_epochs(
+        owner_update_election_mode(
             RawOrigin::Signed(owner_coldkey.clone()),
             subnet_id,
-            new_value,
+            ElectionMode::StakeWeighted,
         );
 
-        assert_eq!(QueueImmunityEpochs::<T>::get(subnet_id), new_value);
+        assert_eq!(
+            SubnetElectionMode::<T>::get(subnet_id),
+            ElectionMode::StakeWeighted
+        );
     }
 
     #[benchmark]
-    fn owner_update_subnet_node_min_weight_decrease_reputation_threshold() {
-        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+    fn owner_set_emergency_validator_set(x: Linear<3, 64>) {
+        // x: emergency validators
+        SubnetPauseCooldownEpochs::<T>::set(0);
         build_activated_subnet::<T>(
             DEFAULT_SUBNET_NAME.into(),
             0,
-            max_subnet_nodes,
+            x,
             DEFAULT_DEPOSIT_AMOUNT,
             DEFAULT_SUBNET_NODE_STAKE,
         );
         let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
 
-        let min_nodes = MinSubnetNodes::<T>::get();
         let max_subnets = MaxSubnets::<T>::get();
         let max_subnet_nodes = MaxSubnetNodes::<T>::get();
 
         let owner_coldkey =
             funded_initializer::<T>("subnet_owner", subnet_id * max_subnets * max_subnet_nodes);
 
-        let new_value = 1;
+        assert_ok!(Network::<T>::owner_pause_subnet(
+            RawOrigin::Signed(owner_coldkey.clone()).into(),
+            subnet_id,
+        ));
+
+        let subnet_node_ids: Vec<u32> = (1..=x)
+            .map(|n| {
+                let hotkey = get_hotkey::<T>(subnet_id, max_subnet_nodes, max_subnets, n);
+                HotkeySubnetNodeId::<T>::get(subnet_id, hotkey).unwrap()
+            })
+            .collect();
 
         #[extrinsic_call]
-        owner_update_subnet_node_min_weight_decrease_reputation_threshold(
+        owner_set_emergency_validator_set(
             RawOrigin::Signed(owner_coldkey.clone()),
             subnet_id,
-            new_value,
+            subnet_node_ids.clone(),
         );
 
-        assert_eq!(
-            SubnetNodeMinWeightDecreaseReputationThreshold::<T>::get(subnet_id),
-            new_value
-        );
+        let data = EmergencySubnetNodeElectionData::<T>::get(subnet_id).unwrap();
+        assert_eq!(data.subnet_node_ids.len(), subnet_node_ids.len());
     }
 
     #[benchmark]
-    fn update_bootnodes() {
+    fn owner_revert_emergency_validator_set() {
         let max_subnet_nodes = MaxSubnetNodes::<T>::get();
-        let min_nodes = MinSubnetNodes::<T>::get();
-        let max_subnets = MaxSubnets::<T>::get();
         build_activated_subnet::<T>(
             DEFAULT_SUBNET_NAME.into(),
             0,
@@ -2419,68 +2403,649 @@ mod benchmarks {
         );
         let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
 
+        let max_subnets = MaxSubnets::<T>::get();
+
         let owner_coldkey =
             funded_initializer::<T>("subnet_owner", subnet_id * max_subnets * max_subnet_nodes);
 
-        let new_access = funded_initializer::<T>("new", 0);
-        SubnetBootnodeAccess::<T>::insert(subnet_id, BTreeSet::from([new_access.clone()]));
-        let bv = |b: u8| BoundedVec::<u8, DefaultMaxVectorLength>::try_from(vec![b]).unwrap();
-        let add_set = BTreeSet::from([bv(1), bv(2)]);
-
-        #[extrinsic_call]
-        update_bootnodes(
-            RawOrigin::Signed(new_access.clone()),
+        EmergencySubnetNodeElectionData::<T>::insert(
             subnet_id,
-            add_set.clone(),
-            BTreeSet::new(),
+            EmergencySubnetValidatorData {
+                subnet_node_ids: (1..=MinSubnetNodes::<T>::get()).collect(),
+                target_emergency_validators_epochs: 1,
+                total_epochs: 0,
+                max_emergency_validators_epoch: 0,
+            },
         );
 
-        // Verify bootnodes added
-        let stored = SubnetBootnodes::<T>::get(subnet_id);
-        assert!(stored.contains(&bv(1)));
-        assert!(stored.contains(&bv(2)));
+        #[extrinsic_call]
+        owner_revert_emergency_validator_set(RawOrigin::Signed(owner_coldkey.clone()), subnet_id);
+
+        assert!(!EmergencySubnetNodeElectionData::<T>::contains_key(
+            subnet_id
+        ));
     }
 
     #[benchmark]
-    fn remove_subnet_node() {
+    fn owner_update_min_max_stake() {
         let max_subnet_nodes = MaxSubnetNodes::<T>::get();
-        let min_nodes = MinSubnetNodes::<T>::get();
-        let max_subnets = MaxSubnets::<T>::get();
-        let end = 4;
         build_activated_subnet::<T>(
             DEFAULT_SUBNET_NAME.into(),
             0,
-            end,
+            max_subnet_nodes,
             DEFAULT_DEPOSIT_AMOUNT,
             DEFAULT_SUBNET_NODE_STAKE,
         );
         let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
 
-        let hotkey = get_hotkey::<T>(subnet_id, max_subnet_nodes, max_subnets, end);
+        let min_nodes = MinSubnetNodes::<T>::get();
+        let max_subnets = MaxSubnets::<T>::get();
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
 
-        let hotkey_subnet_node_id =
-            HotkeySubnetNodeId::<T>::get(subnet_id, hotkey.clone()).unwrap();
+        let owner_coldkey =
+            funded_initializer::<T>("subnet_owner", subnet_id * max_subnets * max_subnet_nodes);
+
+        let min = SubnetMinStakeBalance::<T>::get(subnet_id);
+        let new_min = min + 1;
+
+        let max = SubnetMaxStakeBalance::<T>::get(subnet_id);
+        let new_max = max - 1;
 
         #[extrinsic_call]
-        remove_subnet_node(
-            RawOrigin::Signed(hotkey.clone()),
+        owner_update_min_max_stake(
+            RawOrigin::Signed(owner_coldkey.clone()),
             subnet_id,
-            hotkey_subnet_node_id,
+            new_min,
+            new_max,
         );
 
-        assert_eq!(TotalSubnetNodes::<T>::get(subnet_id), end - 1);
+        let value = SubnetMinStakeBalance::<T>::get(subnet_id);
+        assert_eq!(value, new_min);
 
-        let subnet_node_id = HotkeySubnetNodeId::<T>::try_get(subnet_id, hotkey.clone());
-        assert_eq!(subnet_node_id, Err(()));
+        let value = SubnetMaxStakeBalance::<T>::get(subnet_id);
+        assert_eq!(value, new_max);
     }
 
     #[benchmark]
-    fn register_or_update_identity() {
-        let coldkey = funded_initializer::<T>("coldkey", 99);
-        let hotkey = funded_initializer::<T>("hotkey", 0);
+    fn owner_update_delegate_stake_percentage() {
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+        build_activated_subnet::<T>(
+            DEFAULT_SUBNET_NAME.into(),
+            0,
+            max_subnet_nodes,
+            DEFAULT_DEPOSIT_AMOUNT,
+            DEFAULT_SUBNET_NODE_STAKE,
+        );
+        let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
 
-        HotkeyOwner::<T>::insert(hotkey.clone(), coldkey.clone());
-        let name = to_bounded::<DefaultMaxVectorLength>("name");
+        let min_nodes = MinSubnetNodes::<T>::get();
+        let max_subnets = MaxSubnets::<T>::get();
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+
+        let owner_coldkey =
+            funded_initializer::<T>("subnet_owner", subnet_id * max_subnets * max_subnet_nodes);
+
+        let current_value = SubnetDelegateStakeRewardsPercentage::<T>::get(subnet_id);
+
+        let new_value = current_value + 1;
+
+        let block_number = get_current_block_as_u32::<T>();
+
+        let last_update = LastSubnetDelegateStakeRewardsUpdate::<T>::get(subnet_id);
+        let update_period = SubnetDelegateStakeRewardsUpdatePeriod::<T>::get();
+
+        let update_to_block = if block_number - last_update < update_period {
+            last_update + update_period
+        } else {
+            block_number
+        };
+
+        frame_system::Pallet::<T>::set_block_number(u32_to_block::<T>(update_to_block + 1));
+
+        #[extrinsic_call]
+        owner_update_delegate_stake_percentage(
+            RawOrigin::Signed(owner_coldkey.clone()),
+            subnet_id,
+            new_value,
+        );
+
+        let value = SubnetDelegateStakeRewardsPercentage::<T>::get(subnet_id);
+        assert_eq!(value, new_value);
+    }
+
+    #[benchmark]
+    fn owner_update_max_registered_nodes() {
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+        build_activated_subnet::<T>(
+            DEFAULT_SUBNET_NAME.into(),
+            0,
+            max_subnet_nodes,
+            DEFAULT_DEPOSIT_AMOUNT,
+            DEFAULT_SUBNET_NODE_STAKE,
+        );
+        let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
+
+        let min_nodes = MinSubnetNodes::<T>::get();
+        let max_subnets = MaxSubnets::<T>::get();
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+
+        let owner_coldkey =
+            funded_initializer::<T>("subnet_owner", subnet_id * max_subnets * max_subnet_nodes);
+
+        let current_value = MaxRegisteredNodes::<T>::get(subnet_id);
+
+        let new_value = current_value - 1;
+
+        #[extrinsic_call]
+        owner_update_max_registered_nodes(
+            RawOrigin::Signed(owner_coldkey.clone()),
+            subnet_id,
+            new_value,
+        );
+
+        let value = MaxRegisteredNodes::<T>::get(subnet_id);
+        assert_eq!(value, new_value);
+    }
+
+    #[benchmark]
+    fn transfer_subnet_ownership() {
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+        build_activated_subnet::<T>(
+            DEFAULT_SUBNET_NAME.into(),
+            0,
+            max_subnet_nodes,
+            DEFAULT_DEPOSIT_AMOUNT,
+            DEFAULT_SUBNET_NODE_STAKE,
+        );
+        let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
+
+        let min_nodes = MinSubnetNodes::<T>::get();
+        let max_subnets = MaxSubnets::<T>::get();
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+
+        let owner_coldkey =
+            funded_initializer::<T>("subnet_owner", subnet_id * max_subnets * max_subnet_nodes);
+
+        let current_value = MaxRegisteredNodes::<T>::get(subnet_id);
+
+        let new_owner = funded_initializer::<T>("new_subnet_owner", 0);
+
+        #[extrinsic_call]
+        transfer_subnet_ownership(
+            RawOrigin::Signed(owner_coldkey.clone()),
+            subnet_id,
+            new_owner.clone(),
+        );
+
+        let pending_owner = PendingSubnetOwner::<T>::get(subnet_id).unwrap();
+        assert_eq!(new_owner.clone(), pending_owner.clone());
+    }
+
+    #[benchmark]
+    fn accept_subnet_ownership() {
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+        build_activated_subnet::<T>(
+            DEFAULT_SUBNET_NAME.into(),
+            0,
+            max_subnet_nodes,
+            DEFAULT_DEPOSIT_AMOUNT,
+            DEFAULT_SUBNET_NODE_STAKE,
+        );
+        let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
+
+        let min_nodes = MinSubnetNodes::<T>::get();
+        let max_subnets = MaxSubnets::<T>::get();
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+
+        let owner_coldkey =
+            funded_initializer::<T>("subnet_owner", subnet_id * max_subnets * max_subnet_nodes);
+
+        let current_value = MaxRegisteredNodes::<T>::get(subnet_id);
+
+        let new_owner = funded_initializer::<T>("new_subnet_owner", 0);
+
+        assert_ok!(Network::<T>::transfer_subnet_ownership(
+            RawOrigin::Signed(owner_coldkey.clone()).into(),
+            subnet_id,
+            new_owner.clone(),
+        ));
+
+        #[extrinsic_call]
+        accept_subnet_ownership(RawOrigin::Signed(new_owner.clone()), subnet_id);
+
+        let owner = SubnetOwner::<T>::get(subnet_id).unwrap();
+        assert_eq!(new_owner.clone(), owner);
+    }
+
+    #[benchmark]
+    fn owner_add_bootnode_access() {
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+        build_activated_subnet::<T>(
+            DEFAULT_SUBNET_NAME.into(),
+            0,
+            max_subnet_nodes,
+            DEFAULT_DEPOSIT_AMOUNT,
+            DEFAULT_SUBNET_NODE_STAKE,
+        );
+        let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
+
+        let min_nodes = MinSubnetNodes::<T>::get();
+        let max_subnets = MaxSubnets::<T>::get();
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+
+        let owner_coldkey =
+            funded_initializer::<T>("subnet_owner", subnet_id * max_subnets * max_subnet_nodes);
+
+        let new_access = funded_initializer::<T>("new", 0);
+
+        // sanity check
+        assert!(SubnetBootnodeAccess::<T>::get(subnet_id)
+            .get(&new_access.clone())
+            .is_none());
+
+        #[extrinsic_call]
+        owner_add_bootnode_access(
+            RawOrigin::Signed(owner_coldkey.clone()),
+            subnet_id,
+            new_access.clone(),
+        );
+
+        let new_access_set = SubnetBootnodeAccess::<T>::get(subnet_id);
+        assert!(new_access_set.get(&new_access.clone()).is_some());
+    }
+
+    #[benchmark]
+    fn owner_remove_bootnode_access() {
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+        build_activated_subnet::<T>(
+            DEFAULT_SUBNET_NAME.into(),
+            0,
+            max_subnet_nodes,
+            DEFAULT_DEPOSIT_AMOUNT,
+            DEFAULT_SUBNET_NODE_STAKE,
+        );
+        let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
+
+        let min_nodes = MinSubnetNodes::<T>::get();
+        let max_subnets = MaxSubnets::<T>::get();
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+
+        let owner_coldkey =
+            funded_initializer::<T>("subnet_owner", subnet_id * max_subnets * max_subnet_nodes);
+
+        let new_access = funded_initializer::<T>("new", 0);
+
+        // sanity check
+        assert!(SubnetBootnodeAccess::<T>::get(subnet_id)
+            .get(&new_access.clone())
+            .is_none());
+
+        assert_ok!(Network::<T>::owner_add_bootnode_access(
+            RawOrigin::Signed(owner_coldkey.clone()).into(),
+            subnet_id,
+            new_access.clone()
+        ));
+
+        #[extrinsic_call]
+        owner_remove_bootnode_access(
+            RawOrigin::Signed(owner_coldkey.clone()),
+            subnet_id,
+            new_access.clone(),
+        );
+
+        let new_access_set = SubnetBootnodeAccess::<T>::get(subnet_id);
+        assert!(new_access_set.get(&new_access.clone()).into_iter().count() == 0);
+    }
+
+    #[benchmark]
+    fn owner_update_target_node_registrations_per_epoch() {
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+        build_activated_subnet::<T>(
+            DEFAULT_SUBNET_NAME.into(),
+            0,
+            max_subnet_nodes,
+            DEFAULT_DEPOSIT_AMOUNT,
+            DEFAULT_SUBNET_NODE_STAKE,
+        );
+        let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
+
+        let min_nodes = MinSubnetNodes::<T>::get();
+        let max_subnets = MaxSubnets::<T>::get();
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+
+        let owner_coldkey =
+            funded_initializer::<T>("subnet_owner", subnet_id * max_subnets * max_subnet_nodes);
+
+        let new_value = TargetNodeRegistrationsPerEpoch::<T>::get(subnet_id) - 1;
+
+        #[extrinsic_call]
+        owner_update_target_node_registrations_per_epoch(
+            RawOrigin::Signed(owner_coldkey.clone()),
+            subnet_id,
+            new_value,
+        );
+
+        assert_eq!(
+            TargetNodeRegistrationsPerEpoch::<T>::get(subnet_id),
+            new_value
+        );
+    }
+
+    #[benchmark]
+    fn owner_update_node_burn_rate_alpha() {
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+        build_activated_subnet::<T>(
+            DEFAULT_SUBNET_NAME.into(),
+            0,
+            max_subnet_nodes,
+            DEFAULT_DEPOSIT_AMOUNT,
+            DEFAULT_SUBNET_NODE_STAKE,
+        );
+        let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
+
+        let min_nodes = MinSubnetNodes::<T>::get();
+        let max_subnets = MaxSubnets::<T>::get();
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+
+        let owner_coldkey =
+            funded_initializer::<T>("subnet_owner", subnet_id * max_subnets * max_subnet_nodes);
+
+        let new_value = NodeBurnRateAlpha::<T>::get(subnet_id) - 1;
+
+        #[extrinsic_call]
+        owner_update_node_burn_rate_alpha(
+            RawOrigin::Signed(owner_coldkey.clone()),
+            subnet_id,
+            new_value,
+        );
+
+        assert_eq!(NodeBurnRateAlpha::<T>::get(subnet_id), new_value);
+    }
+
+    #[benchmark]
+    fn owner_update_queue_immunity_epochs() {
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+        build_activated_subnet::<T>(
+            DEFAULT_SUBNET_NAME.into(),
+            0,
+            max_subnet_nodes,
+            DEFAULT_DEPOSIT_AMOUNT,
+            DEFAULT_SUBNET_NODE_STAKE,
+        );
+        let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
+
+        let min_nodes = MinSubnetNodes::<T>::get();
+        let max_subnets = MaxSubnets::<T>::get();
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+
+        let owner_coldkey =
+            funded_initializer::<T>("subnet_owner", subnet_id * max_subnets * max_subnet_nodes);
+
+        let new_value = QueueImmunityEpochs::<T>::get(subnet_id) - 1;
+
+        #[extrinsic_call]
+        owner_update_queue_immunity_epochs(
+            RawOrigin::Signed(owner_coldkey.clone()),
+            subnet_id,
+            new_value,
+        );
+
+        assert_eq!(QueueImmunityEpochs::<T>::get(subnet_id), new_value);
+    }
+
+    #[benchmark]
+    fn owner_update_subnet_node_min_weight_decrease_reputation_threshold() {
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+        build_activated_subnet::<T>(
+            DEFAULT_SUBNET_NAME.into(),
+            0,
+            max_subnet_nodes,
+            DEFAULT_DEPOSIT_AMOUNT,
+            DEFAULT_SUBNET_NODE_STAKE,
+        );
+        let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
+
+        let min_nodes = MinSubnetNodes::<T>::get();
+        let max_subnets = MaxSubnets::<T>::get();
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+
+        let owner_coldkey =
+            funded_initializer::<T>("subnet_owner", subnet_id * max_subnets * max_subnet_nodes);
+
+        let new_value = 1;
+
+        #[extrinsic_call]
+        owner_update_subnet_node_min_weight_decrease_reputation_threshold(
+            RawOrigin::Signed(owner_coldkey.clone()),
+            subnet_id,
+            new_value,
+        );
+
+        assert_eq!(
+            SubnetNodeMinWeightDecreaseReputationThreshold::<T>::get(subnet_id),
+            new_value
+        );
+    }
+
+    #[benchmark]
+    fn update_bootnodes(x: Linear<1, 32>) {
+        // x: bootnodes added
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+        let min_nodes = MinSubnetNodes::<T>::get();
+        let max_subnets = MaxSubnets::<T>::get();
+        build_activated_subnet::<T>(
+            DEFAULT_SUBNET_NAME.into(),
+            0,
+            max_subnet_nodes,
+            DEFAULT_DEPOSIT_AMOUNT,
+            DEFAULT_SUBNET_NODE_STAKE,
+        );
+        let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
+
+        let owner_coldkey =
+            funded_initializer::<T>("subnet_owner", subnet_id * max_subnets * max_subnet_nodes);
+
+        let new_access = funded_initializer::<T>("new", 0);
+        SubnetBootnodeAccess::<T>::insert(subnet_id, BTreeSet::from([new_access.clone()]));
+        let bv = |b: u8| BoundedVec::<u8, DefaultMaxVectorLength>::try_from(vec![b]).unwrap();
+        let add_set: BTreeSet<_> = (0..x).map(|b| bv(b as u8)).collect();
+
+        #[extrinsic_call]
+        update_bootnodes(
+            RawOrigin::Signed(new_access.clone()),
+            subnet_id,
+            add_set.clone(),
+            BTreeSet::new(),
+        );
+
+        // Verify bootnodes added
+        let stored = SubnetBootnodes::<T>::get(subnet_id);
+        assert_eq!(stored.len(), x as usize);
+        assert!(add_set.iter().all(|bootnode| stored.contains(bootnode)));
+    }
+
+    #[benchmark]
+    fn register_subnet_node() {
+        let end = 4;
+        build_activated_subnet::<T>(
+            DEFAULT_SUBNET_NAME.into(),
+            0,
+            end,
+            DEFAULT_DEPOSIT_AMOUNT,
+            DEFAULT_SUBNET_NODE_STAKE,
+        );
+        let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
+
+        let max_subnets = MaxSubnets::<T>::get();
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+
+        let n = end + 1;
+        let coldkey = get_coldkey::<T>(subnet_id, max_subnet_nodes, n);
+        let hotkey = get_hotkey::<T>(subnet_id, max_subnet_nodes, max_subnets, n);
+        let peer_id = get_peer_id::<T>(subnet_id, max_subnet_nodes, max_subnets, n);
+        let bootnode_peer_id =
+            get_bootnode_peer_id::<T>(subnet_id, max_subnet_nodes, max_subnets, n);
+        let client_peer_id = get_client_peer_id::<T>(subnet_id, max_subnet_nodes, max_subnets, n);
+
+        assert_ok!(T::Currency::transfer(
+            &get_alice::<T>(), // alice
+            &coldkey.clone(),
+            (DEFAULT_DEPOSIT_AMOUNT
+                + DEFAULT_STAKE_TO_BE_ADDED
+                + 3 * T::PeerIdProofDeposit::get()
+                + 500)
+                .try_into()
+                .ok()
+                .expect("REASON"),
+            ExistenceRequirement::KeepAlive,
+        ));
+
+        // Worst case: the subnet requires a proof for each peer ID
+        let block = get_current_block_as_u32::<T>();
+        SubnetPeerIdProofRequired::<T>::insert(subnet_id, true);
+        for peer in [&peer_id, &bootnode_peer_id, &client_peer_id] {
+            insert_peer_id_proof::<T>(subnet_id, peer, &hotkey, &coldkey, block);
+        }
+
+        let bootnode = BoundedVec::<u8, DefaultMaxVectorLength>::try_from(vec![1u8; 64]).unwrap();
+
+        #[extrinsic_call]
+        register_subnet_node(
+            RawOrigin::Signed(coldkey.clone()),
+            subnet_id,
+            hotkey.clone(),
+            peer_id.clone(),
+            bootnode_peer_id.clone(),
+            client_peer_id.clone(),
+            Some(bootnode),
+            0,
+            DEFAULT_SUBNET_NODE_STAKE,
+            None,
+            None,
+            u128::MAX,
+        );
+
+        let subnet_node_id = HotkeySubnetNodeId::<T>::get(subnet_id, hotkey.clone()).unwrap();
+        assert_eq!(
+            SubnetNodeIdHotkey::<T>::get(subnet_id, subnet_node_id),
+            Some(hotkey.clone())
+        );
+        assert!(!PeerIdProofs::<T>::contains_key(subnet_id, &peer_id));
+    }
+
+    #[benchmark]
+    fn prove_peer_id() {
+        build_activated_subnet::<T>(
+            DEFAULT_SUBNET_NAME.into(),
+            0,
+            0,
+            DEFAULT_DEPOSIT_AMOUNT,
+            DEFAULT_SUBNET_NODE_STAKE,
+        );
+        let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
+        SubnetPeerIdProofRequired::<T>::insert(subnet_id, true);
+
+        let coldkey = funded_initializer::<T>("coldkey", 0);
+        assert_ok!(T::Currency::transfer(
+            &get_alice::<T>(),
+            &coldkey,
+            u128_to_balance::<T>(2 * T::PeerIdProofDeposit::get()).unwrap(),
+            ExistenceRequirement::KeepAlive,
+        ));
+        let hotkey = get_account::<T>("hotkey", 0);
+        let block = get_current_block_as_u32::<T>();
+
+        // Forge a secp256k1 signature with `r` as the generator's x-coordinate and `s = 1`, then
+        // derive the key it recovers to. Recovering with id `1` makes the 64-byte signature try
+        // both recovery ids.
+        let message = Network::<T>::peer_id_proof_message(subnet_id, &hotkey, block);
+        let message_hash = sp_io::hashing::sha2_256(&message);
+        let mut recoverable = [0u8; 65];
+        recoverable[..32].copy_from_slice(&[
+            0x79, 0xbe, 0x66, 0x7e, 0xf9, 0xdc, 0xbb, 0xac, 0x55, 0xa0, 0x62, 0x95, 0xce, 0x87,
+            0x0b, 0x07, 0x02, 0x9b, 0xfc, 0xdb, 0x2d, 0xce, 0x28, 0xd9, 0x59, 0xf2, 0x81, 0x5b,
+            0x16, 0xf8, 0x17, 0x98,
+        ]);
+        recoverable[63] = 1;
+        recoverable[64] = 1;
+        let public_key =
+            sp_io::crypto::secp256k1_ecdsa_recover_compressed(&recoverable, &message_hash).unwrap();
+
+        let mut multihash = vec![0x00, 0x25, 0x08, 0x02, 0x12, 0x21];
+        multihash.extend_from_slice(&public_key);
+        let peer_id = PeerId(Network::<T>::encode_base58(&multihash));
+
+        let signature =
+            BoundedVec::<u8, DefaultMaxVectorLength>::try_from(recoverable[..64].to_vec()).unwrap();
+
+        // Worst case: the proof replaces one and returns its deposit
+        insert_peer_id_proof::<T>(subnet_id, &peer_id, &hotkey, &coldkey, block);
+
+        #[extrinsic_call]
+        prove_peer_id(
+            RawOrigin::Signed(coldkey.clone()),
+            subnet_id,
+            hotkey.clone(),
+            peer_id.clone(),
+            block,
+            signature,
+        );
+
+        assert_eq!(
+            PeerIdProofs::<T>::get(subnet_id, &peer_id),
+            Some(PeerIdProof {
+                hotkey: hotkey.clone(),
+                block,
+                depositor: coldkey.clone(),
+                deposit: T::PeerIdProofDeposit::get(),
+            })
+        );
+        assert_eq!(
+            T::Currency::reserved_balance(&coldkey),
+            u128_to_balance::<T>(T::PeerIdProofDeposit::get()).unwrap()
+        );
+    }
+
+    #[benchmark]
+    fn remove_subnet_node() {
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+        let min_nodes = MinSubnetNodes::<T>::get();
+        let max_subnets = MaxSubnets::<T>::get();
+        let end = 4;
+        build_activated_subnet::<T>(
+            DEFAULT_SUBNET_NAME.into(),
+            0,
+            end,
+            DEFAULT_DEPOSIT_AMOUNT,
+            DEFAULT_SUBNET_NODE_STAKE,
+        );
+        let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
+
+        let hotkey = get_hotkey::<T>(subnet_id, max_subnet_nodes, max_subnets, end);
+
+        let hotkey_subnet_node_id =
+            HotkeySubnetNodeId::<T>::get(subnet_id, hotkey.clone()).unwrap();
+
+        #[extrinsic_call]
+        remove_subnet_node(
+            RawOrigin::Signed(hotkey.clone()),
+            subnet_id,
+            hotkey_subnet_node_id,
+        );
+
+        assert_eq!(TotalSubnetNodes::<T>::get(subnet_id), end - 1);
+
+        let subnet_node_id = HotkeySubnetNodeId::<T>::try_get(subnet_id, hotkey.clone());
+        assert_eq!(subnet_node_id, Err(()));
+    }
+
+    #[benchmark]
+    fn register_or_update_identity() {
+        let coldkey = funded_initializer::<T>("coldkey", 99);
+        let hotkey = funded_initializer::<T>("hotkey", 0);
+
+        HotkeyOwner::<T>::insert(hotkey.clone(), coldkey.clone());
+        let name = to_bounded::<DefaultMaxVectorLength>("name");
         let url = to_bounded::<DefaultMaxUrlLength>("url");
         let image = to_bounded::<DefaultMaxUrlLength>("image");
         let discord = to_bounded::<DefaultMaxSocialIdLength>("discord");
@@ -2877,6 +3442,9 @@ mod benchmarks {
         );
         let starting_delegator_balance = T::Currency::free_balance(&delegate_account.clone());
 
+        // Worst case, the shares are checkpointed
+        enable_reward_history::<T>();
+
         #[extrinsic_call]
         add_to_delegate_stake(
             RawOrigin::Signed(delegate_account.clone()),
@@ -2964,6 +3532,9 @@ mod benchmarks {
             TotalSubnetDelegateStakeBalance::<T>::get(from_subnet_id);
         let prev_next_id = NextSwapQueueId::<T>::get();
 
+        // Worst case, the shares are checkpointed
+        enable_reward_history::<T>();
+
         #[extrinsic_call]
         swap_delegate_stake(
             RawOrigin::Signed(delegate_account.clone()),
@@ -3044,6 +3615,9 @@ mod benchmarks {
         let delegate_shares =
             AccountSubnetDelegateStakeShares::<T>::get(delegate_account.clone(), subnet_id);
 
+        // Worst case, the shares are checkpointed
+        enable_reward_history::<T>();
+
         #[extrinsic_call]
         transfer_delegate_stake(
             RawOrigin::Signed(delegate_account.clone()),
@@ -3105,6 +3679,9 @@ mod benchmarks {
 
         let block = get_current_block_as_u32::<T>();
 
+        // Worst case, the shares are checkpointed
+        enable_reward_history::<T>();
+
         #[extrinsic_call]
         remove_delegate_stake(
             RawOrigin::Signed(delegate_account.clone()),
@@ -3219,6 +3796,9 @@ mod benchmarks {
             ExistenceRequirement::KeepAlive,
         ));
 
+        // Worst case, the shares are checkpointed
+        enable_reward_history::<T>();
+
         #[extrinsic_call]
         add_to_node_delegate_stake(
             RawOrigin::Signed(delegate_node_account.clone()),
@@ -3309,6 +3889,9 @@ mod benchmarks {
         let pre_transfer_balance = T::Currency::free_balance(&delegate_node_account.clone());
         let prev_next_id = NextSwapQueueId::<T>::get();
 
+        // Worst case, the shares are checkpointed
+        enable_reward_history::<T>();
+
         #[extrinsic_call]
         swap_node_delegate_stake(
             RawOrigin::Signed(delegate_node_account.clone()),
@@ -3413,6 +3996,9 @@ mod benchmarks {
             subnet_node_id,
         ));
 
+        // Worst case, the shares are checkpointed
+        enable_reward_history::<T>();
+
         #[extrinsic_call]
         transfer_node_delegate_stake(
             RawOrigin::Signed(delegate_account.clone()),
@@ -3477,6 +4063,9 @@ mod benchmarks {
             subnet_node_id,
         ));
 
+        // Worst case, the shares are checkpointed
+        enable_reward_history::<T>();
+
         #[extrinsic_call]
         remove_node_delegate_stake(
             RawOrigin::Signed(delegate_account.clone()),
@@ -3593,6 +4182,9 @@ mod benchmarks {
 
         let prev_next_id = NextSwapQueueId::<T>::get();
 
+        // Worst case, the shares are checkpointed
+        enable_reward_history::<T>();
+
         #[extrinsic_call]
         swap_from_node_to_subnet(
             RawOrigin::Signed(delegate_account.clone()),
@@ -3683,6 +4275,9 @@ mod benchmarks {
 
         let prev_next_id = NextSwapQueueId::<T>::get();
 
+        // Worst case, the shares are checkpointed
+        enable_reward_history::<T>();
+
         #[extrinsic_call]
         swap_from_subnet_to_node(
             RawOrigin::Signed(delegate_account.clone()),
@@ -3724,9 +4319,10 @@ mod benchmarks {
     }
 
     #[benchmark]
-    fn propose_attestation() {
+    fn propose_attestation(x: Linear<5, 64>) {
+        // x: subnet nodes in the consensus data
         let max_subnet_nodes = MaxSubnetNodes::<T>::get();
-        let end = 5;
+        let end = x;
         build_activated_subnet::<T>(
             DEFAULT_SUBNET_NAME.into(),
             0,
@@ -3939,7 +4535,7 @@ mod benchmarks {
     }
 
     #[benchmark]
-    fn update_coldkey() {
+    fn update_coldkey(x: Linear<1, 64>) {
         let max_subnet_nodes = MaxSubnetNodes::<T>::get();
         let end = 4;
         build_activated_subnet::<T>(
@@ -3959,15 +4555,30 @@ mod benchmarks {
         let hotkey = get_hotkey::<T>(subnet_id, max_subnet_nodes, max_subnets, end);
         let new_coldkey: T::AccountId = get_account::<T>("new_coldkey", 0);
 
+        // Fill the coldkey up to `x` hotkeys, each one is re-owned by the call
+        let mut hotkeys = ColdkeyHotkeys::<T>::get(&coldkey);
+        let mut n = 0;
+        while (hotkeys.len() as u32) < x {
+            let other_hotkey: T::AccountId = get_account::<T>("other_hotkey", n);
+            HotkeyOwner::<T>::insert(&other_hotkey, &coldkey);
+            hotkeys.insert(other_hotkey);
+            n += 1;
+        }
+        ColdkeyHotkeys::<T>::insert(&coldkey, &hotkeys);
+
         #[extrinsic_call]
         update_coldkey(
             RawOrigin::Signed(coldkey.clone()),
             hotkey.clone(),
             new_coldkey.clone(),
+            hotkeys.len() as u32,
         );
 
         let key_owner = HotkeyOwner::<T>::get(hotkey.clone());
         assert_eq!(key_owner, new_coldkey.clone());
+        for other_hotkey in hotkeys {
+            assert_eq!(HotkeyOwner::<T>::get(other_hotkey), new_coldkey.clone());
+        }
     }
 
     #[benchmark]
@@ -4024,7 +4635,7 @@ mod benchmarks {
         let hotkey_subnet_node_id =
             HotkeySubnetNodeId::<T>::get(subnet_id, hotkey.clone()).unwrap();
 
-        let new_peer = peer(1);
+        let new_peer = peer::<T>(1);
 
         #[extrinsic_call]
         update_peer_id(
@@ -4102,7 +4713,7 @@ mod benchmarks {
         let hotkey_subnet_node_id =
             HotkeySubnetNodeId::<T>::get(subnet_id, hotkey.clone()).unwrap();
 
-        let new_peer = peer(1);
+        let new_peer = peer::<T>(1);
 
         #[extrinsic_call]
         update_bootnode_peer_id(
@@ -4140,7 +4751,7 @@ mod benchmarks {
         let hotkey_subnet_node_id =
             HotkeySubnetNodeId::<T>::get(subnet_id, hotkey.clone()).unwrap();
 
-        let new_peer = peer(1);
+        let new_peer = peer::<T>(1);
 
         #[extrinsic_call]
         update_client_peer_id(
@@ -4171,70 +4782,337 @@ mod benchmarks {
             ExistenceRequirement::KeepAlive,
         ));
 
-        make_overwatch_qualified::<T>(coldkey_n);
+        make_overwatch_qualified::<T>(coldkey_n);
+
+        // increase
+        let multipler = OverwatchEpochLengthMultiplier::<T>::get();
+        increase_epochs::<T>(OverwatchEpochLengthMultiplier::<T>::get() + 1);
+
+        #[extrinsic_call]
+        register_overwatch_node(
+            RawOrigin::Signed(coldkey.clone()),
+            hotkey.clone(),
+            DEFAULT_SUBNET_NODE_STAKE,
+        );
+
+        assert_eq!(
+            AccountOverwatchStake::<T>::get(hotkey.clone()),
+            DEFAULT_SUBNET_NODE_STAKE
+        );
+    }
+
+    #[benchmark]
+    fn remove_overwatch_node() {
+        let hotkey = get_account::<T>("overwatch_node", 2);
+        let coldkey_n = 1;
+        let coldkey = get_account::<T>("overwatch_node", coldkey_n);
+        make_overwatch_qualified::<T>(coldkey_n);
+
+        assert_ok!(T::Currency::transfer(
+            &get_alice::<T>(), // alice
+            &coldkey.clone(),
+            (DEFAULT_SUBNET_NODE_STAKE + 500)
+                .try_into()
+                .ok()
+                .expect("REASON"),
+            ExistenceRequirement::KeepAlive,
+        ));
+
+        // increase
+        let multipler = OverwatchEpochLengthMultiplier::<T>::get();
+        increase_epochs::<T>(OverwatchEpochLengthMultiplier::<T>::get() + 1);
+
+        assert_ok!(Network::<T>::register_overwatch_node(
+            RawOrigin::Signed(coldkey.clone()).into(),
+            hotkey.clone(),
+            DEFAULT_SUBNET_NODE_STAKE
+        ));
+
+        // Sanity check
+        assert_ne!(OverwatchNodes::<T>::try_get(1), Err(()));
+
+        #[extrinsic_call]
+        remove_overwatch_node(RawOrigin::Signed(coldkey.clone()), 1);
+
+        assert_eq!(OverwatchNodes::<T>::try_get(1), Err(()));
+    }
+
+    #[benchmark]
+    fn anyone_remove_overwatch_node() {
+        MinSubnetRegistrationEpochs::<T>::set(10000);
+        OverwatchEpochLengthMultiplier::<T>::set(2);
+        OverwatchMinDiversificationRatio::<T>::set(1000000000000000000);
+        OverwatchMinRepScore::<T>::set(1000000000000000000);
+        OverwatchMinAvgAttestationRatio::<T>::set(1000000000000000000);
+        OverwatchMinAge::<T>::set(1000);
+
+        let hotkey = get_account::<T>("overwatch_node", 2);
+        let coldkey_n = 1;
+        let coldkey = get_account::<T>("overwatch_node", coldkey_n);
+        make_overwatch_qualified::<T>(coldkey_n);
+
+        assert_ok!(T::Currency::transfer(
+            &get_alice::<T>(), // alice
+            &coldkey.clone(),
+            (DEFAULT_SUBNET_NODE_STAKE + 500)
+                .try_into()
+                .ok()
+                .expect("REASON"),
+            ExistenceRequirement::KeepAlive,
+        ));
+
+        // increase
+        let multipler = OverwatchEpochLengthMultiplier::<T>::get();
+        increase_epochs::<T>(OverwatchEpochLengthMultiplier::<T>::get() + 1);
+
+        assert_ok!(Network::<T>::register_overwatch_node(
+            RawOrigin::Signed(coldkey.clone()).into(),
+            hotkey.clone(),
+            DEFAULT_SUBNET_NODE_STAKE
+        ));
+
+        // Sanity check
+        assert_ne!(OverwatchNodes::<T>::try_get(1), Err(()));
+
+        make_overwatch_unqualified::<T>(coldkey_n);
+
+        #[extrinsic_call]
+        anyone_remove_overwatch_node(RawOrigin::Signed(coldkey.clone()), 1);
+
+        assert_eq!(OverwatchNodes::<T>::try_get(1), Err(()));
+    }
+
+    #[benchmark]
+    fn set_overwatch_node_peer_id() {
+        let end = 4;
+        build_activated_subnet::<T>(
+            DEFAULT_SUBNET_NAME.into(),
+            0,
+            end,
+            DEFAULT_DEPOSIT_AMOUNT,
+            DEFAULT_SUBNET_NODE_STAKE,
+        );
+        let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
+
+        let hotkey = get_account::<T>("overwatch_node", 2);
+        let coldkey_n = 1;
+        let coldkey = get_account::<T>("overwatch_node", coldkey_n);
+        make_overwatch_qualified::<T>(coldkey_n);
+
+        assert_ok!(T::Currency::transfer(
+            &get_alice::<T>(), // alice
+            &coldkey.clone(),
+            (DEFAULT_SUBNET_NODE_STAKE + 500)
+                .try_into()
+                .ok()
+                .expect("REASON"),
+            ExistenceRequirement::KeepAlive,
+        ));
+
+        // increase
+        let multipler = OverwatchEpochLengthMultiplier::<T>::get();
+        increase_epochs::<T>(OverwatchEpochLengthMultiplier::<T>::get() + 1);
+
+        assert_ok!(Network::<T>::register_overwatch_node(
+            RawOrigin::Signed(coldkey.clone()).into(),
+            hotkey.clone(),
+            DEFAULT_SUBNET_NODE_STAKE
+        ));
+
+        let id = HotkeyOverwatchNodeId::<T>::get(hotkey.clone()).unwrap();
+
+        let peer_id = peer::<T>(1);
+
+        #[extrinsic_call]
+        set_overwatch_node_peer_id(
+            RawOrigin::Signed(coldkey.clone()),
+            subnet_id,
+            id,
+            peer_id.clone(),
+        );
+
+        let exists = OverwatchNodeIndex::<T>::get(id)
+            .get(&subnet_id)
+            .map_or(false, |x_peer_id| *x_peer_id == peer_id.clone());
+        assert!(exists);
+    }
+
+    #[benchmark]
+    fn commit_overwatch_subnet_weights(x: Linear<1, 16>) {
+        // ENSURE EPOCH LENGTH IS BOAVE MAX LINEAR
+        /// x: subnets
+        // overwatch nodes
+        let hotkey = get_account::<T>("overwatch_node", 2);
+        let coldkey_n = 1;
+        let coldkey = get_account::<T>("overwatch_node", coldkey_n);
+
+        // Activate subnets
+        let end = 4;
+        for s in 0..x {
+            let path: Vec<u8> = format!("subnet-name-{s}").into();
+            build_activated_subnet::<T>(
+                path,
+                0,
+                end,
+                DEFAULT_DEPOSIT_AMOUNT,
+                DEFAULT_SUBNET_NODE_STAKE,
+            );
+            increase_epochs::<T>(10000);
+        }
+
+        make_overwatch_node_qualified::<T>(coldkey_n, x);
+
+        let alice = get_alice::<T>();
+        assert_ok!(T::Currency::transfer(
+            &alice, // alice
+            &coldkey.clone(),
+            (DEFAULT_SUBNET_NODE_STAKE + 1000)
+                .try_into()
+                .ok()
+                .expect("REASON"),
+            ExistenceRequirement::KeepAlive,
+        ));
+        // increase epochs if needed (due to linear we can't after)
+        let overwatch_epoch = Network::<T>::get_current_overwatch_epoch_as_u32();
+        if overwatch_epoch == 0 {
+            let multipler = OverwatchEpochLengthMultiplier::<T>::get();
+            increase_epochs::<T>(OverwatchEpochLengthMultiplier::<T>::get() + 1);
+        }
+        assert_ok!(Network::<T>::register_overwatch_node(
+            RawOrigin::Signed(coldkey.clone()).into(),
+            hotkey.clone(),
+            DEFAULT_SUBNET_NODE_STAKE
+        ));
+
+        let id = HotkeyOverwatchNodeId::<T>::get(hotkey.clone()).unwrap();
 
-        // increase
-        let multipler = OverwatchEpochLengthMultiplier::<T>::get();
-        increase_epochs::<T>(OverwatchEpochLengthMultiplier::<T>::get() + 1);
+        // universal commits for testing
+        let weight: u128 = 123456;
+        let salt: Vec<u8> = b"secret-salt".to_vec();
+        let commit_hash = make_commit::<T>(weight, salt.clone());
+
+        let mut commits: Vec<OverwatchCommit<T::Hash>> = Vec::new();
+        for s in 0..x {
+            let path: Vec<u8> = format!("subnet-name-{s}").into();
+            let subnet_id = SubnetName::<T>::get::<Vec<u8>>(path.clone()).unwrap();
+            commits.push(OverwatchCommit {
+                subnet_id,
+                weight: commit_hash,
+            });
+        }
+
+        let epoch = Network::<T>::get_current_epoch_as_u32();
+        set_block_to_overwatch_commit_block::<T>(epoch);
+
+        let overwatch_epoch = Network::<T>::get_current_overwatch_epoch_as_u32();
 
         #[extrinsic_call]
-        register_overwatch_node(
-            RawOrigin::Signed(coldkey.clone()),
-            hotkey.clone(),
-            DEFAULT_SUBNET_NODE_STAKE,
-        );
+        commit_overwatch_subnet_weights(RawOrigin::Signed(coldkey.clone()), id, commits);
 
-        assert_eq!(
-            AccountOverwatchStake::<T>::get(hotkey.clone()),
-            DEFAULT_SUBNET_NODE_STAKE
-        );
+        for s in 0..x {
+            let path: Vec<u8> = format!("subnet-name-{s}").into();
+            let subnet_id = SubnetName::<T>::get::<Vec<u8>>(path.clone()).unwrap();
+            let stored = OverwatchCommits::<T>::get((overwatch_epoch, id, subnet_id)).unwrap();
+            assert_eq!(stored, commit_hash);
+        }
     }
 
     #[benchmark]
-    fn remove_overwatch_node() {
+    fn reveal_overwatch_subnet_weights(x: Linear<1, 16>) {
+        // ENSURE EPOCH LENGTH IS BOAVE MAX LINEAR
+        /// x: subnets
+        // overwatch nodes
         let hotkey = get_account::<T>("overwatch_node", 2);
         let coldkey_n = 1;
         let coldkey = get_account::<T>("overwatch_node", coldkey_n);
-        make_overwatch_qualified::<T>(coldkey_n);
 
+        // Activate subnets
+        let end = 4;
+        for s in 0..x {
+            let path: Vec<u8> = format!("subnet-name-{s}").into();
+            build_activated_subnet::<T>(
+                path,
+                0,
+                end,
+                DEFAULT_DEPOSIT_AMOUNT,
+                DEFAULT_SUBNET_NODE_STAKE,
+            );
+            increase_epochs::<T>(10000);
+        }
+
+        make_overwatch_node_qualified::<T>(coldkey_n, x);
+
+        let alice = get_alice::<T>();
         assert_ok!(T::Currency::transfer(
-            &get_alice::<T>(), // alice
+            &alice, // alice
             &coldkey.clone(),
-            (DEFAULT_SUBNET_NODE_STAKE + 500)
+            (DEFAULT_SUBNET_NODE_STAKE + 1000)
                 .try_into()
                 .ok()
                 .expect("REASON"),
             ExistenceRequirement::KeepAlive,
         ));
-
-        // increase
-        let multipler = OverwatchEpochLengthMultiplier::<T>::get();
-        increase_epochs::<T>(OverwatchEpochLengthMultiplier::<T>::get() + 1);
-
+        // increase epochs if needed (due to linear we can't after)
+        let overwatch_epoch = Network::<T>::get_current_overwatch_epoch_as_u32();
+        if overwatch_epoch == 0 {
+            let multipler = OverwatchEpochLengthMultiplier::<T>::get();
+            increase_epochs::<T>(OverwatchEpochLengthMultiplier::<T>::get() + 1);
+        }
         assert_ok!(Network::<T>::register_overwatch_node(
             RawOrigin::Signed(coldkey.clone()).into(),
             hotkey.clone(),
             DEFAULT_SUBNET_NODE_STAKE
         ));
 
-        // Sanity check
-        assert_ne!(OverwatchNodes::<T>::try_get(1), Err(()));
+        let id = HotkeyOverwatchNodeId::<T>::get(hotkey.clone()).unwrap();
+
+        // universal commits for testing
+        let weight: u128 = 123456;
+        let salt: Vec<u8> = b"secret-salt".to_vec();
+        let commit_hash = make_commit::<T>(weight, salt.clone());
+
+        let mut commits: Vec<OverwatchCommit<T::Hash>> = Vec::new();
+        let mut reveals: Vec<OverwatchReveal> = Vec::new();
+        for s in 0..x {
+            let path: Vec<u8> = format!("subnet-name-{s}").into();
+            let subnet_id = SubnetName::<T>::get::<Vec<u8>>(path.clone()).unwrap();
+            commits.push(OverwatchCommit {
+                subnet_id,
+                weight: commit_hash,
+            });
+            reveals.push(OverwatchReveal {
+                subnet_id,
+                weight,
+                salt: salt.clone(),
+            })
+        }
+
+        let epoch = Network::<T>::get_current_epoch_as_u32();
+        set_block_to_overwatch_commit_block::<T>(epoch);
+
+        let overwatch_epoch = Network::<T>::get_current_overwatch_epoch_as_u32();
+
+        assert_ok!(Network::<T>::commit_overwatch_subnet_weights(
+            RawOrigin::Signed(coldkey.clone()).into(),
+            id,
+            commits
+        ));
+
+        set_block_to_overwatch_reveal_block::<T>(epoch);
 
         #[extrinsic_call]
-        remove_overwatch_node(RawOrigin::Signed(coldkey.clone()), 1);
+        reveal_overwatch_subnet_weights(RawOrigin::Signed(coldkey.clone()), id, reveals);
 
-        assert_eq!(OverwatchNodes::<T>::try_get(1), Err(()));
+        for s in 0..x {
+            let path: Vec<u8> = format!("subnet-name-{s}").into();
+            let subnet_id = SubnetName::<T>::get::<Vec<u8>>(path.clone()).unwrap();
+            let revealed = OverwatchReveals::<T>::get((overwatch_epoch, subnet_id, id)).unwrap();
+            assert_eq!(revealed, weight);
+        }
     }
 
     #[benchmark]
-    fn anyone_remove_overwatch_node() {
-        MinSubnetRegistrationEpochs::<T>::set(10000);
-        OverwatchEpochLengthMultiplier::<T>::set(2);
-        OverwatchMinDiversificationRatio::<T>::set(1000000000000000000);
-        OverwatchMinRepScore::<T>::set(1000000000000000000);
-        OverwatchMinAvgAttestationRatio::<T>::set(1000000000000000000);
-        OverwatchMinAge::<T>::set(1000);
-
+    fn add_to_overwatch_stake() {
         let hotkey = get_account::<T>("overwatch_node", 2);
         let coldkey_n = 1;
         let coldkey = get_account::<T>("overwatch_node", coldkey_n);
@@ -4260,29 +5138,33 @@ mod benchmarks {
             DEFAULT_SUBNET_NODE_STAKE
         ));
 
-        // Sanity check
-        assert_ne!(OverwatchNodes::<T>::try_get(1), Err(()));
+        let id = HotkeyOverwatchNodeId::<T>::get(hotkey.clone()).unwrap();
 
-        make_overwatch_unqualified::<T>(coldkey_n);
+        assert_ok!(T::Currency::transfer(
+            &get_alice::<T>(), // alice
+            &coldkey.clone(),
+            (DEFAULT_SUBNET_NODE_STAKE).try_into().ok().expect("REASON"),
+            ExistenceRequirement::KeepAlive,
+        ));
+
+        let prev_balance = AccountOverwatchStake::<T>::get(hotkey.clone());
 
         #[extrinsic_call]
-        anyone_remove_overwatch_node(RawOrigin::Signed(coldkey.clone()), 1);
+        add_to_overwatch_stake(
+            RawOrigin::Signed(coldkey.clone()),
+            id,
+            hotkey.clone(),
+            DEFAULT_SUBNET_NODE_STAKE,
+        );
 
-        assert_eq!(OverwatchNodes::<T>::try_get(1), Err(()));
+        assert_eq!(
+            prev_balance + DEFAULT_SUBNET_NODE_STAKE,
+            AccountOverwatchStake::<T>::get(hotkey.clone())
+        );
     }
 
     #[benchmark]
-    fn set_overwatch_node_peer_id() {
-        let end = 4;
-        build_activated_subnet::<T>(
-            DEFAULT_SUBNET_NAME.into(),
-            0,
-            end,
-            DEFAULT_DEPOSIT_AMOUNT,
-            DEFAULT_SUBNET_NODE_STAKE,
-        );
-        let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
-
+    fn remove_overwatch_stake() {
         let hotkey = get_account::<T>("overwatch_node", 2);
         let coldkey_n = 1;
         let coldkey = get_account::<T>("overwatch_node", coldkey_n);
@@ -4310,24 +5192,92 @@ mod benchmarks {
 
         let id = HotkeyOverwatchNodeId::<T>::get(hotkey.clone()).unwrap();
 
-        let peer_id = peer(1);
+        assert_ok!(T::Currency::transfer(
+            &get_alice::<T>(), // alice
+            &coldkey.clone(),
+            (DEFAULT_SUBNET_NODE_STAKE).try_into().ok().expect("REASON"),
+            ExistenceRequirement::KeepAlive,
+        ));
+
+        assert_ok!(Network::<T>::add_to_overwatch_stake(
+            RawOrigin::Signed(coldkey.clone()).into(),
+            id,
+            hotkey.clone(),
+            DEFAULT_SUBNET_NODE_STAKE
+        ));
+
+        let prev_balance = AccountOverwatchStake::<T>::get(hotkey.clone());
 
         #[extrinsic_call]
-        set_overwatch_node_peer_id(
+        remove_overwatch_stake(
             RawOrigin::Signed(coldkey.clone()),
-            subnet_id,
-            id,
-            peer_id.clone(),
+            hotkey.clone(),
+            DEFAULT_SUBNET_NODE_STAKE,
+        );
+
+        assert_eq!(
+            prev_balance - DEFAULT_SUBNET_NODE_STAKE,
+            AccountOverwatchStake::<T>::get(hotkey.clone())
+        );
+    }
+
+    #[benchmark]
+    fn pause() {
+        assert!(!TxPause::<T>::get());
+
+        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+            .expect("try_successful_origin failed");
+
+        #[extrinsic_call]
+        pause(origin as T::RuntimeOrigin);
+
+        // Verify the network is now paused
+        assert!(TxPause::<T>::get());
+    }
+
+    #[benchmark]
+    fn unpause() {
+        // sanity check
+        assert!(!TxPause::<T>::get());
+
+        assert_ok!(Network::<T>::do_pause());
+        assert!(TxPause::<T>::get());
+
+        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+            .expect("try_successful_origin failed");
+
+        #[extrinsic_call]
+        unpause(origin as T::RuntimeOrigin);
+
+        assert!(!TxPause::<T>::get());
+    }
+
+    #[benchmark]
+    fn collective_remove_subnet() {
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+        build_activated_subnet::<T>(
+            DEFAULT_SUBNET_NAME.into(),
+            0,
+            max_subnet_nodes,
+            DEFAULT_DEPOSIT_AMOUNT,
+            DEFAULT_SUBNET_NODE_STAKE,
         );
+        let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
+
+        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+            .expect("try_successful_origin failed");
+
+        #[extrinsic_call]
+        collective_remove_subnet(origin as T::RuntimeOrigin, subnet_id);
 
-        let exists = OverwatchNodeIndex::<T>::get(id)
-            .get(&subnet_id)
-            .map_or(false, |x_peer_id| *x_peer_id == peer_id.clone());
-        assert!(exists);
+        assert_eq!(SubnetsData::<T>::try_get(subnet_id), Err(()));
     }
 
     #[benchmark]
-    fn commit_overwatch_subnet_weights() {
+    fn collective_remove_subnet_node() {
+        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
+        let min_nodes = MinSubnetNodes::<T>::get();
+        let max_subnets = MaxSubnets::<T>::get();
         let end = 4;
         build_activated_subnet::<T>(
             DEFAULT_SUBNET_NAME.into(),
@@ -4338,6 +5288,25 @@ mod benchmarks {
         );
         let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
 
+        let hotkey = get_hotkey::<T>(subnet_id, max_subnet_nodes, max_subnets, end);
+
+        let hotkey_subnet_node_id =
+            HotkeySubnetNodeId::<T>::get(subnet_id, hotkey.clone()).unwrap();
+
+        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+            .expect("try_successful_origin failed");
+
+        #[extrinsic_call]
+        collective_remove_subnet_node(origin as T::RuntimeOrigin, subnet_id, hotkey_subnet_node_id);
+
+        assert_eq!(TotalSubnetNodes::<T>::get(subnet_id), end - 1);
+
+        let subnet_node_id = HotkeySubnetNodeId::<T>::try_get(subnet_id, hotkey.clone());
+        assert_eq!(subnet_node_id, Err(()));
+    }
+
+    #[benchmark]
+    fn collective_remove_overwatch_node() {
         let hotkey = get_account::<T>("overwatch_node", 2);
         let coldkey_n = 1;
         let coldkey = get_account::<T>("overwatch_node", coldkey_n);
@@ -4363,675 +5332,574 @@ mod benchmarks {
             DEFAULT_SUBNET_NODE_STAKE
         ));
 
+        // Sanity check
+        assert_ne!(OverwatchNodes::<T>::try_get(1), Err(()));
+
         let id = HotkeyOverwatchNodeId::<T>::get(hotkey.clone()).unwrap();
 
-        let weight: u128 = 123456;
-        let salt: Vec<u8> = b"secret-salt".to_vec();
-        let commit_hash = make_commit::<T>(weight, salt.clone());
+        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+            .expect("try_successful_origin failed");
 
-        let overwatch_epoch = Network::<T>::get_current_overwatch_epoch_as_u32();
+        #[extrinsic_call]
+        collective_remove_overwatch_node(origin as T::RuntimeOrigin, id);
+
+        assert_eq!(OverwatchNodes::<T>::try_get(id), Err(()));
+    }
+
+    #[benchmark]
+    fn set_min_subnet_delegate_stake_factor() {
+        let value = MinSubnetDelegateStakeFactor::<T>::get();
+        let new_value = value - 1;
+
+        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+            .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        commit_overwatch_subnet_weights(
-            RawOrigin::Signed(coldkey.clone()),
-            id,
-            vec![OverwatchCommit {
-                subnet_id,
-                weight: commit_hash,
-            }],
-        );
+        set_min_subnet_delegate_stake_factor(origin as T::RuntimeOrigin, new_value);
 
-        let stored = OverwatchCommits::<T>::get((overwatch_epoch, id, subnet_id)).unwrap();
-        assert_eq!(stored, commit_hash);
+        assert_eq!(MinSubnetDelegateStakeFactor::<T>::get(), new_value);
     }
 
     #[benchmark]
-    fn commit_overwatch_subnet_weights_v2(x: Linear<1, 16>) {
-        // ENSURE EPOCH LENGTH IS BOAVE MAX LINEAR
-        /// x: subnets
-        // overwatch nodes
-        let hotkey = get_account::<T>("overwatch_node", 2);
-        let coldkey_n = 1;
-        let coldkey = get_account::<T>("overwatch_node", coldkey_n);
+    fn set_subnet_owner_percentage() {
+        let value = SubnetOwnerPercentage::<T>::get();
+        let new_value = value - 1;
 
-        // Activate subnets
-        let end = 4;
-        for s in 0..x {
-            let path: Vec<u8> = format!("subnet-name-{s}").into();
-            build_activated_subnet::<T>(
-                path,
-                0,
-                end,
-                DEFAULT_DEPOSIT_AMOUNT,
-                DEFAULT_SUBNET_NODE_STAKE,
-            );
-            increase_epochs::<T>(10000);
-        }
+        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+            .expect("try_successful_origin failed");
 
-        make_overwatch_node_qualified::<T>(coldkey_n, x);
+        #[extrinsic_call]
+        set_subnet_owner_percentage(origin as T::RuntimeOrigin, new_value);
 
-        let alice = get_alice::<T>();
-        assert_ok!(T::Currency::transfer(
-            &alice, // alice
-            &coldkey.clone(),
-            (DEFAULT_SUBNET_NODE_STAKE + 1000)
-                .try_into()
-                .ok()
-                .expect("REASON"),
-            ExistenceRequirement::KeepAlive,
-        ));
-        // increase epochs if needed (due to linear we can't after)
-        let overwatch_epoch = Network::<T>::get_current_overwatch_epoch_as_u32();
-        if overwatch_epoch == 0 {
-            let multipler = OverwatchEpochLengthMultiplier::<T>::get();
-            increase_epochs::<T>(OverwatchEpochLengthMultiplier::<T>::get() + 1);
-        }
-        assert_ok!(Network::<T>::register_overwatch_node(
-            RawOrigin::Signed(coldkey.clone()).into(),
-            hotkey.clone(),
-            DEFAULT_SUBNET_NODE_STAKE
-        ));
+        assert_eq!(SubnetOwnerPercentage::<T>::get(), new_value);
+    }
 
-        let id = HotkeyOverwatchNodeId::<T>::get(hotkey.clone()).unwrap();
+    #[benchmark]
+    fn set_max_subnets() {
+        let epoch_length = T::EpochLength::get();
+        let designated_epoch_slots = T::DesignatedEpochSlots::get();
+        let new_value = epoch_length - designated_epoch_slots;
 
-        // universal commits for testing
-        let weight: u128 = 123456;
-        let salt: Vec<u8> = b"secret-salt".to_vec();
-        let commit_hash = make_commit::<T>(weight, salt.clone());
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
+            .expect("try_successful_origin failed");
 
-        let mut commits: Vec<OverwatchCommit<T::Hash>> = Vec::new();
-        for s in 0..x {
-            let path: Vec<u8> = format!("subnet-name-{s}").into();
-            let subnet_id = SubnetName::<T>::get::<Vec<u8>>(path.clone()).unwrap();
-            commits.push(OverwatchCommit {
-                subnet_id,
-                weight: commit_hash,
-            });
-        }
+        #[extrinsic_call]
+        set_max_subnets(origin as T::RuntimeOrigin, new_value);
 
-        let epoch = Network::<T>::get_current_epoch_as_u32();
-        set_block_to_overwatch_commit_block::<T>(epoch);
+        assert_eq!(MaxSubnets::<T>::get(), new_value);
+    }
 
-        let overwatch_epoch = Network::<T>::get_current_overwatch_epoch_as_u32();
+    #[benchmark]
+    fn set_max_bootnodes() {
+        let value = MaxBootnodes::<T>::get();
+        let new_value = value - 1;
+
+        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+            .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        commit_overwatch_subnet_weights(RawOrigin::Signed(coldkey.clone()), id, commits);
+        set_max_bootnodes(origin as T::RuntimeOrigin, new_value);
 
-        for s in 0..x {
-            let path: Vec<u8> = format!("subnet-name-{s}").into();
-            let subnet_id = SubnetName::<T>::get::<Vec<u8>>(path.clone()).unwrap();
-            let stored = OverwatchCommits::<T>::get((overwatch_epoch, id, subnet_id)).unwrap();
-            assert_eq!(stored, commit_hash);
-        }
+        assert_eq!(MaxBootnodes::<T>::get(), new_value);
     }
 
     #[benchmark]
-    fn reveal_overwatch_subnet_weights(x: Linear<1, 16>) {
-        // ENSURE EPOCH LENGTH IS BOAVE MAX LINEAR
-        /// x: subnets
-        // overwatch nodes
-        let hotkey = get_account::<T>("overwatch_node", 2);
-        let coldkey_n = 1;
-        let coldkey = get_account::<T>("overwatch_node", coldkey_n);
+    fn set_max_subnet_bootnodes_access() {
+        let value = MaxSubnetBootnodeAccess::<T>::get();
+        let new_value = value - 1;
 
-        // Activate subnets
-        let end = 4;
-        for s in 0..x {
-            let path: Vec<u8> = format!("subnet-name-{s}").into();
-            build_activated_subnet::<T>(
-                path,
-                0,
-                end,
-                DEFAULT_DEPOSIT_AMOUNT,
-                DEFAULT_SUBNET_NODE_STAKE,
-            );
-            increase_epochs::<T>(10000);
-        }
+        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+            .expect("try_successful_origin failed");
 
-        make_overwatch_node_qualified::<T>(coldkey_n, x);
+        #[extrinsic_call]
+        set_max_subnet_bootnodes_access(origin as T::RuntimeOrigin, new_value);
 
-        let alice = get_alice::<T>();
-        assert_ok!(T::Currency::transfer(
-            &alice, // alice
-            &coldkey.clone(),
-            (DEFAULT_SUBNET_NODE_STAKE + 1000)
-                .try_into()
-                .ok()
-                .expect("REASON"),
-            ExistenceRequirement::KeepAlive,
-        ));
-        // increase epochs if needed (due to linear we can't after)
-        let overwatch_epoch = Network::<T>::get_current_overwatch_epoch_as_u32();
-        if overwatch_epoch == 0 {
-            let multipler = OverwatchEpochLengthMultiplier::<T>::get();
-            increase_epochs::<T>(OverwatchEpochLengthMultiplier::<T>::get() + 1);
-        }
-        assert_ok!(Network::<T>::register_overwatch_node(
-            RawOrigin::Signed(coldkey.clone()).into(),
-            hotkey.clone(),
-            DEFAULT_SUBNET_NODE_STAKE
-        ));
+        assert_eq!(MaxSubnetBootnodeAccess::<T>::get(), new_value);
+    }
 
-        let id = HotkeyOverwatchNodeId::<T>::get(hotkey.clone()).unwrap();
+    #[benchmark]
+    fn set_max_pause_epochs() {
+        let value = MaxSubnetPauseEpochs::<T>::get();
+        let new_value = value - 1;
 
-        // universal commits for testing
-        let weight: u128 = 123456;
-        let salt: Vec<u8> = b"secret-salt".to_vec();
-        let commit_hash = make_commit::<T>(weight, salt.clone());
+        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+            .expect("try_successful_origin failed");
 
-        let mut commits: Vec<OverwatchCommit<T::Hash>> = Vec::new();
-        let mut reveals: Vec<OverwatchReveal> = Vec::new();
-        for s in 0..x {
-            let path: Vec<u8> = format!("subnet-name-{s}").into();
-            let subnet_id = SubnetName::<T>::get::<Vec<u8>>(path.clone()).unwrap();
-            commits.push(OverwatchCommit {
-                subnet_id,
-                weight: commit_hash,
-            });
-            reveals.push(OverwatchReveal {
-                subnet_id,
-                weight,
-                salt: salt.clone(),
-            })
-        }
+        #[extrinsic_call]
+        set_max_pause_epochs(origin as T::RuntimeOrigin, new_value);
+
+        assert_eq!(MaxSubnetPauseEpochs::<T>::get(), new_value);
+    }
+
+    #[benchmark]
+    fn set_delegate_stake_subnet_removal_interval() {
+        let new_value = 1;
+
+        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+            .expect("try_successful_origin failed");
+
+        #[extrinsic_call]
+        set_delegate_stake_subnet_removal_interval(origin as T::RuntimeOrigin, new_value);
+
+        assert_eq!(DelegateStakeSubnetRemovalInterval::<T>::get(), new_value);
+    }
+
+    #[benchmark]
+    fn set_subnet_removal_intervals() {
+        let min = 1;
+        let max = 2;
+
+        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+            .expect("try_successful_origin failed");
 
-        let epoch = Network::<T>::get_current_epoch_as_u32();
-        set_block_to_overwatch_commit_block::<T>(epoch);
+        #[extrinsic_call]
+        set_subnet_removal_intervals(origin as T::RuntimeOrigin, min, max);
 
-        let overwatch_epoch = Network::<T>::get_current_overwatch_epoch_as_u32();
+        assert_eq!(MinSubnetRemovalInterval::<T>::get(), min);
+        assert_eq!(MaxSubnetRemovalInterval::<T>::get(), max);
+    }
 
-        assert_ok!(Network::<T>::commit_overwatch_subnet_weights(
-            RawOrigin::Signed(coldkey.clone()).into(),
-            id,
-            commits
-        ));
+    #[benchmark]
+    fn set_subnet_pause_cooldown_epochs() {
+        let new_value = 1;
 
-        set_block_to_overwatch_reveal_block::<T>(epoch);
+        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+            .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        reveal_overwatch_subnet_weights(RawOrigin::Signed(coldkey.clone()), id, reveals);
+        set_subnet_pause_cooldown_epochs(origin as T::RuntimeOrigin, new_value);
 
-        for s in 0..x {
-            let path: Vec<u8> = format!("subnet-name-{s}").into();
-            let subnet_id = SubnetName::<T>::get::<Vec<u8>>(path.clone()).unwrap();
-            let revealed = OverwatchReveals::<T>::get((overwatch_epoch, subnet_id, id)).unwrap();
-            assert_eq!(revealed, weight);
-        }
+        assert_eq!(SubnetPauseCooldownEpochs::<T>::get(), new_value);
     }
 
     #[benchmark]
-    fn add_to_overwatch_stake() {
-        let hotkey = get_account::<T>("overwatch_node", 2);
-        let coldkey_n = 1;
-        let coldkey = get_account::<T>("overwatch_node", coldkey_n);
-        make_overwatch_qualified::<T>(coldkey_n);
-
-        assert_ok!(T::Currency::transfer(
-            &get_alice::<T>(), // alice
-            &coldkey.clone(),
-            (DEFAULT_SUBNET_NODE_STAKE + 500)
-                .try_into()
-                .ok()
-                .expect("REASON"),
-            ExistenceRequirement::KeepAlive,
-        ));
+    fn set_min_registration_cost() {
+        let value = MinRegistrationCost::<T>::get();
+        let new_value = value - 1;
 
-        // increase
-        let multipler = OverwatchEpochLengthMultiplier::<T>::get();
-        increase_epochs::<T>(OverwatchEpochLengthMultiplier::<T>::get() + 1);
+        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+            .expect("try_successful_origin failed");
 
-        assert_ok!(Network::<T>::register_overwatch_node(
-            RawOrigin::Signed(coldkey.clone()).into(),
-            hotkey.clone(),
-            DEFAULT_SUBNET_NODE_STAKE
-        ));
+        #[extrinsic_call]
+        set_min_registration_cost(origin as T::RuntimeOrigin, new_value);
 
-        let id = HotkeyOverwatchNodeId::<T>::get(hotkey.clone()).unwrap();
+        assert_eq!(MinRegistrationCost::<T>::get(), new_value);
+    }
 
-        assert_ok!(T::Currency::transfer(
-            &get_alice::<T>(), // alice
-            &coldkey.clone(),
-            (DEFAULT_SUBNET_NODE_STAKE).try_into().ok().expect("REASON"),
-            ExistenceRequirement::KeepAlive,
-        ));
+    #[benchmark]
+    fn set_registration_cost_delay_blocks() {
+        let value = RegistrationCostDecayBlocks::<T>::get();
+        let new_value = value - 1;
 
-        let prev_balance = AccountOverwatchStake::<T>::get(hotkey.clone());
+        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+            .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        add_to_overwatch_stake(
-            RawOrigin::Signed(coldkey.clone()),
-            id,
-            hotkey.clone(),
-            DEFAULT_SUBNET_NODE_STAKE,
-        );
+        set_registration_cost_delay_blocks(origin as T::RuntimeOrigin, new_value);
 
-        assert_eq!(
-            prev_balance + DEFAULT_SUBNET_NODE_STAKE,
-            AccountOverwatchStake::<T>::get(hotkey.clone())
-        );
+        assert_eq!(RegistrationCostDecayBlocks::<T>::get(), new_value);
     }
 
     #[benchmark]
-    fn remove_overwatch_stake() {
-        let hotkey = get_account::<T>("overwatch_node", 2);
-        let coldkey_n = 1;
-        let coldkey = get_account::<T>("overwatch_node", coldkey_n);
-        make_overwatch_qualified::<T>(coldkey_n);
+    fn set_registration_cost_alpha() {
+        let value = RegistrationCostAlpha::<T>::get();
+        let new_value = value - 1;
 
-        assert_ok!(T::Currency::transfer(
-            &get_alice::<T>(), // alice
-            &coldkey.clone(),
-            (DEFAULT_SUBNET_NODE_STAKE + 500)
-                .try_into()
-                .ok()
-                .expect("REASON"),
-            ExistenceRequirement::KeepAlive,
-        ));
+        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+            .expect("try_successful_origin failed");
 
-        // increase
-        let multipler = OverwatchEpochLengthMultiplier::<T>::get();
-        increase_epochs::<T>(OverwatchEpochLengthMultiplier::<T>::get() + 1);
+        #[extrinsic_call]
+        set_registration_cost_alpha(origin as T::RuntimeOrigin, new_value);
 
-        assert_ok!(Network::<T>::register_overwatch_node(
-            RawOrigin::Signed(coldkey.clone()).into(),
-            hotkey.clone(),
-            DEFAULT_SUBNET_NODE_STAKE
-        ));
+        assert_eq!(RegistrationCostAlpha::<T>::get(), new_value);
+    }
 
-        let id = HotkeyOverwatchNodeId::<T>::get(hotkey.clone()).unwrap();
+    #[benchmark]
+    fn set_new_registration_cost_multiplier() {
+        let value = NewRegistrationCostMultiplier::<T>::get();
+        let new_value = value - 1;
 
-        assert_ok!(T::Currency::transfer(
-            &get_alice::<T>(), // alice
-            &coldkey.clone(),
-            (DEFAULT_SUBNET_NODE_STAKE).try_into().ok().expect("REASON"),
-            ExistenceRequirement::KeepAlive,
-        ));
+        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+            .expect("try_successful_origin failed");
 
-        assert_ok!(Network::<T>::add_to_overwatch_stake(
-            RawOrigin::Signed(coldkey.clone()).into(),
-            id,
-            hotkey.clone(),
-            DEFAULT_SUBNET_NODE_STAKE
-        ));
+        #[extrinsic_call]
+        set_new_registration_cost_multiplier(origin as T::RuntimeOrigin, new_value);
 
-        let prev_balance = AccountOverwatchStake::<T>::get(hotkey.clone());
+        assert_eq!(NewRegistrationCostMultiplier::<T>::get(), new_value);
+    }
+
+    #[benchmark]
+    fn set_max_min_delegate_stake_multiplier() {
+        let value = MaxMinDelegateStakeMultiplier::<T>::get();
+        let new_value = value - 1;
+
+        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
+            .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        remove_overwatch_stake(
-            RawOrigin::Signed(coldkey.clone()),
-            hotkey.clone(),
-            DEFAULT_SUBNET_NODE_STAKE,
-        );
+        set_max_min_delegate_stake_multiplier(origin as T::RuntimeOrigin, new_value);
 
-        assert_eq!(
-            prev_balance - DEFAULT_SUBNET_NODE_STAKE,
-            AccountOverwatchStake::<T>::get(hotkey.clone())
-        );
+        assert_eq!(MaxMinDelegateStakeMultiplier::<T>::get(), new_value);
     }
 
     #[benchmark]
-    fn pause() {
-        assert!(!TxPause::<T>::get());
+    fn set_churn_limits() {
+        let min = 1;
+        let max = 2;
 
         let origin = T::MajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        pause(origin as T::RuntimeOrigin);
+        set_churn_limits(origin as T::RuntimeOrigin, min, max);
 
-        // Verify the network is now paused
-        assert!(TxPause::<T>::get());
+        assert_eq!(MinChurnLimit::<T>::get(), min);
+        assert_eq!(MaxChurnLimit::<T>::get(), max);
     }
 
     #[benchmark]
-    fn unpause() {
-        // sanity check
-        assert!(!TxPause::<T>::get());
-
-        assert_ok!(Network::<T>::do_pause());
-        assert!(TxPause::<T>::get());
+    fn set_queue_epochs() {
+        let min = 1;
+        let max = 2;
 
         let origin = T::MajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        unpause(origin as T::RuntimeOrigin);
+        set_queue_epochs(origin as T::RuntimeOrigin, min, max);
 
-        assert!(!TxPause::<T>::get());
+        assert_eq!(MinQueueEpochs::<T>::get(), min);
+        assert_eq!(MaxQueueEpochs::<T>::get(), max);
     }
 
     #[benchmark]
-    fn collective_remove_subnet() {
-        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
-        build_activated_subnet::<T>(
-            DEFAULT_SUBNET_NAME.into(),
-            0,
-            max_subnet_nodes,
-            DEFAULT_DEPOSIT_AMOUNT,
-            DEFAULT_SUBNET_NODE_STAKE,
-        );
-        let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
+    fn set_max_swap_queue_calls_per_block() {
+        let value = MaxSwapQueueCallsPerBlock::<T>::get();
+        let new_value = value - 1;
 
         let origin = T::MajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        collective_remove_subnet(origin as T::RuntimeOrigin, subnet_id);
+        set_max_swap_queue_calls_per_block(origin as T::RuntimeOrigin, new_value);
 
-        assert_eq!(SubnetsData::<T>::try_get(subnet_id), Err(()));
+        assert_eq!(MaxSwapQueueCallsPerBlock::<T>::get(), new_value);
     }
 
     #[benchmark]
-    fn collective_remove_subnet_node() {
-        let max_subnet_nodes = MaxSubnetNodes::<T>::get();
-        let min_nodes = MinSubnetNodes::<T>::get();
-        let max_subnets = MaxSubnets::<T>::get();
-        let end = 4;
-        build_activated_subnet::<T>(
-            DEFAULT_SUBNET_NAME.into(),
-            0,
-            end,
-            DEFAULT_DEPOSIT_AMOUNT,
-            DEFAULT_SUBNET_NODE_STAKE,
-        );
-        let subnet_id = SubnetName::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_NAME.into()).unwrap();
+    fn set_max_pending_swaps_per_account() {
+        let value = MaxPendingSwapsPerAccount::<T>::get();
+        let new_value = value - 1;
 
-        let hotkey = get_hotkey::<T>(subnet_id, max_subnet_nodes, max_subnets, end);
+        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+            .expect("try_successful_origin failed");
 
-        let hotkey_subnet_node_id =
-            HotkeySubnetNodeId::<T>::get(subnet_id, hotkey.clone()).unwrap();
+        #[extrinsic_call]
+        set_max_pending_swaps_per_account(origin as T::RuntimeOrigin, new_value);
+
+        assert_eq!(MaxPendingSwapsPerAccount::<T>::get(), new_value);
+    }
+
+    #[benchmark]
+    fn set_backup_proposer_epoch_progression() {
+        let new_value: u128 = 750000000000000000;
 
         let origin = T::MajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        collective_remove_subnet_node(origin as T::RuntimeOrigin, subnet_id, hotkey_subnet_node_id);
-
-        assert_eq!(TotalSubnetNodes::<T>::get(subnet_id), end - 1);
+        set_backup_proposer_epoch_progression(origin as T::RuntimeOrigin, new_value);
 
-        let subnet_node_id = HotkeySubnetNodeId::<T>::try_get(subnet_id, hotkey.clone());
-        assert_eq!(subnet_node_id, Err(()));
+        assert_eq!(BackupProposerEpochProgression::<T>::get(), new_value);
     }
 
     #[benchmark]
-    fn collective_remove_overwatch_node() {
-        let hotkey = get_account::<T>("overwatch_node", 2);
-        let coldkey_n = 1;
-        let coldkey = get_account::<T>("overwatch_node", coldkey_n);
-        make_overwatch_qualified::<T>(coldkey_n);
+    fn set_backup_validator_reward_factor() {
+        let new_value: u128 = 250000000000000000;
 
-        assert_ok!(T::Currency::transfer(
-            &get_alice::<T>(), // alice
-            &coldkey.clone(),
-            (DEFAULT_SUBNET_NODE_STAKE + 500)
-                .try_into()
-                .ok()
-                .expect("REASON"),
-            ExistenceRequirement::KeepAlive,
-        ));
+        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+            .expect("try_successful_origin failed");
 
-        // increase
-        let multipler = OverwatchEpochLengthMultiplier::<T>::get();
-        increase_epochs::<T>(OverwatchEpochLengthMultiplier::<T>::get() + 1);
+        #[extrinsic_call]
+        set_backup_validator_reward_factor(origin as T::RuntimeOrigin, new_value);
+
+        assert_eq!(BackupValidatorRewardFactor::<T>::get(), new_value);
+    }
+
+    #[benchmark]
+    fn set_consensus_archive_depth() {
+        let new_value: u32 = 100;
+
+        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+            .expect("try_successful_origin failed");
 
-        assert_ok!(Network::<T>::register_overwatch_node(
-            RawOrigin::Signed(coldkey.clone()).into(),
-            hotkey.clone(),
-            DEFAULT_SUBNET_NODE_STAKE
-        ));
+        #[extrinsic_call]
+        set_consensus_archive_depth(origin as T::RuntimeOrigin, new_value);
 
-        // Sanity check
-        assert_ne!(OverwatchNodes::<T>::try_get(1), Err(()));
+        assert_eq!(ConsensusArchiveDepth::<T>::get(), new_value);
+    }
 
-        let id = HotkeyOverwatchNodeId::<T>::get(hotkey.clone()).unwrap();
+    #[benchmark]
+    fn set_reward_history_depth() {
+        let new_value: u32 = 100;
 
         let origin = T::MajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        collective_remove_overwatch_node(origin as T::RuntimeOrigin, id);
+        set_reward_history_depth(origin as T::RuntimeOrigin, new_value);
 
-        assert_eq!(OverwatchNodes::<T>::try_get(id), Err(()));
+        assert_eq!(RewardHistoryDepth::<T>::get(), new_value);
     }
 
     #[benchmark]
-    fn set_min_subnet_delegate_stake_factor() {
-        let value = MinSubnetDelegateStakeFactor::<T>::get();
-        let new_value = value - 1;
+    fn set_attestor_min_reward_factor() {
+        let new_value = Network::<T>::percentage_factor_as_u128() / 2;
 
         let origin = T::MajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        set_min_subnet_delegate_stake_factor(origin as T::RuntimeOrigin, new_value);
+        set_attestor_min_reward_factor(origin as T::RuntimeOrigin, new_value);
 
-        assert_eq!(MinSubnetDelegateStakeFactor::<T>::get(), new_value);
+        assert_eq!(AttestorMinRewardFactor::<T>::get(), new_value);
     }
 
     #[benchmark]
-    fn set_subnet_owner_percentage() {
-        let value = SubnetOwnerPercentage::<T>::get();
-        let new_value = value - 1;
+    fn set_attestor_reward_exponent() {
+        let new_value = 2;
 
         let origin = T::MajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        set_subnet_owner_percentage(origin as T::RuntimeOrigin, new_value);
+        set_attestor_reward_exponent(origin as T::RuntimeOrigin, new_value);
 
-        assert_eq!(SubnetOwnerPercentage::<T>::get(), new_value);
+        assert_eq!(AttestorRewardExponent::<T>::get(), new_value);
     }
 
     #[benchmark]
-    fn set_max_subnets() {
-        let epoch_length = T::EpochLength::get();
-        let designated_epoch_slots = T::DesignatedEpochSlots::get();
-        let new_value = epoch_length - designated_epoch_slots;
+    fn set_in_consensus_subnet_reputation_factor() {
+        let new_value = Network::<T>::percentage_factor_as_u128() / 2;
 
-        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        set_max_subnets(origin as T::RuntimeOrigin, new_value);
+        set_in_consensus_subnet_reputation_factor(origin as T::RuntimeOrigin, new_value);
 
-        assert_eq!(MaxSubnets::<T>::get(), new_value);
+        assert_eq!(InConsensusSubnetReputationFactor::<T>::get(), new_value);
     }
 
     #[benchmark]
-    fn set_max_bootnodes() {
-        let value = MaxBootnodes::<T>::get();
-        let new_value = value - 1;
+    fn set_less_than_min_nodes_subnet_reputation_factor() {
+        let new_value = Network::<T>::percentage_factor_as_u128() / 2;
 
         let origin = T::MajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        set_max_bootnodes(origin as T::RuntimeOrigin, new_value);
+        set_less_than_min_nodes_subnet_reputation_factor(origin as T::RuntimeOrigin, new_value);
 
-        assert_eq!(MaxBootnodes::<T>::get(), new_value);
+        assert_eq!(
+            LessThanMinNodesSubnetReputationFactor::<T>::get(),
+            new_value
+        );
     }
 
     #[benchmark]
-    fn set_max_subnet_bootnodes_access() {
-        let value = MaxSubnetBootnodeAccess::<T>::get();
-        let new_value = value - 1;
+    fn set_max_emergency_subnet_nodes() {
+        let new_value = MinSubnetNodes::<T>::get() + 1;
 
         let origin = T::MajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        set_max_subnet_bootnodes_access(origin as T::RuntimeOrigin, new_value);
+        set_max_emergency_subnet_nodes(origin as T::RuntimeOrigin, new_value);
 
-        assert_eq!(MaxSubnetBootnodeAccess::<T>::get(), new_value);
+        assert_eq!(MaxEmergencySubnetNodes::<T>::get(), new_value);
     }
 
     #[benchmark]
-    fn set_max_pause_epochs() {
-        let value = MaxSubnetPauseEpochs::<T>::get();
-        let new_value = value - 1;
+    fn set_max_emergency_validator_epochs_multiplier() {
+        let new_value = Network::<T>::percentage_factor_as_u128() * 2;
 
         let origin = T::MajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        set_max_pause_epochs(origin as T::RuntimeOrigin, new_value);
+        set_max_emergency_validator_epochs_multiplier(origin as T::RuntimeOrigin, new_value);
 
-        assert_eq!(MaxSubnetPauseEpochs::<T>::get(), new_value);
+        assert_eq!(MaxEmergencyValidatorEpochsMultiplier::<T>::get(), new_value);
     }
 
     #[benchmark]
-    fn set_delegate_stake_subnet_removal_interval() {
-        let new_value = 1;
+    fn set_max_pause_epochs_subnet_reputation_factor() {
+        let new_value = Network::<T>::percentage_factor_as_u128() / 2;
 
         let origin = T::MajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        set_delegate_stake_subnet_removal_interval(origin as T::RuntimeOrigin, new_value);
+        set_max_pause_epochs_subnet_reputation_factor(origin as T::RuntimeOrigin, new_value);
 
-        assert_eq!(DelegateStakeSubnetRemovalInterval::<T>::get(), new_value);
+        assert_eq!(MaxPauseEpochsSubnetReputationFactor::<T>::get(), new_value);
     }
 
     #[benchmark]
-    fn set_subnet_removal_intervals() {
-        let min = 1;
-        let max = 2;
+    fn set_min_subnet_reputation() {
+        let new_value = Network::<T>::percentage_factor_as_u128() / 2;
 
         let origin = T::MajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        set_subnet_removal_intervals(origin as T::RuntimeOrigin, min, max);
+        set_min_subnet_reputation(origin as T::RuntimeOrigin, new_value);
 
-        assert_eq!(MinSubnetRemovalInterval::<T>::get(), min);
-        assert_eq!(MaxSubnetRemovalInterval::<T>::get(), max);
+        assert_eq!(MinSubnetReputation::<T>::get(), new_value);
     }
 
     #[benchmark]
-    fn set_subnet_pause_cooldown_epochs() {
-        let new_value = 1;
+    fn set_not_in_consensus_subnet_reputation_factor() {
+        let new_value = Network::<T>::percentage_factor_as_u128() / 2;
 
         let origin = T::MajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        set_subnet_pause_cooldown_epochs(origin as T::RuntimeOrigin, new_value);
+        set_not_in_consensus_subnet_reputation_factor(origin as T::RuntimeOrigin, new_value);
 
-        assert_eq!(SubnetPauseCooldownEpochs::<T>::get(), new_value);
+        assert_eq!(NotInConsensusSubnetReputationFactor::<T>::get(), new_value);
     }
 
     #[benchmark]
-    fn set_min_registration_cost() {
-        let value = MinRegistrationCost::<T>::get();
-        let new_value = value - 1;
+    fn set_overwatch_stake_weight_factor() {
+        let new_value = Network::<T>::percentage_factor_as_u128() * 2;
 
         let origin = T::MajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        set_min_registration_cost(origin as T::RuntimeOrigin, new_value);
+        set_overwatch_stake_weight_factor(origin as T::RuntimeOrigin, new_value);
 
-        assert_eq!(MinRegistrationCost::<T>::get(), new_value);
+        assert_eq!(OverwatchStakeWeightFactor::<T>::get(), new_value);
     }
 
     #[benchmark]
-    fn set_registration_cost_delay_blocks() {
-        let value = RegistrationCostDecayBlocks::<T>::get();
-        let new_value = value - 1;
+    fn set_overwatch_weight_factor() {
+        let new_value = Network::<T>::percentage_factor_as_u128() / 2;
 
         let origin = T::MajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        set_registration_cost_delay_blocks(origin as T::RuntimeOrigin, new_value);
+        set_overwatch_weight_factor(origin as T::RuntimeOrigin, new_value);
 
-        assert_eq!(RegistrationCostDecayBlocks::<T>::get(), new_value);
+        assert_eq!(OverwatchWeightFactor::<T>::get(), new_value);
     }
 
     #[benchmark]
-    fn set_registration_cost_alpha() {
-        let value = RegistrationCostAlpha::<T>::get();
-        let new_value = value - 1;
+    fn set_validator_proposal_absent_subnet_reputation_factor() {
+        let new_value = Network::<T>::percentage_factor_as_u128() / 2;
 
         let origin = T::MajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        set_registration_cost_alpha(origin as T::RuntimeOrigin, new_value);
+        set_validator_proposal_absent_subnet_reputation_factor(
+            origin as T::RuntimeOrigin,
+            new_value,
+        );
 
-        assert_eq!(RegistrationCostAlpha::<T>::get(), new_value);
+        assert_eq!(ValidatorAbsentSubnetReputationFactor::<T>::get(), new_value);
     }
 
     #[benchmark]
-    fn set_new_registration_cost_multiplier() {
-        let value = NewRegistrationCostMultiplier::<T>::get();
-        let new_value = value - 1;
+    fn set_validator_reward_k() {
+        let new_value = 2;
 
         let origin = T::MajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        set_new_registration_cost_multiplier(origin as T::RuntimeOrigin, new_value);
+        set_validator_reward_k(origin as T::RuntimeOrigin, new_value);
 
-        assert_eq!(NewRegistrationCostMultiplier::<T>::get(), new_value);
+        assert_eq!(ValidatorRewardK::<T>::get(), new_value);
     }
 
     #[benchmark]
-    fn set_max_min_delegate_stake_multiplier() {
-        let value = MaxMinDelegateStakeMultiplier::<T>::get();
-        let new_value = value - 1;
+    fn set_validator_reward_midpoint() {
+        let new_value = Network::<T>::percentage_factor_as_u128() / 2;
 
-        let origin = T::SuperMajorityCollectiveOrigin::try_successful_origin()
+        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        set_max_min_delegate_stake_multiplier(origin as T::RuntimeOrigin, new_value);
+        set_validator_reward_midpoint(origin as T::RuntimeOrigin, new_value);
 
-        assert_eq!(MaxMinDelegateStakeMultiplier::<T>::get(), new_value);
+        assert_eq!(ValidatorRewardMidpoint::<T>::get(), new_value);
     }
 
     #[benchmark]
-    fn set_churn_limits() {
+    fn set_churn_limit_multipliers() {
         let min = 1;
-        let max = 2;
+        let max = 4;
 
         let origin = T::MajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        set_churn_limits(origin as T::RuntimeOrigin, min, max);
+        set_churn_limit_multipliers(origin as T::RuntimeOrigin, min, max);
 
-        assert_eq!(MinChurnLimit::<T>::get(), min);
-        assert_eq!(MaxChurnLimit::<T>::get(), max);
+        assert_eq!(MinChurnLimitMultiplier::<T>::get(), min);
+        assert_eq!(MaxChurnLimitMultiplier::<T>::get(), max);
     }
 
     #[benchmark]
-    fn set_queue_epochs() {
-        let min = 1;
-        let max = 2;
+    fn set_min_max_node_reputation() {
+        let min = Network::<T>::percentage_factor_as_u128() / 10;
+        let max = Network::<T>::percentage_factor_as_u128() / 2;
 
         let origin = T::MajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        set_queue_epochs(origin as T::RuntimeOrigin, min, max);
+        set_min_max_node_reputation(origin as T::RuntimeOrigin, min, max);
 
-        assert_eq!(MinQueueEpochs::<T>::get(), min);
-        assert_eq!(MaxQueueEpochs::<T>::get(), max);
+        assert_eq!(MinMinSubnetNodeReputation::<T>::get(), min);
+        assert_eq!(MaxMinSubnetNodeReputation::<T>::get(), max);
     }
 
     #[benchmark]
-    fn set_max_swap_queue_calls_per_block() {
-        let value = MaxSwapQueueCallsPerBlock::<T>::get();
-        let new_value = value - 1;
+    fn set_min_max_node_reputation_factor() {
+        let min = Network::<T>::percentage_factor_as_u128() / 10;
+        let max = Network::<T>::percentage_factor_as_u128() / 2;
 
         let origin = T::MajorityCollectiveOrigin::try_successful_origin()
             .expect("try_successful_origin failed");
 
         #[extrinsic_call]
-        set_max_swap_queue_calls_per_block(origin as T::RuntimeOrigin, new_value);
+        set_min_max_node_reputation_factor(origin as T::RuntimeOrigin, min, max);
 
-        assert_eq!(MaxSwapQueueCallsPerBlock::<T>::get(), new_value);
+        assert_eq!(MinNodeReputationFactor::<T>::get(), min);
+        assert_eq!(MaxNodeReputationFactor::<T>::get(), max);
+    }
+
+    #[benchmark]
+    fn set_subnet_weight_factors() {
+        let new_value = SubnetWeightFactorsData {
+            delegate_stake: Network::<T>::percentage_factor_as_u128() / 4,
+            node_count: Network::<T>::percentage_factor_as_u128() / 4,
+            net_flow: Network::<T>::percentage_factor_as_u128() / 4,
+        };
+
+        let origin = T::MajorityCollectiveOrigin::try_successful_origin()
+            .expect("try_successful_origin failed");
+
+        #[extrinsic_call]
+        set_subnet_weight_factors(origin as T::RuntimeOrigin, new_value.clone());
+
+        assert_eq!(SubnetWeightFactors::<T>::get(), new_value);
     }
 
     #[benchmark]
@@ -5867,6 +6735,143 @@ mod benchmarks {
         };
     }
 
+    #[benchmark]
+    fn cancel_swap_queue_item() {
+        let deposit_amount: u128 = 10000000000000000000000;
+        let amount: u128 = 1000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<T>::get();
+        let end = MinSubnetNodes::<T>::get();
+
+        let from_subnet_name: Vec<u8> = "subnet-name".into();
+        build_activated_subnet::<T>(
+            from_subnet_name.clone().into(),
+            0,
+            end,
+            DEFAULT_DEPOSIT_AMOUNT,
+            DEFAULT_SUBNET_NODE_STAKE,
+        );
+        let from_subnet_id = SubnetName::<T>::get(from_subnet_name.clone()).unwrap();
+
+        let to_subnet_name: Vec<u8> = "subnet-name-2".into();
+        build_activated_subnet::<T>(
+            to_subnet_name.clone().into(),
+            0,
+            end,
+            DEFAULT_DEPOSIT_AMOUNT,
+            DEFAULT_SUBNET_NODE_STAKE,
+        );
+        let to_subnet_id = SubnetName::<T>::get(to_subnet_name.clone()).unwrap();
+
+        let account = get_account::<T>("account", 0);
+
+        let _ = T::Currency::deposit_creating(
+            &account.clone(),
+            (amount + 500).try_into().ok().expect("REASON"),
+        );
+
+        let total_subnet_delegate_stake_shares =
+            TotalSubnetDelegateStakeShares::<T>::get(from_subnet_id);
+        let total_subnet_delegate_stake_balance =
+            TotalSubnetDelegateStakeBalance::<T>::get(from_subnet_id);
+
+        let mut delegate_stake_to_be_added_as_shares = Network::<T>::convert_to_shares(
+            amount,
+            total_subnet_delegate_stake_shares,
+            total_subnet_delegate_stake_balance,
+        );
+
+        if total_subnet_delegate_stake_shares == 0 {
+            delegate_stake_to_be_added_as_shares =
+                delegate_stake_to_be_added_as_shares.saturating_sub(1000);
+        }
+
+        frame_system::Pallet::<T>::set_block_number(u32_to_block::<T>(
+            get_current_block_as_u32::<T>()
+                + DelegateStakeCooldownEpochs::<T>::get() * T::EpochLength::get()
+                + 1,
+        ));
+
+        let starting_delegator_balance = T::Currency::free_balance(&account.clone());
+
+        assert_ok!(Network::<T>::add_to_delegate_stake(
+            RawOrigin::Signed(account.clone()).into(),
+            from_subnet_id,
+            amount,
+        ));
+
+        let delegate_shares =
+            AccountSubnetDelegateStakeShares::<T>::get(account.clone(), from_subnet_id);
+        assert_eq!(delegate_shares, delegate_stake_to_be_added_as_shares);
+        assert_ne!(delegate_shares, 0);
+
+        let total_subnet_delegate_stake_shares =
+            TotalSubnetDelegateStakeShares::<T>::get(from_subnet_id);
+        let total_subnet_delegate_stake_balance =
+            TotalSubnetDelegateStakeBalance::<T>::get(from_subnet_id);
+
+        let mut from_delegate_balance = Network::<T>::convert_to_balance(
+            delegate_shares,
+            total_subnet_delegate_stake_shares,
+            total_subnet_delegate_stake_balance,
+        );
+        // The first depositor will lose a percentage of their deposit depending on the size
+        // https://docs.openzeppelin.com/contracts/4.x/erc4626#inflation-attack
+        // assert_eq!(from_delegate_balance, delegate_stake_to_be_added_as_shares);
+
+        let prev_total_subnet_delegate_stake_balance =
+            TotalSubnetDelegateStakeBalance::<T>::get(from_subnet_id);
+        let prev_next_id = NextSwapQueueId::<T>::get();
+
+        assert_ok!(Network::<T>::swap_delegate_stake(
+            RawOrigin::Signed(account.clone()).into(),
+            from_subnet_id,
+            to_subnet_id,
+            delegate_shares,
+        ));
+        let from_delegate_shares =
+            AccountSubnetDelegateStakeShares::<T>::get(account.clone(), from_subnet_id);
+        assert_eq!(from_delegate_shares, 0);
+
+        assert_ne!(
+            prev_total_subnet_delegate_stake_balance,
+            TotalSubnetDelegateStakeBalance::<T>::get(from_subnet_id)
+        );
+        assert!(
+            prev_total_subnet_delegate_stake_balance
+                > TotalSubnetDelegateStakeBalance::<T>::get(from_subnet_id)
+        );
+
+        // Check the queue
+        let starting_to_subnet_id = to_subnet_id;
+        let call_queue = SwapCallQueue::<T>::get(prev_next_id);
+        assert_eq!(call_queue.clone().unwrap().id, prev_next_id);
+        match &call_queue.clone().unwrap().call {
+            QueuedSwapCall::SwapToSubnetDelegateStake {
+                account_id,
+                to_subnet_id,
+                balance,
+            } => {
+                assert_eq!(*account_id, account.clone());
+                assert_eq!(*to_subnet_id, starting_to_subnet_id);
+                assert_ne!(*balance, 0);
+            }
+            QueuedSwapCall::SwapToNodeDelegateStake { .. } => assert!(false),
+        };
+
+        let next_id = NextSwapQueueId::<T>::get();
+        assert_eq!(prev_next_id + 1, next_id);
+        let queue = SwapQueueOrder::<T>::get();
+        assert!(queue
+            .first()
+            .map_or(false, |&first_id| first_id == prev_next_id));
+
+        #[extrinsic_call]
+        cancel_swap_queue_item(RawOrigin::Signed(account.clone()), prev_next_id);
+
+        assert!(SwapCallQueue::<T>::get(prev_next_id).is_none());
+        assert!(!SwapQueueOrder::<T>::get().contains(&prev_next_id));
+    }
+
     #[benchmark]
     fn elect_validator(x: Linear<3, 64>) {
         // x: min nodes, max nodes
@@ -5906,6 +6911,9 @@ mod benchmarks {
             AccountSubnetDelegateStakeShares::<T>::get(&account_id, subnet_id),
             0
         );
+        // Worst case, the shares are checkpointed
+        enable_reward_history::<T>();
+
         #[block]
         {
             Network::<T>::handle_increase_account_delegate_stake(
@@ -5933,6 +6941,9 @@ mod benchmarks {
             AccountNodeDelegateStakeShares::<T>::get((&account_id, subnet_id, subnet_node_id)),
             0
         );
+        // Worst case, the shares are checkpointed
+        enable_reward_history::<T>();
+
         #[block]
         {
             Network::<T>::handle_increase_account_node_delegate_stake_shares(
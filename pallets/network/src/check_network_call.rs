@@ -0,0 +1,240 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use codec::{Decode, Encode};
+use core::marker::PhantomData;
+use frame_support::{pallet_prelude::Weight, traits::IsSubType};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    impl_tx_ext_default,
+    traits::{
+        AsSystemOriginSigner, DispatchInfoOf, Dispatchable, TransactionExtension, ValidateResult,
+    },
+    transaction_validity::{
+        InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidityError,
+        ValidTransaction,
+    },
+};
+
+/// Priority of a valid `propose_attestation`, the call every other attestation waits on
+pub const PROPOSE_ATTESTATION_PRIORITY: TransactionPriority = TransactionPriority::MAX / 2;
+
+/// Priority of a valid `attest`
+pub const ATTEST_PRIORITY: TransactionPriority = TransactionPriority::MAX / 4;
+
+/// Priority of a valid overwatch commit or reveal
+pub const OVERWATCH_PRIORITY: TransactionPriority = TransactionPriority::MAX / 8;
+
+/// Tag prefix of `propose_attestation`, `attest` requires its tag until the proposal is on chain
+pub const PROPOSE_ATTESTATION_TAG_PREFIX: &str = "NetworkProposeAttestation";
+
+/// Storage reads of `validate_propose_attestation` when the backup validator proposes
+///
+/// `SubnetSlot`, `System::Number`, `SubnetElectedValidator`, `SubnetBackupValidator`, the
+/// `SubnetNodeIdHotkey` of both, `BackupProposerEpochProgression` and
+/// `SubnetConsensusSubmission`.
+const VALIDATE_PROPOSE_ATTESTATION_READS: u64 = 8;
+
+/// Storage reads of `validate_attest`
+///
+/// `SubnetSlot`, `System::Number`, `HotkeySubnetNodeId`, `SubnetNodesData`,
+/// `EmergencySubnetNodeElectionData` and `SubnetConsensusSubmission`.
+const VALIDATE_ATTEST_READS: u64 = 6;
+
+/// Storage reads of `validate_overwatch_call`
+///
+/// `OverwatchNodeIdHotkey`, `HotkeyOwner`, `OverwatchNodeBlacklist`, `System::Number`,
+/// `OverwatchEpochLengthMultiplier` and `OverwatchCommitCutoffPercent`.
+const VALIDATE_OVERWATCH_CALL_READS: u64 = 6;
+
+/// `InvalidTransaction::Custom` codes returned by `CheckNetworkCall`
+#[repr(u8)]
+pub enum CheckNetworkCallError {
+    /// The signer isn't the subnet epoch's elected (or eligible backup) validator
+    NotEpochValidator = 0,
+    /// Consensus data was already submitted for the subnet epoch
+    AlreadyProposed = 1,
+    /// The signer's subnet node can't attest the subnet epoch's consensus data
+    NotAttestor = 2,
+    /// The signer's subnet node already attested the subnet epoch's consensus data
+    AlreadyAttested = 3,
+    /// The signer isn't a key of the overwatch node, or its coldkey is blacklisted
+    NotOverwatchNode = 4,
+    /// The overwatch epoch isn't in its commit period
+    NotCommitPeriod = 5,
+    /// The overwatch epoch isn't in its reveal period
+    NotRevealPeriod = 6,
+}
+
+impl From<CheckNetworkCallError> for TransactionValidityError {
+    fn from(error: CheckNetworkCallError) -> Self {
+        InvalidTransaction::Custom(error as u8).into()
+    }
+}
+
+/// Validates the network pallet's fee-waived calls before they enter the transaction pool
+///
+/// `propose_attestation`, `attest` and the overwatch commit and reveal calls don't pay fees, so
+/// without this extension any account could fill the pool with them for free and they'd only
+/// fail on execution. Valid calls are given a priority above fee-paying transactions, and a
+/// `provides` tag so only one per validator, attestor or overwatch node is pooled at a time.
+/// Attestations of consensus data that isn't on chain yet require the proposal's tag, so they're
+/// only included after it.
+///
+/// Every other call passes through untouched.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, Default, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckNetworkCall<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> CheckNetworkCall<T> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: Config + Send + Sync> core::fmt::Debug for CheckNetworkCall<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "CheckNetworkCall")
+    }
+}
+
+impl<T: Config + Send + Sync> CheckNetworkCall<T> {
+    /// Validate `call` signed by `who`, or `None` if it isn't one of the checked calls
+    pub fn validate_network_call(
+        who: &T::AccountId,
+        call: &Call<T>,
+    ) -> Option<Result<ValidTransaction, TransactionValidityError>> {
+        let result: Result<ValidTransaction, CheckNetworkCallError> = match call {
+            Call::propose_attestation { subnet_id, .. } => {
+                Pallet::<T>::validate_propose_attestation(*subnet_id, who)
+                    .map_err(|error| match error {
+                        Error::<T>::SubnetRewardsAlreadySubmitted => {
+                            CheckNetworkCallError::AlreadyProposed
+                        }
+                        _ => CheckNetworkCallError::NotEpochValidator,
+                    })
+                    .map(|subnet_epoch| {
+                        ValidTransaction::with_tag_prefix(PROPOSE_ATTESTATION_TAG_PREFIX)
+                            .priority(PROPOSE_ATTESTATION_PRIORITY)
+                            .and_provides((subnet_id, subnet_epoch))
+                            .into()
+                    })
+            }
+            Call::attest { subnet_id, .. } => Pallet::<T>::validate_attest(*subnet_id, who)
+                .map_err(|error| match error {
+                    Error::<T>::AlreadyAttested => CheckNetworkCallError::AlreadyAttested,
+                    _ => CheckNetworkCallError::NotAttestor,
+                })
+                .map(|(subnet_epoch, subnet_node_id, proposed)| {
+                    let mut valid: ValidTransaction =
+                        ValidTransaction::with_tag_prefix("NetworkAttest")
+                            .priority(ATTEST_PRIORITY)
+                            .and_provides((subnet_id, subnet_epoch, subnet_node_id))
+                            .into();
+                    if !proposed {
+                        valid.requires.push(
+                            (PROPOSE_ATTESTATION_TAG_PREFIX, (subnet_id, subnet_epoch)).encode(),
+                        );
+                    }
+                    valid
+                }),
+            Call::commit_overwatch_subnet_weights {
+                overwatch_node_id, ..
+            } => Self::validate_overwatch_call(*overwatch_node_id, who, true),
+            Call::reveal_overwatch_subnet_weights {
+                overwatch_node_id, ..
+            } => Self::validate_overwatch_call(*overwatch_node_id, who, false),
+            _ => return None,
+        };
+
+        Some(result.map_err(Into::into))
+    }
+
+    fn validate_overwatch_call(
+        overwatch_node_id: u32,
+        who: &T::AccountId,
+        commit: bool,
+    ) -> Result<ValidTransaction, CheckNetworkCallError> {
+        Pallet::<T>::ensure_overwatch_node_caller(overwatch_node_id, who)
+            .map_err(|_| CheckNetworkCallError::NotOverwatchNode)?;
+
+        match (commit, Pallet::<T>::in_overwatch_commit_period()) {
+            (true, false) => return Err(CheckNetworkCallError::NotCommitPeriod),
+            (false, true) => return Err(CheckNetworkCallError::NotRevealPeriod),
+            _ => {}
+        }
+
+        let overwatch_epoch = Pallet::<T>::get_current_overwatch_epoch_as_u32();
+
+        Ok(ValidTransaction::with_tag_prefix(if commit {
+            "NetworkOverwatchCommit"
+        } else {
+            "NetworkOverwatchReveal"
+        })
+        .priority(OVERWATCH_PRIORITY)
+        .and_provides((overwatch_node_id, overwatch_epoch))
+        .into())
+    }
+}
+
+impl<T: Config + Send + Sync> TransactionExtension<<T as frame_system::Config>::RuntimeCall>
+    for CheckNetworkCall<T>
+where
+    <T as frame_system::Config>::RuntimeCall: Dispatchable + IsSubType<Call<T>>,
+    <<T as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
+        AsSystemOriginSigner<T::AccountId> + Clone,
+{
+    const IDENTIFIER: &'static str = "CheckNetworkCall";
+    type Implicit = ();
+    type Val = ();
+    type Pre = ();
+
+    fn weight(&self, call: &<T as frame_system::Config>::RuntimeCall) -> Weight {
+        match call.is_sub_type() {
+            Some(Call::propose_attestation { .. }) => {
+                T::DbWeight::get().reads(VALIDATE_PROPOSE_ATTESTATION_READS)
+            }
+            Some(Call::attest { .. }) => T::DbWeight::get().reads(VALIDATE_ATTEST_READS),
+            Some(Call::commit_overwatch_subnet_weights { .. })
+            | Some(Call::reveal_overwatch_subnet_weights { .. }) => {
+                T::DbWeight::get().reads(VALIDATE_OVERWATCH_CALL_READS)
+            }
+            _ => Weight::zero(),
+        }
+    }
+
+    fn validate(
+        &self,
+        origin: <<T as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin,
+        call: &<T as frame_system::Config>::RuntimeCall,
+        _info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+        _len: usize,
+        _self_implicit: Self::Implicit,
+        _inherited_implication: &impl Encode,
+        _source: TransactionSource,
+    ) -> ValidateResult<Self::Val, <T as frame_system::Config>::RuntimeCall> {
+        let (Some(who), Some(call)) = (origin.as_system_origin_signer(), call.is_sub_type()) else {
+            return Ok((Default::default(), (), origin));
+        };
+
+        match Self::validate_network_call(who, call) {
+            Some(result) => Ok((result?, (), origin)),
+            None => Ok((Default::default(), (), origin)),
+        }
+    }
+
+    impl_tx_ext_default!(<T as frame_system::Config>::RuntimeCall; prepare);
+}
@@ -0,0 +1,98 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::pallet_prelude::Weight;
+
+impl<T: Config> Pallet<T> {
+    /// Archive the finalized consensus data of a subnet epoch
+    ///
+    /// The first archived epoch of a subnet is where pruning starts from
+    pub fn archive_consensus_submission(
+        subnet_id: u32,
+        subnet_epoch: u32,
+        consensus_submission_data: ConsensusSubmissionData<T::AccountId>,
+    ) -> Weight {
+        let db_weight = T::DbWeight::get();
+
+        ConsensusSubmissionArchive::<T>::insert(subnet_id, subnet_epoch, consensus_submission_data);
+
+        if !ConsensusArchivePruneEpoch::<T>::contains_key(subnet_id) {
+            ConsensusArchivePruneEpoch::<T>::insert(subnet_id, subnet_epoch);
+        }
+
+        // Reads:
+        // - ConsensusArchivePruneEpoch
+        // Writes:
+        // - ConsensusSubmissionArchive
+        // - ConsensusArchivePruneEpoch
+        db_weight.reads_writes(1, 2)
+    }
+
+    /// Prune `SubnetConsensusSubmission`, `ConsensusSubmissionArchive`, `SubnetElectedValidator`
+    /// and `SubnetBackupValidator`
+    ///
+    /// Subnets keep their last `ConsensusArchiveDepth` subnet epochs, older epochs are removed one
+    /// at a time starting from `ConsensusArchivePruneEpoch`. Removed subnets are cleared through
+    /// the `CleanupQueue` instead.
+    ///
+    /// Stops once `weight_meter` can't afford the next step, the rest is pruned in later blocks.
+    pub fn prune_consensus_archive(weight_meter: &mut WeightMeter) {
+        let db_weight = T::DbWeight::get();
+
+        // ConsensusArchiveDepth
+        if !weight_meter.can_consume(db_weight.reads(1)) {
+            return;
+        }
+        weight_meter.consume(db_weight.reads(1));
+        let depth = ConsensusArchiveDepth::<T>::get();
+
+        for (subnet_id, prune_epoch) in ConsensusArchivePruneEpoch::<T>::iter() {
+            // ConsensusArchivePruneEpoch | SubnetSlot
+            if !weight_meter.can_consume(db_weight.reads(2)) {
+                return;
+            }
+            weight_meter.consume(db_weight.reads(2));
+
+            let cutoff = Self::get_current_subnet_epoch_as_u32(subnet_id).saturating_sub(depth);
+
+            if prune_epoch >= cutoff {
+                continue;
+            }
+
+            // ConsensusArchivePruneEpoch, charged with the first epoch so it is always written
+            if !weight_meter.can_consume(db_weight.writes(5)) {
+                return;
+            }
+            weight_meter.consume(db_weight.writes(1));
+
+            let mut epoch = prune_epoch;
+            while epoch < cutoff && weight_meter.can_consume(db_weight.writes(4)) {
+                SubnetConsensusSubmission::<T>::remove(subnet_id, epoch);
+                ConsensusSubmissionArchive::<T>::remove(subnet_id, epoch);
+                SubnetElectedValidator::<T>::remove(subnet_id, epoch);
+                SubnetBackupValidator::<T>::remove(subnet_id, epoch);
+                weight_meter.consume(db_weight.writes(4));
+                epoch += 1;
+            }
+
+            ConsensusArchivePruneEpoch::<T>::insert(subnet_id, epoch);
+
+            if epoch < cutoff {
+                return;
+            }
+        }
+    }
+}
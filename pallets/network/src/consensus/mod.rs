@@ -1,2 +1,3 @@
 use super::*;
+pub mod archive;
 pub mod subnet_validator;
@@ -17,7 +17,6 @@ use super::*;
 use frame_support::pallet_prelude::DispatchResultWithPostInfo;
 use frame_support::pallet_prelude::Pays;
 use frame_support::pallet_prelude::Weight;
-use libm::{exp, fmax, fmin};
 
 impl<T: Config> Pallet<T> {
     /// Proposes attestation and submits consensus data for a subnet epoch.
@@ -32,8 +31,8 @@ impl<T: Config> Pallet<T> {
     /// * `subnet_id` - The ID of the subnet for which consensus data is being submitted.
     /// * `hotkey` - The hotkey of the elected validator submitting the consensus data.
     /// * `data` - A vector of consensus data containing scores for each peer in the subnet.
-    ///   Duplicates (based on `subnet_node_id`) are automatically removed, and only peers
-    ///   with `Included` classification are retained.
+    ///   Sorted by `subnet_node_id`, a `subnet_node_id` may only appear once. Only peers with
+    ///   `Included` classification are retained.
     /// * `prioritize_queue_node_id` - Optional node ID from the registration queue to move
     ///   to the front of the queue. The node must exist in the queue or this parameter is ignored.
     /// * `remove_queue_node_id` - Optional node ID from the registration queue to remove.
@@ -54,8 +53,8 @@ impl<T: Config> Pallet<T> {
     /// 2. Verifies the caller is the elected validator for this epoch
     /// 3. Ensures consensus has not already been submitted for this epoch
     /// 4. Qualifies the consensus data by:
-    ///    - Removing duplicates based on peer_id
-    ///    - Filtering out non-Included peers
+    ///    - Sorting it by `subnet_node_id` and rejecting duplicate `subnet_node_id`s
+    ///    - Filtering out unknown and non-Included peers, reported in `ConsensusDataRejected`
     ///    - Validating scores don't overflow when summed
     /// 5. Validates queue operations (prioritize/remove) if specified
     /// 6. Stores the consensus submission with the validator's auto-attestation
@@ -65,8 +64,16 @@ impl<T: Config> Pallet<T> {
     /// * `NoElectedValidator` - No validator is elected for the current subnet epoch
     /// * `InvalidValidator` - The caller's hotkey doesn't match the elected validator
     /// * `SubnetRewardsAlreadySubmitted` - Consensus has already been submitted for this epoch
+    /// * `ConsensusDataTooLong` - `data` has more entries than `MaxSubnetNodes`
+    /// * `DuplicateSubnetNodeId` - `data` contains the same `subnet_node_id` more than once
     /// * `ScoreOverflow` - The sum of all scores would overflow u128
     ///
+    /// # Events
+    ///
+    /// * `ConsensusDataRejected` - Entries of `data` that were removed because their subnet node
+    ///   doesn't exist or isn't `Included`, with the reason for each. Only emitted if any were
+    ///   removed.
+    ///
     /// # Returns
     ///
     /// Returns `Ok(Pays::No.into())` on success, indicating the transaction fee is waived.
@@ -89,16 +96,12 @@ impl<T: Config> Pallet<T> {
         let subnet_epoch = subnet_epoch_data.subnet_epoch;
         let subnet_epoch_progression = subnet_epoch_data.subnet_epoch_progression;
 
-        // --- Ensure current subnet validator by its hotkey
-        let validator_id = SubnetElectedValidator::<T>::get(subnet_id, subnet_epoch)
-            .ok_or(Error::<T>::NoElectedValidator)?;
-
-        // --- If hotkey is hotkey, ensure it matches validator, otherwise if coldkey -> get hotkey
-        // If the epoch is 0, this will break
-        ensure!(
-            SubnetNodeIdHotkey::<T>::get(subnet_id, validator_id) == Some(hotkey.clone()),
-            Error::<T>::InvalidValidator
-        );
+        let (validator_id, is_backup) = Self::get_proposing_validator_id(
+            subnet_id,
+            &hotkey,
+            subnet_epoch,
+            subnet_epoch_progression,
+        )?;
 
         // - Note: we don't check stake balance here
 
@@ -112,17 +115,41 @@ impl<T: Config> Pallet<T> {
         // --- Qualify the data
         //
 
-        // Remove duplicates based on peer_id
-        data.dedup_by(|a, b| a.subnet_node_id == b.subnet_node_id);
+        ensure!(
+            data.len() as u32 <= MaxSubnetNodes::<T>::get(),
+            Error::<T>::ConsensusDataTooLong
+        );
+
+        // Canonical order, duplicates are adjacent once sorted
+        data.sort_by_key(|x| x.subnet_node_id);
+        ensure!(
+            !data
+                .windows(2)
+                .any(|pair| pair[0].subnet_node_id == pair[1].subnet_node_id),
+            Error::<T>::DuplicateSubnetNodeId
+        );
 
         // Remove queue classified entries
         // Each peer must have an inclusion classification at minimum
+        let mut rejected: Vec<(u32, ConsensusDataRejectionReason)> = Vec::new();
         data.retain(
             |x| match SubnetNodesData::<T>::try_get(subnet_id, x.subnet_node_id) {
                 Ok(subnet_node) => {
-                    subnet_node.has_classification(&SubnetNodeClass::Included, subnet_epoch)
+                    let included =
+                        subnet_node.has_classification(&SubnetNodeClass::Included, subnet_epoch);
+                    if !included {
+                        rejected
+                            .push((x.subnet_node_id, ConsensusDataRejectionReason::NotIncluded));
+                    }
+                    included
+                }
+                Err(()) => {
+                    rejected.push((
+                        x.subnet_node_id,
+                        ConsensusDataRejectionReason::UnknownSubnetNodeId,
+                    ));
+                    false
                 }
-                Err(()) => false,
             },
         );
 
@@ -197,9 +224,11 @@ impl<T: Config> Pallet<T> {
             validator_id: validator_id,
             block,
             validator_epoch_progress: subnet_epoch_progression,
-            validator_reward_factor: Self::get_validator_reward_multiplier(
-                subnet_epoch_progression,
-            ),
+            validator_reward_factor: if is_backup {
+                Self::get_backup_validator_reward_multiplier(subnet_epoch_progression)
+            } else {
+                Self::get_validator_reward_multiplier(subnet_epoch_progression)
+            },
             attests: attests,
             subnet_nodes: subnet_nodes,
             prioritize_queue_node_id: prioritize_queue_node_id,
@@ -210,11 +239,28 @@ impl<T: Config> Pallet<T> {
 
         SubnetConsensusSubmission::<T>::insert(subnet_id, subnet_epoch, consensus_data);
 
-        Self::deposit_event(Event::ValidatorSubmission {
-            subnet_id: subnet_id,
-            account_id: hotkey,
-            epoch: subnet_epoch,
-        });
+        if !rejected.is_empty() {
+            Self::deposit_event(Event::ConsensusDataRejected {
+                subnet_id: subnet_id,
+                epoch: subnet_epoch,
+                // `data` is bounded by `MaxSubnetNodes` above
+                rejected: BoundedVec::truncate_from(rejected),
+            });
+        }
+
+        if is_backup {
+            Self::deposit_event(Event::BackupValidatorSubmission {
+                subnet_id: subnet_id,
+                account_id: hotkey,
+                epoch: subnet_epoch,
+            });
+        } else {
+            Self::deposit_event(Event::ValidatorSubmission {
+                subnet_id: subnet_id,
+                account_id: hotkey,
+                epoch: subnet_epoch,
+            });
+        }
 
         Ok(Pays::No.into())
     }
@@ -392,25 +438,160 @@ impl<T: Config> Pallet<T> {
         Ok(Pays::No.into())
     }
 
+    /// Get the subnet node ID `hotkey` proposes as in `subnet_epoch`, and whether it's the backup
+    ///
+    /// The elected validator can always propose. The backup validator can once the epoch
+    /// progression reaches `BackupProposerEpochProgression`.
+    pub fn get_proposing_validator_id(
+        subnet_id: u32,
+        hotkey: &T::AccountId,
+        subnet_epoch: u32,
+        subnet_epoch_progression: u128,
+    ) -> Result<(u32, bool), Error<T>> {
+        // --- Ensure current subnet validator by its hotkey
+        let elected_validator_id = SubnetElectedValidator::<T>::get(subnet_id, subnet_epoch)
+            .ok_or(Error::<T>::NoElectedValidator)?;
+
+        // If the epoch is 0, this will break
+        if SubnetNodeIdHotkey::<T>::get(subnet_id, elected_validator_id).as_ref() == Some(hotkey) {
+            return Ok((elected_validator_id, false));
+        }
+
+        // --- Otherwise it must be the backup validator once its window is open
+        // Before `BackupProposerEpochProgression` the backup isn't a validator of the epoch
+        let backup_validator_id = SubnetBackupValidator::<T>::get(subnet_id, subnet_epoch)
+            .ok_or(Error::<T>::InvalidValidator)?;
+        ensure!(
+            SubnetNodeIdHotkey::<T>::get(subnet_id, backup_validator_id).as_ref() == Some(hotkey)
+                && subnet_epoch_progression >= BackupProposerEpochProgression::<T>::get(),
+            Error::<T>::InvalidValidator
+        );
+
+        Ok((backup_validator_id, true))
+    }
+
+    /// Check `hotkey` can propose the current epoch's consensus data without writing anything
+    ///
+    /// Used to reject fee-waived `propose_attestation` calls at transaction pool admission,
+    /// see `CheckNetworkCall`. Returns the subnet epoch.
+    pub fn validate_propose_attestation(
+        subnet_id: u32,
+        hotkey: &T::AccountId,
+    ) -> Result<u32, Error<T>> {
+        let subnet_epoch_data = Self::get_current_subnet_epoch_data(subnet_id)
+            .ok_or(Error::<T>::SubnetEpochDataIsNone)?;
+        let subnet_epoch = subnet_epoch_data.subnet_epoch;
+
+        Self::get_proposing_validator_id(
+            subnet_id,
+            hotkey,
+            subnet_epoch,
+            subnet_epoch_data.subnet_epoch_progression,
+        )?;
+
+        ensure!(
+            !SubnetConsensusSubmission::<T>::contains_key(subnet_id, subnet_epoch),
+            Error::<T>::SubnetRewardsAlreadySubmitted
+        );
+
+        Ok(subnet_epoch)
+    }
+
+    /// Check `hotkey` can attest the current epoch's consensus data without writing anything
+    ///
+    /// The node must be `Included`, in the emergency validator set if there is one and, once the
+    /// consensus data is proposed, part of the submission's node set and not have attested yet.
+    /// Used to reject fee-waived `attest` calls at transaction pool admission, see
+    /// `CheckNetworkCall`. Returns the subnet epoch, the subnet node ID and whether the consensus
+    /// data was proposed.
+    pub fn validate_attest(
+        subnet_id: u32,
+        hotkey: &T::AccountId,
+    ) -> Result<(u32, u32, bool), Error<T>> {
+        let subnet_epoch = Self::get_current_subnet_epoch_as_u32(subnet_id);
+
+        let subnet_node_id = HotkeySubnetNodeId::<T>::try_get(subnet_id, hotkey)
+            .map_err(|_| Error::<T>::InvalidHotkeySubnetNodeId)?;
+
+        let subnet_node = SubnetNodesData::<T>::try_get(subnet_id, subnet_node_id)
+            .map_err(|_| Error::<T>::InvalidSubnetNodeId)?;
+        ensure!(
+            subnet_node.has_classification(&SubnetNodeClass::Included, subnet_epoch),
+            Error::<T>::InvalidSubnetNodeId
+        );
+
+        if let Some(emergency_validator_data) = EmergencySubnetNodeElectionData::<T>::get(subnet_id)
+        {
+            ensure!(
+                emergency_validator_data
+                    .subnet_node_ids
+                    .contains(&subnet_node_id),
+                Error::<T>::InvalidEmergencySubnetNodeId
+            );
+        }
+
+        // The proposal may still be in the transaction pool
+        let Some(submission) = SubnetConsensusSubmission::<T>::get(subnet_id, subnet_epoch) else {
+            return Ok((subnet_epoch, subnet_node_id, false));
+        };
+        ensure!(
+            submission
+                .subnet_nodes
+                .iter()
+                .any(|node| node.id == subnet_node_id),
+            Error::<T>::InvalidSubnetNodeId
+        );
+        ensure!(
+            !submission.attests.contains_key(&subnet_node_id),
+            Error::<T>::AlreadyAttested
+        );
+
+        Ok((subnet_epoch, subnet_node_id, true))
+    }
+
     pub fn get_attestor_reward_multiplier(progress: u128) -> u128 {
-        Self::get_f64_as_percentage(Self::concave_down_decreasing(
-            Self::get_percent_as_f64(progress),
-            Self::get_percent_as_f64(AttestorMinRewardFactor::<T>::get()),
-            1.0,
-            AttestorRewardExponent::<T>::get() as f64,
-        ))
-        .clamp(0, Self::percentage_factor_as_u128())
+        let percentage_factor = Self::percentage_factor_as_u128();
+        Self::fixed_concave_down_decreasing(
+            progress,
+            AttestorMinRewardFactor::<T>::get(),
+            percentage_factor,
+            (AttestorRewardExponent::<T>::get() as u128).saturating_mul(percentage_factor),
+        )
+        .clamp(0, percentage_factor)
     }
 
     pub fn get_validator_reward_multiplier(progress: u128) -> u128 {
-        Self::get_f64_as_percentage(Self::sigmoid_decreasing(
-            Self::get_percent_as_f64(progress),
-            Self::get_percent_as_f64(ValidatorRewardMidpoint::<T>::get()),
-            ValidatorRewardK::<T>::get() as f64,
-            0.0,
-            1.0,
-        ))
-        .clamp(0, Self::percentage_factor_as_u128())
+        let percentage_factor = Self::percentage_factor_as_u128();
+        Self::fixed_sigmoid_decreasing(
+            progress,
+            ValidatorRewardMidpoint::<T>::get(),
+            (ValidatorRewardK::<T>::get() as u128).saturating_mul(percentage_factor),
+            0,
+            percentage_factor,
+        )
+        .clamp(0, percentage_factor)
+    }
+
+    /// Validator reward multiplier of a backup validator
+    ///
+    /// The validator curve restarts once the backup window opens at
+    /// `BackupProposerEpochProgression` and is scaled down by `BackupValidatorRewardFactor`
+    pub fn get_backup_validator_reward_multiplier(progress: u128) -> u128 {
+        let percentage_factor = Self::percentage_factor_as_u128();
+        let window_start = BackupProposerEpochProgression::<T>::get().min(percentage_factor);
+
+        // Progression through the backup window
+        let backup_progress = Self::percent_div(
+            progress.saturating_sub(window_start),
+            percentage_factor.saturating_sub(window_start),
+        )
+        .clamp(0, percentage_factor);
+
+        Self::percent_mul(
+            Self::get_validator_reward_multiplier(backup_progress),
+            BackupValidatorRewardFactor::<T>::get(),
+        )
+        .clamp(0, percentage_factor)
     }
 
     /// Return the validators reward that submitted data on the previous epoch
@@ -59,6 +59,8 @@ use frame_system::pallet_prelude::OriginFor;
 use frame_system::{self as system, ensure_signed};
 pub use pallet::*;
 use scale_info::prelude::vec::Vec;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_core::OpaquePeerId as PeerId;
 use sp_runtime::traits::TrailingZeroInput;
 use sp_runtime::Saturating;
@@ -100,6 +102,11 @@ pub mod overwatch_nodes;
 pub use overwatch_nodes::*;
 pub mod bank;
 pub use bank::*;
+pub mod check_network_call;
+pub use check_network_call::*;
+pub mod migrations;
+#[cfg(any(feature = "try-runtime", test))]
+pub mod try_state;
 
 // mod rewards;
 // mod rewards_v4;
@@ -114,9 +121,13 @@ pub mod pallet {
     use sp_std::vec;
     use sp_std::vec::Vec;
 
+    /// The in-code storage version, see `migrations` for the steps between versions
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
     // The `Pallet` struct serves as a placeholder to implement traits, methods and dispatchables
     // (`Call`s) in this pallet.
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
 
@@ -173,6 +184,18 @@ pub mod pallet {
         /// Epoch slots (see `on_initialize`)
         #[pallet::constant]
         type DesignatedEpochSlots: Get<u32>;
+
+        /// Deposit reserved from the caller of `prove_peer_id` until the proof is used, replaced
+        /// or pruned after expiring
+        #[pallet::constant]
+        type PeerIdProofDeposit: Get<u128>;
+
+        /// Whether `on_initialize` reports the weight it consumed to the block
+        ///
+        /// Should only be `false` in runtimes built for the EVM test suite, see the `evm-tests`
+        /// runtime feature
+        #[pallet::constant]
+        type ReportHooksWeight: Get<bool>;
     }
 
     /// Events that functions in this pallet can emit.
@@ -661,6 +684,54 @@ pub mod pallet {
             account_id: T::AccountId,
             call: QueuedSwapCall<T::AccountId>,
         },
+        SwapCallQueueCancelled {
+            id: u32,
+            account_id: T::AccountId,
+            balance: u128,
+        },
+        SetMaxPendingSwapsPerAccount(u32),
+        SubnetElectionModeUpdate {
+            subnet_id: u32,
+            owner: T::AccountId,
+            value: ElectionMode,
+        },
+        SetBackupProposerEpochProgression(u128),
+        SetBackupValidatorRewardFactor(u128),
+        BackupValidatorSubmission {
+            subnet_id: u32,
+            account_id: T::AccountId,
+            epoch: u32,
+        },
+        /// Entries removed from a validators consensus data before it was stored
+        ///
+        /// Each entry is the subnet node ID and why it was removed, unknown or not `Included`
+        /// subnet nodes. Duplicate subnet node IDs reject the whole submission instead.
+        ConsensusDataRejected {
+            subnet_id: u32,
+            epoch: u32,
+            rejected: BoundedVec<(u32, ConsensusDataRejectionReason), GetMaxSubnetNodes<T>>,
+        },
+        SetConsensusArchiveDepth(u32),
+        SetRewardHistoryDepth(u32),
+        PeerIdProven {
+            subnet_id: u32,
+            hotkey: T::AccountId,
+            peer_id: PeerId,
+        },
+        SubnetPeerIdProofRequiredUpdate {
+            subnet_id: u32,
+            owner: T::AccountId,
+            value: bool,
+        },
+        /// Entries removed from an overwatch nodes commits before they were stored
+        ///
+        /// Each entry is the subnet ID and why it was removed, unknown or not `Active` subnets.
+        /// Duplicate subnet IDs reject the whole commit instead.
+        OverwatchCommitsRejected {
+            overwatch_node_id: u32,
+            epoch: u32,
+            rejected: BoundedVec<(u32, OverwatchCommitRejectionReason), GetMaxSubnets<T>>,
+        },
     }
 
     /// Errors that can be returned by this pallet.
@@ -926,6 +997,44 @@ pub mod pallet {
         SwapCallNotFound,
         /// Coldkey is blacklisted from being an Overwatch Node
         ColdkeyBlacklisted,
+        /// Swap queue is full, see `SwapQueueOrder`
+        SwapQueueFull,
+        /// Account has reached the maximum pending swap calls, see `MaxPendingSwapsPerAccount`
+        MaxPendingSwapsPerAccountReached,
+        /// Consensus data contains the same subnet node ID more than once
+        DuplicateSubnetNodeId,
+        /// Commits contain the same subnet ID more than once
+        DuplicateSubnetId,
+        /// Consensus archive depth must keep at least the current and previous subnet epochs
+        InvalidConsensusArchiveDepth,
+        /// Peer ID has an RSA key and the subnet doesn't accept `KeyType::Rsa`
+        PeerIdRsaKeyTypeNotAllowed,
+        /// Peer ID has an Ed25519 key and the subnet doesn't accept `KeyType::Ed25519`
+        PeerIdEd25519KeyTypeNotAllowed,
+        /// Peer ID has a Secp256k1 key and the subnet doesn't accept `KeyType::Secp256k1`
+        PeerIdSecp256k1KeyTypeNotAllowed,
+        /// Peer ID has an ECDSA key and the subnet doesn't accept `KeyType::Ecdsa`
+        PeerIdEcdsaKeyTypeNotAllowed,
+        /// Peer ID is the hash of an RSA or ECDSA key and the subnet accepts neither
+        PeerIdHashedKeyTypeNotAllowed,
+        /// Subnet requires a proof of ownership of the peer ID by the hotkey, see `prove_peer_id`
+        PeerIdProofRequired,
+        /// Peer ID proof signature doesn't verify against the key embedded in the peer ID
+        InvalidPeerIdProof,
+        /// Peer ID proof block is in the future or older than `PEER_ID_PROOF_VALIDITY_BLOCKS`
+        PeerIdProofExpired,
+        /// Subnet doesn't require peer ID proofs, see `owner_update_peer_id_proof_required`
+        PeerIdProofNotRequired,
+        /// Peer ID was proven for another hotkey
+        PeerIdProofHotkeyMismatch,
+        /// `hotkeys` witness is below the hotkeys owned by the caller
+        HotkeysWitnessTooLow,
+        /// New coldkey already has hotkeys, unbondings, an identity, reputation or subnet nodes
+        ColdkeyInUse,
+        /// Consensus data has more entries than `MaxSubnetNodes`
+        ConsensusDataTooLong,
+        /// Overwatch commits have more entries than `MaxSubnets`
+        OverwatchCommitsTooLong,
     }
 
     /// Subnet data
@@ -995,6 +1104,7 @@ pub mod pallet {
         Ord,
         scale_info::TypeInfo,
     )]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
     pub enum SubnetState {
         #[default]
         Registered,
@@ -1018,6 +1128,7 @@ pub mod pallet {
         Ord,
         scale_info::TypeInfo,
     )]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
     pub enum KeyType {
         #[default]
         Rsa,
@@ -1026,6 +1137,32 @@ pub mod pallet {
         Ecdsa,
     }
 
+    /// How the validator is chosen from `SubnetNodeElectionSlots` each epoch
+    #[derive(
+        Default,
+        EnumIter,
+        FromRepr,
+        Copy,
+        Encode,
+        Decode,
+        Clone,
+        PartialOrd,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        Ord,
+        scale_info::TypeInfo,
+    )]
+    pub enum ElectionMode {
+        /// Every electable node has an equal chance
+        #[default]
+        Uniform,
+        /// Chance is proportional to the node's hotkey stake in the subnet
+        StakeWeighted,
+        /// Chance is proportional to `SubnetNodeReputation`
+        ReputationWeighted,
+    }
+
     /// Configuration data for a subnet during its registration phase before activation.
     ///
     /// This struct contains all the parameters and metadata needed to register a new subnet.
@@ -1055,7 +1192,7 @@ pub mod pallet {
     ///   during the subnet's registration phase. After activation, registration typically
     ///   opens to all eligible participants.
     /// * `key_types` - Set of cryptographic key types (signature algorithms) that the subnet
-    ///   accepts for node registration. This is informational metadata not enforced onchain.
+    ///   accepts for node registration. Subnet node peer IDs must embed one of these key types.
     /// * `bootnodes` - Set of multiaddresses or connection information for official bootnodes
     ///   that help new nodes discover and connect to the subnet network. Can be updated by
     ///   the subnet owner and whitelisted accounts. This is informational metadata for
@@ -1088,6 +1225,51 @@ pub mod pallet {
         pub bootnodes: BTreeSet<BoundedVec<u8, DefaultMaxVectorLength>>,
     }
 
+    /// Subnet registered and activated at genesis
+    ///
+    /// Built through the same registration, node registration, delegate staking and activation
+    /// paths as the dispatchables, so every balance is withdrawn from the given accounts and the
+    /// genesis parameters must allow the subnet to activate in epoch 0, i.e.
+    /// `MinSubnetRegistrationEpochs` of 0 and enough delegate stake to meet
+    /// `MinSubnetDelegateStakeFactor`.
+    ///
+    /// # Fields
+    ///
+    /// * `owner` - Account registering and owning the subnet, pays the registration cost.
+    /// * `min_stake` / `max_stake` - Subnet node stake range.
+    /// * `delegate_stake_percentage` - Percentage of emissions to delegate stakers (1e18 = 100%).
+    /// * `bootnodes` - Official bootnode multiaddresses, at least one.
+    /// * `nodes` - Subnet nodes registered in order, each coldkey is whitelisted for its nodes.
+    /// * `delegate_stakes` - Accounts and balances delegate staked to the subnet before activation.
+    #[derive(Clone, PartialEq, Eq, RuntimeDebug, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase", deny_unknown_fields)]
+    pub struct GenesisSubnet<AccountId> {
+        pub name: Vec<u8>,
+        pub repo: Vec<u8>,
+        pub description: Vec<u8>,
+        pub owner: AccountId,
+        pub min_stake: u128,
+        pub max_stake: u128,
+        pub delegate_stake_percentage: u128,
+        pub bootnodes: Vec<Vec<u8>>,
+        pub nodes: Vec<GenesisSubnetNode<AccountId>>,
+        pub delegate_stakes: Vec<(AccountId, u128)>,
+    }
+
+    /// Subnet node registered at genesis, staking `stake` from `coldkey`
+    ///
+    /// The three peer IDs must be distinct, as on `register_subnet_node`.
+    #[derive(Clone, PartialEq, Eq, RuntimeDebug, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase", deny_unknown_fields)]
+    pub struct GenesisSubnetNode<AccountId> {
+        pub coldkey: AccountId,
+        pub hotkey: AccountId,
+        pub peer_id: PeerId,
+        pub bootnode_peer_id: PeerId,
+        pub client_peer_id: PeerId,
+        pub stake: u128,
+    }
+
     /// Complete subnet information aggregated for RPC queries.
     ///
     /// This struct provides a comprehensive view of a subnet's configuration, state, and
@@ -1150,18 +1332,50 @@ pub mod pallet {
     #[derive(
         Encode, Decode, Clone, PartialOrd, PartialEq, Eq, RuntimeDebug, Ord, scale_info::TypeInfo,
     )]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    #[cfg_attr(
+        feature = "std",
+        serde(bound(
+            serialize = "AccountId: Serialize",
+            deserialize = "AccountId: Deserialize<'de> + Ord"
+        ))
+    )]
     pub struct SubnetInfo<AccountId> {
         pub id: u32,
         pub friendly_id: Option<u32>,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::bytes_as_string")
+        )]
         pub name: Vec<u8>,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::bytes_as_string")
+        )]
         pub repo: Vec<u8>,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::bytes_as_string")
+        )]
         pub description: Vec<u8>,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::bytes_as_string")
+        )]
         pub misc: Vec<u8>,
         pub state: SubnetState,
         pub start_epoch: u32,
         pub churn_limit: u32,
         pub churn_limit_multiplier: u32,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub min_stake: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub max_stake: u128,
         pub queue_immunity_epochs: u32,
         pub target_node_registrations_per_epoch: u32,
@@ -1169,11 +1383,31 @@ pub mod pallet {
         pub subnet_node_queue_epochs: u32,
         pub idle_classification_epochs: u32,
         pub included_classification_epochs: u32,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub delegate_stake_percentage: u128,
         pub last_delegate_stake_rewards_update: u32,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub node_burn_rate_alpha: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub current_node_burn_rate: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::option_map_as_pairs")
+        )]
         pub initial_coldkeys: Option<BTreeMap<AccountId, u32>>,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::option_map_as_pairs")
+        )]
         pub initial_coldkey_data: Option<BTreeMap<AccountId, u32>>,
         pub max_registered_nodes: u32,
         pub owner: Option<AccountId>,
@@ -1183,24 +1417,84 @@ pub mod pallet {
         pub key_types: BTreeSet<KeyType>,
         pub slot_index: Option<u32>,
         pub slot_assignment: Option<u32>,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub subnet_node_min_weight_decrease_reputation_threshold: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub reputation: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub min_subnet_node_reputation: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub absent_decrease_reputation_factor: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub included_increase_reputation_factor: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub below_min_weight_decrease_reputation_factor: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub non_attestor_decrease_reputation_factor: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub non_consensus_attestor_decrease_reputation_factor: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub validator_absent_subnet_node_reputation_factor: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub validator_non_consensus_subnet_node_reputation_factor: u128,
         pub bootnode_access: BTreeSet<AccountId>,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::bytes_set_as_strings")
+        )]
         pub bootnodes: BTreeSet<BoundedVec<u8, DefaultMaxVectorLength>>,
         pub total_nodes: u32,
         pub total_active_nodes: u32,
         pub total_electable_nodes: u32,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub current_min_delegate_stake: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub total_subnet_stake: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub total_subnet_delegate_stake_shares: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub total_subnet_delegate_stake_balance: u128,
     }
 
@@ -1253,48 +1547,151 @@ pub mod pallet {
         Ord,
         scale_info::TypeInfo,
     )]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
     pub struct SubnetNode<AccountId> {
         pub id: u32,
         pub hotkey: AccountId,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::peer_id_as_string")
+        )]
         pub peer_id: PeerId,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::peer_id_as_string")
+        )]
         pub bootnode_peer_id: PeerId,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::peer_id_as_string")
+        )]
         pub client_peer_id: PeerId,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::option_bytes_as_string")
+        )]
         pub bootnode: Option<BoundedVec<u8, DefaultMaxVectorLength>>,
         pub classification: SubnetNodeClassification,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub delegate_reward_rate: u128,
         pub last_delegate_reward_rate_update: u32,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::option_bytes_as_string")
+        )]
         pub unique: Option<BoundedVec<u8, DefaultMaxVectorLength>>,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::option_bytes_as_string")
+        )]
         pub non_unique: Option<BoundedVec<u8, DefaultMaxVectorLength>>,
     }
 
     /// Subnet Node Info
     /// RPC helper
     #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
     pub struct SubnetNodeInfo<AccountId> {
         pub subnet_id: u32,
         pub subnet_node_id: u32,
         pub coldkey: AccountId,
         pub hotkey: AccountId,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::peer_id_as_string")
+        )]
         pub peer_id: PeerId,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::peer_id_as_string")
+        )]
         pub bootnode_peer_id: PeerId,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::peer_id_as_string")
+        )]
         pub client_peer_id: PeerId,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::option_bytes_as_string")
+        )]
         pub bootnode: Option<BoundedVec<u8, DefaultMaxVectorLength>>,
         pub identity: ColdkeyIdentityData,
         pub classification: SubnetNodeClassification,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub delegate_reward_rate: u128,
         pub last_delegate_reward_rate_update: u32,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::option_bytes_as_string")
+        )]
         pub unique: Option<BoundedVec<u8, DefaultMaxVectorLength>>,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::option_bytes_as_string")
+        )]
         pub non_unique: Option<BoundedVec<u8, DefaultMaxVectorLength>>,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub stake_balance: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub total_node_delegate_stake_shares: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub node_delegate_stake_balance: u128,
         pub coldkey_reputation: Reputation,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub subnet_node_reputation: u128,
         pub node_slot_index: Option<u32>,
         pub consecutive_idle_epochs: u32,
         pub consecutive_included_epochs: u32,
     }
 
+    /// RPC filter for subnet node queries, unset fields match every node
+    ///
+    /// * `min_class` - Minimum classification of the node.
+    /// * `coldkey` - Coldkey owning the node.
+    /// * `min_reputation` - Minimum subnet node reputation.
+    /// * `has_bootnode` - Whether the node has a bootnode set.
+    #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub struct SubnetNodeFilter<AccountId> {
+        pub min_class: Option<SubnetNodeClass>,
+        pub coldkey: Option<AccountId>,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::option_u128_as_string")
+        )]
+        pub min_reputation: Option<u128>,
+        pub has_bootnode: Option<bool>,
+    }
+
+    /// RPC helper for paginated queries
+    ///
+    /// `next_cursor` is the start key of the next page, or `None` on the last page.
+    #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub struct RpcPage<Item, Cursor> {
+        pub items: Vec<Item>,
+        pub next_cursor: Option<Cursor>,
+    }
+
     /// RPC helper for node stakes
     #[derive(
         Default,
@@ -1308,10 +1705,15 @@ pub mod pallet {
         Ord,
         scale_info::TypeInfo,
     )]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
     pub struct SubnetNodeStakeInfo<AccountId> {
         pub subnet_id: Option<u32>,
         pub subnet_node_id: Option<u32>,
         pub hotkey: AccountId,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub balance: u128,
     }
 
@@ -1328,9 +1730,18 @@ pub mod pallet {
         Ord,
         scale_info::TypeInfo,
     )]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
     pub struct DelegateStakeInfo {
         pub subnet_id: u32,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub shares: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub balance: u128,
     }
 
@@ -1347,10 +1758,19 @@ pub mod pallet {
         Ord,
         scale_info::TypeInfo,
     )]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
     pub struct NodeDelegateStakeInfo {
         pub subnet_id: u32,
         pub subnet_node_id: u32,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub shares: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub balance: u128,
     }
 
@@ -1401,6 +1821,7 @@ pub mod pallet {
         Ord,
         scale_info::TypeInfo,
     )]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
     pub enum SubnetNodeClass {
         #[default]
         Registered,
@@ -1438,6 +1859,7 @@ pub mod pallet {
         PartialOrd,
         scale_info::TypeInfo,
     )]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
     pub struct SubnetNodeClassification {
         pub node_class: SubnetNodeClass,
         pub start_epoch: u32,
@@ -1454,11 +1876,52 @@ pub mod pallet {
     ///
     /// Scoring is calculated off-chain between subnet nodes hosting AI subnets together
     #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
     pub struct SubnetNodeConsensusData {
         pub subnet_node_id: u32,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub score: u128,
     }
 
+    /// Why an entry of `SubnetNodeConsensusData` was removed from a validators submission
+    #[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub enum ConsensusDataRejectionReason {
+        /// Subnet node ID doesn't exist in the subnet
+        UnknownSubnetNodeId,
+        /// Subnet node isn't classified as `SubnetNodeClass::Included` for the epoch
+        NotIncluded,
+    }
+
+    /// Why an `OverwatchCommit` was removed from an overwatch nodes commits
+    #[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub enum OverwatchCommitRejectionReason {
+        /// Subnet ID doesn't exist
+        UnknownSubnetId,
+        /// Subnet exists but isn't `SubnetState::Active`
+        SubnetNotActive,
+    }
+
+    /// `MaxSubnetNodes` as a `Get`, bounds vectors by the current storage value
+    pub struct GetMaxSubnetNodes<T>(PhantomData<T>);
+
+    impl<T: Config> Get<u32> for GetMaxSubnetNodes<T> {
+        fn get() -> u32 {
+            MaxSubnetNodes::<T>::get()
+        }
+    }
+
+    /// `MaxSubnets` as a `Get`, bounds vectors by the current storage value
+    pub struct GetMaxSubnets<T>(PhantomData<T>);
+
+    impl<T: Config> Get<u32> for GetMaxSubnets<T> {
+        fn get() -> u32 {
+            MaxSubnets::<T>::get()
+        }
+    }
+
     /// Identity data associated with a coldkey account.
     ///
     /// This struct stores public identity information that coldkey holders can set to
@@ -1501,16 +1964,57 @@ pub mod pallet {
         Ord,
         scale_info::TypeInfo,
     )]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
     pub struct ColdkeyIdentityData {
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::bytes_as_string")
+        )]
         pub name: BoundedVec<u8, DefaultMaxVectorLength>,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::bytes_as_string")
+        )]
         pub url: BoundedVec<u8, DefaultMaxUrlLength>,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::bytes_as_string")
+        )]
         pub image: BoundedVec<u8, DefaultMaxUrlLength>,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::bytes_as_string")
+        )]
         pub discord: BoundedVec<u8, DefaultMaxSocialIdLength>,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::bytes_as_string")
+        )]
         pub x: BoundedVec<u8, DefaultMaxSocialIdLength>,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::bytes_as_string")
+        )]
         pub telegram: BoundedVec<u8, DefaultMaxSocialIdLength>,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::bytes_as_string")
+        )]
         pub github: BoundedVec<u8, DefaultMaxUrlLength>,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::bytes_as_string")
+        )]
         pub hugging_face: BoundedVec<u8, DefaultMaxUrlLength>,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::bytes_as_string")
+        )]
         pub description: BoundedVec<u8, DefaultMaxVectorLength>,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::bytes_as_string")
+        )]
         pub misc: BoundedVec<u8, DefaultMaxVectorLength>,
     }
 
@@ -1533,10 +2037,23 @@ pub mod pallet {
     ///   metadata, signatures, or other information for off-chain verification or
     ///   coordination purposes.
     #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
     pub struct AttestEntry {
         pub block: u32,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub attestor_progress: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub reward_factor: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::option_bytes_as_hex")
+        )]
         pub data: Option<BoundedVec<u8, DefaultValidatorArgsLimit>>,
     }
 
@@ -1573,11 +2090,28 @@ pub mod pallet {
     ///   This is set by the proposing validator and executed during consensus finalization
     ///   if the submission is accepted and the node has passed its immunity period.
     #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
     pub struct ConsensusSubmissionData<AccountId> {
         pub validator_subnet_node_id: u32,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub validator_epoch_progress: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub validator_reward_factor: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub attestation_ratio: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub weight_sum: u128,
         pub data_length: u32,
         pub data: Vec<SubnetNodeConsensusData>,
@@ -1587,6 +2121,15 @@ pub mod pallet {
         pub remove_queue_node_id: Option<u32>,
     }
 
+    /// RPC helper for an archived consensus submission, see `ConsensusSubmissionArchive`
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub struct ConsensusSubmissionInfo<AccountId> {
+        pub subnet_id: u32,
+        pub epoch: u32, // Subnet epoch
+        pub submission: ConsensusSubmissionData<AccountId>,
+    }
+
     /// Reasons for a subnet's removal from the network.
     ///
     /// This enum tracks why a subnet was removed, which is important for
@@ -1622,6 +2165,41 @@ pub mod pallet {
         PauseExpired,
     }
 
+    /// Storage prefix cleared in `on_idle`, see `CleanupQueue`
+    ///
+    /// Each variant names the storage map to clear and the first key of the prefix:
+    ///
+    /// * Subnet node maps and consensus data are keyed by the removed subnet ID. Peer ID proofs
+    ///   hold deposits and are left to `prune_expired_peer_id_proofs`.
+    /// * `OverwatchCommits` and `OverwatchReveals` are keyed by a past overwatch epoch.
+    /// * Reward history maps are keyed by an epoch older than `RewardHistoryDepth`.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub enum CleanupTask {
+        SubnetNodesData(u32),
+        RegisteredSubnetNodesData(u32),
+        PeerIdSubnetNodeId(u32),
+        BootnodePeerIdSubnetNodeId(u32),
+        ClientPeerIdSubnetNodeId(u32),
+        BootnodeSubnetNodeId(u32),
+        UniqueParamSubnetNodeId(u32),
+        HotkeySubnetNodeId(u32),
+        SubnetNodeIdHotkey(u32),
+        SubnetNodeReputation(u32),
+        SubnetNodeConsecutiveIncludedEpochs(u32),
+        SubnetElectedValidator(u32),
+        SubnetBackupValidator(u32),
+        NodeSlotIndex(u32),
+        SubnetConsensusSubmission(u32),
+        ConsensusSubmissionArchive(u32),
+        OverwatchCommits(u32),
+        OverwatchReveals(u32),
+        AccountRewardHistory(u32),
+        SubnetDelegateRewardHistory(u32),
+        NodeDelegateRewardHistory(u32),
+        AccountSubnetDelegateSharesHistory(u32),
+        AccountNodeDelegateSharesHistory(u32),
+    }
+
     /// Consensus data for a subnet epoch, storing the validator's submission and attestations.
     ///
     /// This struct represents the complete consensus state for a subnet during a specific epoch.
@@ -1653,8 +2231,9 @@ pub mod pallet {
     ///   submission achieves sufficient attestation and the node has passed its immunity period.
     /// * `data` - A vector of consensus data entries submitted by the elected validator,
     ///   where each entry contains a subnet node ID and their corresponding performance score.
-    ///   Only peers with `Included` classification are retained, and duplicates are automatically
-    ///   removed based on subnet node ID.
+    ///   Sorted by subnet node ID, submissions with duplicate subnet node IDs are rejected with
+    ///   `DuplicateSubnetNodeId`. Only peers with `Included` classification are retained, the
+    ///   others are reported in `ConsensusDataRejected`.
     /// * `args` - Optional arbitrary arguments for subnet-specific validation and coordination.
     ///   This data is not used in any onchain logic but allows subnets to pass custom parameters
     ///   that validators can use for off-chain validation or coordination purposes.
@@ -1707,14 +2286,79 @@ pub mod pallet {
     ///   among all active subnet nodes based on their consensus scores. This represents
     ///   the reward pool that will be split according to node performance.
     #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
     pub struct RewardsData {
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub overall_subnet_reward: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub subnet_owner_reward: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub subnet_rewards: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub delegate_stake_rewards: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub subnet_node_rewards: u128,
     }
 
+    /// Rewards credited to an account in an epoch, see `AccountRewardHistory`
+    ///
+    /// * `node_stake_rewards` - Validator and subnet node rewards added to the hotkeys stake.
+    /// * `subnet_owner_rewards` - Subnet owner rewards added to the owners balance.
+    /// * `overwatch_rewards` - Overwatch rewards added to the hotkeys overwatch stake.
+    #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub struct AccountEpochRewards {
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
+        pub node_stake_rewards: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
+        pub subnet_owner_rewards: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
+        pub overwatch_rewards: u128,
+    }
+
+    /// Rewards added to a delegate stake pool in an epoch and the pools shares at that time
+    ///
+    /// Delegate stake rewards increase the pools balance instead of crediting each delegator,
+    /// a delegators part is `rewards * shares / total_shares`.
+    #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub struct DelegatePoolEpochRewards {
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
+        pub rewards: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
+        pub total_shares: u128,
+    }
+
     // Overwatch nodes
 
     #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
@@ -1779,11 +2423,16 @@ pub mod pallet {
     }
 
     #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
     pub struct Reputation {
         /// Epoch when the node first elected subnet validator node to submit consensus.
         pub start_epoch: u32,
 
         /// Current reputation weight.
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub score: u128,
 
         /// Track total nodes under a coldkey ever, this can only increase.
@@ -1799,12 +2448,20 @@ pub mod pallet {
         pub total_decreases: u32,
 
         /// Average attestation rate.
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub average_attestation: u128,
 
         /// Last epoch the node was selected as validator.
         pub last_validator_epoch: u32,
 
         /// Current overwatch node reputation weight.
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
         pub ow_score: u128,
     }
 
@@ -1829,10 +2486,6 @@ pub mod pallet {
             self.computational_ops += ops;
         }
 
-        fn add_clear_prefix(&mut self, removed_count: u32) {
-            self.total_writes += removed_count as u64;
-        }
-
         fn add_remove(&mut self) {
             self.total_writes += 1;
         }
@@ -1871,11 +2524,34 @@ pub mod pallet {
     /// bootnodes: List of official subnet bootnodes
     /// node_bootnodes: List of all node bootnodes
     #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
     pub struct AllSubnetBootnodes {
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::bytes_set_as_strings")
+        )]
         pub bootnodes: BTreeSet<BoundedVec<u8, DefaultMaxVectorLength>>,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::bytes_set_as_strings")
+        )]
         pub node_bootnodes: BTreeSet<BoundedVec<u8, DefaultMaxVectorLength>>,
     }
 
+    /// Proof that `hotkey` owns a peer ID, see `prove_peer_id`
+    ///
+    /// * `hotkey` - Hotkey the peer ID was proven for.
+    /// * `block` - Block the proof was signed at.
+    /// * `depositor` - Account the deposit is reserved from.
+    /// * `deposit` - Deposit reserved until the proof is used, replaced or pruned.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct PeerIdProof<AccountId> {
+        pub hotkey: AccountId,
+        pub block: u32,
+        pub depositor: AccountId,
+        pub deposit: u128,
+    }
+
     ///
     ///
     #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
@@ -2079,6 +2755,20 @@ pub mod pallet {
         // 33.0%
         330000000000000000
     }
+    #[pallet::type_value]
+    pub fn DefaultBackupProposerEpochProgression() -> u128 {
+        // 50.0%
+        500000000000000000
+    }
+    #[pallet::type_value]
+    pub fn DefaultBackupValidatorRewardFactor() -> u128 {
+        // 50.0%
+        500000000000000000
+    }
+    #[pallet::type_value]
+    pub fn DefaultConsensusArchiveDepth() -> u32 {
+        672
+    }
     /// This type value is referenced in:
     /// - BaseSlashPercentage
     #[pallet::type_value]
@@ -2705,6 +3395,12 @@ pub mod pallet {
         16
     }
     /// This type value is referenced in:
+    /// - MaxPendingSwapsPerAccount
+    #[pallet::type_value]
+    pub fn DefaultMaxPendingSwapsPerAccount() -> u32 {
+        8
+    }
+    /// This type value is referenced in:
     /// - MaximumHooksWeightV2
     #[pallet::type_value]
     pub fn DefaultMaximumHooksWeightV2<T: Config>() -> Weight {
@@ -2830,6 +3526,10 @@ pub mod pallet {
     #[pallet::storage]
     pub type SubnetNodeElectionSlots<T> = StorageMap<_, Identity, u32, Vec<u32>, ValueQuery>;
 
+    /// Election mode of each subnet, set by the subnet owner
+    #[pallet::storage]
+    pub type SubnetElectionMode<T> = StorageMap<_, Identity, u32, ElectionMode, ValueQuery>;
+
     #[derive(
         Default,
         Encode,
@@ -3137,6 +3837,18 @@ pub mod pallet {
     pub type ValidatorRewardMidpoint<T> =
         StorageValue<_, u128, ValueQuery, DefaultValidatorRewardMidpoint>;
 
+    /// Subnet epoch progression after which the backup validator can propose if the elected
+    /// validator hasn't
+    #[pallet::storage]
+    pub type BackupProposerEpochProgression<T> =
+        StorageValue<_, u128, ValueQuery, DefaultBackupProposerEpochProgression>;
+
+    /// Max percentage of the validator reward multiplier a backup validator receives
+    /// Used in `get_backup_validator_reward_multiplier`
+    #[pallet::storage]
+    pub type BackupValidatorRewardFactor<T> =
+        StorageValue<_, u128, ValueQuery, DefaultBackupValidatorRewardFactor>;
+
     #[pallet::storage]
     pub type AttestorRewardExponent<T> =
         StorageValue<_, u64, ValueQuery, DefaultAttestorRewardExponent>;
@@ -3327,6 +4039,36 @@ pub mod pallet {
     #[pallet::storage]
     pub type SubnetKeyTypes<T> = StorageMap<_, Identity, u32, BTreeSet<KeyType>, ValueQuery>;
 
+    /// Whether the subnet requires peer IDs to be proven by the hotkey registering them, set by
+    /// the subnet owner
+    #[pallet::storage]
+    pub type SubnetPeerIdProofRequired<T> = StorageMap<_, Identity, u32, bool, ValueQuery>;
+
+    /// Unused peer ID ownership proofs, consumed on registration or peer ID updates
+    ///
+    /// Proofs of removed subnets are left to expire, see `PeerIdProofsBySignedBlock`.
+    /// subnet_id --> peer_id --> proof
+    #[pallet::storage]
+    pub type PeerIdProofs<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u32,
+        Blake2_128Concat,
+        PeerId,
+        PeerIdProof<T::AccountId>,
+        OptionQuery,
+    >;
+
+    /// `PeerIdProofs` by the block they were signed at, to prune them once expired
+    /// signed block --> (subnet_id, peer_id) --> ()
+    #[pallet::storage]
+    pub type PeerIdProofsBySignedBlock<T> =
+        StorageDoubleMap<_, Identity, u32, Blake2_128Concat, (u32, PeerId), (), OptionQuery>;
+
+    /// Next signed block of `PeerIdProofsBySignedBlock` to prune, set on the first proof
+    #[pallet::storage]
+    pub type PeerIdProofPruneBlock<T> = StorageValue<_, u32, OptionQuery>;
+
     /// Min required stake balance for a Subnet Node in a specified subnet
     #[pallet::storage]
     pub type SubnetMinStakeBalance<T> =
@@ -3609,11 +4351,122 @@ pub mod pallet {
     pub type SubnetElectedValidator<T> =
         StorageDoubleMap<_, Identity, u32, Identity, u32, u32, OptionQuery>;
 
+    /// Backup validator, can propose once `BackupProposerEpochProgression` is reached
+    // subnet ID => epoch  => Subnet Node ID
+    #[pallet::storage]
+    pub type SubnetBackupValidator<T> =
+        StorageDoubleMap<_, Identity, u32, Identity, u32, u32, OptionQuery>;
+
     /// Consensus submissions (attestation proposals by elected validator)
     #[pallet::storage] // subnet ID => epoch  => data
     pub type SubnetConsensusSubmission<T: Config> =
         StorageDoubleMap<_, Identity, u32, Identity, u32, ConsensusData<T::AccountId>>;
 
+    /// Finalized consensus submissions, as used to distribute rewards
+    /// Pruned in `on_idle` once older than `ConsensusArchiveDepth` subnet epochs
+    #[pallet::storage] // subnet ID => epoch  => data
+    pub type ConsensusSubmissionArchive<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u32,
+        Identity,
+        u32,
+        ConsensusSubmissionData<T::AccountId>,
+        OptionQuery,
+    >;
+
+    /// Number of subnet epochs `SubnetConsensusSubmission` and `ConsensusSubmissionArchive` are kept
+    #[pallet::storage]
+    pub type ConsensusArchiveDepth<T> =
+        StorageValue<_, u32, ValueQuery, DefaultConsensusArchiveDepth>;
+
+    /// Oldest subnet epoch that may still have consensus data, i.e. the next epoch to prune
+    #[pallet::storage] // subnet ID => epoch
+    pub type ConsensusArchivePruneEpoch<T> = StorageMap<_, Identity, u32, u32, OptionQuery>;
+
+    /// Number of epochs of reward history kept, `0` disables recording reward history
+    #[pallet::storage]
+    pub type RewardHistoryDepth<T> = StorageValue<_, u32, ValueQuery, DefaultZeroU32>;
+
+    /// Oldest epoch that may still have reward history, i.e. the next epoch to prune
+    #[pallet::storage]
+    pub type RewardHistoryPruneEpoch<T> = StorageValue<_, u32, OptionQuery>;
+
+    /// Rewards credited to each account per epoch while `RewardHistoryDepth` is set
+    #[pallet::storage] // epoch => account => rewards
+    pub type AccountRewardHistory<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u32,
+        Blake2_128Concat,
+        T::AccountId,
+        AccountEpochRewards,
+        ValueQuery,
+    >;
+
+    /// Subnet delegate stake pool rewards per epoch while `RewardHistoryDepth` is set
+    #[pallet::storage] // epoch => subnet ID => rewards
+    pub type SubnetDelegateRewardHistory<T> =
+        StorageDoubleMap<_, Identity, u32, Identity, u32, DelegatePoolEpochRewards, OptionQuery>;
+
+    /// Node delegate stake pool rewards per epoch while `RewardHistoryDepth` is set
+    #[pallet::storage] // epoch => (subnet ID, subnet node ID) => rewards
+    pub type NodeDelegateRewardHistory<T> = StorageDoubleMap<
+        _,
+        Identity,
+        u32,
+        Identity,
+        (u32, u32),
+        DelegatePoolEpochRewards,
+        OptionQuery,
+    >;
+
+    /// Shares an account held in a subnet delegate stake pool in the epochs before `epoch`
+    ///
+    /// Recorded on the first change of the shares counting from `epoch` while
+    /// `RewardHistoryDepth` is set, epochs after the last checkpoint use the current shares.
+    #[pallet::storage] // epoch => account => subnet ID => shares
+    pub type AccountSubnetDelegateSharesHistory<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Identity, u32>,
+            NMapKey<Blake2_128Concat, T::AccountId>,
+            NMapKey<Identity, u32>,
+        ),
+        u128,
+        OptionQuery,
+    >;
+
+    /// Shares an account held in a node delegate stake pool in the epochs before `epoch`, see
+    /// `AccountSubnetDelegateSharesHistory`
+    #[pallet::storage] // epoch => account => (subnet ID, subnet node ID) => shares
+    pub type AccountNodeDelegateSharesHistory<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Identity, u32>,
+            NMapKey<Blake2_128Concat, T::AccountId>,
+            NMapKey<Identity, (u32, u32)>,
+        ),
+        u128,
+        OptionQuery,
+    >;
+
+    /// Prefixes waiting to be cleared in `on_idle`, processed in FIFO order
+    #[pallet::storage] // queue index => task
+    pub type CleanupQueue<T> = StorageMap<_, Identity, u32, CleanupTask, OptionQuery>;
+
+    /// Index of the next `CleanupQueue` task to process
+    #[pallet::storage]
+    pub type CleanupQueueHead<T> = StorageValue<_, u32, ValueQuery>;
+
+    /// Index the next `CleanupQueue` task is inserted at
+    #[pallet::storage]
+    pub type CleanupQueueTail<T> = StorageValue<_, u32, ValueQuery>;
+
+    /// `clear_prefix` cursor of the task at `CleanupQueueHead`, if partially cleared
+    #[pallet::storage]
+    pub type CleanupCursor<T> = StorageValue<_, Vec<u8>, OptionQuery>;
+
     /// Minimum attestation ratio to form consensus
     #[pallet::storage]
     pub type MinAttestationPercentage<T> =
@@ -4099,11 +4952,16 @@ pub mod pallet {
     //
 
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
     pub enum QueuedSwapCall<AccountId> {
         // swap_delegate_stake
         SwapToSubnetDelegateStake {
             account_id: AccountId,
             to_subnet_id: u32,
+            #[cfg_attr(
+                feature = "std",
+                serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+            )]
             balance: u128,
         },
         // swap_node_delegate_stake
@@ -4111,6 +4969,10 @@ pub mod pallet {
             account_id: AccountId,
             to_subnet_id: u32,
             to_subnet_node_id: u32,
+            #[cfg_attr(
+                feature = "std",
+                serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+            )]
             balance: u128,
         },
     }
@@ -4130,6 +4992,59 @@ pub mod pallet {
                 QueuedSwapCall::SwapToNodeDelegateStake { balance, .. } => *balance,
             }
         }
+
+        pub fn get_account_id(&self) -> &AccountId {
+            match self {
+                QueuedSwapCall::SwapToSubnetDelegateStake { account_id, .. } => account_id,
+                QueuedSwapCall::SwapToNodeDelegateStake { account_id, .. } => account_id,
+            }
+        }
+    }
+
+    /// RPC helper for an accounts pending swap calls
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub struct QueuedSwapInfo<AccountId> {
+        pub id: u32,
+        pub call: QueuedSwapCall<AccountId>,
+        pub queued_at_block: u32,
+        pub execute_after_blocks: u32,
+        pub position: u32,                 // Index in `SwapQueueOrder`
+        pub expected_execution_block: u32, // Block the call is expected to be executed in
+    }
+
+    /// RPC helper for the rewards of an account in an epoch
+    ///
+    /// Delegate stake rewards are derived from the shares the account held in each pool that epoch.
+    #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub struct AccountRewardsInfo {
+        pub epoch: u32,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
+        pub node_stake_rewards: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
+        pub subnet_owner_rewards: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
+        pub overwatch_rewards: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
+        pub delegate_stake_rewards: u128,
+        #[cfg_attr(
+            feature = "std",
+            serde(with = "crate::rpc_info::serde_helpers::u128_as_string")
+        )]
+        pub node_delegate_stake_rewards: u128,
     }
 
     /// List of current swaps in order
@@ -4155,6 +5070,16 @@ pub mod pallet {
     pub type MaxSwapQueueCallsPerBlock<T> =
         StorageValue<_, u32, ValueQuery, DefaultMaxSwapQueueCallsPerBlock>;
 
+    /// Maximum number of pending swap calls per account
+    #[pallet::storage]
+    pub type MaxPendingSwapsPerAccount<T> =
+        StorageValue<_, u32, ValueQuery, DefaultMaxPendingSwapsPerAccount>;
+
+    /// Pending swap call IDs of each account
+    #[pallet::storage]
+    pub type AccountSwapQueueIds<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BTreeSet<u32>, ValueQuery>;
+
     #[pallet::storage]
     pub type MaximumHooksWeightV2<T> =
         StorageValue<_, Weight, ValueQuery, DefaultMaximumHooksWeightV2<T>>;
@@ -4180,7 +5105,7 @@ pub mod pallet {
         /// * `subnet_data` - Subnet registration data `RegistrationSubnetData`.
         ///
         #[pallet::call_index(0)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::register_subnet())]
         pub fn register_subnet(
             origin: OriginFor<T>,
             max_cost: u128,
@@ -4207,7 +5132,7 @@ pub mod pallet {
         /// * `subnet_node_id` - Subnet node ID of activator.
         ///
         #[pallet::call_index(1)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::activate_subnet())]
         pub fn activate_subnet(origin: OriginFor<T>, subnet_id: u32) -> DispatchResultWithPostInfo {
             let coldkey: T::AccountId = ensure_signed(origin)?;
 
@@ -4222,28 +5147,28 @@ pub mod pallet {
         }
 
         #[pallet::call_index(2)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_pause_subnet())]
         pub fn owner_pause_subnet(origin: OriginFor<T>, subnet_id: u32) -> DispatchResult {
             Self::is_paused()?;
             Self::do_owner_pause_subnet(origin, subnet_id)
         }
 
         #[pallet::call_index(3)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_unpause_subnet())]
         pub fn owner_unpause_subnet(origin: OriginFor<T>, subnet_id: u32) -> DispatchResult {
             Self::is_paused()?;
             Self::do_owner_unpause_subnet(origin, subnet_id)
         }
 
         #[pallet::call_index(4)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_deactivate_subnet())]
         pub fn owner_deactivate_subnet(origin: OriginFor<T>, subnet_id: u32) -> DispatchResult {
             Self::is_paused()?;
             Self::do_owner_deactivate_subnet(origin, subnet_id)
         }
 
         #[pallet::call_index(5)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_update_name())]
         pub fn owner_update_name(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4254,7 +5179,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(6)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_update_repo())]
         pub fn owner_update_repo(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4265,7 +5190,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(7)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_update_description())]
         pub fn owner_update_description(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4276,7 +5201,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(8)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_update_misc())]
         pub fn owner_update_misc(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4287,7 +5212,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(9)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_update_churn_limit())]
         pub fn owner_update_churn_limit(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4298,7 +5223,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(10)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_update_registration_queue_epochs())]
         pub fn owner_update_registration_queue_epochs(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4309,7 +5234,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(11)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_update_idle_classification_epochs())]
         pub fn owner_update_idle_classification_epochs(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4320,7 +5245,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(12)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_update_included_classification_epochs())]
         pub fn owner_update_included_classification_epochs(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4331,7 +5256,9 @@ pub mod pallet {
         }
 
         #[pallet::call_index(13)]
-        #[pallet::weight({0})]
+        #[pallet::weight(
+            T::WeightInfo::owner_update_non_consensus_attestor_decrease_reputation_factor()
+        )]
         pub fn owner_update_non_consensus_attestor_decrease_reputation_factor(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4344,7 +5271,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(14)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_add_or_update_initial_coldkeys())]
         pub fn owner_add_or_update_initial_coldkeys(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4355,7 +5282,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(15)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_remove_initial_coldkeys())]
         pub fn owner_remove_initial_coldkeys(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4366,7 +5293,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(16)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_update_key_types())]
         pub fn owner_update_key_types(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4377,7 +5304,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(17)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_set_emergency_validator_set(subnet_node_ids.len() as u32))]
         pub fn owner_set_emergency_validator_set(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4388,7 +5315,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(18)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_revert_emergency_validator_set())]
         pub fn owner_revert_emergency_validator_set(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4398,7 +5325,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(19)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_update_min_max_stake())]
         pub fn owner_update_min_max_stake(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4410,7 +5337,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(20)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_update_delegate_stake_percentage())]
         pub fn owner_update_delegate_stake_percentage(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4421,7 +5348,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(21)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_update_max_registered_nodes())]
         pub fn owner_update_max_registered_nodes(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4445,7 +5372,7 @@ pub mod pallet {
         /// * Must be owner
         ///
         #[pallet::call_index(22)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::transfer_subnet_ownership())]
         pub fn transfer_subnet_ownership(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4468,14 +5395,14 @@ pub mod pallet {
         /// * Must be pending owner
         ///
         #[pallet::call_index(23)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::accept_subnet_ownership())]
         pub fn accept_subnet_ownership(origin: OriginFor<T>, subnet_id: u32) -> DispatchResult {
             Self::is_paused()?;
             Self::do_accept_subnet_ownership(origin, subnet_id)
         }
 
         #[pallet::call_index(24)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_add_bootnode_access())]
         pub fn owner_add_bootnode_access(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4486,7 +5413,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(25)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_remove_bootnode_access())]
         pub fn owner_remove_bootnode_access(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4497,7 +5424,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(26)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_update_target_node_registrations_per_epoch())]
         pub fn owner_update_target_node_registrations_per_epoch(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4508,7 +5435,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(27)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_update_node_burn_rate_alpha())]
         pub fn owner_update_node_burn_rate_alpha(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4519,7 +5446,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(28)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_update_queue_immunity_epochs())]
         pub fn owner_update_queue_immunity_epochs(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4530,7 +5457,9 @@ pub mod pallet {
         }
 
         #[pallet::call_index(29)]
-        #[pallet::weight({0})]
+        #[pallet::weight(
+            T::WeightInfo::owner_update_subnet_node_min_weight_decrease_reputation_threshold()
+        )]
         pub fn owner_update_subnet_node_min_weight_decrease_reputation_threshold(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4543,7 +5472,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(30)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_update_min_subnet_node_reputation())]
         pub fn owner_update_min_subnet_node_reputation(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4554,7 +5483,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(31)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_update_absent_decrease_reputation_factor())]
         pub fn owner_update_absent_decrease_reputation_factor(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4565,7 +5494,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(32)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_update_included_increase_reputation_factor())]
         pub fn owner_update_included_increase_reputation_factor(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4576,7 +5505,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(33)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_update_below_min_weight_decrease_reputation_factor())]
         pub fn owner_update_below_min_weight_decrease_reputation_factor(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4589,7 +5518,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(34)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_update_non_attestor_decrease_reputation_factor())]
         pub fn owner_update_non_attestor_decrease_reputation_factor(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4600,7 +5529,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(35)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::owner_update_validator_absent_decrease_reputation_factor())]
         pub fn owner_update_validator_absent_decrease_reputation_factor(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4613,7 +5542,9 @@ pub mod pallet {
         }
 
         #[pallet::call_index(36)]
-        #[pallet::weight({0})]
+        #[pallet::weight(
+            T::WeightInfo::owner_update_validator_non_consensus_decrease_reputation_factor()
+        )]
         pub fn owner_update_validator_non_consensus_decrease_reputation_factor(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4626,7 +5557,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(37)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::update_bootnodes((add.len() + remove.len()) as u32))]
         pub fn update_bootnodes(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4659,7 +5590,7 @@ pub mod pallet {
         /// * `stake_to_be_added` must be the minimum required stake balance
         ///
         #[pallet::call_index(38)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::register_subnet_node())]
         pub fn register_subnet_node(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4703,8 +5634,7 @@ pub mod pallet {
         /// * Caller must be owner of Subnet Node, hotkey or coldkey
         ///
         #[pallet::call_index(39)]
-        // #[pallet::weight(T::WeightInfo::remove_subnet_node())]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::remove_subnet_node())]
         pub fn remove_subnet_node(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4732,7 +5662,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(40)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::update_peer_id())]
         pub fn update_peer_id(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4752,7 +5682,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(41)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::update_bootnode())]
         pub fn update_bootnode(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4772,7 +5702,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(42)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::update_bootnode_peer_id())]
         pub fn update_bootnode_peer_id(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4792,7 +5722,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(43)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::update_client_peer_id())]
         pub fn update_client_peer_id(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4825,7 +5755,7 @@ pub mod pallet {
         /// * If decreasing rate, new rate must not be more than a 1% decrease nominally
         ///
         #[pallet::call_index(44)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::update_node_delegate_reward_rate())]
         pub fn update_node_delegate_reward_rate(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4859,7 +5789,7 @@ pub mod pallet {
         /// * `a` - The unique parameter
         ///
         #[pallet::call_index(45)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::update_unique())]
         pub fn update_unique(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4889,7 +5819,7 @@ pub mod pallet {
         /// * `non_unique` - The non-unique parameter
         ///
         #[pallet::call_index(46)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::update_non_unique())]
         pub fn update_non_unique(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4924,8 +5854,7 @@ pub mod pallet {
         /// * Must have amount free in wallet
         ///
         #[pallet::call_index(47)]
-        // #[pallet::weight(T::WeightInfo::add_stake())]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::add_stake())]
         pub fn add_stake(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4970,7 +5899,7 @@ pub mod pallet {
         /// * If Subnet Node, must have available staked balance greater than minimum required stake balance
         ///
         #[pallet::call_index(48)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::remove_stake())]
         pub fn remove_stake(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -4991,10 +5920,10 @@ pub mod pallet {
             // Else they can remove entire balance because they are not validating subnets
             //		They are removed in `do_remove_subnet_node()` when self or consensus removed
             // This includes registered, active, and deactivated subnet nodes
-            // Note that `HotkeySubnetNodeId` is cleaned when subnet is removed so we don't check if the
-            // subnet exists
+            // Note that `HotkeySubnetNodeId` is only cleared in `on_idle` after the subnet is removed
+            // so we check if the subnet exists
             let is_subnet_node: bool = match HotkeySubnetNodeId::<T>::try_get(subnet_id, &hotkey) {
-                Ok(subnet_node_id) => {
+                Ok(subnet_node_id) if SubnetsData::<T>::contains_key(subnet_id) => {
                     let subnet_epoch = Self::get_current_subnet_epoch_as_u32(subnet_id);
                     let is_chosen_validator: bool =
                         Self::is_chosen_validator(subnet_id, subnet_node_id, subnet_epoch);
@@ -5020,7 +5949,7 @@ pub mod pallet {
                     }
                     true
                 }
-                Err(()) => false,
+                _ => false,
             };
 
             // Remove stake
@@ -5043,7 +5972,7 @@ pub mod pallet {
         /// * Must be owner of stake balance
         ///
         #[pallet::call_index(49)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::claim_unbondings())]
         pub fn claim_unbondings(origin: OriginFor<T>) -> DispatchResult {
             let coldkey: T::AccountId = ensure_signed(origin)?;
 
@@ -5071,7 +6000,7 @@ pub mod pallet {
         /// * Subnet must exist
         ///
         #[pallet::call_index(50)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::add_to_delegate_stake())]
         pub fn add_to_delegate_stake(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -5105,7 +6034,7 @@ pub mod pallet {
         /// * `to_subnet_id` subnet must exist
         ///
         #[pallet::call_index(51)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::swap_delegate_stake())]
         pub fn swap_delegate_stake(
             origin: OriginFor<T>,
             from_subnet_id: u32,
@@ -5142,7 +6071,7 @@ pub mod pallet {
         /// * `to_subnet_id` subnet must exist
         ///
         #[pallet::call_index(52)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::transfer_delegate_stake())]
         pub fn transfer_delegate_stake(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -5172,8 +6101,7 @@ pub mod pallet {
         /// * Must have balance
         ///
         #[pallet::call_index(53)]
-        // #[pallet::weight(T::WeightInfo::remove_delegate_stake())]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::remove_delegate_stake())]
         pub fn remove_delegate_stake(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -5201,7 +6129,7 @@ pub mod pallet {
         ///
         ///
         #[pallet::call_index(54)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::donate_delegate_stake())]
         pub fn donate_delegate_stake(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -5253,7 +6181,7 @@ pub mod pallet {
         /// * `node_delegate_stake_to_be_added` - Amount TENSOR to delegate stake
         ///
         #[pallet::call_index(55)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::add_to_node_delegate_stake())]
         pub fn add_to_node_delegate_stake(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -5292,7 +6220,7 @@ pub mod pallet {
         /// * `to_subnet_id` subnet must exist
         ///
         #[pallet::call_index(56)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::swap_node_delegate_stake())]
         pub fn swap_node_delegate_stake(
             origin: OriginFor<T>,
             from_subnet_id: u32,
@@ -5333,7 +6261,7 @@ pub mod pallet {
         /// * `to_subnet_id` subnet must exist
         ///
         #[pallet::call_index(57)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::transfer_node_delegate_stake())]
         pub fn transfer_node_delegate_stake(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -5361,7 +6289,7 @@ pub mod pallet {
         /// * `node_delegate_stake_shares_to_be_removed` - Pool shares to remove
         ///
         #[pallet::call_index(58)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::remove_node_delegate_stake())]
         pub fn remove_node_delegate_stake(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -5395,7 +6323,7 @@ pub mod pallet {
         /// * `amount` - Amount TENSOR to add to pool
         ///
         #[pallet::call_index(59)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::donate_node_delegate_stake())]
         pub fn donate_node_delegate_stake(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -5407,8 +6335,10 @@ pub mod pallet {
             Self::is_paused()?;
 
             // --- Ensure Subnet Node exists, otherwise at risk of burning tokens
+            // Node data of a removed subnet may exist until cleared in `on_idle`
             ensure!(
-                SubnetNodesData::<T>::contains_key(subnet_id, subnet_node_id),
+                SubnetsData::<T>::contains_key(subnet_id)
+                    && SubnetNodesData::<T>::contains_key(subnet_id, subnet_node_id),
                 Error::<T>::InvalidSubnetNodeId
             );
 
@@ -5449,7 +6379,7 @@ pub mod pallet {
         /// * `node_delegate_stake_shares_to_swap` - Shares to remove from delegate pool and add balance to subnet
         ///
         #[pallet::call_index(60)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::swap_from_node_to_subnet())]
         pub fn swap_from_node_to_subnet(
             origin: OriginFor<T>,
             from_subnet_id: u32,
@@ -5483,7 +6413,7 @@ pub mod pallet {
         /// * `delegate_stake_shares_to_swap` - Shares to remove from delegate pool and add balance to node
         ///
         #[pallet::call_index(61)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::swap_from_subnet_to_node())]
         pub fn swap_from_subnet_to_node(
             origin: OriginFor<T>,
             from_subnet_id: u32,
@@ -5508,7 +6438,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(62)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::update_swap_queue())]
         pub fn update_swap_queue(
             origin: OriginFor<T>,
             id: u32,
@@ -5530,7 +6460,7 @@ pub mod pallet {
         /// * Caller must be owner of Subnet Node, hotkey or coldkey
         ///
         #[pallet::call_index(63)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::register_or_update_identity())]
         pub fn register_or_update_identity(
             origin: OriginFor<T>,
             hotkey: T::AccountId,
@@ -5566,7 +6496,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(64)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::remove_identity())]
         pub fn remove_identity(origin: OriginFor<T>) -> DispatchResult {
             let coldkey: T::AccountId = ensure_signed(origin)?;
             Self::do_remove_identity(coldkey);
@@ -5582,10 +6512,20 @@ pub mod pallet {
         /// * `data` - Vector of SubnetNodeConsensusData on each Subnet Node for scoring each
         /// * `args` (Optional) - Data that can be used by the subnet
         ///
+        /// # Errors
+        ///
+        /// * `ConsensusDataTooLong` - `data` has more entries than `MaxSubnetNodes`
+        /// * `DuplicateSubnetNodeId` - `data` contains the same subnet node ID more than once
+        ///
+        /// # Events
+        ///
+        /// * `ConsensusDataRejected` - Entries of `data` removed because their subnet node doesn't
+        ///   exist or isn't `Included`
+        ///
         /// Returns Ok(Pays::No.into()) on success
         ///
         #[pallet::call_index(65)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::propose_attestation(data.len() as u32))]
         pub fn propose_attestation(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -5619,7 +6559,7 @@ pub mod pallet {
         /// Returns Ok(Pays::No.into()) on success
         ///
         #[pallet::call_index(66)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::attest())]
         pub fn attest(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -5634,63 +6574,72 @@ pub mod pallet {
 
         /// Update coldkey
         ///
+        /// Moves every hotkey of the caller, its unbondings, identity, reputation and subnet nodes
+        /// to `new_coldkey`. `new_coldkey` must be unused, nothing is moved back to the caller.
+        ///
         /// # Arguments
         ///
         /// * `hotkey` - Current hotkey.
         /// * `new_coldkey` - New coldkey
-        /// * `subnet_id` - Optional parameter used for subnet owners
+        /// * `hotkeys` - Witness of the hotkeys owned by the caller, see `ColdkeyHotkeys`. The
+        ///   weight is charged on it and refunded to the actual count.
         ///
         #[pallet::call_index(67)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::update_coldkey(*hotkeys))]
         pub fn update_coldkey(
             origin: OriginFor<T>,
             hotkey: T::AccountId,
             new_coldkey: T::AccountId,
-        ) -> DispatchResult {
+            hotkeys: u32,
+        ) -> DispatchResultWithPostInfo {
             let curr_coldkey: T::AccountId = ensure_signed(origin)?;
 
             Self::is_paused()?;
 
             ensure!(&hotkey != &new_coldkey, Error::<T>::ColdkeyMatchesHotkey);
 
-            HotkeyOwner::<T>::try_mutate_exists(hotkey, |maybe_coldkey| -> DispatchResult {
-                match maybe_coldkey {
-                    Some(coldkey) if *coldkey == curr_coldkey => {
-                        // Condition met, update or remove
-                        *maybe_coldkey = Some(new_coldkey.clone());
-                        // Update StakeUnbondingLedger
-                        // StakeUnbondingLedger::<T>::swap(&curr_coldkey, &new_coldkey);
+            ensure!(
+                Self::is_hotkey_owner(&hotkey, &curr_coldkey),
+                Error::<T>::NotKeyOwner
+            );
+
+            // Swapping with a used coldkey would hand its hotkeys and unbondings to the caller
+            ensure!(
+                ColdkeyHotkeys::<T>::get(&new_coldkey).is_empty()
+                    && StakeUnbondingLedger::<T>::get(&new_coldkey).is_empty()
+                    && !ColdkeyIdentity::<T>::contains_key(&new_coldkey)
+                    && !ColdkeyReputation::<T>::contains_key(&new_coldkey)
+                    && ColdkeySubnetNodes::<T>::get(&new_coldkey).is_empty(),
+                Error::<T>::ColdkeyInUse
+            );
 
-                        StakeUnbondingLedger::<T>::swap(&curr_coldkey, &new_coldkey);
+            let moved_hotkeys = ColdkeyHotkeys::<T>::decode_len(&curr_coldkey).unwrap_or(0) as u32;
 
-                        // Update coldkeys list of hotkeys
-                        ColdkeyHotkeys::<T>::swap(&curr_coldkey, &new_coldkey);
+            ensure!(moved_hotkeys <= hotkeys, Error::<T>::HotkeysWitnessTooLow);
 
-                        // Identity is not required so we ensure it exists first
-                        if let Ok(coldkey_identity) = ColdkeyIdentity::<T>::try_get(&curr_coldkey) {
-                            ColdkeyIdentity::<T>::swap(&curr_coldkey, &new_coldkey);
-                            ColdkeyIdentityNameOwner::<T>::insert(
-                                coldkey_identity.name.clone(),
-                                &new_coldkey,
-                            );
-                        };
+            // `new_coldkey` is unused, the swaps below only move the callers entries to it
+            StakeUnbondingLedger::<T>::swap(&curr_coldkey, &new_coldkey);
 
-                        ColdkeyReputation::<T>::swap(&curr_coldkey, &new_coldkey);
+            // Update coldkeys list of hotkeys, `hotkey` included
+            ColdkeyHotkeys::<T>::swap(&curr_coldkey, &new_coldkey);
+            Self::set_hotkeys_owner(&new_coldkey);
 
-                        ColdkeySubnetNodes::<T>::swap(&curr_coldkey, &new_coldkey);
+            // Identity is not required so we ensure it exists first
+            if let Ok(coldkey_identity) = ColdkeyIdentity::<T>::try_get(&curr_coldkey) {
+                ColdkeyIdentity::<T>::swap(&curr_coldkey, &new_coldkey);
+                ColdkeyIdentityNameOwner::<T>::insert(coldkey_identity.name.clone(), &new_coldkey);
+            };
 
-                        Self::deposit_event(Event::UpdateColdkey {
-                            coldkey: curr_coldkey,
-                            new_coldkey: new_coldkey,
-                        });
+            ColdkeyReputation::<T>::swap(&curr_coldkey, &new_coldkey);
 
-                        Ok(())
-                    }
-                    // --- Revert from here if not exist
-                    Some(_) => Err(Error::<T>::NotKeyOwner.into()),
-                    None => Err(Error::<T>::NotKeyOwner.into()),
-                }
-            })
+            ColdkeySubnetNodes::<T>::swap(&curr_coldkey, &new_coldkey);
+
+            Self::deposit_event(Event::UpdateColdkey {
+                coldkey: curr_coldkey,
+                new_coldkey: new_coldkey,
+            });
+
+            Ok(Some(T::WeightInfo::update_coldkey(moved_hotkeys)).into())
         }
 
         /// Update hotkey (subnet node, overwatch node)
@@ -5711,7 +6660,7 @@ pub mod pallet {
         /// * `new_hotkey` - New hotkey to replace the old hotkey.
         ///
         #[pallet::call_index(68)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::update_hotkey())]
         pub fn update_hotkey(
             origin: OriginFor<T>,
             old_hotkey: T::AccountId,
@@ -5780,8 +6729,10 @@ pub mod pallet {
             // Check if hotkey has a subnet ID and update node and stake balance
             // *Note: Each hotkey is unique to each subnet
             if let Some(subnet_id) = HotkeySubnetId::<T>::take(&old_hotkey) {
-                // HotkeySubnetNodeId is removed when node and subnet is removed
+                // HotkeySubnetNodeId is removed when the node is removed, and in `on_idle` after the
+                // subnet is removed
                 if let Some(subnet_node_id) = HotkeySubnetNodeId::<T>::get(&subnet_id, &old_hotkey)
+                    .filter(|_| SubnetsData::<T>::contains_key(subnet_id))
                 {
                     // --- Update nodes hotkey by inserting and overriding
                     SubnetNodeIdHotkey::<T>::insert(subnet_id, subnet_node_id, &new_hotkey);
@@ -5819,7 +6770,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(69)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::register_overwatch_node())]
         pub fn register_overwatch_node(
             origin: OriginFor<T>,
             hotkey: T::AccountId,
@@ -5830,7 +6781,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(70)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::remove_overwatch_node())]
         pub fn remove_overwatch_node(
             origin: OriginFor<T>,
             overwatch_node_id: u32,
@@ -5843,7 +6794,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(71)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::anyone_remove_overwatch_node())]
         pub fn anyone_remove_overwatch_node(
             origin: OriginFor<T>,
             overwatch_node_id: u32,
@@ -5887,7 +6838,7 @@ pub mod pallet {
         /// Returns Ok(Pays::No.into()) on success
         ///
         #[pallet::call_index(72)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_overwatch_node_peer_id())]
         pub fn set_overwatch_node_peer_id(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -5914,10 +6865,20 @@ pub mod pallet {
         /// * `overwatch_node_id` - Caller Overwatch Node ID.
         /// * `mut commit_weights` - Vector of hashed subnet commits, see `OverwatchCommit`.
         ///
+        /// # Errors
+        ///
+        /// * `OverwatchCommitsTooLong` - `commit_weights` has more entries than `MaxSubnets`
+        /// * `DuplicateSubnetId` - `commit_weights` contains the same subnet ID more than once
+        ///
+        /// # Events
+        ///
+        /// * `OverwatchCommitsRejected` - Commits removed because their subnet doesn't exist or
+        ///   isn't `Active`
+        ///
         /// Returns Ok(Pays::No.into()) on success
         ///
         #[pallet::call_index(73)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::commit_overwatch_subnet_weights(commit_weights.len() as u32))]
         pub fn commit_overwatch_subnet_weights(
             origin: OriginFor<T>,
             overwatch_node_id: u32,
@@ -5946,7 +6907,7 @@ pub mod pallet {
         /// Returns Ok(Pays::No.into()) on success
         ///
         #[pallet::call_index(74)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::reveal_overwatch_subnet_weights(reveals.len() as u32))]
         pub fn reveal_overwatch_subnet_weights(
             origin: OriginFor<T>,
             overwatch_node_id: u32,
@@ -5970,7 +6931,7 @@ pub mod pallet {
         /// * Must have amount free in wallet
         ///
         #[pallet::call_index(75)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::add_to_overwatch_stake())]
         pub fn add_to_overwatch_stake(
             origin: OriginFor<T>,
             overwatch_node_id: u32,
@@ -6009,7 +6970,7 @@ pub mod pallet {
         /// * If Overwatch Node, must have available staked balance greater than minimum required stake balance
         ///
         #[pallet::call_index(76)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::remove_overwatch_stake())]
         pub fn remove_overwatch_stake(
             origin: OriginFor<T>,
             hotkey: T::AccountId,
@@ -6051,7 +7012,7 @@ pub mod pallet {
         /// Requires majority vote
         ///
         #[pallet::call_index(77)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::pause())]
         pub fn pause(origin: OriginFor<T>) -> DispatchResult {
             T::MajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_pause()
@@ -6064,14 +7025,14 @@ pub mod pallet {
         /// Requires majority vote
         ///
         #[pallet::call_index(78)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::unpause())]
         pub fn unpause(origin: OriginFor<T>) -> DispatchResult {
             T::MajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_unpause()
         }
 
         #[pallet::call_index(79)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::collective_remove_subnet())]
         pub fn collective_remove_subnet(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -6081,7 +7042,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(80)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::collective_remove_subnet_node())]
         pub fn collective_remove_subnet_node(
             origin: OriginFor<T>,
             subnet_id: u32,
@@ -6092,7 +7053,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(81)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::collective_remove_overwatch_node())]
         pub fn collective_remove_overwatch_node(
             origin: OriginFor<T>,
             overwatch_node_id: u32,
@@ -6108,7 +7069,7 @@ pub mod pallet {
         /// Requires super majority vote
         ///
         #[pallet::call_index(82)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_min_subnet_delegate_stake_factor())]
         pub fn set_min_subnet_delegate_stake_factor(
             origin: OriginFor<T>,
             value: u128,
@@ -6124,42 +7085,42 @@ pub mod pallet {
         /// Requires super majority vote
         ///
         #[pallet::call_index(83)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_subnet_owner_percentage())]
         pub fn set_subnet_owner_percentage(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_subnet_owner_percentage(value)
         }
 
         #[pallet::call_index(84)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_max_subnets())]
         pub fn set_max_subnets(origin: OriginFor<T>, value: u32) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_max_subnets(value)
         }
 
         #[pallet::call_index(85)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_max_bootnodes())]
         pub fn set_max_bootnodes(origin: OriginFor<T>, value: u32) -> DispatchResult {
             T::MajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_max_bootnodes(value)
         }
 
         #[pallet::call_index(86)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_max_subnet_bootnodes_access())]
         pub fn set_max_subnet_bootnodes_access(origin: OriginFor<T>, value: u32) -> DispatchResult {
             T::MajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_max_subnet_bootnodes_access(value)
         }
 
         #[pallet::call_index(87)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_max_pause_epochs())]
         pub fn set_max_pause_epochs(origin: OriginFor<T>, value: u32) -> DispatchResult {
             T::MajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_max_pause_epochs(value)
         }
 
         #[pallet::call_index(88)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_delegate_stake_subnet_removal_interval())]
         pub fn set_delegate_stake_subnet_removal_interval(
             origin: OriginFor<T>,
             value: u32,
@@ -6169,7 +7130,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(89)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_subnet_removal_intervals())]
         pub fn set_subnet_removal_intervals(
             origin: OriginFor<T>,
             min: u32,
@@ -6180,7 +7141,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(90)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_subnet_pause_cooldown_epochs())]
         pub fn set_subnet_pause_cooldown_epochs(
             origin: OriginFor<T>,
             value: u32,
@@ -6190,14 +7151,14 @@ pub mod pallet {
         }
 
         #[pallet::call_index(91)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_min_registration_cost())]
         pub fn set_min_registration_cost(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_min_registration_cost(value)
         }
 
         #[pallet::call_index(92)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_registration_cost_delay_blocks())]
         pub fn set_registration_cost_delay_blocks(
             origin: OriginFor<T>,
             value: u32,
@@ -6207,14 +7168,14 @@ pub mod pallet {
         }
 
         #[pallet::call_index(93)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_registration_cost_alpha())]
         pub fn set_registration_cost_alpha(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::MajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_registration_cost_alpha(value)
         }
 
         #[pallet::call_index(94)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_new_registration_cost_multiplier())]
         pub fn set_new_registration_cost_multiplier(
             origin: OriginFor<T>,
             value: u128,
@@ -6224,7 +7185,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(95)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_max_min_delegate_stake_multiplier())]
         pub fn set_max_min_delegate_stake_multiplier(
             origin: OriginFor<T>,
             value: u128,
@@ -6234,21 +7195,21 @@ pub mod pallet {
         }
 
         #[pallet::call_index(96)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_churn_limits())]
         pub fn set_churn_limits(origin: OriginFor<T>, min: u32, max: u32) -> DispatchResult {
             T::MajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_churn_limits(min, max)
         }
 
         #[pallet::call_index(97)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_queue_epochs())]
         pub fn set_queue_epochs(origin: OriginFor<T>, min: u32, max: u32) -> DispatchResult {
             T::MajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_queue_epochs(min, max)
         }
 
         #[pallet::call_index(98)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_max_swap_queue_calls_per_block())]
         pub fn set_max_swap_queue_calls_per_block(
             origin: OriginFor<T>,
             value: u32,
@@ -6258,7 +7219,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(99)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_min_idle_classification_epochs())]
         pub fn set_min_idle_classification_epochs(
             origin: OriginFor<T>,
             value: u32,
@@ -6268,7 +7229,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(100)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_max_idle_classification_epochs())]
         pub fn set_max_idle_classification_epochs(
             origin: OriginFor<T>,
             value: u32,
@@ -6278,7 +7239,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(101)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_subnet_activation_enactment_epochs())]
         pub fn set_subnet_activation_enactment_epochs(
             origin: OriginFor<T>,
             value: u32,
@@ -6288,7 +7249,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(102)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_included_classification_epochs())]
         pub fn set_included_classification_epochs(
             origin: OriginFor<T>,
             min: u32,
@@ -6299,14 +7260,14 @@ pub mod pallet {
         }
 
         #[pallet::call_index(103)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_subnet_stakes())]
         pub fn set_subnet_stakes(origin: OriginFor<T>, min: u128, max: u128) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_subnet_stakes(min, max)
         }
 
         #[pallet::call_index(104)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_delegate_stake_percentages())]
         pub fn set_delegate_stake_percentages(
             origin: OriginFor<T>,
             min: u128,
@@ -6317,7 +7278,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(105)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_min_max_registered_nodes())]
         pub fn set_min_max_registered_nodes(
             origin: OriginFor<T>,
             min: u32,
@@ -6328,7 +7289,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(106)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_max_subnet_delegate_stake_rewards_percentage_change())]
         pub fn set_max_subnet_delegate_stake_rewards_percentage_change(
             origin: OriginFor<T>,
             value: u128,
@@ -6338,7 +7299,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(107)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_subnet_delegate_stake_rewards_update_period())]
         pub fn set_subnet_delegate_stake_rewards_update_period(
             origin: OriginFor<T>,
             value: u32,
@@ -6348,14 +7309,14 @@ pub mod pallet {
         }
 
         #[pallet::call_index(108)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_min_attestation_percentage())]
         pub fn set_min_attestation_percentage(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_min_attestation_percentage(value)
         }
 
         #[pallet::call_index(109)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_super_majority_attestation_ratio())]
         pub fn set_super_majority_attestation_ratio(
             origin: OriginFor<T>,
             value: u128,
@@ -6365,56 +7326,56 @@ pub mod pallet {
         }
 
         #[pallet::call_index(110)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_base_validator_reward())]
         pub fn set_base_validator_reward(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_base_validator_reward(value)
         }
 
         #[pallet::call_index(111)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_base_slash_percentage())]
         pub fn set_base_slash_percentage(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_base_slash_percentage(value)
         }
 
         #[pallet::call_index(112)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_max_slash_amount())]
         pub fn set_max_slash_amount(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_max_slash_amount(value)
         }
 
         #[pallet::call_index(113)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_reputation_increase_factor())]
         pub fn set_reputation_increase_factor(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::MajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_reputation_increase_factor(value)
         }
 
         #[pallet::call_index(114)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_reputation_decrease_factor())]
         pub fn set_reputation_decrease_factor(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::MajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_reputation_decrease_factor(value)
         }
 
         #[pallet::call_index(115)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_network_max_stake_balance())]
         pub fn set_network_max_stake_balance(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_network_max_stake_balance(value)
         }
 
         #[pallet::call_index(116)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_min_delegate_stake_deposit())]
         pub fn set_min_delegate_stake_deposit(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_min_delegate_stake_deposit(value)
         }
 
         #[pallet::call_index(117)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_node_reward_rate_update_period())]
         pub fn set_node_reward_rate_update_period(
             origin: OriginFor<T>,
             value: u32,
@@ -6424,21 +7385,21 @@ pub mod pallet {
         }
 
         #[pallet::call_index(118)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_max_reward_rate_decrease())]
         pub fn set_max_reward_rate_decrease(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::MajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_max_reward_rate_decrease(value)
         }
 
         #[pallet::call_index(119)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_subnet_distribution_power())]
         pub fn set_subnet_distribution_power(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_subnet_distribution_power(value)
         }
 
         #[pallet::call_index(120)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_delegate_stake_weight_factor())]
         pub fn set_delegate_stake_weight_factor(
             origin: OriginFor<T>,
             value: u128,
@@ -6448,7 +7409,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(121)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_inflation_sigmoid_steepness())]
         pub fn set_inflation_sigmoid_steepness(
             origin: OriginFor<T>,
             value: u128,
@@ -6458,14 +7419,14 @@ pub mod pallet {
         }
 
         #[pallet::call_index(122)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_max_overwatch_nodes())]
         pub fn set_max_overwatch_nodes(origin: OriginFor<T>, value: u32) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_max_overwatch_nodes(value)
         }
 
         #[pallet::call_index(123)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_overwatch_epoch_length_multiplier())]
         pub fn set_overwatch_epoch_length_multiplier(
             origin: OriginFor<T>,
             value: u32,
@@ -6475,7 +7436,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(124)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_overwatch_commit_cutoff_percent())]
         pub fn set_overwatch_commit_cutoff_percent(
             origin: OriginFor<T>,
             value: u128,
@@ -6485,7 +7446,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(125)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_overwatch_min_diversification_ratio())]
         pub fn set_overwatch_min_diversification_ratio(
             origin: OriginFor<T>,
             value: u128,
@@ -6495,14 +7456,14 @@ pub mod pallet {
         }
 
         #[pallet::call_index(126)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_overwatch_min_rep_score())]
         pub fn set_overwatch_min_rep_score(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_overwatch_min_rep_score(value)
         }
 
         #[pallet::call_index(127)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_overwatch_min_avg_attestation_ratio())]
         pub fn set_overwatch_min_avg_attestation_ratio(
             origin: OriginFor<T>,
             value: u128,
@@ -6512,14 +7473,14 @@ pub mod pallet {
         }
 
         #[pallet::call_index(128)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_overwatch_min_age())]
         pub fn set_overwatch_min_age(origin: OriginFor<T>, value: u32) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_overwatch_min_age(value)
         }
 
         #[pallet::call_index(129)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_overwatch_min_stake_balance())]
         pub fn set_overwatch_min_stake_balance(
             origin: OriginFor<T>,
             value: u128,
@@ -6529,21 +7490,21 @@ pub mod pallet {
         }
 
         #[pallet::call_index(130)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_min_max_subnet_node())]
         pub fn set_min_max_subnet_node(origin: OriginFor<T>, min: u32, max: u32) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_min_max_subnet_node(min, max)
         }
 
         #[pallet::call_index(131)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_tx_rate_limit())]
         pub fn set_tx_rate_limit(origin: OriginFor<T>, value: u32) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_tx_rate_limit(value)
         }
 
         #[pallet::call_index(132)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::collective_set_coldkey_overwatch_node_eligibility())]
         pub fn collective_set_coldkey_overwatch_node_eligibility(
             origin: OriginFor<T>,
             coldkey: T::AccountId,
@@ -6554,7 +7515,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(133)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_min_subnet_registration_epochs())]
         pub fn set_min_subnet_registration_epochs(
             origin: OriginFor<T>,
             value: u32,
@@ -6564,14 +7525,14 @@ pub mod pallet {
         }
 
         #[pallet::call_index(134)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_subnet_registration_epochs())]
         pub fn set_subnet_registration_epochs(origin: OriginFor<T>, value: u32) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_subnet_registration_epochs(value)
         }
 
         #[pallet::call_index(135)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_min_active_node_stake_epochs())]
         pub fn set_min_active_node_stake_epochs(
             origin: OriginFor<T>,
             value: u32,
@@ -6581,7 +7542,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(136)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_delegate_stake_cooldown_epochs())]
         pub fn set_delegate_stake_cooldown_epochs(
             origin: OriginFor<T>,
             value: u32,
@@ -6591,7 +7552,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(137)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_node_delegate_stake_cooldown_epochs())]
         pub fn set_node_delegate_stake_cooldown_epochs(
             origin: OriginFor<T>,
             value: u32,
@@ -6601,14 +7562,14 @@ pub mod pallet {
         }
 
         #[pallet::call_index(138)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_min_stake_cooldown_epochs())]
         pub fn set_min_stake_cooldown_epochs(origin: OriginFor<T>, value: u32) -> DispatchResult {
             T::MajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_min_stake_cooldown_epochs(value)
         }
 
         #[pallet::call_index(139)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_max_unbondings())]
         pub fn set_max_unbondings(origin: OriginFor<T>, value: u32) -> DispatchResult {
             T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_max_unbondings(value)
@@ -6616,35 +7577,37 @@ pub mod pallet {
 
         /// Set midpoint on sigmoid for inflation mech
         #[pallet::call_index(140)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_sigmoid_midpoint())]
         pub fn set_sigmoid_midpoint(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::MajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_sigmoid_midpoint(value)
         }
 
         #[pallet::call_index(141)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_maximum_hooks_weight())]
         pub fn set_maximum_hooks_weight(origin: OriginFor<T>, value: u32) -> DispatchResult {
             T::MajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_maximum_hooks_weight(value)
         }
 
         #[pallet::call_index(142)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_base_node_burn_amount())]
         pub fn set_base_node_burn_amount(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::MajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_base_node_burn_amount(value)
         }
 
         #[pallet::call_index(143)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_node_burn_rates())]
         pub fn set_node_burn_rates(origin: OriginFor<T>, min: u128, max: u128) -> DispatchResult {
             T::MajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_node_burn_rates(min, max)
         }
 
         #[pallet::call_index(144)]
-        #[pallet::weight({0})]
+        #[pallet::weight(
+            T::WeightInfo::set_max_subnet_node_min_weight_decrease_reputation_threshold()
+        )]
         pub fn set_max_subnet_node_min_weight_decrease_reputation_threshold(
             origin: OriginFor<T>,
             value: u128,
@@ -6654,35 +7617,35 @@ pub mod pallet {
         }
 
         #[pallet::call_index(145)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_validator_reward_k())]
         pub fn set_validator_reward_k(origin: OriginFor<T>, value: u64) -> DispatchResult {
             T::MajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_validator_reward_k(value)
         }
 
         #[pallet::call_index(146)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_validator_reward_midpoint())]
         pub fn set_validator_reward_midpoint(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::MajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_validator_reward_midpoint(value)
         }
 
         #[pallet::call_index(147)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_attestor_reward_exponent())]
         pub fn set_attestor_reward_exponent(origin: OriginFor<T>, value: u64) -> DispatchResult {
             T::MajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_attestor_reward_exponent(value)
         }
 
         #[pallet::call_index(148)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_attestor_min_reward_factor())]
         pub fn set_attestor_min_reward_factor(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::MajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_attestor_min_reward_factor(value)
         }
 
         #[pallet::call_index(149)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_min_max_node_reputation())]
         pub fn set_min_max_node_reputation(
             origin: OriginFor<T>,
             min: u128,
@@ -6693,7 +7656,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(150)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_min_max_node_reputation_factor())]
         pub fn set_min_max_node_reputation_factor(
             origin: OriginFor<T>,
             min: u128,
@@ -6704,14 +7667,14 @@ pub mod pallet {
         }
 
         #[pallet::call_index(151)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_min_subnet_reputation())]
         pub fn set_min_subnet_reputation(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::MajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_min_subnet_reputation(value)
         }
 
         #[pallet::call_index(152)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_not_in_consensus_subnet_reputation_factor())]
         pub fn set_not_in_consensus_subnet_reputation_factor(
             origin: OriginFor<T>,
             value: u128,
@@ -6721,7 +7684,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(153)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_max_pause_epochs_subnet_reputation_factor())]
         pub fn set_max_pause_epochs_subnet_reputation_factor(
             origin: OriginFor<T>,
             value: u128,
@@ -6731,7 +7694,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(154)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_less_than_min_nodes_subnet_reputation_factor())]
         pub fn set_less_than_min_nodes_subnet_reputation_factor(
             origin: OriginFor<T>,
             value: u128,
@@ -6741,7 +7704,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(155)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_validator_proposal_absent_subnet_reputation_factor())]
         pub fn set_validator_proposal_absent_subnet_reputation_factor(
             origin: OriginFor<T>,
             value: u128,
@@ -6751,7 +7714,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(156)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_in_consensus_subnet_reputation_factor())]
         pub fn set_in_consensus_subnet_reputation_factor(
             origin: OriginFor<T>,
             value: u128,
@@ -6761,14 +7724,14 @@ pub mod pallet {
         }
 
         #[pallet::call_index(157)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_overwatch_weight_factor())]
         pub fn set_overwatch_weight_factor(origin: OriginFor<T>, value: u128) -> DispatchResult {
             T::MajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_overwatch_weight_factor(value)
         }
 
         #[pallet::call_index(158)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_max_emergency_validator_epochs_multiplier())]
         pub fn set_max_emergency_validator_epochs_multiplier(
             origin: OriginFor<T>,
             value: u128,
@@ -6778,14 +7741,14 @@ pub mod pallet {
         }
 
         #[pallet::call_index(159)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_max_emergency_subnet_nodes())]
         pub fn set_max_emergency_subnet_nodes(origin: OriginFor<T>, value: u32) -> DispatchResult {
             T::MajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_max_emergency_subnet_nodes(value)
         }
 
         #[pallet::call_index(160)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_overwatch_stake_weight_factor())]
         pub fn set_overwatch_stake_weight_factor(
             origin: OriginFor<T>,
             value: u128,
@@ -6795,7 +7758,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(161)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_subnet_weight_factors())]
         pub fn set_subnet_weight_factors(
             origin: OriginFor<T>,
             value: SubnetWeightFactorsData,
@@ -6805,7 +7768,7 @@ pub mod pallet {
         }
 
         #[pallet::call_index(162)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::set_churn_limit_multipliers())]
         pub fn set_churn_limit_multipliers(
             origin: OriginFor<T>,
             min: u32,
@@ -6814,6 +7777,172 @@ pub mod pallet {
             T::MajorityCollectiveOrigin::ensure_origin(origin)?;
             Self::do_set_churn_limit_multipliers(min, max)
         }
+
+        /// Cancel a queued swap call and withdraw its balance into the unbonding ledger
+        ///
+        /// # Arguments
+        ///
+        /// * `id` - Swap queue ID.
+        ///
+        /// # Requirements
+        ///
+        /// * Caller must be the account the swap call was queued for
+        ///
+        /// # Notes
+        ///
+        /// * The balance is unbonded using the cooldown of the swap target, see
+        ///   `DelegateStakeCooldownEpochs` and `NodeDelegateStakeCooldownEpochs`
+        ///
+        #[pallet::call_index(163)]
+        #[pallet::weight(T::WeightInfo::cancel_swap_queue_item())]
+        pub fn cancel_swap_queue_item(origin: OriginFor<T>, id: u32) -> DispatchResult {
+            let account_id: T::AccountId = ensure_signed(origin)?;
+
+            Self::is_paused()?;
+
+            Self::do_cancel_swap_queue_item(account_id, id)
+        }
+
+        #[pallet::call_index(164)]
+        #[pallet::weight(T::WeightInfo::set_max_pending_swaps_per_account())]
+        pub fn set_max_pending_swaps_per_account(
+            origin: OriginFor<T>,
+            value: u32,
+        ) -> DispatchResult {
+            T::SuperMajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_max_pending_swaps_per_account(value)
+        }
+
+        /// Update how the subnet's validator is elected each epoch
+        ///
+        /// # Arguments
+        ///
+        /// * `subnet_id` - Subnet ID
+        /// * `value` - See `ElectionMode`
+        ///
+        /// # Requirements
+        ///
+        /// * Caller must be the subnet owner
+        ///
+        /// # Notes
+        ///
+        /// * Takes effect from the next election
+        ///
+        #[pallet::call_index(165)]
+        #[pallet::weight(T::WeightInfo::owner_update_election_mode())]
+        pub fn owner_update_election_mode(
+            origin: OriginFor<T>,
+            subnet_id: u32,
+            value: ElectionMode,
+        ) -> DispatchResult {
+            Self::is_paused()?;
+            Self::do_owner_update_election_mode(origin, subnet_id, value)
+        }
+
+        #[pallet::call_index(166)]
+        #[pallet::weight(T::WeightInfo::set_backup_proposer_epoch_progression())]
+        pub fn set_backup_proposer_epoch_progression(
+            origin: OriginFor<T>,
+            value: u128,
+        ) -> DispatchResult {
+            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_backup_proposer_epoch_progression(value)
+        }
+
+        #[pallet::call_index(167)]
+        #[pallet::weight(T::WeightInfo::set_backup_validator_reward_factor())]
+        pub fn set_backup_validator_reward_factor(
+            origin: OriginFor<T>,
+            value: u128,
+        ) -> DispatchResult {
+            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_backup_validator_reward_factor(value)
+        }
+
+        #[pallet::call_index(168)]
+        #[pallet::weight(T::WeightInfo::set_consensus_archive_depth())]
+        pub fn set_consensus_archive_depth(origin: OriginFor<T>, value: u32) -> DispatchResult {
+            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_consensus_archive_depth(value)
+        }
+
+        #[pallet::call_index(169)]
+        #[pallet::weight(T::WeightInfo::set_reward_history_depth())]
+        pub fn set_reward_history_depth(origin: OriginFor<T>, value: u32) -> DispatchResult {
+            T::MajorityCollectiveOrigin::ensure_origin(origin)?;
+            Self::do_set_reward_history_depth(value)
+        }
+
+        /// Prove a hotkey owns a peer ID
+        ///
+        /// The proof is stored until it's used by `register_subnet_node`, `update_peer_id`,
+        /// `update_bootnode_peer_id` or `update_client_peer_id` for the hotkey. `PeerIdProofDeposit`
+        /// is reserved from the caller until the proof is used, replaced by another proof of the
+        /// peer ID or pruned by `on_idle` after expiring.
+        ///
+        /// # Arguments
+        ///
+        /// * `subnet_id` - Subnet ID
+        /// * `hotkey` - Hotkey the peer ID is registered to
+        /// * `peer_id` - Peer ID with an inlined Ed25519 or Secp256k1 key
+        /// * `block` - Block the proof was signed at
+        /// * `signature` - Signature of `peer_id_proof_message` by the peer ID's key
+        ///
+        /// # Requirements
+        ///
+        /// * The subnet requires peer ID proofs
+        /// * `block` must be within `PEER_ID_PROOF_VALIDITY_BLOCKS` of the current block
+        /// * Ed25519 signatures are 64 bytes, Secp256k1 signatures are DER encoded, 64 byte
+        ///   compact or 65 byte recoverable signatures of the SHA-256 hash of the message
+        /// * The caller can reserve `PeerIdProofDeposit`
+        ///
+        #[pallet::call_index(170)]
+        #[pallet::weight(T::WeightInfo::prove_peer_id())]
+        pub fn prove_peer_id(
+            origin: OriginFor<T>,
+            subnet_id: u32,
+            hotkey: T::AccountId,
+            peer_id: PeerId,
+            block: u32,
+            signature: BoundedVec<u8, DefaultMaxVectorLength>,
+        ) -> DispatchResult {
+            let depositor: T::AccountId = ensure_signed(origin)?;
+            Self::is_paused()?;
+            Self::do_prove_peer_id(
+                depositor,
+                subnet_id,
+                hotkey,
+                peer_id,
+                block,
+                signature.into_inner(),
+            )
+        }
+
+        /// Update whether registering or updating peer IDs requires a proof of ownership
+        ///
+        /// # Arguments
+        ///
+        /// * `subnet_id` - Subnet ID
+        /// * `value` - Require `prove_peer_id` proofs
+        ///
+        /// # Requirements
+        ///
+        /// * Caller must be the subnet owner
+        ///
+        /// # Notes
+        ///
+        /// * Peer IDs with RSA or ECDSA keys can't be proven
+        ///
+        #[pallet::call_index(171)]
+        #[pallet::weight(T::WeightInfo::owner_update_peer_id_proof_required())]
+        pub fn owner_update_peer_id_proof_required(
+            origin: OriginFor<T>,
+            subnet_id: u32,
+            value: bool,
+        ) -> DispatchResult {
+            Self::is_paused()?;
+            Self::do_owner_update_peer_id_proof_required(origin, subnet_id, value)
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -7573,24 +8702,27 @@ pub mod pallet {
         ///
         /// ## 6. All Node Data (via `clean_subnet_nodes`)
         ///
-        /// Removes all subnet node data including:
-        /// - `SubnetNodesData` - All node metadata (cleared via prefix)
-        /// - `RegisteredSubnetNodesData` - Registration data (cleared via prefix)
+        /// Removes all subnet node data including the following. Prefixes marked `(queued)` are
+        /// pushed to `CleanupQueue` and cleared over the following blocks in `on_idle`, so the
+        /// removal itself does a constant amount of work regardless of the number of nodes:
+        /// - `SubnetNodesData` - All node metadata (queued)
+        /// - `RegisteredSubnetNodesData` - Registration data (queued)
         /// - `TotalActiveSubnetNodes` - Active node counter
         /// - `TotalSubnetNodes` - Total node counter
         /// - `TotalSubnetNodeUids` - Node ID counter
         /// - `TotalActiveNodes` - Global active node counter (decremented)
-        /// - `PeerIdSubnetNodeId` - Peer ID mappings (cleared via prefix)
-        /// - `BootnodePeerIdSubnetNodeId` - Bootnode peer mappings (cleared via prefix)
-        /// - `ClientPeerIdSubnetNodeId` - Client peer mappings (cleared via prefix)
-        /// - `BootnodeSubnetNodeId` - Bootnode node mappings (cleared via prefix)
-        /// - `UniqueParamSubnetNodeId` - Unique parameter tracking (cleared via prefix)
-        /// - `HotkeySubnetNodeId` - Hotkey to node ID mappings (cleared via prefix)
-        /// - `SubnetNodeIdHotkey` - Reverse hotkey mappings (cleared via prefix)
-        /// - `SubnetNodeReputation` - Individual node reputations (cleared via prefix)
-        /// - `SubnetNodeConsecutiveIncludedEpochs` - Inclusion streaks (cleared via prefix)
-        /// - `SubnetElectedValidator` - Validator election results (cleared via prefix)
-        /// - `NodeSlotIndex` - Slot index mappings (cleared via prefix)
+        /// - `PeerIdSubnetNodeId` - Peer ID mappings (queued)
+        /// - `BootnodePeerIdSubnetNodeId` - Bootnode peer mappings (queued)
+        /// - `ClientPeerIdSubnetNodeId` - Client peer mappings (queued)
+        /// - `BootnodeSubnetNodeId` - Bootnode node mappings (queued)
+        /// - `UniqueParamSubnetNodeId` - Unique parameter tracking (queued)
+        /// - `HotkeySubnetNodeId` - Hotkey to node ID mappings (queued)
+        /// - `SubnetNodeIdHotkey` - Reverse hotkey mappings (queued)
+        /// - `SubnetNodeReputation` - Individual node reputations (queued)
+        /// - `SubnetNodeConsecutiveIncludedEpochs` - Inclusion streaks (queued)
+        /// - `SubnetElectedValidator` - Validator election results (queued)
+        /// - `SubnetBackupValidator` - Backup validator election results (queued)
+        /// - `NodeSlotIndex` - Slot index mappings (queued)
         /// - `SubnetNodeElectionSlots` - Election slot arrays
         /// - `SubnetNodeQueue` - Node queue
         /// - `TotalElectableNodes` - Global electable node counter (decremented)
@@ -7600,10 +8732,10 @@ pub mod pallet {
         ///
         /// ## Consensus Submission Data (Preserved)
         ///
-        /// - **`SubnetConsensusSubmission`**: Historical consensus data is **NOT** removed
-        ///   - Preserves consensus history for auditing and analysis
+        /// - **`SubnetConsensusSubmission`**, **`ConsensusSubmissionArchive`**: Historical consensus
+        ///   data is **NOT** removed inline
+        ///   - Queued to `CleanupQueue` and cleared in `on_idle`
         ///   - Does not impact blockchain logic for active subnets
-        ///   - Can be queried for historical subnet performance
         ///
         /// ## Stake Data (Preserved - User Action Required)
         ///
@@ -7781,7 +8913,7 @@ pub mod pallet {
         /// - Removal is **permanent** and **immediate** (no grace period)
         /// - Subnet ID can be reused after sufficient time
         /// - Epoch slot is freed for assignment to new subnets
-        /// - Historical consensus data is preserved for analysis
+        /// - Node and consensus data is cleared over the following blocks in `on_idle`
         /// - Total subnet count is not decremented (only active count)
         /// - Removal during registration period returns all registration costs (governance decision)
         ///
@@ -7822,6 +8954,8 @@ pub mod pallet {
             InitialColdkeyData::<T>::remove(subnet_id);
             MaxRegisteredNodes::<T>::remove(subnet_id);
             SubnetKeyTypes::<T>::remove(subnet_id);
+            SubnetPeerIdProofRequired::<T>::remove(subnet_id);
+            SubnetElectionMode::<T>::remove(subnet_id);
             TargetNodeRegistrationsPerEpoch::<T>::remove(subnet_id);
             NodeBurnRateAlpha::<T>::remove(subnet_id);
             CurrentNodeBurnRate::<T>::remove(subnet_id);
@@ -7870,6 +9004,16 @@ pub mod pallet {
             // let _ = Self::clean_subnet_nodes(subnet_id);
             weight = weight.saturating_add(Self::clean_subnet_nodes(subnet_id));
 
+            // Consensus data is no longer pruned by epoch, clear all of it in `on_idle`
+            ConsensusArchivePruneEpoch::<T>::remove(subnet_id);
+            weight = weight.saturating_add(Self::enqueue_cleanup_task(
+                CleanupTask::SubnetConsensusSubmission(subnet_id),
+            ));
+            weight = weight.saturating_add(Self::enqueue_cleanup_task(
+                CleanupTask::ConsensusSubmissionArchive(subnet_id),
+            ));
+            weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
             Self::deposit_event(Event::SubnetDeactivated {
                 subnet_id: subnet_id,
                 reason: reason,
@@ -7887,11 +9031,6 @@ pub mod pallet {
         pub fn clean_subnet_nodes(subnet_id: u32) -> Weight {
             let mut weight_acc = WeightAccumulator::<T>::new();
 
-            // Remove all subnet nodes data
-            let removed_subnet_nodes_data =
-                SubnetNodesData::<T>::clear_prefix(subnet_id, u32::MAX, None);
-            weight_acc.add_clear_prefix(removed_subnet_nodes_data.unique);
-
             let total_nodes = TotalActiveSubnetNodes::<T>::take(subnet_id);
             weight_acc.add_take();
 
@@ -7904,52 +9043,29 @@ pub mod pallet {
             let _ = TotalSubnetNodeUids::<T>::remove(subnet_id);
             weight_acc.add_remove();
 
-            let peer_id_subnet_node_id_removed =
-                PeerIdSubnetNodeId::<T>::clear_prefix(subnet_id, u32::MAX, None);
-            weight_acc.add_clear_prefix(peer_id_subnet_node_id_removed.unique);
-
-            let bootnode_peer_id_subnet_node_id_removed =
-                BootnodePeerIdSubnetNodeId::<T>::clear_prefix(subnet_id, u32::MAX, None);
-            weight_acc.add_clear_prefix(bootnode_peer_id_subnet_node_id_removed.unique);
-
-            let client_peer_id_subnet_node_id_removed =
-                ClientPeerIdSubnetNodeId::<T>::clear_prefix(subnet_id, u32::MAX, None);
-            weight_acc.add_clear_prefix(client_peer_id_subnet_node_id_removed.unique);
-
-            let bootnode_subnet_node_id_removed =
-                BootnodeSubnetNodeId::<T>::clear_prefix(subnet_id, u32::MAX, None);
-            weight_acc.add_clear_prefix(bootnode_subnet_node_id_removed.unique);
-
-            let subnet_node_unique_param_removed =
-                UniqueParamSubnetNodeId::<T>::clear_prefix(subnet_id, u32::MAX, None);
-            weight_acc.add_clear_prefix(subnet_node_unique_param_removed.unique);
-
-            let hotkey_subnet_node_id_removed =
-                HotkeySubnetNodeId::<T>::clear_prefix(subnet_id, u32::MAX, None);
-            weight_acc.add_clear_prefix(hotkey_subnet_node_id_removed.unique);
-
-            let subnet_node_is_hotkey_removed =
-                SubnetNodeIdHotkey::<T>::clear_prefix(subnet_id, u32::MAX, None);
-            weight_acc.add_clear_prefix(subnet_node_is_hotkey_removed.unique);
-
-            let subnet_node_reputations =
-                SubnetNodeReputation::<T>::clear_prefix(subnet_id, u32::MAX, None);
-            weight_acc.add_clear_prefix(subnet_node_reputations.unique);
-
-            let subnet_node_consecutive_included_epochs_removed =
-                SubnetNodeConsecutiveIncludedEpochs::<T>::clear_prefix(subnet_id, u32::MAX, None);
-            weight_acc.add_clear_prefix(subnet_node_consecutive_included_epochs_removed.unique);
-            let registered_subnet_nodes_data_removed =
-                RegisteredSubnetNodesData::<T>::clear_prefix(subnet_id, u32::MAX, None);
-            weight_acc.add_clear_prefix(registered_subnet_nodes_data_removed.unique);
-
-            let subnet_elected_validator =
-                SubnetElectedValidator::<T>::clear_prefix(subnet_id, u32::MAX, None);
-            weight_acc.add_clear_prefix(subnet_elected_validator.unique);
-
-            let node_slot_index_removed =
-                NodeSlotIndex::<T>::clear_prefix(subnet_id, u32::MAX, None);
-            weight_acc.add_clear_prefix(node_slot_index_removed.unique);
+            // Maps that grow with the number of subnet nodes are cleared in `on_idle`
+            // See `process_cleanup_queue`
+            for task in [
+                CleanupTask::SubnetNodesData(subnet_id),
+                CleanupTask::RegisteredSubnetNodesData(subnet_id),
+                CleanupTask::PeerIdSubnetNodeId(subnet_id),
+                CleanupTask::BootnodePeerIdSubnetNodeId(subnet_id),
+                CleanupTask::ClientPeerIdSubnetNodeId(subnet_id),
+                CleanupTask::BootnodeSubnetNodeId(subnet_id),
+                CleanupTask::UniqueParamSubnetNodeId(subnet_id),
+                CleanupTask::HotkeySubnetNodeId(subnet_id),
+                CleanupTask::SubnetNodeIdHotkey(subnet_id),
+                CleanupTask::SubnetNodeReputation(subnet_id),
+                CleanupTask::SubnetNodeConsecutiveIncludedEpochs(subnet_id),
+                CleanupTask::SubnetElectedValidator(subnet_id),
+                CleanupTask::SubnetBackupValidator(subnet_id),
+                CleanupTask::NodeSlotIndex(subnet_id),
+            ] {
+                Self::enqueue_cleanup_task(task);
+                // CleanupQueueTail | CleanupQueue
+                weight_acc.add_mutate();
+                weight_acc.add_writes(1);
+            }
 
             let electable_nodes = SubnetNodeElectionSlots::<T>::take(subnet_id).len() as u32;
             weight_acc.add_take();
@@ -8050,10 +9166,16 @@ pub mod pallet {
         /// - **Error**: `PeerIdsMustBeUnique`
         /// - **Rationale**: Each peer ID serves different network functions
         ///
-        /// ### Loosely validate Libp2p Peer ID Format
-        /// - **Checks**: All three peer IDs validated via `validate_peer_id()`
+        /// ### Validate Libp2p Peer IDs
+        /// - **Checks**: All three peer IDs decoded via `ensure_peer_id_key_type()`
         /// - **Errors**: `InvalidPeerId`, `InvalidBootnodePeerId`, `InvalidClientPeerId`
-        /// - **Format**: Must be valid base58-encoded libp2p peer IDs
+        /// - **Format**: Must be libp2p peer IDs with a key type in `SubnetKeyTypes`
+        ///
+        /// ### Peer ID Ownership Proofs
+        /// - **Check**: If `SubnetPeerIdProofRequired`, each peer ID has an unexpired `PeerIdProofs`
+        ///   entry for the hotkey, submitted with `prove_peer_id`
+        /// - **Errors**: `PeerIdProofRequired`, `PeerIdProofHotkeyMismatch`, `PeerIdProofExpired`
+        /// - **Rationale**: Prevents squatting peer IDs owned by others
         ///
         /// ### Subnet-Wide Peer ID Uniqueness
         /// - **Checks**: Each peer ID must not exist in subnet via:
@@ -8216,6 +9338,9 @@ pub mod pallet {
         /// ## Peer ID Validation
         /// - `PeerIdsMustBeUnique` - Peer IDs must be distinct from each other
         /// - `InvalidPeerId` / `InvalidBootnodePeerId` / `InvalidClientPeerId` - Invalid libp2p format
+        /// - `PeerId*KeyTypeNotAllowed` - Peer ID key type not in `SubnetKeyTypes`
+        /// - `PeerIdProofRequired` / `PeerIdProofExpired` - Missing or expired peer ID proof
+        /// - `PeerIdProofHotkeyMismatch` - Peer ID proven for another hotkey
         /// - `PeerIdExist` / `BootnodePeerIdExist` / `ClientPeerIdExist` - Peer ID already used in subnet
         /// - `BootnodeExist` - Bootnode multiaddr already used in subnet
         ///
@@ -8359,16 +9484,23 @@ pub mod pallet {
                 Error::<T>::MaxRegisteredNodes
             );
 
-            // Validate peer IDs
-            ensure!(Self::validate_peer_id(&peer_id), Error::<T>::InvalidPeerId);
-            ensure!(
-                Self::validate_peer_id(&client_peer_id),
-                Error::<T>::InvalidClientPeerId
-            );
-            ensure!(
-                Self::validate_peer_id(&bootnode_peer_id),
-                Error::<T>::InvalidBootnodePeerId
-            );
+            // Validate peer IDs and their key types
+            Self::ensure_peer_id_key_type(subnet_id, &peer_id, Error::<T>::InvalidPeerId)?;
+            Self::ensure_peer_id_key_type(
+                subnet_id,
+                &client_peer_id,
+                Error::<T>::InvalidClientPeerId,
+            )?;
+            Self::ensure_peer_id_key_type(
+                subnet_id,
+                &bootnode_peer_id,
+                Error::<T>::InvalidBootnodePeerId,
+            )?;
+
+            // Ensure the hotkey proved it owns the peer IDs if the subnet requires it
+            Self::ensure_peer_id_proof(subnet_id, &hotkey, &peer_id)?;
+            Self::ensure_peer_id_proof(subnet_id, &hotkey, &bootnode_peer_id)?;
+            Self::ensure_peer_id_proof(subnet_id, &hotkey, &client_peer_id)?;
 
             // Ensure peer and boostrap peer ID doesn't already exist within subnet regardless of coldkey
 
@@ -8777,6 +9909,8 @@ pub mod pallet {
                 // block weight. The maximum number of subnets being removed does not currently surpass the
                 // maximum block weight, although, this is meant for future-proofing and optimizing
                 Self::do_epoch_preliminaries(&mut weight_meter, block, current_epoch);
+
+                weight_meter.consume(Self::enqueue_reward_history_cleanup(current_epoch));
             } else if (block - 1) >= overwatch_epoch_length
                 && (block - 1) % overwatch_epoch_length == 0
             {
@@ -8784,6 +9918,18 @@ pub mod pallet {
                 let block_step_weight = Self::calculate_overwatch_rewards();
                 // `consume(..)` saturates at zero
                 weight_meter.consume(block_step_weight);
+
+                // Commits and reveals from before the epoch just calculated are no longer used
+                if let Some(overwatch_epoch) =
+                    Self::get_current_overwatch_epoch_as_u32().checked_sub(2)
+                {
+                    weight_meter.consume(Self::enqueue_cleanup_task(
+                        CleanupTask::OverwatchCommits(overwatch_epoch),
+                    ));
+                    weight_meter.consume(Self::enqueue_cleanup_task(
+                        CleanupTask::OverwatchReveals(overwatch_epoch),
+                    ));
+                }
             } else if (block - 2) >= epoch_length && (block - 2) % epoch_length == 0 {
                 // Calculate rewards
                 // Distribute to foundation/treasury
@@ -8814,16 +9960,29 @@ pub mod pallet {
             // Attempt stake swap queue on every block
             Self::execute_ready_swap_calls(block, &mut weight_meter);
 
-            // for EVM tests (Weights in on_initialize change the block weight/gas)
-            Weight::from_parts(0, 0)
+            if !T::ReportHooksWeight::get() {
+                return Weight::zero();
+            }
 
-            // weight_meter.consumed()
+            weight_meter.consumed()
         }
 
         fn on_finalize(block_number: BlockNumberFor<T>) {}
 
         fn on_idle(block_number: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
-            return Weight::from_parts(0, 0);
+            let mut weight_meter = WeightMeter::with_limit(remaining_weight);
+
+            Self::process_cleanup_queue(&mut weight_meter);
+            Self::prune_consensus_archive(&mut weight_meter);
+            Self::prune_expired_peer_id_proofs(&mut weight_meter);
+
+            weight_meter.consumed()
+        }
+
+        /// Check the stake, delegate stake, node count, slot and key invariants, see `do_try_state`
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_block_number: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
         }
     }
 
@@ -8872,8 +10031,11 @@ pub mod pallet {
                             // If the conversion fails, the stake value is worthless or near worthless
                             queue.remove(0);
                             SwapCallQueue::<T>::remove(&first_id);
-                            // SwapCallQueue
-                            weight_meter.consume(db_weight.writes(1));
+                            AccountSwapQueueIds::<T>::mutate(item.call.get_account_id(), |ids| {
+                                ids.remove(&first_id);
+                            });
+                            // SwapCallQueue, AccountSwapQueueIds
+                            weight_meter.consume(db_weight.reads_writes(1, 2));
                             return true;
                         }
                     }
@@ -8939,383 +10101,320 @@ pub mod pallet {
         }
     }
 
+    /// Network genesis parameters and initial subnets
+    ///
+    /// Every parameter is optional and keeps its storage default when unset, so chain spec
+    /// presets only list what they tune. Parameters are set before `subnets` are built.
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
-        pub subnet_name: Vec<u8>,
-        pub subnet_nodes: Vec<(T::AccountId, PeerId)>,
+        // Subnet registration, enactment, pausing and removal
+        pub max_subnets: Option<u32>,
+        pub min_registration_cost: Option<u128>,
+        pub registration_cost_decay_blocks: Option<u32>,
+        pub registration_cost_alpha: Option<u128>,
+        pub new_registration_cost_multiplier: Option<u128>,
+        pub min_subnet_registration_epochs: Option<u32>,
+        pub subnet_registration_epochs: Option<u32>,
+        pub subnet_enactment_epochs: Option<u32>,
+        pub max_bootnodes: Option<u32>,
+        pub max_subnet_bootnode_access: Option<u32>,
+        pub min_subnet_nodes: Option<u32>,
+        pub max_subnet_nodes: Option<u32>,
+        pub min_max_registered_nodes: Option<u32>,
+        pub max_max_registered_nodes: Option<u32>,
+        pub subnet_owner_percentage: Option<u128>,
+        pub max_subnet_pause_epochs: Option<u32>,
+        pub subnet_pause_cooldown_epochs: Option<u32>,
+        pub delegate_stake_subnet_removal_interval: Option<u32>,
+        pub min_subnet_removal_interval: Option<u32>,
+        pub max_subnet_removal_interval: Option<u32>,
+
+        // Subnet node queue, classification and burn
+        pub min_churn_limit: Option<u32>,
+        pub max_churn_limit: Option<u32>,
+        pub min_churn_limit_multiplier: Option<u32>,
+        pub max_churn_limit_multiplier: Option<u32>,
+        pub min_queue_epochs: Option<u32>,
+        pub max_queue_epochs: Option<u32>,
+        pub min_idle_classification_epochs: Option<u32>,
+        pub max_idle_classification_epochs: Option<u32>,
+        pub min_included_classification_epochs: Option<u32>,
+        pub max_included_classification_epochs: Option<u32>,
+        pub base_node_burn_amount: Option<u128>,
+        pub min_node_burn_rate: Option<u128>,
+        pub max_node_burn_rate: Option<u128>,
+
+        // Staking, delegate staking and cooldowns
+        pub min_subnet_min_stake: Option<u128>,
+        pub max_subnet_min_stake: Option<u128>,
+        pub network_max_stake_balance: Option<u128>,
+        pub min_delegate_stake_percentage: Option<u128>,
+        pub max_delegate_stake_percentage: Option<u128>,
+        pub min_delegate_stake_deposit: Option<u128>,
+        pub min_subnet_delegate_stake_factor: Option<u128>,
+        pub max_min_delegate_stake_multiplier: Option<u128>,
+        pub max_subnet_delegate_stake_rewards_percentage_change: Option<u128>,
+        pub subnet_delegate_stake_rewards_update_period: Option<u32>,
+        pub node_reward_rate_update_period: Option<u32>,
+        pub max_reward_rate_decrease: Option<u128>,
+        pub min_active_node_stake_epochs: Option<u32>,
+        pub stake_cooldown_epochs: Option<u32>,
+        pub delegate_stake_cooldown_epochs: Option<u32>,
+        pub node_delegate_stake_cooldown_epochs: Option<u32>,
+        pub max_unbondings: Option<u32>,
+        pub max_swap_queue_calls_per_block: Option<u32>,
+        pub max_pending_swaps_per_account: Option<u32>,
+        pub tx_rate_limit: Option<u32>,
+
+        // Consensus, rewards and slashing
+        pub min_attestation_percentage: Option<u128>,
+        pub super_majority_attestation_ratio: Option<u128>,
+        pub base_validator_reward: Option<u128>,
+        pub base_slash_percentage: Option<u128>,
+        pub max_slash_amount: Option<u128>,
+        pub validator_reward_k: Option<u64>,
+        pub validator_reward_midpoint: Option<u128>,
+        pub backup_proposer_epoch_progression: Option<u128>,
+        pub backup_validator_reward_factor: Option<u128>,
+        pub attestor_reward_exponent: Option<u64>,
+        pub attestor_min_reward_factor: Option<u128>,
+        pub consensus_archive_depth: Option<u32>,
+        pub reward_history_depth: Option<u32>,
+        pub max_emergency_validator_epochs_multiplier: Option<u128>,
+        pub max_emergency_subnet_nodes: Option<u32>,
+
+        // Emissions
+        pub subnet_distribution_power: Option<u128>,
+        pub delegate_stake_weight_factor: Option<u128>,
+        pub inflation_sigmoid_steepness: Option<u128>,
+        pub inflation_sigmoid_midpoint: Option<u128>,
+
+        // Reputation factors
+        pub coldkey_reputation_increase_factor: Option<u128>,
+        pub coldkey_reputation_decrease_factor: Option<u128>,
+        pub min_min_subnet_node_reputation: Option<u128>,
+        pub max_min_subnet_node_reputation: Option<u128>,
+        pub min_node_reputation_factor: Option<u128>,
+        pub max_node_reputation_factor: Option<u128>,
+        pub max_subnet_node_min_weight_decrease_reputation_threshold: Option<u128>,
+        pub min_subnet_reputation: Option<u128>,
+        pub not_in_consensus_subnet_reputation_factor: Option<u128>,
+        pub max_pause_epochs_subnet_reputation_factor: Option<u128>,
+        pub less_than_min_nodes_subnet_reputation_factor: Option<u128>,
+        pub validator_absent_subnet_reputation_factor: Option<u128>,
+        pub in_consensus_subnet_reputation_factor: Option<u128>,
+
+        // Overwatch
+        pub max_overwatch_nodes: Option<u32>,
+        pub overwatch_epoch_length_multiplier: Option<u32>,
+        pub overwatch_commit_cutoff_percent: Option<u128>,
+        pub overwatch_min_diversification_ratio: Option<u128>,
+        pub overwatch_min_rep_score: Option<u128>,
+        pub overwatch_min_avg_attestation_ratio: Option<u128>,
+        pub overwatch_min_age: Option<u32>,
+        pub overwatch_min_stake_balance: Option<u128>,
+        pub overwatch_weight_factor: Option<u128>,
+        pub overwatch_stake_weight_factor: Option<u128>,
+
+        /// Subnets registered and activated at genesis, in order of their subnet IDs
+        pub subnets: Vec<GenesisSubnet<T::AccountId>>,
     }
 
     #[pallet::genesis_build]
     impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
         fn build(&self) {
-            // [TESTING: LOCAL]
-            MinSubnetRegistrationEpochs::<T>::set(0);
-            OverwatchEpochLengthMultiplier::<T>::set(2);
-            OverwatchMinDiversificationRatio::<T>::set(0);
-            OverwatchMinRepScore::<T>::set(0);
-            OverwatchMinAvgAttestationRatio::<T>::set(0);
-            OverwatchMinAge::<T>::set(0);
-
-            // // [TESTING: BENCHMARKING && EVM TESTS]
-            // // Enable subnets to register right when conditions are met
-            // MinSubnetRegistrationEpochs::<T>::set(0);
-            // // Enable testing overwatch nodes on each epoch
-            // OverwatchEpochLengthMultiplier::<T>::set(1);
-            // OverwatchMinDiversificationRatio::<T>::set(0);
-            // OverwatchMinRepScore::<T>::set(0);
-            // OverwatchMinAvgAttestationRatio::<T>::set(0);
-            // OverwatchMinAge::<T>::set(0);
-            // DelegateStakeCooldownEpochs::<T>::set(0);
-            // NodeDelegateStakeCooldownEpochs::<T>::put(0);
-            // StakeCooldownEpochs::<T>::put(0);
-            // MinActiveNodeStakeEpochs::<T>::put(0);
-            // SubnetDelegateStakeRewardsUpdatePeriod::<T>::put(0);
-            // NodeRewardRateUpdatePeriod::<T>::put(0);
-            // MinSubnetDelegateStakeFactor::<T>::put(0);
-            // MaxMinDelegateStakeMultiplier::<T>::put(1000000000000000000); // 100%
-            // SubnetPauseCooldownEpochs::<T>::put(0);
-
-            // [TESTING: EVM TESTS]
-            // Enable subnets to register right when conditions are met
-            // MinSubnetRegistrationEpochs::<T>::set(0);
-            // OverwatchEpochLengthMultiplier::<T>::set(1);
-            // OverwatchMinDiversificationRatio::<T>::set(0);
-            // OverwatchMinRepScore::<T>::set(0);
-            // OverwatchMinAvgAttestationRatio::<T>::set(0);
-            // OverwatchMinAge::<T>::set(0);
-            // DelegateStakeCooldownEpochs::<T>::set(0);
-            // NodeDelegateStakeCooldownEpochs::<T>::put(0);
-            // StakeCooldownEpochs::<T>::put(0);
-            // MinActiveNodeStakeEpochs::<T>::put(0);
-            // SubnetDelegateStakeRewardsUpdatePeriod::<T>::put(0);
-            // NodeRewardRateUpdatePeriod::<T>::put(0);
-            // MinSubnetDelegateStakeFactor::<T>::put(0);
-            // MaxMinDelegateStakeMultiplier::<T>::put(1000000000000000000); // 100%
-            // SubnetPauseCooldownEpochs::<T>::put(0);
-
-            // use fp_account::AccountId20;
-            // use sp_core::H160;
-            // use sp_core::U256;
-
-            // if self.subnet_name.last().is_none() {
-            //     return;
-            // }
-
-            // let subnet_id = 1;
-            // let friendly_uid = 1;
-
-            // SubnetIdFriendlyUid::<T>::insert(subnet_id, friendly_uid);
-            // FriendlyUidSubnetId::<T>::insert(friendly_uid, subnet_id);
-
-            // let subnet_data = SubnetData {
-            //     id: subnet_id,
-            //     friendly_id: subnet_id,
-            //     name: self.subnet_name.clone(),
-            //     repo: Vec::new(),
-            //     description: Vec::new(),
-            //     misc: Vec::new(),
-            //     state: SubnetState::Active,
-            //     start_epoch: 0,
-            // };
-
-            // SubnetRegistrationEpoch::<T>::insert(subnet_id, 1);
-            // // Store unique name
-            // SubnetName::<T>::insert(self.subnet_name.clone(), subnet_id);
-            // // Store repo
-            // SubnetRepo::<T>::insert(self.subnet_name.clone(), subnet_id);
-            // // Store subnet data
-            // SubnetsData::<T>::insert(subnet_id, subnet_data.clone());
-            // // Increase total subnets count
-            // TotalSubnetUids::<T>::mutate(|n: &mut u32| *n += 1);
-
-            // // Add bootnodes
-            // let raw_bootnode = b"p2p/127.0.0.1/33130".to_vec();
-            // // Try converting to a bounded vec (panics if too long)
-            // let bounded: BoundedVec<u8, DefaultMaxVectorLength> = raw_bootnode
-            //     .try_into()
-            //     .expect("bootnode string fits in bounded vec");
-            // // Put it into a BTreeSet
-            // let bootnodes = BTreeSet::from([bounded]);
-            // SubnetBootnodes::<T>::insert(subnet_id, bootnodes);
-
-            // // Increase delegate stake to allow activation of subnet model
-            // let min_stake_balance = MinSubnetMinStake::<T>::get();
-            // // --- Get minimum subnet stake balance
-            // let min_subnet_stake_balance = min_stake_balance;
-
-            // let total_issuance_as_balance = T::Currency::total_issuance();
-
-            // let alith = &self.subnet_nodes.iter().next();
-
-            // let alith_balance = T::Currency::free_balance(&alith.unwrap().0);
-
-            // let total_issuance: u128 = total_issuance_as_balance.try_into().unwrap_or(0);
-
-            // let total_staked: u128 = TotalStake::<T>::get();
-
-            // let total_delegate_staked: u128 = TotalDelegateStake::<T>::get();
-
-            // let total_node_delegate_staked: u128 = TotalNodeDelegateStake::<T>::get();
-
-            // let total_network_issuance = total_issuance
-            //     .saturating_add(total_staked)
-            //     .saturating_add(total_delegate_staked)
-            //     .saturating_add(total_node_delegate_staked);
-
-            // let factor: u128 = MinSubnetDelegateStakeFactor::<T>::get();
-
-            // let x = U256::from(total_network_issuance);
-            // let y = U256::from(factor);
-
-            // // x * y / 100.0
-
-            // let result = x * y / U256([0xde0b6b3a7640000, 0x0, 0x0, 0x0]);
-
-            // let min_subnet_delegate_stake_balance: u128 = result.try_into().unwrap_or(u128::MAX);
-
-            // // --- Mitigate inflation attack
-            // TotalSubnetDelegateStakeShares::<T>::mutate(subnet_id, |mut n| {
-            //     n.saturating_accrue(1000)
-            // });
-
-            // // =================
-            // // convert_to_shares
-            // // =================
-            // let total_subnet_delegated_stake_balance =
-            //     TotalSubnetDelegateStakeBalance::<T>::get(subnet_id);
-
-            // let balance = U256::from(min_subnet_delegate_stake_balance);
-            // let total_shares = U256::from(0) + U256::from(10_u128.pow(1));
-            // let total_balance = U256::from(total_subnet_delegated_stake_balance) + U256::from(1);
-
-            // let shares = balance * total_shares / total_balance;
-            // let shares: u128 = shares.try_into().unwrap_or(u128::MAX);
-
-            // // =====================================
-            // // increase_account_delegate_stake
-            // // =====================================
-            // // -- increase total subnet delegate stake balance
-            // TotalSubnetDelegateStakeBalance::<T>::mutate(subnet_id, |mut n| {
-            //     n.saturating_accrue(min_subnet_delegate_stake_balance)
-            // });
-            // // -- increase total subnet delegate stake shares
-            // TotalSubnetDelegateStakeShares::<T>::mutate(subnet_id, |mut n| {
-            //     n.saturating_accrue(shares)
-            // });
-            // TotalDelegateStake::<T>::set(min_subnet_delegate_stake_balance);
-
-            // // Store subnet data
-            // SubnetsData::<T>::insert(subnet_id, &subnet_data);
-
-            // // Store owner
-            // SubnetOwner::<T>::insert(subnet_id, &alith.unwrap().0);
-
-            // // Store the stake balance range
-            // SubnetMinStakeBalance::<T>::insert(subnet_id, 0);
-            // SubnetMaxStakeBalance::<T>::insert(subnet_id, 0);
-
-            // // Add delegate state ratio
-            // SubnetDelegateStakeRewardsPercentage::<T>::insert(subnet_id, 100000000000000000);
-            // LastSubnetDelegateStakeRewardsUpdate::<T>::insert(subnet_id, 0);
-
-            // // Add classification epochs
-            // SubnetNodeQueueEpochs::<T>::insert(subnet_id, 0);
-            // IdleClassificationEpochs::<T>::insert(subnet_id, 0);
-            // IncludedClassificationEpochs::<T>::insert(subnet_id, 0);
-
-            // // Add queue variables
-            // ChurnLimit::<T>::insert(subnet_id, 0);
-
-            // // Store min nodes reputation
-            // MinSubnetNodeReputation::<T>::insert(subnet_id, 100000000000000000);
-
-            // // Store whitelisted coldkeys for registration period
-            // // SubnetRegistrationInitialColdkeys::<T>::insert(
-            // // 	subnet_id,
-            // // 	BTreeSet::new()
-            // // );
-
-            // MaxRegisteredNodes::<T>::insert(subnet_id, 256);
-            // let keytypes = BTreeSet::from([KeyType::Rsa]);
-            // SubnetKeyTypes::<T>::insert(subnet_id, keytypes);
-            // LastSubnetRegistrationBlock::<T>::set(0);
-            // SubnetRegistrationEpoch::<T>::insert(subnet_id, 0);
-
-            // //
-            // //
-            // //
-            // // --- Initialize subnet nodes
-            // // Only initialize to test using subnet nodes
-            // // If testing using subnet nodes in a subnet, comment out the ``for`` loop
-            // //
-            // //
-            // //
-
-            // let mut stake_amount: u128 = MinSubnetMinStake::<T>::get();
-
-            // let mut count = 0;
-            // for (account_id, peer_id) in &self.subnet_nodes {
-            //     // Redundant
-            //     // Unique subnet_id -> PeerId
-            //     // Ensure peer ID doesn't already exist within subnet regardless of account_id
-            //     let peer_exists: bool =
-            //         match PeerIdSubnetNodeId::<T>::try_get(subnet_id, peer_id.clone()) {
-            //             Ok(_) => true,
-            //             Err(()) => false,
-            //         };
-
-            //     if peer_exists {
-            //         continue;
-            //     }
-
-            //     // ====================
-            //     // Initiate stake logic
-            //     // ====================
-            //     // T::Currency::withdraw(
-            //     // 	&account_id,
-            //     // 	stake_amount,
-            //     // 	WithdrawReasons::except(WithdrawReasons::TIP),
-            //     // 	ExistenceRequirement::KeepAlive,
-            //     // );
-
-            //     // -- increase account subnet staking balance
-            //     AccountSubnetStake::<T>::insert(
-            //         account_id,
-            //         subnet_id,
-            //         AccountSubnetStake::<T>::get(account_id, subnet_id)
-            //             .saturating_add(stake_amount),
-            //     );
-
-            //     // -- increase total subnet stake
-            //     TotalSubnetStake::<T>::mutate(subnet_id, |mut n| *n += stake_amount);
-
-            //     // -- increase total stake overall
-            //     TotalStake::<T>::mutate(|mut n| *n += stake_amount);
-
-            //     // To ensure the AccountId that owns the PeerId, they must sign the PeerId for others to verify
-            //     // This ensures others cannot claim to own a PeerId they are not the owner of
-            //     // Self::validate_signature(&Encode::encode(&peer_id), &signature, &signer)?;
-
-            //     // ========================
-            //     // Insert peer into storage
-            //     // ========================
-            //     let classification = SubnetNodeClassification {
-            //         node_class: SubnetNodeClass::Validator,
-            //         start_epoch: 0,
-            //     };
-
-            //     let bounded_peer_id: BoundedVec<u8, DefaultMaxVectorLength> =
-            //         BoundedVec::try_from(peer_id.clone().0).expect("Vec is within bounds");
-
-            //     TotalSubnetNodeUids::<T>::mutate(subnet_id, |n: &mut u32| *n += 1);
-            //     let current_uid = TotalSubnetNodeUids::<T>::get(subnet_id);
-
-            //     HotkeySubnetNodeId::<T>::insert(subnet_id, account_id.clone(), current_uid);
-
-            //     // Insert Subnet Node ID -> hotkey
-            //     SubnetNodeIdHotkey::<T>::insert(subnet_id, current_uid, account_id.clone());
-
-            //     // Insert hotkey -> coldkey
-            //     HotkeyOwner::<T>::insert(account_id.clone(), account_id.clone());
-
-            //     let subnet_node: SubnetNode<T::AccountId> = SubnetNode {
-            //         id: current_uid,
-            //         hotkey: account_id.clone(),
-            //         peer_id: peer_id.clone(),
-            //         bootnode_peer_id: peer_id.clone(),
-            //         client_peer_id: peer_id.clone(),
-            //         bootnode: Some(BoundedVec::new()),
-            //         classification: classification,
-            //         delegate_reward_rate: 0,
-            //         last_delegate_reward_rate_update: 0,
-            //         unique: Some(bounded_peer_id),
-            //         non_unique: Some(BoundedVec::new()),
-            //     };
-
-            //     ColdkeyHotkeys::<T>::insert(
-            //         &account_id.clone(),
-            //         BTreeSet::from([account_id.clone()]),
-            //     );
-
-            //     ColdkeySubnetNodes::<T>::mutate(&account_id.clone(), |node_map| {
-            //         node_map
-            //             .entry(subnet_id)
-            //             .or_insert_with(BTreeSet::new)
-            //             .insert(current_uid);
-            //     });
-
-            //     HotkeySubnetId::<T>::insert(&account_id.clone(), subnet_id);
-
-            //     // Insert SubnetNodesData
-            //     SubnetNodesData::<T>::insert(subnet_id, current_uid, subnet_node);
-
-            //     // Insert subnet peer account to keep peer_ids unique within subnets
-            //     PeerIdSubnetNodeId::<T>::insert(subnet_id, peer_id.clone(), current_uid);
-
-            //     // Increase total subnet nodes
-            //     TotalSubnetNodes::<T>::mutate(subnet_id, |n: &mut u32| *n += 1);
-            //     TotalNodes::<T>::mutate(|n: &mut u32| *n += 1);
-
-            //     // ===================================
-            //     // Give delegate stake balance to each user
-            //     // ===================================
-            //     let delegate_stake_amount = 1000;
-
-            //     // -- increase account subnet staking shares balance
-            //     AccountSubnetDelegateStakeShares::<T>::mutate(
-            //         account_id.clone(),
-            //         subnet_id,
-            //         |mut n| n.saturating_accrue(delegate_stake_amount),
-            //     );
-
-            //     // -- increase total subnet delegate stake balance
-            //     TotalSubnetDelegateStakeBalance::<T>::mutate(subnet_id, |mut n| {
-            //         n.saturating_accrue(delegate_stake_amount)
-            //     });
-
-            //     // -- increase total subnet delegate stake shares
-            //     TotalSubnetDelegateStakeShares::<T>::mutate(subnet_id, |mut n| {
-            //         n.saturating_accrue(delegate_stake_amount)
-            //     });
-
-            //     TotalDelegateStake::<T>::mutate(|mut n| n.saturating_accrue(delegate_stake_amount));
-
-            //     // ===================================
-            //     // Give node delegate stake balance to each user
-            //     // ===================================
-            //     let node_delegate_stake_amount = 1000;
-
-            //     // -- increase account subnet staking shares balance
-            //     AccountNodeDelegateStakeShares::<T>::mutate(
-            //         (account_id.clone(), subnet_id, current_uid),
-            //         |mut n| n.saturating_accrue(node_delegate_stake_amount),
-            //     );
-
-            //     // -- increase total subnet delegate stake balance
-            //     TotalNodeDelegateStakeBalance::<T>::mutate(subnet_id, current_uid, |mut n| {
-            //         n.saturating_accrue(node_delegate_stake_amount)
-            //     });
-
-            //     // -- increase total subnet delegate stake shares
-            //     TotalNodeDelegateStakeShares::<T>::mutate(subnet_id, current_uid, |mut n| {
-            //         n.saturating_accrue(node_delegate_stake_amount)
-            //     });
-
-            //     TotalNodeDelegateStake::<T>::mutate(|mut n| {
-            //         n.saturating_accrue(node_delegate_stake_amount)
-            //     });
-
-            //     ColdkeyReputation::<T>::mutate(&account_id.clone(), |rep| {
-            //         rep.lifetime_node_count = rep.lifetime_node_count.saturating_add(1);
-            //         rep.total_active_nodes = rep.total_active_nodes.saturating_add(1);
-            //     });
-
-            //     let current_count = NodeRegistrationsThisEpoch::<T>::get(subnet_id);
-            //     NodeRegistrationsThisEpoch::<T>::insert(subnet_id, current_count.saturating_add(1));
-
-            //     count += 1;
-            // }
+            // Subnet registration, enactment, pausing and removal
+            Self::put_value::<MaxSubnets<T>, _>(self.max_subnets);
+            Self::put_value::<MinRegistrationCost<T>, _>(self.min_registration_cost);
+            Self::put_value::<RegistrationCostDecayBlocks<T>, _>(
+                self.registration_cost_decay_blocks,
+            );
+            Self::put_value::<RegistrationCostAlpha<T>, _>(self.registration_cost_alpha);
+            Self::put_value::<NewRegistrationCostMultiplier<T>, _>(
+                self.new_registration_cost_multiplier,
+            );
+            Self::put_value::<MinSubnetRegistrationEpochs<T>, _>(
+                self.min_subnet_registration_epochs,
+            );
+            Self::put_value::<SubnetRegistrationEpochs<T>, _>(self.subnet_registration_epochs);
+            Self::put_value::<SubnetEnactmentEpochs<T>, _>(self.subnet_enactment_epochs);
+            Self::put_value::<MaxBootnodes<T>, _>(self.max_bootnodes);
+            Self::put_value::<MaxSubnetBootnodeAccess<T>, _>(self.max_subnet_bootnode_access);
+            Self::put_value::<MinSubnetNodes<T>, _>(self.min_subnet_nodes);
+            Self::put_value::<MaxSubnetNodes<T>, _>(self.max_subnet_nodes);
+            Self::put_value::<MinMaxRegisteredNodes<T>, _>(self.min_max_registered_nodes);
+            Self::put_value::<MaxMaxRegisteredNodes<T>, _>(self.max_max_registered_nodes);
+            Self::put_value::<SubnetOwnerPercentage<T>, _>(self.subnet_owner_percentage);
+            Self::put_value::<MaxSubnetPauseEpochs<T>, _>(self.max_subnet_pause_epochs);
+            Self::put_value::<SubnetPauseCooldownEpochs<T>, _>(self.subnet_pause_cooldown_epochs);
+            Self::put_value::<DelegateStakeSubnetRemovalInterval<T>, _>(
+                self.delegate_stake_subnet_removal_interval,
+            );
+            Self::put_value::<MinSubnetRemovalInterval<T>, _>(self.min_subnet_removal_interval);
+            Self::put_value::<MaxSubnetRemovalInterval<T>, _>(self.max_subnet_removal_interval);
+
+            // Subnet node queue, classification and burn
+            Self::put_value::<MinChurnLimit<T>, _>(self.min_churn_limit);
+            Self::put_value::<MaxChurnLimit<T>, _>(self.max_churn_limit);
+            Self::put_value::<MinChurnLimitMultiplier<T>, _>(self.min_churn_limit_multiplier);
+            Self::put_value::<MaxChurnLimitMultiplier<T>, _>(self.max_churn_limit_multiplier);
+            Self::put_value::<MinQueueEpochs<T>, _>(self.min_queue_epochs);
+            Self::put_value::<MaxQueueEpochs<T>, _>(self.max_queue_epochs);
+            Self::put_value::<MinIdleClassificationEpochs<T>, _>(
+                self.min_idle_classification_epochs,
+            );
+            Self::put_value::<MaxIdleClassificationEpochs<T>, _>(
+                self.max_idle_classification_epochs,
+            );
+            Self::put_value::<MinIncludedClassificationEpochs<T>, _>(
+                self.min_included_classification_epochs,
+            );
+            Self::put_value::<MaxIncludedClassificationEpochs<T>, _>(
+                self.max_included_classification_epochs,
+            );
+            Self::put_value::<BaseNodeBurnAmount<T>, _>(self.base_node_burn_amount);
+            Self::put_value::<MinNodeBurnRate<T>, _>(self.min_node_burn_rate);
+            Self::put_value::<MaxNodeBurnRate<T>, _>(self.max_node_burn_rate);
+
+            // Staking, delegate staking and cooldowns
+            Self::put_value::<MinSubnetMinStake<T>, _>(self.min_subnet_min_stake);
+            Self::put_value::<MaxSubnetMinStake<T>, _>(self.max_subnet_min_stake);
+            Self::put_value::<NetworkMaxStakeBalance<T>, _>(self.network_max_stake_balance);
+            Self::put_value::<MinDelegateStakePercentage<T>, _>(self.min_delegate_stake_percentage);
+            Self::put_value::<MaxDelegateStakePercentage<T>, _>(self.max_delegate_stake_percentage);
+            Self::put_value::<MinDelegateStakeDeposit<T>, _>(self.min_delegate_stake_deposit);
+            Self::put_value::<MinSubnetDelegateStakeFactor<T>, _>(
+                self.min_subnet_delegate_stake_factor,
+            );
+            Self::put_value::<MaxMinDelegateStakeMultiplier<T>, _>(
+                self.max_min_delegate_stake_multiplier,
+            );
+            Self::put_value::<MaxSubnetDelegateStakeRewardsPercentageChange<T>, _>(
+                self.max_subnet_delegate_stake_rewards_percentage_change,
+            );
+            Self::put_value::<SubnetDelegateStakeRewardsUpdatePeriod<T>, _>(
+                self.subnet_delegate_stake_rewards_update_period,
+            );
+            Self::put_value::<NodeRewardRateUpdatePeriod<T>, _>(
+                self.node_reward_rate_update_period,
+            );
+            Self::put_value::<MaxRewardRateDecrease<T>, _>(self.max_reward_rate_decrease);
+            Self::put_value::<MinActiveNodeStakeEpochs<T>, _>(self.min_active_node_stake_epochs);
+            Self::put_value::<StakeCooldownEpochs<T>, _>(self.stake_cooldown_epochs);
+            Self::put_value::<DelegateStakeCooldownEpochs<T>, _>(
+                self.delegate_stake_cooldown_epochs,
+            );
+            Self::put_value::<NodeDelegateStakeCooldownEpochs<T>, _>(
+                self.node_delegate_stake_cooldown_epochs,
+            );
+            Self::put_value::<MaxUnbondings<T>, _>(self.max_unbondings);
+            Self::put_value::<MaxSwapQueueCallsPerBlock<T>, _>(self.max_swap_queue_calls_per_block);
+            Self::put_value::<MaxPendingSwapsPerAccount<T>, _>(self.max_pending_swaps_per_account);
+            Self::put_value::<TxRateLimit<T>, _>(self.tx_rate_limit);
+
+            // Consensus, rewards and slashing
+            Self::put_value::<MinAttestationPercentage<T>, _>(self.min_attestation_percentage);
+            Self::put_value::<SuperMajorityAttestationRatio<T>, _>(
+                self.super_majority_attestation_ratio,
+            );
+            Self::put_value::<BaseValidatorReward<T>, _>(self.base_validator_reward);
+            Self::put_value::<BaseSlashPercentage<T>, _>(self.base_slash_percentage);
+            Self::put_value::<MaxSlashAmount<T>, _>(self.max_slash_amount);
+            Self::put_value::<ValidatorRewardK<T>, _>(self.validator_reward_k);
+            Self::put_value::<ValidatorRewardMidpoint<T>, _>(self.validator_reward_midpoint);
+            Self::put_value::<BackupProposerEpochProgression<T>, _>(
+                self.backup_proposer_epoch_progression,
+            );
+            Self::put_value::<BackupValidatorRewardFactor<T>, _>(
+                self.backup_validator_reward_factor,
+            );
+            Self::put_value::<AttestorRewardExponent<T>, _>(self.attestor_reward_exponent);
+            Self::put_value::<AttestorMinRewardFactor<T>, _>(self.attestor_min_reward_factor);
+            Self::put_value::<ConsensusArchiveDepth<T>, _>(self.consensus_archive_depth);
+            Self::put_value::<RewardHistoryDepth<T>, _>(self.reward_history_depth);
+            Self::put_value::<MaxEmergencyValidatorEpochsMultiplier<T>, _>(
+                self.max_emergency_validator_epochs_multiplier,
+            );
+            Self::put_value::<MaxEmergencySubnetNodes<T>, _>(self.max_emergency_subnet_nodes);
+
+            // Emissions
+            Self::put_value::<SubnetDistributionPower<T>, _>(self.subnet_distribution_power);
+            Self::put_value::<DelegateStakeWeightFactor<T>, _>(self.delegate_stake_weight_factor);
+            Self::put_value::<InflationSigmoidSteepness<T>, _>(self.inflation_sigmoid_steepness);
+            Self::put_value::<InflationSigmoidMidpoint<T>, _>(self.inflation_sigmoid_midpoint);
+
+            // Reputation factors
+            Self::put_value::<ColdkeyReputationIncreaseFactor<T>, _>(
+                self.coldkey_reputation_increase_factor,
+            );
+            Self::put_value::<ColdkeyReputationDecreaseFactor<T>, _>(
+                self.coldkey_reputation_decrease_factor,
+            );
+            Self::put_value::<MinMinSubnetNodeReputation<T>, _>(
+                self.min_min_subnet_node_reputation,
+            );
+            Self::put_value::<MaxMinSubnetNodeReputation<T>, _>(
+                self.max_min_subnet_node_reputation,
+            );
+            Self::put_value::<MinNodeReputationFactor<T>, _>(self.min_node_reputation_factor);
+            Self::put_value::<MaxNodeReputationFactor<T>, _>(self.max_node_reputation_factor);
+            Self::put_value::<MaxSubnetNodeMinWeightDecreaseReputationThreshold<T>, _>(
+                self.max_subnet_node_min_weight_decrease_reputation_threshold,
+            );
+            Self::put_value::<MinSubnetReputation<T>, _>(self.min_subnet_reputation);
+            Self::put_value::<NotInConsensusSubnetReputationFactor<T>, _>(
+                self.not_in_consensus_subnet_reputation_factor,
+            );
+            Self::put_value::<MaxPauseEpochsSubnetReputationFactor<T>, _>(
+                self.max_pause_epochs_subnet_reputation_factor,
+            );
+            Self::put_value::<LessThanMinNodesSubnetReputationFactor<T>, _>(
+                self.less_than_min_nodes_subnet_reputation_factor,
+            );
+            Self::put_value::<ValidatorAbsentSubnetReputationFactor<T>, _>(
+                self.validator_absent_subnet_reputation_factor,
+            );
+            Self::put_value::<InConsensusSubnetReputationFactor<T>, _>(
+                self.in_consensus_subnet_reputation_factor,
+            );
+
+            // Overwatch
+            Self::put_value::<MaxOverwatchNodes<T>, _>(self.max_overwatch_nodes);
+            Self::put_value::<OverwatchEpochLengthMultiplier<T>, _>(
+                self.overwatch_epoch_length_multiplier,
+            );
+            Self::put_value::<OverwatchCommitCutoffPercent<T>, _>(
+                self.overwatch_commit_cutoff_percent,
+            );
+            Self::put_value::<OverwatchMinDiversificationRatio<T>, _>(
+                self.overwatch_min_diversification_ratio,
+            );
+            Self::put_value::<OverwatchMinRepScore<T>, _>(self.overwatch_min_rep_score);
+            Self::put_value::<OverwatchMinAvgAttestationRatio<T>, _>(
+                self.overwatch_min_avg_attestation_ratio,
+            );
+            Self::put_value::<OverwatchMinAge<T>, _>(self.overwatch_min_age);
+            Self::put_value::<OverwatchMinStakeBalance<T>, _>(self.overwatch_min_stake_balance);
+            Self::put_value::<OverwatchWeightFactor<T>, _>(self.overwatch_weight_factor);
+            Self::put_value::<OverwatchStakeWeightFactor<T>, _>(self.overwatch_stake_weight_factor);
+
+            for subnet in &self.subnets {
+                Pallet::<T>::do_build_genesis_subnet(subnet);
+            }
+        }
+    }
+
+    impl<T: Config> GenesisConfig<T> {
+        /// Put a genesis parameter into its storage value, keeping the default if unset
+        fn put_value<S, V>(value: Option<V>)
+        where
+            S: frame_support::storage::StorageValue<V>,
+            V: codec::FullCodec,
+        {
+            if let Some(value) = value {
+                S::put(value);
+            }
         }
     }
 }
@@ -0,0 +1,25 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations of the network pallet
+//!
+//! Each `vN` module migrates storage from version `N - 1` to `N` with `UncheckedOnRuntimeUpgrade`
+//! steps, wrapped in a `VersionedMigration` that only runs them, and bumps the on-chain storage
+//! version, if the on-chain version is `N - 1`. Bump `STORAGE_VERSION` in the pallet with each new
+//! version and append its migration to the runtime's `Migrations`.
+
+use super::*;
+pub mod v1;
+pub mod v2;
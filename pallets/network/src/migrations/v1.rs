@@ -0,0 +1,162 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Version 1: backfill `SubnetBootnodesV2` from `SubnetBootnodes`
+
+use super::*;
+use core::marker::PhantomData;
+use frame_support::{
+    migrations::VersionedMigration, pallet_prelude::Weight, traits::UncheckedOnRuntimeUpgrade,
+};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+const LOG_TARGET: &str = "runtime::network::migrations";
+
+/// Migrate the network pallet's storage from version 0 to 1
+pub type MigrateV0ToV1<T> = VersionedMigration<
+    0,
+    1,
+    MigrateSubnetBootnodesToV2<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+/// Insert the peer ID of every `SubnetBootnodes` multiaddress into `SubnetBootnodesV2`
+///
+/// `SubnetBootnodes` is left untouched as `get_bootnodes` and `update_bootnodes` still use it.
+/// Bootnodes without a decodable `/p2p/` peer ID are skipped, as are bootnodes that would take a
+/// subnet's `SubnetBootnodesV2` past `MaxBootnodes`. Peer IDs already in `SubnetBootnodesV2` keep
+/// their entry.
+pub struct MigrateSubnetBootnodesToV2<T>(PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for MigrateSubnetBootnodesToV2<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let max_bootnodes = MaxBootnodes::<T>::get() as usize;
+        let mut reads: u64 = 1;
+        let mut writes: u64 = 0;
+        let mut migrated: u32 = 0;
+        let mut skipped: u32 = 0;
+
+        for (subnet_id, bootnodes) in SubnetBootnodes::<T>::iter() {
+            reads.saturating_accrue(2);
+
+            let mut bootnodes_v2 = SubnetBootnodesV2::<T>::get(subnet_id);
+            let prev_len = bootnodes_v2.len();
+
+            for bootnode in bootnodes {
+                match bootnode_peer_id::<T>(&bootnode) {
+                    Some(peer_id) if bootnodes_v2.contains_key(&peer_id) => {}
+                    Some(peer_id) if bootnodes_v2.len() < max_bootnodes => {
+                        bootnodes_v2.insert(peer_id, bootnode);
+                        migrated.saturating_inc();
+                    }
+                    _ => skipped.saturating_inc(),
+                }
+            }
+
+            if bootnodes_v2.len() != prev_len {
+                SubnetBootnodesV2::<T>::insert(subnet_id, bootnodes_v2);
+                writes.saturating_inc();
+            }
+        }
+
+        log::info!(
+            target: LOG_TARGET,
+            "migrated {} bootnodes into SubnetBootnodesV2, skipped {}",
+            migrated,
+            skipped
+        );
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        let state: Vec<PreUpgradeSubnetBootnodes> = SubnetBootnodes::<T>::iter()
+            .map(|(subnet_id, bootnodes)| {
+                (subnet_id, bootnodes, SubnetBootnodesV2::<T>::get(subnet_id))
+            })
+            .collect();
+        Ok(state.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let state: Vec<PreUpgradeSubnetBootnodes> = Decode::decode(&mut &state[..])
+            .map_err(|_| "failed to decode the pre-upgrade SubnetBootnodes")?;
+        let max_bootnodes = MaxBootnodes::<T>::get() as usize;
+
+        for (subnet_id, bootnodes, prev_bootnodes_v2) in state {
+            ensure!(
+                SubnetBootnodes::<T>::get(subnet_id) == bootnodes,
+                "SubnetBootnodes changed"
+            );
+
+            let bootnodes_v2 = SubnetBootnodesV2::<T>::get(subnet_id);
+            ensure!(
+                prev_bootnodes_v2
+                    .iter()
+                    .all(|(peer_id, bootnode)| bootnodes_v2.get(peer_id) == Some(bootnode)),
+                "existing SubnetBootnodesV2 entry changed"
+            );
+
+            // Every added entry is one of the subnet's bootnodes keyed by its peer ID
+            ensure!(
+                bootnodes_v2
+                    .iter()
+                    .filter(|(peer_id, _)| !prev_bootnodes_v2.contains_key(*peer_id))
+                    .all(|(peer_id, bootnode)| bootnodes.contains(bootnode)
+                        && bootnode_peer_id::<T>(bootnode).as_ref() == Some(peer_id)),
+                "unexpected SubnetBootnodesV2 entry"
+            );
+
+            // One entry per new peer ID, truncated at `MaxBootnodes`
+            let new_peer_ids = bootnodes
+                .iter()
+                .filter_map(|bootnode| bootnode_peer_id::<T>(bootnode))
+                .filter(|peer_id| !prev_bootnodes_v2.contains_key(peer_id))
+                .collect::<BTreeSet<_>>()
+                .len();
+            let expected_len = prev_bootnodes_v2.len()
+                + new_peer_ids.min(max_bootnodes.saturating_sub(prev_bootnodes_v2.len()));
+            ensure!(
+                bootnodes_v2.len() == expected_len,
+                "unexpected SubnetBootnodesV2 entry count"
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Subnet ID, its `SubnetBootnodes` and its `SubnetBootnodesV2` before the upgrade
+#[cfg(feature = "try-runtime")]
+type PreUpgradeSubnetBootnodes = (
+    u32,
+    BTreeSet<BoundedVec<u8, DefaultMaxVectorLength>>,
+    BTreeMap<PeerId, BoundedVec<u8, DefaultMaxVectorLength>>,
+);
+
+/// Peer ID of a bootnode multiaddress, e.g. `/ip4/127.0.0.1/tcp/30333/p2p/12D3KooW...`
+///
+/// Returns `None` if the multiaddress has no `/p2p/` (or legacy `/ipfs/`) peer ID, or if it
+/// doesn't decode.
+pub fn bootnode_peer_id<T: Config>(bootnode: &[u8]) -> Option<PeerId> {
+    let mut segments = bootnode.split(|&byte| byte == b'/');
+    segments.find(|segment| *segment == b"p2p" || *segment == b"ipfs")?;
+    let peer_id = PeerId(segments.next()?.to_vec());
+    Pallet::<T>::decode_peer_id(&peer_id).map(|_| peer_id)
+}
@@ -0,0 +1,124 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Version 2: backfill `AccountSwapQueueIds` from `SwapCallQueue`
+
+use super::*;
+use core::marker::PhantomData;
+use frame_support::{
+    migrations::VersionedMigration, pallet_prelude::Weight, traits::UncheckedOnRuntimeUpgrade,
+};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+const LOG_TARGET: &str = "runtime::network::migrations";
+
+/// Migrate the network pallet's storage from version 1 to 2
+pub type MigrateV1ToV2<T> = VersionedMigration<
+    1,
+    2,
+    MigrateAccountSwapQueueIds<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+>;
+
+/// Insert the ID of every `SwapCallQueue` item into the `AccountSwapQueueIds` of its account
+///
+/// Swaps queued before `AccountSwapQueueIds` existed are missing from it, so they can't be
+/// cancelled and don't count towards `MaxPendingSwapsPerAccount`. This includes items that never
+/// made it into a full `SwapQueueOrder`, cancelling them returns their balance. Accounts can end
+/// up above `MaxPendingSwapsPerAccount` and can't queue swaps until they are below it again.
+pub struct MigrateAccountSwapQueueIds<T>(PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for MigrateAccountSwapQueueIds<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let mut reads: u64 = 0;
+        let mut writes: u64 = 0;
+        let mut migrated: u32 = 0;
+
+        let mut queue_ids: BTreeMap<T::AccountId, BTreeSet<u32>> = BTreeMap::new();
+        for (id, item) in SwapCallQueue::<T>::iter() {
+            reads.saturating_inc();
+            queue_ids
+                .entry(item.call.get_account_id().clone())
+                .or_default()
+                .insert(id);
+        }
+
+        for (account_id, ids) in queue_ids {
+            reads.saturating_inc();
+
+            let mut account_queue_ids = AccountSwapQueueIds::<T>::get(&account_id);
+            let prev_len = account_queue_ids.len();
+            account_queue_ids.extend(ids);
+
+            if account_queue_ids.len() != prev_len {
+                migrated.saturating_accrue((account_queue_ids.len() - prev_len) as u32);
+                AccountSwapQueueIds::<T>::insert(&account_id, account_queue_ids);
+                writes.saturating_inc();
+            }
+        }
+
+        log::info!(
+            target: LOG_TARGET,
+            "migrated {} swap queue IDs into AccountSwapQueueIds",
+            migrated
+        );
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        // The expected `AccountSwapQueueIds` after the upgrade, the existing IDs and the queue's
+        let mut expected: BTreeMap<T::AccountId, BTreeSet<u32>> =
+            AccountSwapQueueIds::<T>::iter().collect();
+        for (id, item) in SwapCallQueue::<T>::iter() {
+            expected
+                .entry(item.call.get_account_id().clone())
+                .or_default()
+                .insert(id);
+        }
+        Ok(expected.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let expected: BTreeMap<T::AccountId, BTreeSet<u32>> = Decode::decode(&mut &state[..])
+            .map_err(|_| "failed to decode the expected AccountSwapQueueIds")?;
+
+        ensure!(
+            AccountSwapQueueIds::<T>::iter().count() == expected.len(),
+            "unexpected AccountSwapQueueIds account count"
+        );
+
+        for (account_id, ids) in expected {
+            ensure!(
+                AccountSwapQueueIds::<T>::get(&account_id) == ids,
+                "AccountSwapQueueIds doesn't match the existing and queued IDs"
+            );
+        }
+
+        // Every queued item can be cancelled by its account
+        for (id, item) in SwapCallQueue::<T>::iter() {
+            ensure!(
+                AccountSwapQueueIds::<T>::get(item.call.get_account_id()).contains(&id),
+                "queued swap missing from AccountSwapQueueIds"
+            );
+        }
+
+        Ok(())
+    }
+}
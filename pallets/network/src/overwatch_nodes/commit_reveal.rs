@@ -11,17 +11,7 @@ impl<T: Config> Pallet<T> {
     ) -> DispatchResultWithPostInfo {
         let key: T::AccountId = ensure_signed(origin)?;
 
-        let (hotkey, coldkey) = match Self::get_overwatch_node_hotkey_coldkey(overwatch_node_id) {
-            Some((hotkey, coldkey)) => (hotkey, coldkey),
-            None => return Err(Error::<T>::NotKeyOwner.into()),
-        };
-
-        ensure!(key == hotkey || key == coldkey, Error::<T>::NotKeyOwner);
-
-        ensure!(
-            !OverwatchNodeBlacklist::<T>::get(coldkey.clone()),
-            Error::<T>::ColdkeyBlacklisted
-        );
+        Self::ensure_overwatch_node_caller(overwatch_node_id, &key)?;
 
         // --- Check if we are in commit period
         ensure!(
@@ -32,22 +22,71 @@ impl<T: Config> Pallet<T> {
         Self::perform_commit_overwatch_subnet_weights(overwatch_node_id, commit_weights)
     }
 
+    /// Ensure `key` is the overwatch node's hotkey or coldkey, and the coldkey isn't blacklisted
+    pub fn ensure_overwatch_node_caller(
+        overwatch_node_id: u32,
+        key: &T::AccountId,
+    ) -> Result<(), Error<T>> {
+        let (hotkey, coldkey) = Self::get_overwatch_node_hotkey_coldkey(overwatch_node_id)
+            .ok_or(Error::<T>::NotKeyOwner)?;
+
+        ensure!(key == &hotkey || key == &coldkey, Error::<T>::NotKeyOwner);
+
+        ensure!(
+            !OverwatchNodeBlacklist::<T>::get(coldkey),
+            Error::<T>::ColdkeyBlacklisted
+        );
+
+        Ok(())
+    }
+
     pub fn perform_commit_overwatch_subnet_weights(
         overwatch_node_id: u32,
         mut commit_weights: Vec<OverwatchCommit<T::Hash>>,
     ) -> DispatchResultWithPostInfo {
-        // Remove dups
-        commit_weights.dedup_by(|a, b| a.subnet_id == b.subnet_id);
+        ensure!(
+            commit_weights.len() as u32 <= MaxSubnets::<T>::get(),
+            Error::<T>::OverwatchCommitsTooLong
+        );
 
-        let subnets: BTreeSet<_> = SubnetsData::<T>::iter().map(|(id, _)| id).collect();
+        // Canonical order, duplicates are adjacent once sorted
+        commit_weights.sort_by_key(|x| x.subnet_id);
+        ensure!(
+            !commit_weights
+                .windows(2)
+                .any(|pair| pair[0].subnet_id == pair[1].subnet_id),
+            Error::<T>::DuplicateSubnetId
+        );
 
-        // Qualify IDs - remove subnet IDs that do not exist
-        commit_weights.retain(|x| subnets.contains(&x.subnet_id));
+        // Qualify IDs - remove subnet IDs that do not exist or aren't active
+        let mut rejected: Vec<(u32, OverwatchCommitRejectionReason)> = Vec::new();
+        commit_weights.retain(|x| match SubnetsData::<T>::try_get(x.subnet_id) {
+            Ok(subnet) => {
+                let active = subnet.state == SubnetState::Active;
+                if !active {
+                    rejected.push((x.subnet_id, OverwatchCommitRejectionReason::SubnetNotActive));
+                }
+                active
+            }
+            Err(()) => {
+                rejected.push((x.subnet_id, OverwatchCommitRejectionReason::UnknownSubnetId));
+                false
+            }
+        });
 
         ensure!(!commit_weights.is_empty(), Error::<T>::CommitsEmpty);
 
         let overwatch_epoch = Self::get_current_overwatch_epoch_as_u32();
 
+        if !rejected.is_empty() {
+            Self::deposit_event(Event::OverwatchCommitsRejected {
+                overwatch_node_id,
+                epoch: overwatch_epoch,
+                // `commit_weights` is bounded by `MaxSubnets` above
+                rejected: BoundedVec::truncate_from(rejected),
+            });
+        }
+
         for commit in commit_weights {
             Self::do_commit_subnet_weight(overwatch_node_id, commit, overwatch_epoch)
                 .map_err(|e| e)?;
@@ -85,17 +124,7 @@ impl<T: Config> Pallet<T> {
     ) -> DispatchResultWithPostInfo {
         let key: T::AccountId = ensure_signed(origin)?;
 
-        let (hotkey, coldkey) = match Self::get_overwatch_node_hotkey_coldkey(overwatch_node_id) {
-            Some((hotkey, coldkey)) => (hotkey, coldkey),
-            None => return Err(Error::<T>::NotKeyOwner.into()),
-        };
-
-        ensure!(key == hotkey || key == coldkey, Error::<T>::NotKeyOwner);
-
-        ensure!(
-            !OverwatchNodeBlacklist::<T>::get(coldkey.clone()),
-            Error::<T>::ColdkeyBlacklisted
-        );
+        Self::ensure_overwatch_node_caller(overwatch_node_id, &key)?;
 
         // --- Check if we are in reveal period
         ensure!(
@@ -0,0 +1,108 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// Estimate the rewards a subnet receives in the current epoch
+    ///
+    /// Runs `calculate_rewards` against the current epochs `FinalSubnetEmissionWeights`.
+    /// Returns `None` if the subnet has no emission weight this epoch, e.g. it is not active.
+    pub fn estimate_subnet_epoch_rewards(subnet_id: u32) -> Option<RewardsData> {
+        let current_epoch = Self::get_current_epoch_as_u32();
+        let emission_weights = FinalSubnetEmissionWeights::<T>::get(current_epoch);
+        let subnet_weight = *emission_weights.weights.get(&subnet_id)?;
+
+        let (rewards_data, _) = Self::calculate_rewards(
+            subnet_id,
+            emission_weights.validator_emissions,
+            subnet_weight,
+        );
+
+        Some(rewards_data)
+    }
+
+    /// Estimate the yearly return of the subnet delegate stake pool
+    ///
+    /// The current epochs `delegate_stake_rewards` annualized over `EpochsPerYear`, divided by
+    /// `TotalSubnetDelegateStakeBalance`. Returned as a percentage where `1e18` is 100%.
+    pub fn estimate_delegate_apr(subnet_id: u32) -> u128 {
+        let Some(rewards_data) = Self::estimate_subnet_epoch_rewards(subnet_id) else {
+            return 0;
+        };
+
+        Self::annualized_rate(
+            rewards_data.delegate_stake_rewards,
+            TotalSubnetDelegateStakeBalance::<T>::get(subnet_id),
+        )
+    }
+
+    /// Estimate the yearly return of a subnet nodes delegate stake pool
+    ///
+    /// The nodes share of `subnet_node_rewards` is taken from its score in the latest archived
+    /// consensus submission, assuming a full reward factor, then cut by the nodes
+    /// `delegate_reward_rate`. Returned as a percentage where `1e18` is 100%.
+    pub fn estimate_node_delegate_apr(subnet_id: u32, subnet_node_id: u32) -> u128 {
+        let Ok(subnet_node) = SubnetNodesData::<T>::try_get(subnet_id, subnet_node_id) else {
+            return 0;
+        };
+
+        if subnet_node.delegate_reward_rate == 0 {
+            return 0;
+        }
+
+        let Some(rewards_data) = Self::estimate_subnet_epoch_rewards(subnet_id) else {
+            return 0;
+        };
+
+        let Some(submission) = Self::latest_consensus_submission(subnet_id) else {
+            return 0;
+        };
+
+        let Some(node_data) = submission
+            .data
+            .iter()
+            .find(|data| data.subnet_node_id == subnet_node_id)
+        else {
+            return 0;
+        };
+
+        let node_weight = Self::percent_div(node_data.score, submission.weight_sum);
+        let node_reward = Self::percent_mul(node_weight, rewards_data.subnet_node_rewards);
+        let node_delegate_reward = Self::percent_mul(node_reward, subnet_node.delegate_reward_rate);
+
+        Self::annualized_rate(
+            node_delegate_reward,
+            TotalNodeDelegateStakeBalance::<T>::get(subnet_id, subnet_node_id),
+        )
+    }
+
+    /// Most recent archived consensus submission within `ConsensusArchiveDepth`
+    fn latest_consensus_submission(
+        subnet_id: u32,
+    ) -> Option<ConsensusSubmissionData<T::AccountId>> {
+        let current_subnet_epoch = Self::get_current_subnet_epoch_as_u32(subnet_id);
+        let depth = ConsensusArchiveDepth::<T>::get();
+
+        (0..depth)
+            .map_while(|offset| current_subnet_epoch.checked_sub(offset))
+            .find_map(|subnet_epoch| ConsensusSubmissionArchive::<T>::get(subnet_id, subnet_epoch))
+    }
+
+    fn annualized_rate(epoch_rewards: u128, total_balance: u128) -> u128 {
+        let yearly_rewards = epoch_rewards.saturating_mul(T::EpochsPerYear::get() as u128);
+        Self::percent_div(yearly_rewards, total_balance)
+    }
+}
@@ -14,6 +14,16 @@
 // limitations under the License.
 
 use super::*;
+use sp_core::U256;
+
+/// Max items returned in one page by the paginated RPC queries
+pub const MAX_RPC_PAGE_LIMIT: u32 = 256;
+
+/// Max IDs visited for one page by the paginated RPC queries
+///
+/// A page that runs out of scans before it fills up returns early with a `next_cursor`, so a
+/// filter matching few nodes can return short or empty pages that aren't the last page.
+pub const MAX_RPC_PAGE_SCAN: u32 = 1024;
 
 impl<T: Config> Pallet<T> {
     pub fn get_subnet_info(subnet_id: u32) -> Option<SubnetInfo<T::AccountId>> {
@@ -159,6 +169,11 @@ impl<T: Config> Pallet<T> {
         subnet_id: u32,
         subnet_node_id: u32,
     ) -> Option<SubnetNodeInfo<T::AccountId>> {
+        // Node data of a removed subnet may exist until cleared in `on_idle`
+        if !SubnetsData::<T>::contains_key(subnet_id) {
+            return None;
+        }
+
         let subnet_node = if SubnetNodesData::<T>::contains_key(subnet_id, subnet_node_id) {
             SubnetNodesData::<T>::get(subnet_id, subnet_node_id)
         } else if RegisteredSubnetNodesData::<T>::contains_key(subnet_id, subnet_node_id) {
@@ -238,6 +253,191 @@ impl<T: Config> Pallet<T> {
         infos
     }
 
+    /// Get a page of subnets info, ordered by subnet ID
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - First subnet ID of the page, the `next_cursor` of the previous page.
+    /// * `limit` - Max subnets in the page, capped at `MAX_RPC_PAGE_LIMIT`.
+    pub fn get_all_subnets_info_paged(
+        start: u32,
+        limit: u32,
+    ) -> RpcPage<SubnetInfo<T::AccountId>, u32> {
+        let limit = Self::rpc_page_limit(limit);
+        let max_subnet_id = TotalSubnetUids::<T>::get();
+        let mut items: Vec<SubnetInfo<T::AccountId>> = Vec::new();
+        let mut scan_budget = MAX_RPC_PAGE_SCAN;
+
+        let mut subnet_id = start.max(1);
+        while subnet_id <= max_subnet_id {
+            if items.len() as u32 >= limit || scan_budget == 0 {
+                return RpcPage {
+                    items,
+                    next_cursor: Some(subnet_id),
+                };
+            }
+            scan_budget -= 1;
+            if let Some(subnet_info) = Self::get_subnet_info(subnet_id) {
+                items.push(subnet_info);
+            }
+            subnet_id = subnet_id.saturating_add(1);
+        }
+
+        RpcPage {
+            items,
+            next_cursor: None,
+        }
+    }
+
+    /// Get a page of subnet nodes info of a subnet, ordered by subnet node ID
+    ///
+    /// # Arguments
+    ///
+    /// * `subnet_id` - Subnet ID.
+    /// * `start` - First subnet node ID of the page, the `next_cursor` of the previous page.
+    /// * `limit` - Max nodes in the page, capped at `MAX_RPC_PAGE_LIMIT`.
+    /// * `filter` - Nodes not matching the filter are skipped.
+    pub fn get_subnet_nodes_info_paged(
+        subnet_id: u32,
+        start: u32,
+        limit: u32,
+        filter: SubnetNodeFilter<T::AccountId>,
+    ) -> RpcPage<SubnetNodeInfo<T::AccountId>, u32> {
+        let limit = Self::rpc_page_limit(limit);
+        let mut items: Vec<SubnetNodeInfo<T::AccountId>> = Vec::new();
+        let mut scan_budget = MAX_RPC_PAGE_SCAN;
+
+        let next_cursor = Self::fill_subnet_nodes_page(
+            subnet_id,
+            start,
+            limit,
+            &filter,
+            &mut scan_budget,
+            &mut items,
+        );
+
+        RpcPage { items, next_cursor }
+    }
+
+    /// Get a page of subnet nodes info across all subnets, ordered by subnet ID then subnet
+    /// node ID
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - First `(subnet_id, subnet_node_id)` of the page, the `next_cursor` of the
+    ///   previous page.
+    /// * `limit` - Max nodes in the page, capped at `MAX_RPC_PAGE_LIMIT`.
+    /// * `filter` - Nodes not matching the filter are skipped.
+    pub fn get_all_subnet_nodes_info_paged(
+        start: (u32, u32),
+        limit: u32,
+        filter: SubnetNodeFilter<T::AccountId>,
+    ) -> RpcPage<SubnetNodeInfo<T::AccountId>, (u32, u32)> {
+        let limit = Self::rpc_page_limit(limit);
+        let max_subnet_id = TotalSubnetUids::<T>::get();
+        let mut items: Vec<SubnetNodeInfo<T::AccountId>> = Vec::new();
+        let mut scan_budget = MAX_RPC_PAGE_SCAN;
+
+        let (mut subnet_id, mut start_subnet_node_id) = (start.0.max(1), start.1);
+        while subnet_id <= max_subnet_id {
+            // Visiting a subnet counts as a scan, most subnet IDs may have been removed
+            if scan_budget == 0 {
+                return RpcPage {
+                    items,
+                    next_cursor: Some((subnet_id, start_subnet_node_id)),
+                };
+            }
+            scan_budget -= 1;
+
+            if let Some(subnet_node_id) = Self::fill_subnet_nodes_page(
+                subnet_id,
+                start_subnet_node_id,
+                limit,
+                &filter,
+                &mut scan_budget,
+                &mut items,
+            ) {
+                return RpcPage {
+                    items,
+                    next_cursor: Some((subnet_id, subnet_node_id)),
+                };
+            }
+            subnet_id = subnet_id.saturating_add(1);
+            start_subnet_node_id = 1;
+        }
+
+        RpcPage {
+            items,
+            next_cursor: None,
+        }
+    }
+
+    /// Push the subnet's nodes matching `filter` from `start` until `items` holds `limit` nodes
+    /// or `scan_budget` node IDs were visited, returns the subnet node ID to resume from if the
+    /// page filled up or ran out of scans before the last node
+    fn fill_subnet_nodes_page(
+        subnet_id: u32,
+        start: u32,
+        limit: u32,
+        filter: &SubnetNodeFilter<T::AccountId>,
+        scan_budget: &mut u32,
+        items: &mut Vec<SubnetNodeInfo<T::AccountId>>,
+    ) -> Option<u32> {
+        // Node data of a removed subnet may exist until cleared in `on_idle`
+        if !SubnetsData::<T>::contains_key(subnet_id) {
+            return None;
+        }
+
+        let max_subnet_node_id = TotalSubnetNodeUids::<T>::get(subnet_id);
+
+        let mut subnet_node_id = start.max(1);
+        while subnet_node_id <= max_subnet_node_id {
+            if items.len() as u32 >= limit || *scan_budget == 0 {
+                return Some(subnet_node_id);
+            }
+            *scan_budget -= 1;
+            if let Some(subnet_node_info) = Self::get_subnet_node_info(subnet_id, subnet_node_id) {
+                if Self::subnet_node_matches_filter(&subnet_node_info, filter) {
+                    items.push(subnet_node_info);
+                }
+            }
+            subnet_node_id = subnet_node_id.saturating_add(1);
+        }
+
+        None
+    }
+
+    fn subnet_node_matches_filter(
+        subnet_node_info: &SubnetNodeInfo<T::AccountId>,
+        filter: &SubnetNodeFilter<T::AccountId>,
+    ) -> bool {
+        if let Some(min_class) = &filter.min_class {
+            if subnet_node_info.classification.node_class < *min_class {
+                return false;
+            }
+        }
+        if let Some(coldkey) = &filter.coldkey {
+            if subnet_node_info.coldkey != *coldkey {
+                return false;
+            }
+        }
+        if let Some(min_reputation) = filter.min_reputation {
+            if subnet_node_info.subnet_node_reputation < min_reputation {
+                return false;
+            }
+        }
+        if let Some(has_bootnode) = filter.has_bootnode {
+            if subnet_node_info.bootnode.is_some() != has_bootnode {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn rpc_page_limit(limit: u32) -> u32 {
+        limit.clamp(1, MAX_RPC_PAGE_LIMIT)
+    }
+
     /// Get the elected validators node info
     pub fn get_elected_validator_info(
         subnet_id: u32,
@@ -343,6 +543,11 @@ impl<T: Config> Pallet<T> {
 
     /// Get all bootnodes organized by the official bootnodes and node bootnodes
     pub fn get_bootnodes(subnet_id: u32) -> AllSubnetBootnodes {
+        // Node data of a removed subnet may exist until cleared in `on_idle`
+        if !SubnetsData::<T>::contains_key(subnet_id) {
+            return AllSubnetBootnodes::default();
+        }
+
         let bootnodes: BTreeSet<BoundedVec<u8, DefaultMaxVectorLength>> =
             SubnetBootnodes::<T>::get(subnet_id);
 
@@ -458,6 +663,211 @@ impl<T: Config> Pallet<T> {
         node_delegate_stake
     }
 
+    /// Archived consensus submission of a subnet epoch, `None` if not submitted or pruned
+    pub fn get_consensus_submission(
+        subnet_id: u32,
+        subnet_epoch: u32,
+    ) -> Option<ConsensusSubmissionInfo<T::AccountId>> {
+        let submission = ConsensusSubmissionArchive::<T>::get(subnet_id, subnet_epoch)?;
+
+        Some(ConsensusSubmissionInfo {
+            subnet_id,
+            epoch: subnet_epoch,
+            submission,
+        })
+    }
+
+    /// Archived consensus submissions from `from` to `to` subnet epochs inclusive
+    ///
+    /// Epochs without a submission are skipped. The range is capped at `ConsensusArchiveDepth`
+    /// epochs, anything older is pruned.
+    pub fn get_consensus_history(
+        subnet_id: u32,
+        from: u32,
+        to: u32,
+    ) -> Vec<ConsensusSubmissionInfo<T::AccountId>> {
+        let max_to = from.saturating_add(ConsensusArchiveDepth::<T>::get().saturating_sub(1));
+
+        (from..=to.min(max_to))
+            .filter_map(|subnet_epoch| Self::get_consensus_submission(subnet_id, subnet_epoch))
+            .collect()
+    }
+
+    /// Rewards of an account from `from` to `to` epochs inclusive
+    ///
+    /// Epochs without rewards are skipped. The range is capped at `RewardHistoryDepth` epochs,
+    /// anything older is pruned. Delegate stake rewards are the accounts part of each pools
+    /// rewards using the shares it held in that epoch, rebuilt from its current shares and the
+    /// share checkpoints newer than the epoch.
+    pub fn get_account_rewards(
+        account_id: T::AccountId,
+        from: u32,
+        to: u32,
+    ) -> Vec<AccountRewardsInfo> {
+        let depth = RewardHistoryDepth::<T>::get();
+        if depth == 0 {
+            return Vec::new();
+        }
+        let current_epoch = Self::get_current_epoch_as_u32();
+        let from = from.max(current_epoch.saturating_sub(depth));
+        let to = to.min(from.saturating_add(depth - 1));
+        if from > to {
+            return Vec::new();
+        }
+
+        let mut subnet_shares: BTreeMap<u32, u128> =
+            AccountSubnetDelegateStakeShares::<T>::iter_prefix(&account_id).collect();
+        let mut node_shares: BTreeMap<(u32, u32), u128> =
+            AccountNodeDelegateStakeShares::<T>::iter_prefix((&account_id,)).collect();
+
+        // Checkpoints count from the next epoch at the latest
+        let mut epoch = current_epoch.saturating_add(1);
+        while epoch > to.saturating_add(1) {
+            Self::apply_delegate_share_checkpoints(
+                &account_id,
+                epoch,
+                &mut subnet_shares,
+                &mut node_shares,
+            );
+            epoch -= 1;
+        }
+
+        let mut history: Vec<AccountRewardsInfo> = Vec::new();
+        for epoch in (from..=to).rev() {
+            Self::apply_delegate_share_checkpoints(
+                &account_id,
+                epoch.saturating_add(1),
+                &mut subnet_shares,
+                &mut node_shares,
+            );
+
+            let rewards = AccountRewardHistory::<T>::get(epoch, &account_id);
+
+            let delegate_stake_rewards = subnet_shares
+                .iter()
+                .filter(|(_, shares)| **shares != 0)
+                .filter_map(|(subnet_id, shares)| {
+                    SubnetDelegateRewardHistory::<T>::get(epoch, subnet_id)
+                        .map(|pool| Self::delegate_pool_rewards_share(*shares, &pool))
+                })
+                .fold(0u128, |acc, x| acc.saturating_add(x));
+
+            let node_delegate_stake_rewards = node_shares
+                .iter()
+                .filter(|(_, shares)| **shares != 0)
+                .filter_map(|(subnet_node, shares)| {
+                    NodeDelegateRewardHistory::<T>::get(epoch, subnet_node)
+                        .map(|pool| Self::delegate_pool_rewards_share(*shares, &pool))
+                })
+                .fold(0u128, |acc, x| acc.saturating_add(x));
+
+            let info = AccountRewardsInfo {
+                epoch,
+                node_stake_rewards: rewards.node_stake_rewards,
+                subnet_owner_rewards: rewards.subnet_owner_rewards,
+                overwatch_rewards: rewards.overwatch_rewards,
+                delegate_stake_rewards,
+                node_delegate_stake_rewards,
+            };
+
+            if info
+                != (AccountRewardsInfo {
+                    epoch,
+                    ..AccountRewardsInfo::default()
+                })
+            {
+                history.push(info);
+            }
+        }
+
+        history.reverse();
+        history
+    }
+
+    /// Replace the shares with the ones the account held in the epochs before `epoch`
+    fn apply_delegate_share_checkpoints(
+        account_id: &T::AccountId,
+        epoch: u32,
+        subnet_shares: &mut BTreeMap<u32, u128>,
+        node_shares: &mut BTreeMap<(u32, u32), u128>,
+    ) {
+        subnet_shares.extend(AccountSubnetDelegateSharesHistory::<T>::iter_prefix((
+            epoch, account_id,
+        )));
+        node_shares.extend(AccountNodeDelegateSharesHistory::<T>::iter_prefix((
+            epoch, account_id,
+        )));
+    }
+
+    fn delegate_pool_rewards_share(shares: u128, pool: &DelegatePoolEpochRewards) -> u128 {
+        Self::checked_mul_div(
+            U256::from(shares),
+            U256::from(pool.rewards),
+            U256::from(pool.total_shares),
+        )
+        .and_then(|share| share.try_into().ok())
+        .unwrap_or(0)
+    }
+
+    /// Get an accounts pending swap calls in queue order
+    ///
+    /// The expected execution block only accounts for `MaxSwapQueueCallsPerBlock`, not the
+    /// weight left for the block hooks.
+    pub fn get_account_swap_queue(account_id: T::AccountId) -> Vec<QueuedSwapInfo<T::AccountId>> {
+        let account_queue_ids = AccountSwapQueueIds::<T>::get(&account_id);
+        let mut queued_swaps: Vec<QueuedSwapInfo<T::AccountId>> = Vec::new();
+
+        if account_queue_ids.is_empty() {
+            return queued_swaps;
+        }
+
+        let max_calls_per_block = MaxSwapQueueCallsPerBlock::<T>::get().max(1);
+
+        // Swaps are executed in `on_initialize`, the earliest is the next block
+        let mut block = Self::get_current_block_as_u32().saturating_add(1);
+        let mut calls_in_block = 0;
+
+        for (position, id) in SwapQueueOrder::<T>::get().iter().enumerate() {
+            let item = match SwapCallQueue::<T>::get(id) {
+                Some(item) => item,
+                None => continue,
+            };
+
+            // Calls are executed in order, a call can't execute before the ones ahead of it
+            let ready_block = item
+                .queued_at_block
+                .saturating_add(item.execute_after_blocks);
+            if ready_block > block {
+                block = ready_block;
+                calls_in_block = 0;
+            }
+            if calls_in_block >= max_calls_per_block {
+                block = block.saturating_add(1);
+                calls_in_block = 0;
+            }
+            calls_in_block += 1;
+
+            if !account_queue_ids.contains(id) {
+                continue;
+            }
+
+            queued_swaps.push(QueuedSwapInfo {
+                id: item.id,
+                call: item.call,
+                queued_at_block: item.queued_at_block,
+                execute_after_blocks: item.execute_after_blocks,
+                position: position as u32,
+                expected_execution_block: block,
+            });
+
+            if queued_swaps.len() == account_queue_ids.len() {
+                break;
+            }
+        }
+
+        queued_swaps
+    }
+
     pub fn get_overwatch_commits_for_epoch_and_node(
         epoch: u32,
         overwatch_node_id: u32,
@@ -1,2 +1,5 @@
 use super::*;
+pub mod estimates;
 pub mod info;
+#[cfg(feature = "std")]
+pub mod serde_helpers;
@@ -0,0 +1,192 @@
+use super::*;
+use serde::{Deserializer, Serializer};
+
+/// (De)serializes an optional map as a list of `(key, value)` pairs, JSON objects only allow
+/// string keys and `AccountId20` serializes as a byte array
+pub mod option_map_as_pairs {
+    use super::*;
+
+    pub fn serialize<S, K, V>(
+        map: &Option<BTreeMap<K, V>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        K: Serialize,
+        V: Serialize,
+    {
+        map.as_ref()
+            .map(|map| map.iter().collect::<Vec<_>>())
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D, K, V>(deserializer: D) -> Result<Option<BTreeMap<K, V>>, D::Error>
+    where
+        D: Deserializer<'de>,
+        K: Deserialize<'de> + Ord,
+        V: Deserialize<'de>,
+    {
+        Ok(Option::<Vec<(K, V)>>::deserialize(deserializer)?
+            .map(|pairs| pairs.into_iter().collect()))
+    }
+}
+
+/// (De)serializes a `u128` as a decimal string, JSON numbers lose precision above 2^53
+pub mod u128_as_string {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &u128, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u128, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// `u128_as_string` for an optional `u128`
+pub mod option_u128_as_string {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<u128>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.map(|value| value.to_string()).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<u128>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|value| value.parse().map_err(serde::de::Error::custom))
+            .transpose()
+    }
+}
+
+/// (De)serializes text stored as bytes, e.g. names and multiaddrs, as a string
+///
+/// Bytes that aren't valid UTF-8 are replaced with `U+FFFD`.
+pub mod bytes_as_string {
+    use super::*;
+
+    pub fn serialize<S, T>(bytes: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: AsRef<[u8]>,
+    {
+        serializer.serialize_str(&String::from_utf8_lossy(bytes.as_ref()))
+    }
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: TryFrom<Vec<u8>>,
+    {
+        T::try_from(String::deserialize(deserializer)?.into_bytes())
+            .map_err(|_| serde::de::Error::custom("string too long"))
+    }
+}
+
+/// `bytes_as_string` for optional bytes
+pub mod option_bytes_as_string {
+    use super::*;
+
+    pub fn serialize<S, T>(bytes: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: AsRef<[u8]>,
+    {
+        bytes
+            .as_ref()
+            .map(|bytes| String::from_utf8_lossy(bytes.as_ref()))
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: TryFrom<Vec<u8>>,
+    {
+        Option::<String>::deserialize(deserializer)?
+            .map(|value| {
+                T::try_from(value.into_bytes())
+                    .map_err(|_| serde::de::Error::custom("string too long"))
+            })
+            .transpose()
+    }
+}
+
+/// `bytes_as_string` for a set of bytes
+pub mod bytes_set_as_strings {
+    use super::*;
+
+    pub fn serialize<S, T>(set: &BTreeSet<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: AsRef<[u8]>,
+    {
+        serializer.collect_seq(
+            set.iter()
+                .map(|bytes| String::from_utf8_lossy(bytes.as_ref())),
+        )
+    }
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<BTreeSet<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: TryFrom<Vec<u8>> + Ord,
+    {
+        Vec::<String>::deserialize(deserializer)?
+            .into_iter()
+            .map(|value| {
+                T::try_from(value.into_bytes())
+                    .map_err(|_| serde::de::Error::custom("string too long"))
+            })
+            .collect()
+    }
+}
+
+/// (De)serializes a peer ID as its string, peer IDs are stored as their base58 or CID bytes
+pub mod peer_id_as_string {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(peer_id: &PeerId, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&String::from_utf8_lossy(&peer_id.0))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<PeerId, D::Error> {
+        Ok(PeerId(String::deserialize(deserializer)?.into_bytes()))
+    }
+}
+
+/// (De)serializes optional opaque bytes, e.g. validator args, as a `0x` prefixed hex string
+pub mod option_bytes_as_hex {
+    use super::*;
+
+    pub fn serialize<S, T>(bytes: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: AsRef<[u8]>,
+    {
+        bytes
+            .as_ref()
+            .map(|bytes| sp_core::bytes::to_hex(bytes.as_ref(), false))
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: TryFrom<Vec<u8>>,
+    {
+        Option::<String>::deserialize(deserializer)?
+            .map(|value| {
+                let bytes = sp_core::bytes::from_hex(&value).map_err(serde::de::Error::custom)?;
+                T::try_from(bytes).map_err(|_| serde::de::Error::custom("bytes too long"))
+            })
+            .transpose()
+    }
+}
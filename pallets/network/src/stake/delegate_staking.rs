@@ -16,6 +16,7 @@
 // Enables accounts to delegate stake to subnets for a portion of emissions
 
 use super::*;
+use frame_support::pallet_prelude::Weight;
 use sp_runtime::Saturating;
 
 impl<T: Config> Pallet<T> {
@@ -347,12 +348,16 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Returns the weight of the shares checkpoint, the rest is covered by the callers
+    /// benchmarks
     pub fn increase_account_delegate_stake(
         account_id: &T::AccountId,
         subnet_id: u32,
         amount: u128,
         shares: u128,
-    ) {
+    ) -> Weight {
+        let weight = Self::checkpoint_account_subnet_delegate_shares(account_id, subnet_id);
+
         // -- increase account subnet staking shares balance
         AccountSubnetDelegateStakeShares::<T>::mutate(account_id, subnet_id, |mut n| {
             n.saturating_accrue(shares)
@@ -371,14 +376,20 @@ impl<T: Config> Pallet<T> {
         SubnetNetFlow::<T>::mutate(subnet_id, |flow| {
             *flow = flow.saturating_add(amount as i128);
         });
+
+        weight
     }
 
+    /// Returns the weight of the shares checkpoint, the rest is covered by the callers
+    /// benchmarks
     pub fn decrease_account_delegate_stake(
         account_id: &T::AccountId,
         subnet_id: u32,
         amount: u128,
         shares: u128,
-    ) {
+    ) -> Weight {
+        let weight = Self::checkpoint_account_subnet_delegate_shares(account_id, subnet_id);
+
         // -- decrease account subnet staking shares balance
         AccountSubnetDelegateStakeShares::<T>::mutate(account_id, subnet_id, |mut n| {
             n.saturating_reduce(shares)
@@ -397,6 +408,8 @@ impl<T: Config> Pallet<T> {
         SubnetNetFlow::<T>::mutate(subnet_id, |flow| {
             *flow = flow.saturating_sub(amount as i128);
         });
+
+        weight
     }
 
     /// Rewards are deposited here from the ``rewards.rs`` or by donations
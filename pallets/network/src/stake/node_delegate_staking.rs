@@ -16,6 +16,7 @@
 // Enables accounts to delegate stake to subnets for a portion of emissions
 
 use super::*;
+use frame_support::pallet_prelude::Weight;
 use sp_runtime::Saturating;
 
 impl<T: Config> Pallet<T> {
@@ -398,13 +399,18 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Returns the weight of the shares checkpoint, the rest is covered by the callers
+    /// benchmarks
     pub fn increase_account_node_delegate_stake_shares(
         account_id: &T::AccountId,
         subnet_id: u32,
         subnet_node_id: u32,
         amount: u128,
         shares: u128,
-    ) {
+    ) -> Weight {
+        let weight =
+            Self::checkpoint_account_node_delegate_shares(account_id, subnet_id, subnet_node_id);
+
         // -- increase account subnet staking shares balance
         AccountNodeDelegateStakeShares::<T>::mutate(
             (account_id, subnet_id, subnet_node_id),
@@ -422,15 +428,22 @@ impl<T: Config> Pallet<T> {
         });
 
         TotalNodeDelegateStake::<T>::mutate(|mut n| n.saturating_accrue(amount));
+
+        weight
     }
 
+    /// Returns the weight of the shares checkpoint, the rest is covered by the callers
+    /// benchmarks
     pub fn decrease_account_node_delegate_stake_shares(
         account_id: &T::AccountId,
         subnet_id: u32,
         subnet_node_id: u32,
         amount: u128,
         shares: u128,
-    ) {
+    ) -> Weight {
+        let weight =
+            Self::checkpoint_account_node_delegate_shares(account_id, subnet_id, subnet_node_id);
+
         // -- decrease account subnet staking shares balance
         AccountNodeDelegateStakeShares::<T>::mutate(
             (account_id, subnet_id, subnet_node_id),
@@ -448,6 +461,8 @@ impl<T: Config> Pallet<T> {
         });
 
         TotalNodeDelegateStake::<T>::mutate(|mut n| n.saturating_reduce(amount));
+
+        weight
     }
 
     /// Rewards are deposited here from the ``rewards.rs`` or by donations
@@ -23,6 +23,10 @@ impl<T: Config> Pallet<T> {
     ///
     /// Queues a swap call to be executed after a certain number of blocks.
     ///
+    /// Fails if `SwapQueueOrder` is full or the account already has `MaxPendingSwapsPerAccount`
+    /// pending swap calls. Callers rely on the extrinsic being reverted in either case, the
+    /// stake has already been removed from the "from" pool.
+    ///
     /// Only callable by
     /// - `do_swap_delegate_stake`
     /// - `do_swap_node_delegate_stake`
@@ -40,6 +44,16 @@ impl<T: Config> Pallet<T> {
     ) -> DispatchResult {
         let id = NextSwapQueueId::<T>::get();
 
+        let mut account_queue_ids = AccountSwapQueueIds::<T>::get(&account_id);
+        ensure!(
+            (account_queue_ids.len() as u32) < MaxPendingSwapsPerAccount::<T>::get(),
+            Error::<T>::MaxPendingSwapsPerAccountReached
+        );
+
+        // Add ID to the end of the queue
+        SwapQueueOrder::<T>::try_mutate(|queue| queue.try_push(id))
+            .map_err(|_| Error::<T>::SwapQueueFull)?;
+
         let queued_item = QueuedSwapItem {
             id,
             call: call.clone(),
@@ -50,10 +64,8 @@ impl<T: Config> Pallet<T> {
         // Add to data storage
         SwapCallQueue::<T>::insert(&id, &queued_item);
 
-        // Add ID to the end of the queue
-        SwapQueueOrder::<T>::mutate(|queue| {
-            let _ = queue.try_push(id); // Handle error if queue is full
-        });
+        account_queue_ids.insert(id);
+        AccountSwapQueueIds::<T>::insert(&account_id, account_queue_ids);
 
         NextSwapQueueId::<T>::mutate(|next_id| *next_id = next_id.saturating_add(1));
 
@@ -73,6 +85,8 @@ impl<T: Config> Pallet<T> {
     ) -> DispatchResult {
         SwapCallQueue::<T>::mutate(&id, |item_opt| -> DispatchResult {
             let item = item_opt.as_mut().ok_or(Error::<T>::SwapCallNotFound)?;
+            // The account can't change, `AccountSwapQueueIds` is keyed by it
+            ensure!(item.call.get_account_id() == &key, Error::<T>::NotKeyOwner);
             let call_balance = item.call.get_queue_balance();
 
             match new_call {
@@ -132,4 +146,62 @@ impl<T: Config> Pallet<T> {
 
         Ok(())
     }
+
+    /// Cancel a queued swap call
+    ///
+    /// # Description
+    ///
+    /// Removes the swap call from the queue and adds its balance to the caller's unbonding
+    /// ledger. The cooldown matches removing stake from the swap target, i.e.
+    /// `DelegateStakeCooldownEpochs` for subnets and `NodeDelegateStakeCooldownEpochs` for nodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Account ID of the caller.
+    /// * `id` - Swap queue ID.
+    ///
+    pub fn do_cancel_swap_queue_item(key: T::AccountId, id: u32) -> DispatchResult {
+        let item = SwapCallQueue::<T>::get(&id).ok_or(Error::<T>::SwapCallNotFound)?;
+
+        let (account_id, balance, cooldown_epochs) = match item.call {
+            QueuedSwapCall::SwapToSubnetDelegateStake {
+                account_id,
+                to_subnet_id: _,
+                balance,
+            } => (account_id, balance, DelegateStakeCooldownEpochs::<T>::get()),
+            QueuedSwapCall::SwapToNodeDelegateStake {
+                account_id,
+                to_subnet_id: _,
+                to_subnet_node_id: _,
+                balance,
+            } => (
+                account_id,
+                balance,
+                NodeDelegateStakeCooldownEpochs::<T>::get(),
+            ),
+        };
+
+        ensure!(&account_id == &key, Error::<T>::NotKeyOwner);
+
+        Self::add_balance_to_unbonding_ledger(
+            &key,
+            balance,
+            cooldown_epochs.saturating_mul(T::EpochLength::get()),
+            Self::get_current_block_as_u32(),
+        )?;
+
+        SwapCallQueue::<T>::remove(&id);
+        SwapQueueOrder::<T>::mutate(|queue| queue.retain(|&queue_id| queue_id != id));
+        AccountSwapQueueIds::<T>::mutate(&key, |ids| {
+            ids.remove(&id);
+        });
+
+        Self::deposit_event(Event::SwapCallQueueCancelled {
+            id,
+            account_id: key,
+            balance,
+        });
+
+        Ok(())
+    }
 }
@@ -202,32 +202,11 @@ impl<T: Config> Pallet<T> {
         balance: u128,
     ) -> DispatchResult {
         let call = QueuedSwapCall::SwapToSubnetDelegateStake {
-            account_id,
+            account_id: account_id.clone(),
             to_subnet_id,
             balance,
         };
 
-        let id = NextSwapQueueId::<T>::get();
-
-        let queued_item = QueuedSwapItem {
-            id,
-            call,
-            queued_at_block: Self::get_current_block_as_u32(),
-            execute_after_blocks: T::EpochLength::get(),
-        };
-
-        // Add to data storage
-        SwapCallQueue::<T>::insert(&id, &queued_item);
-
-        // Add ID to the end of the queue
-        SwapQueueOrder::<T>::mutate(|queue| {
-            let _ = queue.try_push(id); // Handle error if queue is full
-        });
-
-        NextSwapQueueId::<T>::mutate(|next_id| *next_id = next_id.saturating_add(1));
-
-        // Self::deposit_event(Event::SwapCallQueued { id, who });
-
-        Ok(())
+        Self::queue_swap(account_id, call)
     }
 }
@@ -16,35 +16,35 @@
 // Enables accounts to delegate stake to subnets for a portion of emissions
 
 use super::*;
-use libm::{exp, pow};
 use sp_runtime::traits::Saturating;
 
+/// All rates and years are 1e18 fixed-point, see `utilities/fixed_point.rs`
 pub struct Inflation {
     /// Initial maximum inflation percentage, from time=0
-    pub initial_max: f64,
+    pub initial_max: u128,
 
     /// Initial minimum inflation percentage, from time=0
-    pub initial_min: f64,
+    pub initial_min: u128,
 
     /// Terminal inflation percentage, to time=INF
-    pub terminal: f64,
+    pub terminal: u128,
 
     /// Rate per year, at which inflation is lowered until reaching terminal
     ///  i.e. inflation(year) == MAX(terminal, initial_max*((1-taper)^year))
-    pub taper: f64,
+    pub taper: u128,
 
     /// Percentage of total inflation allocated to the foundation
-    pub foundation: f64,
+    pub foundation: u128,
     /// Duration of foundation pool inflation, in years
-    pub foundation_term: f64,
+    pub foundation_term: u128,
 }
 
-const DEFAULT_INITIAL_MAX: f64 = 0.1;
-const DEFAULT_INITIAL_MIN: f64 = 0.06;
-const DEFAULT_TERMINAL: f64 = 0.015;
-const DEFAULT_TAPER: f64 = 0.1;
-const DEFAULT_FOUNDATION: f64 = 0.2;
-const DEFAULT_FOUNDATION_TERM: f64 = 7.0;
+const DEFAULT_INITIAL_MAX: u128 = 100_000_000_000_000_000; // 10%
+const DEFAULT_INITIAL_MIN: u128 = 60_000_000_000_000_000; // 6%
+const DEFAULT_TERMINAL: u128 = 15_000_000_000_000_000; // 1.5%
+const DEFAULT_TAPER: u128 = 100_000_000_000_000_000; // 10%
+const DEFAULT_FOUNDATION: u128 = 200_000_000_000_000_000; // 20%
+const DEFAULT_FOUNDATION_TERM: u128 = 7_000_000_000_000_000_000; // 7 years
 
 impl Default for Inflation {
     fn default() -> Self {
@@ -61,57 +61,58 @@ impl Default for Inflation {
 
 impl Inflation {
     /// portion of total that goes to validators
-    pub fn validator(&self, u: f64, mid: f64, k: f64, year: f64) -> f64 {
-        self.total(u, mid, k, year) - self.foundation(u, mid, k, year)
+    pub fn validator<T: Config>(&self, u: u128, mid: u128, k: u128, year: u128) -> u128 {
+        self.total::<T>(u, mid, k, year)
+            .saturating_sub(self.foundation::<T>(u, mid, k, year))
     }
 
     /// portion of total that goes to foundation
-    pub fn foundation(&self, u: f64, mid: f64, k: f64, year: f64) -> f64 {
+    pub fn foundation<T: Config>(&self, u: u128, mid: u128, k: u128, year: u128) -> u128 {
         if year < self.foundation_term {
-            self.total(u, mid, k, year) * self.foundation
+            Pallet::<T>::percent_mul(self.total::<T>(u, mid, k, year), self.foundation)
         } else {
-            0.0
+            0
         }
     }
 
     /// inflation rate at year
-    pub fn total(&self, x: f64, mid: f64, k: f64, year: f64) -> f64 {
-        let c = (x - mid).abs();
-        let d = k * c;
-        let exp = exp(d);
-        let sigmoid = if x > mid {
-            1.0 / (1.0 + exp)
-        } else {
-            exp / (1.0 + exp)
-        };
+    pub fn total<T: Config>(&self, x: u128, mid: u128, k: u128, year: u128) -> u128 {
+        let sigmoid = Pallet::<T>::fixed_sigmoid_decreasing_v2(x, mid, k);
 
-        let max = self.current_max_rate(year);
+        let max = self.current_max_rate::<T>(year);
 
-        self.terminal + (max - self.terminal) * sigmoid
+        self.terminal.saturating_add(Pallet::<T>::percent_mul(
+            max.saturating_sub(self.terminal),
+            sigmoid,
+        ))
     }
 
-    pub fn year_from_epoch(&self, epoch: u32, epochs_per_year: u32) -> f64 {
-        epoch as f64 / epochs_per_year as f64
+    pub fn year_from_epoch<T: Config>(&self, epoch: u32, epochs_per_year: u32) -> u128 {
+        Pallet::<T>::percent_div(epoch as u128, epochs_per_year as u128)
     }
 
-    pub fn current_max_rate(&self, year: f64) -> f64 {
-        let tapered = self.initial_max * pow(1.0 - self.taper, year);
+    pub fn current_max_rate<T: Config>(&self, year: u128) -> u128 {
+        let tapered = Pallet::<T>::percent_mul(
+            self.initial_max,
+            Pallet::<T>::percent_pow(
+                Pallet::<T>::percentage_factor_as_u128().saturating_sub(self.taper),
+                year,
+            ),
+        );
 
-        if tapered > self.terminal {
-            tapered
-        } else {
-            self.terminal
-        }
+        tapered.max(self.terminal)
     }
 
-    pub fn current_min_rate(&self, year: f64) -> f64 {
-        let tapered = self.initial_min * pow(1.0 - self.taper, year);
+    pub fn current_min_rate<T: Config>(&self, year: u128) -> u128 {
+        let tapered = Pallet::<T>::percent_mul(
+            self.initial_min,
+            Pallet::<T>::percent_pow(
+                Pallet::<T>::percentage_factor_as_u128().saturating_sub(self.taper),
+                year,
+            ),
+        );
 
-        if tapered > self.terminal {
-            tapered
-        } else {
-            self.terminal
-        }
+        tapered.max(self.terminal)
     }
 
     /// Get the yearly inflation rate
@@ -127,43 +128,61 @@ impl Inflation {
     /// *mid: Sigmoid midpoint
     /// *f: Sigmoid steepness
     /// *sigmoid_fn: Sigmoid function
-    pub fn inflation<F>(&self, x: f64, mid: f64, k: f64, year: f64, sigmoid_fn: F) -> f64
+    pub fn inflation<T: Config, F>(
+        &self,
+        x: u128,
+        mid: u128,
+        k: u128,
+        year: u128,
+        sigmoid_fn: F,
+    ) -> u128
     where
-        F: Fn(f64, f64, f64, f64, f64) -> f64,
+        F: Fn(u128, u128, u128, u128, u128) -> u128,
     {
-        let max = self.current_max_rate(year);
+        let max = self.current_max_rate::<T>(year);
         if max == self.terminal {
             return max;
         }
-        let min = self.current_min_rate(year);
-        min + (max - min) * sigmoid_fn(x, mid, k, 0.0, 1.0)
+        let min = self.current_min_rate::<T>(year);
+        min.saturating_add(Pallet::<T>::percent_mul(
+            max.saturating_sub(min),
+            sigmoid_fn(x, mid, k, 0, Pallet::<T>::percentage_factor_as_u128()),
+        ))
     }
 }
 
 impl<T: Config> Pallet<T> {
-    pub fn get_inflation(node_utilization: f64, year: f64) -> f64 {
-        let mid = Self::get_percent_as_f64(InflationSigmoidMidpoint::<T>::get());
-        let k = InflationSigmoidSteepness::<T>::get() as f64;
+    pub fn get_inflation(node_utilization: u128, year: u128) -> u128 {
+        let mid = InflationSigmoidMidpoint::<T>::get();
+        let k =
+            InflationSigmoidSteepness::<T>::get().saturating_mul(Self::percentage_factor_as_u128());
 
         let inflation = Inflation::default();
 
-        inflation.inflation(node_utilization, mid, k, year, Self::sigmoid_decreasing)
+        inflation.inflation::<T, _>(
+            node_utilization,
+            mid,
+            k,
+            year,
+            Self::fixed_sigmoid_decreasing,
+        )
     }
 
-    pub fn get_epoch_inflation_rate(epoch: u32, node_utilization: f64) -> f64 {
-        let epochs_per_year: f64 = T::EpochsPerYear::get() as f64;
-        let year: f64 = epoch as f64 / epochs_per_year;
-        let yearly_rate: f64 = Self::get_inflation(node_utilization, year);
+    pub fn get_epoch_inflation_rate(epoch: u32, node_utilization: u128) -> u128 {
+        let epochs_per_year = T::EpochsPerYear::get() as u128;
+        let year = Self::percent_div(epoch as u128, epochs_per_year);
+        let yearly_rate = Self::get_inflation(node_utilization, year);
 
-        yearly_rate / epochs_per_year
+        yearly_rate / epochs_per_year.max(1)
     }
 
-    fn get_subnet_node_utilization() -> f64 {
+    fn get_subnet_node_utilization() -> u128 {
         let max_subnets: u32 = MaxSubnets::<T>::get();
         let max_nodes: u32 = max_subnets.saturating_mul(MaxSubnetNodes::<T>::get());
         let total_active_nodes: u32 = TotalActiveNodes::<T>::get();
 
-        (total_active_nodes as f64 / max_nodes as f64).clamp(0.0, 1.0)
+        Self::percent_div(total_active_nodes as u128, max_nodes as u128)
+            .min(Self::percentage_factor_as_u128())
     }
 
     /// Get the epochs emissions for validators and foundation
@@ -177,28 +196,27 @@ impl<T: Config> Pallet<T> {
     /// *(validator emissions, foundation emissions)
     ///
     pub fn get_epoch_emissions(epoch: u32) -> (u128, u128) {
-        let mid = Self::get_percent_as_f64(InflationSigmoidMidpoint::<T>::get());
-        let k = InflationSigmoidSteepness::<T>::get() as f64;
+        let mid = InflationSigmoidMidpoint::<T>::get();
+        let k =
+            InflationSigmoidSteepness::<T>::get().saturating_mul(Self::percentage_factor_as_u128());
 
-        let epochs_per_year: f64 = T::EpochsPerYear::get() as f64;
-        let year: f64 = epoch as f64 / epochs_per_year;
-        let node_utilization = Self::get_subnet_node_utilization().min(1.0);
+        let epochs_per_year = T::EpochsPerYear::get() as u128;
+        let year = Self::percent_div(epoch as u128, epochs_per_year);
+        let node_utilization = Self::get_subnet_node_utilization();
 
         let (validator_rate, foundation_rate) = {
             let inflation = Inflation::default();
             (
-                (inflation).validator(node_utilization, mid, k, year),
-                (inflation).foundation(node_utilization, mid, k, year),
+                inflation.validator::<T>(node_utilization, mid, k, year),
+                inflation.foundation::<T>(node_utilization, mid, k, year),
             )
         };
 
-        let total_issuance: f64 = 100000000e+18 as f64;
-        let validator_epoch_rate = validator_rate / epochs_per_year;
-        let foundation_epoch_rate = foundation_rate / epochs_per_year;
+        let total_issuance: u128 = 100_000_000_000_000_000_000_000_000;
 
         (
-            (total_issuance * validator_epoch_rate) as u128,
-            (total_issuance * foundation_epoch_rate) as u128,
+            Self::percent_mul(total_issuance, validator_rate) / epochs_per_year.max(1),
+            Self::percent_mul(total_issuance, foundation_rate) / epochs_per_year.max(1),
         )
     }
 }
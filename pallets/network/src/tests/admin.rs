@@ -11,8 +11,8 @@ use crate::{
     MaxIdleClassificationEpochs, MaxIncludedClassificationEpochs, MaxMaxRegisteredNodes,
     MaxMinDelegateStakeMultiplier, MaxMinSubnetNodeReputation, MaxNodeBurnRate,
     MaxNodeReputationFactor, MaxOverwatchNodes, MaxPauseEpochsSubnetReputationFactor,
-    MaxQueueEpochs, MaxRewardRateDecrease, MaxSlashAmount, MaxSubnetBootnodeAccess,
-    MaxSubnetDelegateStakeRewardsPercentageChange, MaxSubnetMinStake,
+    MaxPendingSwapsPerAccount, MaxQueueEpochs, MaxRewardRateDecrease, MaxSlashAmount,
+    MaxSubnetBootnodeAccess, MaxSubnetDelegateStakeRewardsPercentageChange, MaxSubnetMinStake,
     MaxSubnetNodeMinWeightDecreaseReputationThreshold, MaxSubnetNodes, MaxSubnetPauseEpochs,
     MaxSubnetRemovalInterval, MaxSubnets, MaxSwapQueueCallsPerBlock, MaxUnbondings,
     MaximumHooksWeightV2, MinActiveNodeStakeEpochs, MinAttestationPercentage, MinChurnLimit,
@@ -32,6 +32,10 @@ use crate::{
     SubnetWeightFactors, SubnetWeightFactorsData, SuperMajorityAttestationRatio, TxRateLimit,
     ValidatorAbsentSubnetReputationFactor, ValidatorRewardK, ValidatorRewardMidpoint,
 };
+use crate::{
+    BackupProposerEpochProgression, BackupValidatorRewardFactor, ConsensusArchiveDepth,
+    RewardHistoryDepth, RewardHistoryPruneEpoch,
+};
 use frame_support::{assert_err, assert_ok};
 
 //
@@ -1335,6 +1339,26 @@ fn test_set_max_swap_queue_calls_per_block() {
     });
 }
 
+#[test]
+fn test_set_max_pending_swaps_per_account() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        let new_value: u32 = 4;
+
+        assert_ok!(Network::set_max_pending_swaps_per_account(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(4, 5)),
+            new_value
+        ));
+
+        assert_eq!(MaxPendingSwapsPerAccount::<Test>::get(), new_value);
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SetMaxPendingSwapsPerAccount(new_value)
+        );
+    });
+}
+
 // === Collective Removal Tests ===
 
 #[test]
@@ -1602,6 +1626,90 @@ fn test_set_validator_reward_midpoint() {
     });
 }
 
+#[test]
+fn test_set_backup_proposer_epoch_progression() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        let new_value: u128 = 750000000000000000;
+
+        assert_ok!(Network::set_backup_proposer_epoch_progression(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            new_value
+        ));
+
+        assert_eq!(BackupProposerEpochProgression::<Test>::get(), new_value);
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SetBackupProposerEpochProgression(new_value)
+        );
+
+        assert_err!(
+            Network::set_backup_proposer_epoch_progression(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+                Network::percentage_factor_as_u128() + 1
+            ),
+            Error::<Test>::InvalidPercent
+        );
+    });
+}
+
+#[test]
+fn test_set_backup_validator_reward_factor() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        let new_value: u128 = 250000000000000000;
+
+        assert_ok!(Network::set_backup_validator_reward_factor(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            new_value
+        ));
+
+        assert_eq!(BackupValidatorRewardFactor::<Test>::get(), new_value);
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SetBackupValidatorRewardFactor(new_value)
+        );
+
+        assert_err!(
+            Network::set_backup_validator_reward_factor(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+                Network::percentage_factor_as_u128() + 1
+            ),
+            Error::<Test>::InvalidPercent
+        );
+    });
+}
+
+#[test]
+fn test_set_consensus_archive_depth() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        let new_value: u32 = 100;
+
+        assert_ok!(Network::set_consensus_archive_depth(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            new_value
+        ));
+
+        assert_eq!(ConsensusArchiveDepth::<Test>::get(), new_value);
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SetConsensusArchiveDepth(new_value)
+        );
+
+        assert_err!(
+            Network::set_consensus_archive_depth(
+                RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+                1
+            ),
+            Error::<Test>::InvalidConsensusArchiveDepth
+        );
+    });
+}
+
 #[test]
 fn test_set_attestor_reward_exponent() {
     new_test_ext().execute_with(|| {
@@ -1939,3 +2047,29 @@ fn test_set_max_pause_epochs_subnet_reputation_factor() {
         );
     });
 }
+
+#[test]
+fn test_set_reward_history_depth() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(System::block_number() + 1);
+
+        assert_eq!(RewardHistoryPruneEpoch::<Test>::get(), None);
+
+        let new_value: u32 = 100;
+
+        assert_ok!(Network::set_reward_history_depth(
+            RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+            new_value
+        ));
+
+        assert_eq!(RewardHistoryDepth::<Test>::get(), new_value);
+        assert_eq!(
+            RewardHistoryPruneEpoch::<Test>::get(),
+            Some(Network::get_current_epoch_as_u32())
+        );
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SetRewardHistoryDepth(new_value)
+        );
+    });
+}
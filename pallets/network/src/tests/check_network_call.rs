@@ -0,0 +1,313 @@
+use super::mock::*;
+use crate::tests::test_utils::*;
+use crate::{
+    CheckNetworkCall, CheckNetworkCallError, MinSubnetMinStake, OverwatchCommit, OverwatchNode,
+    OverwatchNodeBlacklist, OverwatchNodeIdHotkey, OverwatchNodes, OverwatchReveal,
+    SubnetElectedValidator, SubnetName, SubnetNodeIdHotkey, TotalActiveSubnets, TotalSubnetNodes,
+    ATTEST_PRIORITY, OVERWATCH_PRIORITY, PROPOSE_ATTESTATION_PRIORITY,
+};
+use frame_support::assert_ok;
+use frame_support::dispatch::GetDispatchInfo;
+use sp_runtime::traits::TransactionExtension;
+use sp_runtime::transaction_validity::{
+    InvalidTransaction, TransactionSource, TransactionValidityError,
+};
+
+//
+//
+//
+//
+//
+//
+//
+// CheckNetworkCall
+//
+//
+//
+//
+//
+//
+//
+
+fn custom_error(error: CheckNetworkCallError) -> TransactionValidityError {
+    InvalidTransaction::Custom(error as u8).into()
+}
+
+fn propose_attestation_call(subnet_id: u32) -> crate::Call<Test> {
+    crate::Call::propose_attestation {
+        subnet_id,
+        data: Vec::new(),
+        prioritize_queue_node_id: None,
+        remove_queue_node_id: None,
+        args: None,
+        attest_data: None,
+    }
+}
+
+/// Build an activated subnet and elect its validator, returning the subnet ID and validator hotkey
+fn build_subnet_with_elected_validator() -> (u32, AccountId) {
+    let subnet_name: Vec<u8> = "subnet-name".into();
+    let deposit_amount: u128 = 10000000000000000000000;
+    let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+    build_activated_subnet(subnet_name.clone(), 0, 12, deposit_amount, stake_amount);
+
+    let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+    let block_number = System::block_number();
+    let epoch = block_number / EpochLength::get();
+
+    set_block_to_subnet_slot_epoch(epoch, subnet_id);
+    let subnet_epoch = Network::get_current_subnet_epoch_as_u32(subnet_id);
+
+    Network::elect_validator(subnet_id, subnet_epoch, block_number);
+
+    let validator_id = SubnetElectedValidator::<Test>::get(subnet_id, subnet_epoch).unwrap();
+    let hotkey = SubnetNodeIdHotkey::<Test>::get(subnet_id, validator_id).unwrap();
+
+    (subnet_id, hotkey)
+}
+
+#[test]
+fn test_check_network_call_propose_attestation() {
+    new_test_ext().execute_with(|| {
+        let (subnet_id, hotkey) = build_subnet_with_elected_validator();
+        let call = propose_attestation_call(subnet_id);
+
+        let valid = CheckNetworkCall::<Test>::validate_network_call(&hotkey, &call)
+            .unwrap()
+            .unwrap();
+        assert_eq!(valid.priority, PROPOSE_ATTESTATION_PRIORITY);
+        assert_eq!(valid.provides.len(), 1);
+
+        // Not the elected validator
+        assert_eq!(
+            CheckNetworkCall::<Test>::validate_network_call(&account(999999), &call),
+            Some(Err(custom_error(CheckNetworkCallError::NotEpochValidator)))
+        );
+
+        assert_ok!(Network::propose_attestation(
+            RuntimeOrigin::signed(hotkey.clone()),
+            subnet_id,
+            Vec::new(),
+            None,
+            None,
+            None,
+            None,
+        ));
+
+        // Already submitted for the epoch
+        assert_eq!(
+            CheckNetworkCall::<Test>::validate_network_call(&hotkey, &call),
+            Some(Err(custom_error(CheckNetworkCallError::AlreadyProposed)))
+        );
+    });
+}
+
+#[test]
+fn test_check_network_call_attest() {
+    new_test_ext().execute_with(|| {
+        let (subnet_id, validator_hotkey) = build_subnet_with_elected_validator();
+        let subnets = TotalActiveSubnets::<Test>::get();
+        let max_subnet_nodes = crate::MaxSubnetNodes::<Test>::get();
+        let total_subnet_nodes = TotalSubnetNodes::<Test>::get(subnet_id);
+
+        let call = crate::Call::attest {
+            subnet_id,
+            attest_data: None,
+        };
+
+        let attestor_hotkey = (1..=total_subnet_nodes)
+            .filter_map(|subnet_node_id| SubnetNodeIdHotkey::<Test>::get(subnet_id, subnet_node_id))
+            .find(|hotkey| *hotkey != validator_hotkey)
+            .unwrap();
+
+        // Consensus data isn't on chain yet, the attestation waits on the proposal
+        let proposal = CheckNetworkCall::<Test>::validate_network_call(
+            &validator_hotkey,
+            &propose_attestation_call(subnet_id),
+        )
+        .unwrap()
+        .unwrap();
+        let valid = CheckNetworkCall::<Test>::validate_network_call(&attestor_hotkey, &call)
+            .unwrap()
+            .unwrap();
+        assert_eq!(valid.priority, ATTEST_PRIORITY);
+        assert_eq!(valid.requires, proposal.provides);
+
+        assert_eq!(
+            CheckNetworkCall::<Test>::validate_network_call(&account(999999), &call),
+            Some(Err(custom_error(CheckNetworkCallError::NotAttestor)))
+        );
+
+        assert_ok!(Network::propose_attestation(
+            RuntimeOrigin::signed(validator_hotkey.clone()),
+            subnet_id,
+            get_subnet_node_consensus_data(subnets, max_subnet_nodes, 0, total_subnet_nodes),
+            None,
+            None,
+            None,
+            None,
+        ));
+
+        let valid = CheckNetworkCall::<Test>::validate_network_call(&attestor_hotkey, &call)
+            .unwrap()
+            .unwrap();
+        assert_eq!(valid.priority, ATTEST_PRIORITY);
+        assert!(valid.requires.is_empty());
+        assert_eq!(valid.provides.len(), 1);
+
+        // Not a subnet node
+        assert_eq!(
+            CheckNetworkCall::<Test>::validate_network_call(&account(999999), &call),
+            Some(Err(custom_error(CheckNetworkCallError::NotAttestor)))
+        );
+
+        // The validator auto-attests its own proposal
+        assert_eq!(
+            CheckNetworkCall::<Test>::validate_network_call(&validator_hotkey, &call),
+            Some(Err(custom_error(CheckNetworkCallError::AlreadyAttested)))
+        );
+
+        assert_ok!(Network::attest(
+            RuntimeOrigin::signed(attestor_hotkey.clone()),
+            subnet_id,
+            None,
+        ));
+
+        assert_eq!(
+            CheckNetworkCall::<Test>::validate_network_call(&attestor_hotkey, &call),
+            Some(Err(custom_error(CheckNetworkCallError::AlreadyAttested)))
+        );
+    });
+}
+
+#[test]
+fn test_check_network_call_overwatch_commit_reveal() {
+    new_test_ext().execute_with(|| {
+        let coldkey: AccountId = account(1);
+        let hotkey: AccountId = account(2);
+        let overwatch_node_id = 1;
+
+        OverwatchNodes::<Test>::insert(
+            overwatch_node_id,
+            OverwatchNode {
+                id: overwatch_node_id,
+                hotkey: hotkey.clone(),
+            },
+        );
+        set_hotkey_owner(&coldkey, &hotkey);
+        OverwatchNodeIdHotkey::<Test>::insert(overwatch_node_id, hotkey.clone());
+
+        let commit_call = crate::Call::commit_overwatch_subnet_weights {
+            overwatch_node_id,
+            commit_weights: vec![OverwatchCommit {
+                subnet_id: 1,
+                weight: make_commit(1, b"salt".to_vec()),
+            }],
+        };
+        let reveal_call = crate::Call::reveal_overwatch_subnet_weights {
+            overwatch_node_id,
+            reveals: vec![OverwatchReveal {
+                subnet_id: 1,
+                weight: 1,
+                salt: b"salt".to_vec(),
+            }],
+        };
+
+        set_block_to_overwatch_commit_block(1);
+
+        let valid = CheckNetworkCall::<Test>::validate_network_call(&hotkey, &commit_call)
+            .unwrap()
+            .unwrap();
+        assert_eq!(valid.priority, OVERWATCH_PRIORITY);
+        assert!(
+            CheckNetworkCall::<Test>::validate_network_call(&coldkey, &commit_call)
+                .unwrap()
+                .is_ok()
+        );
+        assert_eq!(
+            CheckNetworkCall::<Test>::validate_network_call(&hotkey, &reveal_call),
+            Some(Err(custom_error(CheckNetworkCallError::NotRevealPeriod)))
+        );
+
+        // Not a key of the overwatch node
+        assert_eq!(
+            CheckNetworkCall::<Test>::validate_network_call(&account(3), &commit_call),
+            Some(Err(custom_error(CheckNetworkCallError::NotOverwatchNode)))
+        );
+
+        set_block_to_overwatch_reveal_block(1);
+
+        assert!(
+            CheckNetworkCall::<Test>::validate_network_call(&hotkey, &reveal_call)
+                .unwrap()
+                .is_ok()
+        );
+        assert_eq!(
+            CheckNetworkCall::<Test>::validate_network_call(&hotkey, &commit_call),
+            Some(Err(custom_error(CheckNetworkCallError::NotCommitPeriod)))
+        );
+
+        OverwatchNodeBlacklist::<Test>::insert(coldkey.clone(), true);
+
+        assert_eq!(
+            CheckNetworkCall::<Test>::validate_network_call(&hotkey, &reveal_call),
+            Some(Err(custom_error(CheckNetworkCallError::NotOverwatchNode)))
+        );
+    });
+}
+
+#[test]
+fn test_check_network_call_extension() {
+    new_test_ext().execute_with(|| {
+        let (subnet_id, hotkey) = build_subnet_with_elected_validator();
+
+        let validate = |who: AccountId, call: RuntimeCall| {
+            let info = call.get_dispatch_info();
+            CheckNetworkCall::<Test>::new()
+                .validate(
+                    RuntimeOrigin::signed(who),
+                    &call,
+                    &info,
+                    0,
+                    (),
+                    &(),
+                    TransactionSource::External,
+                )
+                .map(|(valid, _, _)| valid)
+        };
+
+        let call = RuntimeCall::Network(propose_attestation_call(subnet_id));
+
+        assert_eq!(
+            validate(hotkey.clone(), call.clone()).unwrap().priority,
+            PROPOSE_ATTESTATION_PRIORITY
+        );
+        assert_eq!(
+            validate(account(999999), call.clone()),
+            Err(custom_error(CheckNetworkCallError::NotEpochValidator))
+        );
+
+        // Other calls pass through untouched
+        let other_call = RuntimeCall::Network(crate::Call::pause {});
+        assert_eq!(
+            validate(account(999999), other_call).unwrap(),
+            Default::default()
+        );
+
+        // Unsigned origins pass through untouched
+        let info = call.get_dispatch_info();
+        assert!(CheckNetworkCall::<Test>::new()
+            .validate(
+                RuntimeOrigin::none(),
+                &call,
+                &info,
+                0,
+                (),
+                &(),
+                TransactionSource::External,
+            )
+            .is_ok());
+    });
+}
@@ -0,0 +1,203 @@
+use super::mock::*;
+use crate::tests::test_utils::*;
+use crate::{
+    CleanupCursor, CleanupQueue, CleanupQueueHead, CleanupQueueTail, CleanupTask,
+    HotkeySubnetNodeId, MinSubnetMinStake, OverwatchCommits, OverwatchEpochLengthMultiplier,
+    OverwatchReveals, PeerIdSubnetNodeId, SubnetElectedValidator, SubnetName, SubnetNodeIdHotkey,
+    SubnetNodeReputation, SubnetNodesData, SubnetRemovalReason, SubnetsData,
+};
+use frame_support::traits::{Hooks, OnInitialize};
+use frame_support::weights::Weight;
+
+//
+//
+//
+//
+//
+//
+//
+// Cleanup queue
+//
+//
+//
+//
+//
+//
+//
+
+fn queued_tasks() -> Vec<CleanupTask> {
+    (CleanupQueueHead::<Test>::get()..CleanupQueueTail::<Test>::get())
+        .filter_map(|index| CleanupQueue::<Test>::get(index))
+        .collect()
+}
+
+#[test]
+fn test_remove_subnet_enqueues_cleanup_tasks() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+        let end = 4;
+
+        build_activated_subnet(subnet_name.clone(), 0, end, deposit_amount, stake_amount);
+
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+        let nodes = SubnetNodesData::<Test>::iter_prefix(subnet_id).count();
+        assert_eq!(nodes, end as usize);
+
+        Network::do_remove_subnet(subnet_id, SubnetRemovalReason::Owner);
+
+        assert_eq!(SubnetsData::<Test>::contains_key(subnet_id), false);
+
+        let tasks = queued_tasks();
+        assert!(tasks.contains(&CleanupTask::SubnetNodesData(subnet_id)));
+        assert!(tasks.contains(&CleanupTask::HotkeySubnetNodeId(subnet_id)));
+        assert!(tasks.contains(&CleanupTask::SubnetElectedValidator(subnet_id)));
+        assert!(tasks.contains(&CleanupTask::ConsensusSubmissionArchive(subnet_id)));
+
+        // Nothing cleared until `on_idle`
+        assert_eq!(
+            SubnetNodesData::<Test>::iter_prefix(subnet_id).count(),
+            nodes
+        );
+        assert_eq!(Network::get_subnet_node_info(subnet_id, 1), None);
+        assert_eq!(Network::get_subnet_node_hotkey_coldkey(subnet_id, 1), None);
+
+        Network::on_idle(System::block_number(), Weight::MAX);
+
+        assert_eq!(
+            CleanupQueueHead::<Test>::get(),
+            CleanupQueueTail::<Test>::get()
+        );
+        assert_eq!(queued_tasks(), vec![]);
+        assert_eq!(CleanupCursor::<Test>::get(), None);
+
+        assert_eq!(SubnetNodesData::<Test>::iter_prefix(subnet_id).count(), 0);
+        assert_eq!(
+            PeerIdSubnetNodeId::<Test>::iter_prefix(subnet_id).count(),
+            0
+        );
+        assert_eq!(
+            HotkeySubnetNodeId::<Test>::iter_prefix(subnet_id).count(),
+            0
+        );
+        assert_eq!(
+            SubnetNodeIdHotkey::<Test>::iter_prefix(subnet_id).count(),
+            0
+        );
+        assert_eq!(
+            SubnetNodeReputation::<Test>::iter_prefix(subnet_id).count(),
+            0
+        );
+        assert_eq!(
+            SubnetElectedValidator::<Test>::iter_prefix(subnet_id).count(),
+            0
+        );
+    });
+}
+
+#[test]
+fn test_process_cleanup_queue_resumes_from_cursor() {
+    let mut ext = new_test_ext();
+
+    // Subnet doesn't exist
+    let subnet_id = 99;
+    let keys = Network::CLEANUP_MAX_KEYS_PER_BLOCK + 10;
+
+    ext.execute_with(|| {
+        for subnet_node_id in 0..keys {
+            SubnetNodeReputation::<Test>::insert(subnet_id, subnet_node_id, 1);
+            SubnetElectedValidator::<Test>::insert(subnet_id, subnet_node_id, 1);
+        }
+        Network::enqueue_cleanup_task(CleanupTask::SubnetNodeReputation(subnet_id));
+        Network::enqueue_cleanup_task(CleanupTask::SubnetElectedValidator(subnet_id));
+    });
+    // Limits only apply to keys in the backend
+    ext.commit_all().unwrap();
+
+    ext.execute_with(|| {
+        Network::on_idle(System::block_number(), Weight::MAX);
+
+        assert_eq!(
+            SubnetNodeReputation::<Test>::iter_prefix(subnet_id).count(),
+            10
+        );
+        assert_eq!(
+            SubnetElectedValidator::<Test>::iter_prefix(subnet_id).count(),
+            keys as usize
+        );
+        assert!(CleanupCursor::<Test>::get().is_some());
+        assert_eq!(CleanupQueueHead::<Test>::get(), 0);
+    });
+    ext.commit_all().unwrap();
+
+    ext.execute_with(|| {
+        Network::on_idle(System::block_number(), Weight::MAX);
+
+        assert_eq!(
+            SubnetNodeReputation::<Test>::iter_prefix(subnet_id).count(),
+            0
+        );
+        assert_eq!(
+            SubnetElectedValidator::<Test>::iter_prefix(subnet_id).count(),
+            10
+        );
+        assert!(CleanupCursor::<Test>::get().is_some());
+        assert_eq!(CleanupQueueHead::<Test>::get(), 1);
+    });
+    ext.commit_all().unwrap();
+
+    ext.execute_with(|| {
+        Network::on_idle(System::block_number(), Weight::MAX);
+
+        assert_eq!(
+            SubnetElectedValidator::<Test>::iter_prefix(subnet_id).count(),
+            0
+        );
+        assert_eq!(CleanupCursor::<Test>::get(), None);
+        assert_eq!(CleanupQueueHead::<Test>::get(), 2);
+        assert_eq!(CleanupQueueTail::<Test>::get(), 2);
+    });
+}
+
+#[test]
+fn test_overwatch_epoch_enqueues_old_commits_and_reveals() {
+    new_test_ext().execute_with(|| {
+        let overwatch_epoch_length =
+            EpochLength::get() * OverwatchEpochLengthMultiplier::<Test>::get();
+
+        for overwatch_epoch in 0..3 {
+            OverwatchCommits::<Test>::insert(
+                (overwatch_epoch, 1, 1),
+                sp_core::H256::repeat_byte(1),
+            );
+            OverwatchReveals::<Test>::insert((overwatch_epoch, 1, 1), 1);
+        }
+
+        // Overwatch epoch 3 calculates epoch 2, epoch 1 is no longer used
+        let block = overwatch_epoch_length * 3 + 1;
+        System::set_block_number(block);
+        Network::on_initialize(block);
+
+        assert_eq!(
+            queued_tasks(),
+            vec![
+                CleanupTask::OverwatchCommits(1),
+                CleanupTask::OverwatchReveals(1)
+            ]
+        );
+
+        Network::on_idle(block, Weight::MAX);
+
+        for overwatch_epoch in 0..3 {
+            assert_eq!(
+                OverwatchCommits::<Test>::contains_key((overwatch_epoch, 1, 1)),
+                overwatch_epoch != 1
+            );
+            assert_eq!(
+                OverwatchReveals::<Test>::contains_key((overwatch_epoch, 1, 1)),
+                overwatch_epoch != 1
+            );
+        }
+    });
+}
@@ -0,0 +1,236 @@
+use super::mock::*;
+use crate::tests::test_utils::*;
+use crate::{
+    ConsensusArchiveDepth, ConsensusArchivePruneEpoch, ConsensusData, ConsensusSubmissionArchive,
+    ConsensusSubmissionData, FinalSubnetEmissionWeights, MinSubnetMinStake,
+    SubnetConsensusSubmission, SubnetElectedValidator, SubnetName, SubnetRemovalReason,
+};
+use frame_support::traits::Hooks;
+use frame_support::weights::{Weight, WeightMeter};
+
+//
+//
+//
+//
+//
+//
+//
+// Consensus archive
+//
+//
+//
+//
+//
+//
+//
+
+fn insert_consensus_epochs(subnet_id: u32, epochs: core::ops::Range<u32>) {
+    for epoch in epochs {
+        SubnetConsensusSubmission::<Test>::insert(subnet_id, epoch, ConsensusData::default());
+        SubnetElectedValidator::<Test>::insert(subnet_id, epoch, epoch);
+        ConsensusSubmissionArchive::<Test>::insert(
+            subnet_id,
+            epoch,
+            ConsensusSubmissionData {
+                validator_subnet_node_id: epoch,
+                ..ConsensusSubmissionData::default()
+            },
+        );
+    }
+}
+
+#[test]
+fn test_emission_step_archives_consensus_submission() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet(subnet_name.clone(), 0, 4, deposit_amount, stake_amount);
+
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        set_block_to_subnet_slot_epoch(Network::get_current_epoch_as_u32(), subnet_id);
+        let subnet_epoch = Network::get_current_subnet_epoch_as_u32(subnet_id);
+        Network::elect_validator(subnet_id, subnet_epoch, System::block_number());
+
+        run_subnet_consensus_step(subnet_id, None, None);
+
+        let submission = SubnetConsensusSubmission::<Test>::get(subnet_id, subnet_epoch).unwrap();
+
+        // Not archived until finalized
+        assert_eq!(
+            ConsensusSubmissionArchive::<Test>::get(subnet_id, subnet_epoch),
+            None
+        );
+        assert_eq!(
+            Network::get_consensus_submission(subnet_id, subnet_epoch),
+            None
+        );
+
+        increase_epochs(1);
+        set_block_to_subnet_slot_epoch(Network::get_current_epoch_as_u32(), subnet_id);
+
+        Network::handle_subnet_emission_weights(Network::get_current_epoch_as_u32());
+        assert!(
+            FinalSubnetEmissionWeights::<Test>::get(Network::get_current_epoch_as_u32())
+                .weights
+                .get(&subnet_id)
+                .is_some()
+        );
+
+        Network::emission_step(
+            &mut WeightMeter::new(),
+            System::block_number(),
+            Network::get_current_epoch_as_u32(),
+            Network::get_current_subnet_epoch_as_u32(subnet_id),
+            subnet_id,
+        );
+
+        let archived = ConsensusSubmissionArchive::<Test>::get(subnet_id, subnet_epoch).unwrap();
+        assert_eq!(archived.validator_subnet_node_id, submission.validator_id);
+        assert_eq!(archived.data, submission.data);
+        assert_eq!(archived.attests, submission.attests);
+        assert_eq!(archived.data_length, submission.data.len() as u32);
+        assert!(archived.attestation_ratio > 0);
+        assert_eq!(
+            ConsensusArchivePruneEpoch::<Test>::get(subnet_id),
+            Some(subnet_epoch)
+        );
+
+        let info = Network::get_consensus_submission(subnet_id, subnet_epoch).unwrap();
+        assert_eq!(info.subnet_id, subnet_id);
+        assert_eq!(info.epoch, subnet_epoch);
+        assert_eq!(info.submission, archived);
+
+        let history = Network::get_consensus_history(subnet_id, 0, subnet_epoch + 10);
+        assert_eq!(history, vec![info]);
+    });
+}
+
+#[test]
+fn test_get_consensus_history() {
+    new_test_ext().execute_with(|| {
+        let subnet_id = 1;
+        insert_consensus_epochs(subnet_id, 0..10);
+        ConsensusSubmissionArchive::<Test>::remove(subnet_id, 3);
+
+        // Epochs without a submission are skipped
+        let history = Network::get_consensus_history(subnet_id, 2, 5);
+        assert_eq!(
+            history.iter().map(|info| info.epoch).collect::<Vec<u32>>(),
+            vec![2, 4, 5]
+        );
+        assert_eq!(history[0].submission.validator_subnet_node_id, 2);
+
+        // Capped at the archive depth
+        ConsensusArchiveDepth::<Test>::set(4);
+        let history = Network::get_consensus_history(subnet_id, 4, u32::MAX);
+        assert_eq!(
+            history.iter().map(|info| info.epoch).collect::<Vec<u32>>(),
+            vec![4, 5, 6, 7]
+        );
+
+        assert_eq!(Network::get_consensus_history(subnet_id, 5, 4), vec![]);
+    });
+}
+
+#[test]
+fn test_on_idle_prunes_consensus_archive() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet(subnet_name.clone(), 0, 4, deposit_amount, stake_amount);
+
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        increase_epochs(10);
+        let subnet_epoch = Network::get_current_subnet_epoch_as_u32(subnet_id);
+        assert!(subnet_epoch > 5);
+
+        let depth = 3;
+        ConsensusArchiveDepth::<Test>::set(depth);
+
+        insert_consensus_epochs(subnet_id, 1..subnet_epoch + 1);
+        ConsensusArchivePruneEpoch::<Test>::insert(subnet_id, 1);
+
+        Network::on_idle(System::block_number(), Weight::MAX);
+
+        let cutoff = subnet_epoch - depth;
+        for epoch in 1..subnet_epoch + 1 {
+            assert_eq!(
+                SubnetConsensusSubmission::<Test>::contains_key(subnet_id, epoch),
+                epoch >= cutoff
+            );
+            assert_eq!(
+                ConsensusSubmissionArchive::<Test>::contains_key(subnet_id, epoch),
+                epoch >= cutoff
+            );
+            assert_eq!(
+                SubnetElectedValidator::<Test>::contains_key(subnet_id, epoch),
+                epoch >= cutoff
+            );
+        }
+        assert_eq!(
+            ConsensusArchivePruneEpoch::<Test>::get(subnet_id),
+            Some(cutoff)
+        );
+
+        // Nothing else to prune until the next subnet epoch
+        Network::on_idle(System::block_number(), Weight::MAX);
+        assert!(SubnetConsensusSubmission::<Test>::contains_key(
+            subnet_id, cutoff
+        ));
+
+        increase_epochs(1);
+        Network::on_idle(System::block_number(), Weight::MAX);
+        assert!(!SubnetConsensusSubmission::<Test>::contains_key(
+            subnet_id, cutoff
+        ));
+        assert!(!ConsensusSubmissionArchive::<Test>::contains_key(
+            subnet_id, cutoff
+        ));
+        assert_eq!(
+            ConsensusArchivePruneEpoch::<Test>::get(subnet_id),
+            Some(cutoff + 1)
+        );
+    });
+}
+
+#[test]
+fn test_remove_subnet_clears_consensus_archive() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet(subnet_name.clone(), 0, 4, deposit_amount, stake_amount);
+
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        insert_consensus_epochs(subnet_id, 0..10);
+        ConsensusArchivePruneEpoch::<Test>::insert(subnet_id, 0);
+
+        Network::do_remove_subnet(subnet_id, SubnetRemovalReason::Owner);
+
+        // No longer pruned by epoch, queued for cleanup instead
+        assert_eq!(ConsensusArchivePruneEpoch::<Test>::get(subnet_id), None);
+        assert_eq!(
+            SubnetConsensusSubmission::<Test>::iter_prefix(subnet_id).count(),
+            10
+        );
+
+        drain_cleanup_queue();
+
+        assert_eq!(
+            SubnetConsensusSubmission::<Test>::iter_prefix(subnet_id).count(),
+            0
+        );
+        assert_eq!(
+            ConsensusSubmissionArchive::<Test>::iter_prefix(subnet_id).count(),
+            0
+        );
+    });
+}
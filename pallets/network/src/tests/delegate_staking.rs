@@ -366,7 +366,7 @@ fn check_balances() {
             // reset everything
             let _ = AccountSubnetDelegateStakeShares::<Test>::remove(user.clone(), subnet_id);
             let _ = TotalSubnetDelegateStakeShares::<Test>::remove(subnet_id);
-            let _ = TotalSubnetDelegateStakeBalance::<Test>::remove(subnet_id);
+            set_delegate_stake(subnet_id, 0);
 
             let USER_INITIAL_TOKENS: u128 = 10_u128.pow(n);
             let USER_INITIAL_BALANCE: u128 = USER_INITIAL_TOKENS + 500;
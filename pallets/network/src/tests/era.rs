@@ -1,8 +1,12 @@
 use super::mock::*;
 use crate::tests::test_utils::*;
 use crate::Event;
-use crate::SubnetSlot;
+use crate::{
+    ElectionMode, SubnetElectedValidator, SubnetElectionMode, SubnetNodeElectionSlots,
+    SubnetNodeIdHotkey, SubnetNodeReputation, SubnetSlot, TotalSubnetElectableNodes,
+};
 use frame_support::{assert_err, assert_ok};
+use sp_std::collections::btree_map::BTreeMap;
 
 #[test]
 fn test_get_current_subnet_epoch_as_u32() {
@@ -46,3 +50,106 @@ fn test_get_current_subnet_epoch_as_u32() {
         assert_eq!(Network::get_current_subnet_epoch_as_u32(subnet_id), 3);
     })
 }
+
+fn insert_election_slots(subnet_id: u32, weights: &[(u32, u128)]) {
+    SubnetNodeElectionSlots::<Test>::insert(
+        subnet_id,
+        weights.iter().map(|(id, _)| *id).collect::<Vec<u32>>(),
+    );
+    TotalSubnetElectableNodes::<Test>::insert(subnet_id, weights.len() as u32);
+    for (subnet_node_id, weight) in weights {
+        let hotkey = account(*subnet_node_id);
+        SubnetNodeIdHotkey::<Test>::insert(subnet_id, subnet_node_id, &hotkey);
+        Network::increase_account_stake(&hotkey, subnet_id, *weight);
+        SubnetNodeReputation::<Test>::insert(subnet_id, subnet_node_id, weight);
+    }
+}
+
+/// Elects a validator for `epochs` epochs and returns how often each node was elected
+fn election_counts(subnet_id: u32, epochs: u32) -> BTreeMap<u32, u32> {
+    let mut counts: BTreeMap<u32, u32> = BTreeMap::new();
+    for subnet_epoch in 0..epochs {
+        Network::elect_validator(subnet_id, subnet_epoch, subnet_epoch);
+        let validator = SubnetElectedValidator::<Test>::get(subnet_id, subnet_epoch).unwrap();
+        *counts.entry(validator).or_insert(0) += 1;
+    }
+    counts
+}
+
+#[test]
+fn test_elect_validator_uniform() {
+    new_test_ext().execute_with(|| {
+        let subnet_id = 1;
+        insert_election_slots(subnet_id, &[(1, 1), (2, 0), (3, 0)]);
+
+        for subnet_epoch in 0..20 {
+            Network::elect_validator(subnet_id, subnet_epoch, subnet_epoch);
+            let idx = Network::get_random_number(subnet_epoch, 3);
+            assert_eq!(
+                SubnetElectedValidator::<Test>::get(subnet_id, subnet_epoch),
+                Some(idx + 1)
+            );
+        }
+
+        // Weights are ignored
+        assert_eq!(election_counts(subnet_id, 200).len(), 3);
+    });
+}
+
+#[test]
+fn test_elect_validator_stake_weighted() {
+    new_test_ext().execute_with(|| {
+        let subnet_id = 1;
+        SubnetElectionMode::<Test>::insert(subnet_id, ElectionMode::StakeWeighted);
+
+        // Nodes without stake are never elected
+        insert_election_slots(subnet_id, &[(1, 0), (2, 1000), (3, 0)]);
+        let counts = election_counts(subnet_id, 50);
+        assert_eq!(counts.get(&2), Some(&50));
+
+        // Node 1 has 3x the stake of node 2
+        let subnet_id = 2;
+        SubnetElectionMode::<Test>::insert(subnet_id, ElectionMode::StakeWeighted);
+        insert_election_slots(subnet_id, &[(1, 3000), (2, 1000)]);
+        let counts = election_counts(subnet_id, 400);
+        let node_1 = *counts.get(&1).unwrap();
+        let node_2 = *counts.get(&2).unwrap();
+        assert!(node_1 > 2 * node_2);
+        assert!(node_1 < 4 * node_2);
+    });
+}
+
+#[test]
+fn test_elect_validator_reputation_weighted() {
+    new_test_ext().execute_with(|| {
+        let subnet_id = 1;
+        SubnetElectionMode::<Test>::insert(subnet_id, ElectionMode::ReputationWeighted);
+
+        insert_election_slots(subnet_id, &[(1, 0), (2, 0), (3, 500)]);
+        // Stake doesn't matter
+        Network::increase_account_stake(&account(1), subnet_id, 1_000_000);
+
+        let counts = election_counts(subnet_id, 50);
+        assert_eq!(counts.get(&3), Some(&50));
+    });
+}
+
+#[test]
+fn test_elect_validator_weighted_falls_back_to_uniform() {
+    new_test_ext().execute_with(|| {
+        let subnet_id = 1;
+        SubnetElectionMode::<Test>::insert(subnet_id, ElectionMode::StakeWeighted);
+
+        // No node has any stake
+        insert_election_slots(subnet_id, &[(1, 0), (2, 0), (3, 0)]);
+
+        for subnet_epoch in 0..20 {
+            Network::elect_validator(subnet_id, subnet_epoch, subnet_epoch);
+            let idx = Network::get_random_number(subnet_epoch, 3);
+            assert_eq!(
+                SubnetElectedValidator::<Test>::get(subnet_id, subnet_epoch),
+                Some(idx + 1)
+            );
+        }
+    });
+}
@@ -0,0 +1,131 @@
+use super::mock::*;
+use crate::tests::test_utils::*;
+use crate::{
+    AccountSubnetStake, GenesisConfig, GenesisSubnet, GenesisSubnetNode, MaxSubnets,
+    MinSubnetMinStake, MinSubnetNodes, MinSubnetRegistrationEpochs, OverwatchEpochLengthMultiplier,
+    SubnetName, SubnetOwner, SubnetState, SubnetsData, TotalActiveSubnetNodes, TotalActiveSubnets,
+    TotalSubnetDelegateStakeBalance,
+};
+use frame_support::traits::{BuildGenesisConfig, Currency};
+
+//
+//
+//
+//
+//
+//
+//
+// Genesis
+//
+//
+//
+//
+//
+//
+//
+
+fn genesis_subnet(
+    subnet_name: Vec<u8>,
+    nodes: u32,
+    delegate_stake: u128,
+) -> GenesisSubnet<AccountId> {
+    let owner = account(0);
+    let stake = MinSubnetMinStake::<Test>::get();
+    let _ = Balances::deposit_creating(&owner, delegate_stake + ALICE_EXPECTED_BALANCE);
+
+    GenesisSubnet {
+        name: subnet_name.clone(),
+        repo: subnet_name,
+        description: Vec::new(),
+        owner: owner.clone(),
+        min_stake: stake,
+        max_stake: stake * 10,
+        delegate_stake_percentage: 100000000000000000,
+        bootnodes: vec![b"p2p/127.0.0.1/33130".to_vec()],
+        nodes: (1..=nodes)
+            .map(|n| {
+                let coldkey = account(n);
+                let _ = Balances::deposit_creating(&coldkey, stake + ALICE_EXPECTED_BALANCE);
+                GenesisSubnetNode {
+                    coldkey,
+                    hotkey: account(n + 1000),
+                    peer_id: peer(n * 3),
+                    bootnode_peer_id: peer(n * 3 + 1),
+                    client_peer_id: peer(n * 3 + 2),
+                    stake,
+                }
+            })
+            .collect(),
+        delegate_stakes: vec![(owner, delegate_stake)],
+    }
+}
+
+#[test]
+fn test_genesis_build_sets_parameters() {
+    new_test_ext().execute_with(|| {
+        let max_subnets = MaxSubnets::<Test>::get();
+
+        GenesisConfig::<Test> {
+            min_subnet_registration_epochs: Some(0),
+            overwatch_epoch_length_multiplier: Some(1),
+            ..Default::default()
+        }
+        .build();
+
+        assert_eq!(MinSubnetRegistrationEpochs::<Test>::get(), 0);
+        assert_eq!(OverwatchEpochLengthMultiplier::<Test>::get(), 1);
+
+        // Unset parameters keep their defaults
+        assert_eq!(MaxSubnets::<Test>::get(), max_subnets);
+    });
+}
+
+#[test]
+fn test_genesis_build_activates_subnets() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        let nodes = MinSubnetNodes::<Test>::get();
+        let delegate_stake = 100000000000000000000000;
+
+        GenesisConfig::<Test> {
+            min_subnet_registration_epochs: Some(0),
+            subnets: vec![genesis_subnet(subnet_name.clone(), nodes, delegate_stake)],
+            ..Default::default()
+        }
+        .build();
+
+        let subnet_id = SubnetName::<Test>::get(subnet_name).unwrap();
+        let subnet = SubnetsData::<Test>::get(subnet_id).unwrap();
+        assert_eq!(subnet.state, SubnetState::Active);
+        assert_eq!(TotalActiveSubnets::<Test>::get(), 1);
+        assert_eq!(SubnetOwner::<Test>::get(subnet_id), Some(account(0)));
+        assert_eq!(TotalActiveSubnetNodes::<Test>::get(subnet_id), nodes);
+        assert_eq!(
+            AccountSubnetStake::<Test>::get(account(1001), subnet_id),
+            MinSubnetMinStake::<Test>::get()
+        );
+        assert_eq!(
+            TotalSubnetDelegateStakeBalance::<Test>::get(subnet_id),
+            delegate_stake
+        );
+    });
+}
+
+#[test]
+#[should_panic(expected = "genesis subnet failed to activate")]
+fn test_genesis_build_panics_if_subnet_cannot_activate() {
+    new_test_ext().execute_with(|| {
+        let nodes = MinSubnetNodes::<Test>::get();
+
+        GenesisConfig::<Test> {
+            min_subnet_registration_epochs: Some(1),
+            subnets: vec![genesis_subnet(
+                "subnet-name".into(),
+                nodes,
+                100000000000000000000000,
+            )],
+            ..Default::default()
+        }
+        .build();
+    });
+}
@@ -3,7 +3,7 @@ use crate::tests::test_utils::*;
 use crate::Event;
 use crate::{
     ColdkeyIdentity, ColdkeyIdentityNameOwner, DefaultMaxSocialIdLength, DefaultMaxUrlLength,
-    DefaultMaxVectorLength, Error, HotkeyOwner,
+    DefaultMaxVectorLength, Error,
 };
 use frame_support::{assert_err, assert_ok};
 
@@ -31,7 +31,7 @@ fn test_register_or_update_identity() {
         let coldkey = account(99);
         let hotkey = account(98);
 
-        HotkeyOwner::<Test>::insert(hotkey.clone(), coldkey.clone());
+        set_hotkey_owner(&coldkey, &hotkey);
         let name = to_bounded::<DefaultMaxVectorLength>("name");
         let url = to_bounded::<DefaultMaxUrlLength>("url");
         let image = to_bounded::<DefaultMaxUrlLength>("image");
@@ -92,7 +92,7 @@ fn test_register_or_update_identity_try_stealing_error() {
         let coldkey = account(99);
         let hotkey = account(98);
 
-        HotkeyOwner::<Test>::insert(hotkey.clone(), coldkey.clone());
+        set_hotkey_owner(&coldkey, &hotkey);
         let name = to_bounded::<DefaultMaxVectorLength>("name");
         let url = to_bounded::<DefaultMaxUrlLength>("url");
         let image = to_bounded::<DefaultMaxUrlLength>("image");
@@ -145,7 +145,7 @@ fn test_register_or_update_identity_try_stealing_error() {
         let coldkey = account(199);
         let hotkey = account(198);
 
-        HotkeyOwner::<Test>::insert(hotkey.clone(), coldkey.clone());
+        set_hotkey_owner(&coldkey, &hotkey);
 
         // Try stealing identity from a new key
         assert_err!(
@@ -176,7 +176,7 @@ fn test_register_or_update_identity_update_name() {
         let coldkey = account(99);
         let hotkey = account(98);
 
-        HotkeyOwner::<Test>::insert(hotkey.clone(), coldkey.clone());
+        set_hotkey_owner(&coldkey, &hotkey);
         let name = to_bounded::<DefaultMaxVectorLength>("name");
         let url = to_bounded::<DefaultMaxUrlLength>("url");
         let image = to_bounded::<DefaultMaxUrlLength>("image");
@@ -249,7 +249,7 @@ fn test_register_or_update_identity_not_key_owner_error() {
         let hotkey = account(98);
         let fake_hotkey = account(97);
 
-        HotkeyOwner::<Test>::insert(hotkey.clone(), coldkey.clone());
+        set_hotkey_owner(&coldkey, &hotkey);
         let name = to_bounded::<DefaultMaxVectorLength>("name");
         let url = to_bounded::<DefaultMaxUrlLength>("url");
         let image = to_bounded::<DefaultMaxUrlLength>("image");
@@ -288,7 +288,7 @@ fn test_remove_identity() {
         let coldkey = account(99);
         let hotkey = account(98);
 
-        HotkeyOwner::<Test>::insert(hotkey.clone(), coldkey.clone());
+        set_hotkey_owner(&coldkey, &hotkey);
         let name = to_bounded::<DefaultMaxVectorLength>("name");
         let url = to_bounded::<DefaultMaxUrlLength>("url");
         let image = to_bounded::<DefaultMaxUrlLength>("image");
@@ -352,7 +352,7 @@ fn test_remove_identity_readd() {
         let coldkey = account(99);
         let hotkey = account(98);
 
-        HotkeyOwner::<Test>::insert(hotkey.clone(), coldkey.clone());
+        set_hotkey_owner(&coldkey, &hotkey);
         let name = to_bounded::<DefaultMaxVectorLength>("name");
         let url = to_bounded::<DefaultMaxUrlLength>("url");
         let image = to_bounded::<DefaultMaxUrlLength>("image");
@@ -403,7 +403,7 @@ fn test_remove_identity_readd() {
         let coldkey = account(199);
         let hotkey = account(198);
 
-        HotkeyOwner::<Test>::insert(hotkey.clone(), coldkey.clone());
+        set_hotkey_owner(&coldkey, &hotkey);
 
         assert_ok!(Network::register_or_update_identity(
             RuntimeOrigin::signed(coldkey.clone()),
@@ -428,7 +428,7 @@ fn test_register_identity_empty_fields() {
         let coldkey = account(99);
         let hotkey = account(98);
 
-        HotkeyOwner::<Test>::insert(hotkey.clone(), coldkey.clone());
+        set_hotkey_owner(&coldkey, &hotkey);
 
         let name = to_bounded::<DefaultMaxVectorLength>("name");
         let url = to_bounded::<DefaultMaxUrlLength>("url");
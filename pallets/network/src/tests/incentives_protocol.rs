@@ -17,9 +17,14 @@ use crate::{
     TotalSubnetDelegateStakeBalance, TotalSubnetNodes, ValidatorAbsentSubnetNodeReputationFactor,
     ValidatorAbsentSubnetReputationFactor,
 };
+use crate::{
+    BackupProposerEpochProgression, BackupValidatorRewardFactor, ConsensusDataRejectionReason,
+    SubnetBackupValidator, SubnetNodeConsensusData, SubnetNodeElectionSlots,
+    TotalSubnetElectableNodes,
+};
 use frame_support::traits::Currency;
 use frame_support::weights::WeightMeter;
-use frame_support::{assert_err, assert_ok};
+use frame_support::{assert_err, assert_ok, BoundedVec};
 use sp_std::collections::btree_map::BTreeMap;
 
 //
@@ -143,6 +148,196 @@ fn test_propose_attestation() {
     });
 }
 
+#[test]
+fn test_propose_attestation_duplicate_subnet_node_id_error() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet(subnet_name.clone(), 0, 12, deposit_amount, stake_amount);
+
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        let epoch_length = EpochLength::get();
+        let block_number = System::block_number();
+        let epoch = block_number / epoch_length;
+
+        set_block_to_subnet_slot_epoch(epoch, subnet_id);
+        let subnet_epoch = Network::get_current_subnet_epoch_as_u32(subnet_id);
+
+        Network::elect_validator(subnet_id, subnet_epoch, block_number);
+
+        let validator_id = SubnetElectedValidator::<Test>::get(subnet_id, subnet_epoch).unwrap();
+        let hotkey = SubnetNodeIdHotkey::<Test>::get(subnet_id, validator_id).unwrap();
+
+        // Duplicates aren't adjacent
+        let data = vec![
+            SubnetNodeConsensusData {
+                subnet_node_id: 1,
+                score: DEFAULT_SCORE,
+            },
+            SubnetNodeConsensusData {
+                subnet_node_id: 2,
+                score: DEFAULT_SCORE,
+            },
+            SubnetNodeConsensusData {
+                subnet_node_id: 1,
+                score: DEFAULT_SCORE * 2,
+            },
+        ];
+
+        assert_err!(
+            Network::propose_attestation(
+                RuntimeOrigin::signed(hotkey.clone()),
+                subnet_id,
+                data,
+                None,
+                None,
+                None,
+                None,
+            ),
+            Error::<Test>::DuplicateSubnetNodeId
+        );
+
+        assert_eq!(
+            SubnetConsensusSubmission::<Test>::get(subnet_id, subnet_epoch),
+            None
+        );
+    });
+}
+
+#[test]
+fn test_propose_attestation_consensus_data_too_long_error() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet(subnet_name.clone(), 0, 12, deposit_amount, stake_amount);
+
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        let epoch_length = EpochLength::get();
+        let block_number = System::block_number();
+        let epoch = block_number / epoch_length;
+
+        set_block_to_subnet_slot_epoch(epoch, subnet_id);
+        let subnet_epoch = Network::get_current_subnet_epoch_as_u32(subnet_id);
+
+        Network::elect_validator(subnet_id, subnet_epoch, block_number);
+
+        let validator_id = SubnetElectedValidator::<Test>::get(subnet_id, subnet_epoch).unwrap();
+        let hotkey = SubnetNodeIdHotkey::<Test>::get(subnet_id, validator_id).unwrap();
+
+        let data: Vec<SubnetNodeConsensusData> = (1..=MaxSubnetNodes::<Test>::get() + 1)
+            .map(|subnet_node_id| SubnetNodeConsensusData {
+                subnet_node_id,
+                score: DEFAULT_SCORE,
+            })
+            .collect();
+
+        assert_err!(
+            Network::propose_attestation(
+                RuntimeOrigin::signed(hotkey.clone()),
+                subnet_id,
+                data,
+                None,
+                None,
+                None,
+                None,
+            ),
+            Error::<Test>::ConsensusDataTooLong
+        );
+    });
+}
+
+#[test]
+fn test_propose_attestation_rejected_consensus_data() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        let subnets = TotalActiveSubnets::<Test>::get() + 1;
+        let max_subnet_nodes = MaxSubnetNodes::<Test>::get();
+        let end = 12;
+
+        build_activated_subnet(subnet_name.clone(), 0, end, deposit_amount, stake_amount);
+
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+        let total_subnet_nodes = TotalSubnetNodes::<Test>::get(subnet_id);
+
+        let epoch_length = EpochLength::get();
+        let block_number = System::block_number();
+        let epoch = block_number / epoch_length;
+
+        set_block_to_subnet_slot_epoch(epoch, subnet_id);
+        let subnet_epoch = Network::get_current_subnet_epoch_as_u32(subnet_id);
+
+        Network::elect_validator(subnet_id, subnet_epoch, block_number);
+
+        let validator_id = SubnetElectedValidator::<Test>::get(subnet_id, subnet_epoch).unwrap();
+        let hotkey = SubnetNodeIdHotkey::<Test>::get(subnet_id, validator_id).unwrap();
+
+        // Demote a node that isn't the validator below `Included`
+        let idle_node_id = if validator_id == 1 { 2 } else { 1 };
+        SubnetNodesData::<Test>::mutate(subnet_id, idle_node_id, |subnet_node| {
+            subnet_node.classification.node_class = SubnetNodeClass::Idle;
+        });
+
+        let unknown_node_id = total_subnet_nodes + 100;
+
+        // Unsorted, with an unknown and a non-included node
+        let mut data =
+            get_subnet_node_consensus_data(subnets, max_subnet_nodes, 0, total_subnet_nodes);
+        data.push(SubnetNodeConsensusData {
+            subnet_node_id: unknown_node_id,
+            score: DEFAULT_SCORE,
+        });
+        data.reverse();
+
+        assert_ok!(Network::propose_attestation(
+            RuntimeOrigin::signed(hotkey.clone()),
+            subnet_id,
+            data,
+            None,
+            None,
+            None,
+            None,
+        ));
+
+        let submission = SubnetConsensusSubmission::<Test>::get(subnet_id, subnet_epoch).unwrap();
+
+        // Stored sorted and without the rejected entries
+        let stored_ids: Vec<u32> = submission.data.iter().map(|x| x.subnet_node_id).collect();
+        let expected_ids: Vec<u32> = (1..=total_subnet_nodes)
+            .filter(|id| *id != idle_node_id)
+            .collect();
+        assert_eq!(stored_ids, expected_ids);
+
+        assert!(network_events().contains(&Event::ConsensusDataRejected {
+            subnet_id,
+            epoch: subnet_epoch,
+            rejected: BoundedVec::truncate_from(vec![
+                (idle_node_id, ConsensusDataRejectionReason::NotIncluded),
+                (
+                    unknown_node_id,
+                    ConsensusDataRejectionReason::UnknownSubnetNodeId
+                ),
+            ]),
+        }));
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::ValidatorSubmission {
+                subnet_id,
+                account_id: hotkey.clone(),
+                epoch: subnet_epoch,
+            }
+        );
+    });
+}
+
 #[test]
 fn test_validator_absent_propose_attestation_decrease_reputation() {
     new_test_ext().execute_with(|| {
@@ -5333,6 +5528,173 @@ fn test_get_validator_reward_multiplier() {
     });
 }
 
+#[test]
+fn test_get_backup_validator_reward_multiplier() {
+    new_test_ext().execute_with(|| {
+        let percentage_factor = Network::percentage_factor_as_u128();
+        let window_start = BackupProposerEpochProgression::<Test>::get();
+        let max_factor = BackupValidatorRewardFactor::<Test>::get();
+
+        // Curve restarts at the start of the backup window
+        assert_eq!(
+            Network::get_backup_validator_reward_multiplier(window_start),
+            Network::percent_mul(Network::get_validator_reward_multiplier(0), max_factor)
+        );
+
+        let mut last = u128::MAX;
+        for step in 0..=10u128 {
+            let progress = window_start + (percentage_factor - window_start) * step / 10;
+            let factor = Network::get_backup_validator_reward_multiplier(progress);
+            assert!(factor <= max_factor);
+            assert!(factor <= last);
+            last = factor;
+        }
+    });
+}
+
+#[test]
+fn test_elect_validator_elects_backup_validator() {
+    new_test_ext().execute_with(|| {
+        let subnet_id = 1;
+
+        // A single slot has no backup
+        SubnetNodeElectionSlots::<Test>::insert(subnet_id, vec![1]);
+        TotalSubnetElectableNodes::<Test>::insert(subnet_id, 1);
+        Network::elect_validator(subnet_id, 0, 0);
+        assert_eq!(SubnetElectedValidator::<Test>::get(subnet_id, 0), Some(1));
+        assert_eq!(SubnetBackupValidator::<Test>::get(subnet_id, 0), None);
+
+        SubnetNodeElectionSlots::<Test>::insert(subnet_id, vec![1, 2, 3, 4]);
+        TotalSubnetElectableNodes::<Test>::insert(subnet_id, 4);
+        for subnet_epoch in 1..20 {
+            Network::elect_validator(subnet_id, subnet_epoch, subnet_epoch);
+            let validator_id = SubnetElectedValidator::<Test>::get(subnet_id, subnet_epoch);
+            let backup_id = SubnetBackupValidator::<Test>::get(subnet_id, subnet_epoch);
+            assert!(validator_id.is_some());
+            assert!(backup_id.is_some());
+            assert_ne!(validator_id, backup_id);
+        }
+    });
+}
+
+#[test]
+fn test_backup_validator_propose_attestation() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        let subnets = TotalActiveSubnets::<Test>::get() + 1;
+        let max_subnet_nodes = MaxSubnetNodes::<Test>::get();
+        let end = 12;
+
+        build_activated_subnet(subnet_name.clone(), 0, end, deposit_amount, stake_amount);
+
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+        let total_subnet_nodes = TotalSubnetNodes::<Test>::get(subnet_id);
+
+        ValidatorAbsentSubnetReputationFactor::<Test>::set(50000000000000000);
+        ValidatorAbsentSubnetNodeReputationFactor::<Test>::insert(subnet_id, 50000000000000000);
+
+        let epoch_length = EpochLength::get();
+        let block_number = System::block_number();
+        let epoch = block_number / epoch_length;
+
+        set_block_to_subnet_slot_epoch(epoch, subnet_id);
+        let subnet_epoch = Network::get_current_subnet_epoch_as_u32(subnet_id);
+
+        Network::elect_validator(subnet_id, subnet_epoch, System::block_number());
+
+        let validator_id = SubnetElectedValidator::<Test>::get(subnet_id, subnet_epoch).unwrap();
+        let backup_id = SubnetBackupValidator::<Test>::get(subnet_id, subnet_epoch).unwrap();
+        assert_ne!(validator_id, backup_id);
+        let backup_hotkey = SubnetNodeIdHotkey::<Test>::get(subnet_id, backup_id).unwrap();
+
+        let subnet_node_data_vec =
+            get_subnet_node_consensus_data(subnets, max_subnet_nodes, 0, total_subnet_nodes);
+
+        // Backup window isn't open yet
+        assert_err!(
+            Network::propose_attestation(
+                RuntimeOrigin::signed(backup_hotkey.clone()),
+                subnet_id,
+                subnet_node_data_vec.clone(),
+                None,
+                None,
+                None,
+                None,
+            ),
+            Error::<Test>::InvalidValidator
+        );
+
+        // 60% through the subnet epoch
+        System::set_block_number(System::block_number() + epoch_length * 6 / 10);
+        assert_eq!(
+            Network::get_current_subnet_epoch_as_u32(subnet_id),
+            subnet_epoch
+        );
+        let progress = Network::get_current_subnet_epoch_data(subnet_id)
+            .unwrap()
+            .subnet_epoch_progression;
+        assert!(progress >= BackupProposerEpochProgression::<Test>::get());
+
+        assert_ok!(Network::propose_attestation(
+            RuntimeOrigin::signed(backup_hotkey.clone()),
+            subnet_id,
+            subnet_node_data_vec.clone(),
+            None,
+            None,
+            None,
+            None,
+        ));
+
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::BackupValidatorSubmission {
+                subnet_id,
+                account_id: backup_hotkey.clone(),
+                epoch: subnet_epoch,
+            }
+        );
+
+        let submission = SubnetConsensusSubmission::<Test>::get(subnet_id, subnet_epoch).unwrap();
+        assert_eq!(submission.validator_id, backup_id);
+        assert!(submission.attests.contains_key(&backup_id));
+        assert_eq!(
+            submission.validator_reward_factor,
+            Network::get_backup_validator_reward_multiplier(progress)
+        );
+        assert!(submission.validator_reward_factor <= BackupValidatorRewardFactor::<Test>::get());
+
+        let starting_subnet_rep = SubnetReputation::<Test>::get(subnet_id);
+        let starting_validator_rep = SubnetNodeReputation::<Test>::get(subnet_id, validator_id);
+        let starting_backup_rep = SubnetNodeReputation::<Test>::get(subnet_id, backup_id);
+
+        increase_epochs(1);
+
+        let (result, _) = Network::precheck_subnet_consensus_submission(
+            subnet_id,
+            subnet_epoch,
+            Network::get_current_epoch_as_u32(),
+        );
+
+        // The subnet still has its consensus data, only the absent validator is penalized
+        let result = result.unwrap();
+        assert_eq!(result.validator_subnet_node_id, backup_id);
+        assert_eq!(
+            SubnetReputation::<Test>::get(subnet_id),
+            starting_subnet_rep
+        );
+        assert!(
+            starting_validator_rep > SubnetNodeReputation::<Test>::get(subnet_id, validator_id)
+        );
+        assert_eq!(
+            SubnetNodeReputation::<Test>::get(subnet_id, backup_id),
+            starting_backup_rep
+        );
+    });
+}
+
 #[test]
 fn test_emergency_validator_subnet_rewards() {
     new_test_ext().execute_with(|| {
@@ -121,11 +121,15 @@ use crate::{InflationSigmoidMidpoint, InflationSigmoidSteepness};
 fn test_get_inflation_v2() {
     new_test_ext().execute_with(|| {
         let inflation = InflationV2::default();
-        let mut last_emissions = f64::MAX;
+        let mut last_emissions = u128::MAX;
+        let percentage_factor = Network::percentage_factor_as_u128();
 
-        for u in &[0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0] {
-            let emissions = Network::get_inflation_v2(*u);
+        for step in 0..=10u128 {
+            let u = percentage_factor * step / 10;
+            let emissions = Network::get_inflation_v2(u);
             assert!(emissions < last_emissions);
+            assert!(emissions <= inflation.initial_max);
+            assert!(emissions >= inflation.initial_min);
             last_emissions = emissions;
         }
     });
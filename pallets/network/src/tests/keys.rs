@@ -137,6 +137,7 @@ fn test_update_coldkey() {
             RuntimeOrigin::signed(coldkey.clone()),
             hotkey.clone(),
             new_coldkey.clone(), // new_coldkey
+            2,                   // subnet node and overwatch node hotkeys
         ));
 
         assert_eq!(
@@ -259,7 +260,7 @@ fn test_update_coldkey() {
                 RuntimeOrigin::signed(coldkey.clone()),
                 hotkey.clone(),
                 new_coldkey.clone(), // new_coldkey
-                                     // None,
+                2,
             ),
             Error::<Test>::NotKeyOwner
         );
@@ -312,6 +313,7 @@ fn test_update_coldkey() {
             RuntimeOrigin::signed(new_coldkey.clone()),
             new_hotkey.clone(),
             new_coldkey_2.clone(), // new_coldkey
+            2,
         ));
 
         assert_err!(
@@ -319,6 +321,7 @@ fn test_update_coldkey() {
                 RuntimeOrigin::signed(new_coldkey.clone()),
                 new_hotkey.clone(),
                 new_coldkey_2.clone(), // new_coldkey
+                2,
             ),
             Error::<Test>::NotKeyOwner
         );
@@ -356,13 +359,87 @@ fn test_update_coldkey_key_taken_err() {
                 RuntimeOrigin::signed(account(account_n)),
                 account(2),
                 account(account_n),
-                // None,
+                1,
             ),
             Error::<Test>::NotKeyOwner
         );
     });
 }
 
+#[test]
+fn test_update_coldkey_moves_every_hotkey() {
+    new_test_ext().execute_with(|| {
+        let coldkey = account(1);
+        let new_coldkey = account(4);
+
+        insert_overwatch_node(1, 2);
+        insert_overwatch_node(1, 3);
+
+        assert_err!(
+            Network::update_coldkey(
+                RuntimeOrigin::signed(coldkey.clone()),
+                account(2),
+                new_coldkey.clone(),
+                1,
+            ),
+            Error::<Test>::HotkeysWitnessTooLow
+        );
+
+        assert_ok!(Network::update_coldkey(
+            RuntimeOrigin::signed(coldkey.clone()),
+            account(2),
+            new_coldkey.clone(),
+            2,
+        ));
+
+        assert_eq!(ColdkeyHotkeys::<Test>::get(&coldkey).len(), 0);
+        assert_eq!(ColdkeyHotkeys::<Test>::get(&new_coldkey).len(), 2);
+        assert_eq!(HotkeyOwner::<Test>::get(account(2)), new_coldkey);
+        assert_eq!(HotkeyOwner::<Test>::get(account(3)), new_coldkey);
+    });
+}
+
+#[test]
+fn test_update_coldkey_to_used_coldkey_err() {
+    new_test_ext().execute_with(|| {
+        let coldkey = account(1);
+        let victim = account(4);
+        let victim_unbonding = account(6);
+
+        insert_overwatch_node(1, 2);
+        insert_overwatch_node(4, 5);
+        StakeUnbondingLedger::<Test>::insert(&victim_unbonding, BTreeMap::from([(1, 1000)]));
+
+        // Passing a used coldkey can't take over its hotkeys or unbondings
+        for new_coldkey in [victim.clone(), victim_unbonding.clone()] {
+            assert_err!(
+                Network::update_coldkey(
+                    RuntimeOrigin::signed(coldkey.clone()),
+                    account(2),
+                    new_coldkey,
+                    2,
+                ),
+                Error::<Test>::ColdkeyInUse
+            );
+        }
+
+        assert_eq!(HotkeyOwner::<Test>::get(account(2)), coldkey);
+        assert_eq!(HotkeyOwner::<Test>::get(account(5)), victim);
+        assert_eq!(ColdkeyHotkeys::<Test>::get(&victim).len(), 1);
+        assert_eq!(
+            StakeUnbondingLedger::<Test>::get(&victim_unbonding),
+            BTreeMap::from([(1, 1000)])
+        );
+        assert_eq!(StakeUnbondingLedger::<Test>::get(&coldkey).len(), 0);
+
+        // The victim's hotkey stake can't be removed by the caller
+        assert_err!(
+            Network::remove_stake(RuntimeOrigin::signed(coldkey.clone()), 1, account(5), 1000),
+            Error::<Test>::NotKeyOwner
+        );
+    });
+}
+
 #[test]
 fn test_update_hotkey() {
     new_test_ext().execute_with(|| {
@@ -1,5 +1,6 @@
 use super::mock::*;
 use crate::tests::test_utils::*;
+use crate::AttestorMinRewardFactor;
 use sp_core::U256;
 use sp_std::collections::btree_map::BTreeMap;
 
@@ -397,3 +398,272 @@ fn test_concave_down_decreasing_monotonicity() {
         );
     });
 }
+
+//
+// Fixed-point
+//
+
+/// Parse a 1e18 fixed-point value into f64 for comparison against the reference curves
+fn fixed_as_f64(v: U256) -> f64 {
+    v.to_string().parse::<f64>().unwrap() / Network::percentage_factor_as_f64()
+}
+
+fn f64_as_fixed(v: f64) -> U256 {
+    U256::from(Network::get_f64_as_percentage(v))
+}
+
+#[test]
+fn test_fixed_mul_div() {
+    new_test_ext().execute_with(|| {
+        let one = Network::PERCENTAGE_FACTOR;
+        let half = Network::HALF_PERCENT;
+
+        assert_eq!(Network::fixed_mul(one, one), one);
+        assert_eq!(Network::fixed_mul(half, half), one / 4);
+        assert_eq!(Network::fixed_div(half, one), half);
+        assert_eq!(Network::fixed_div(one, half), one * 2);
+
+        // Division by zero returns zero (by design)
+        assert_eq!(Network::fixed_div(one, U256::zero()), U256::zero());
+
+        // Saturates instead of overflowing
+        assert_eq!(Network::fixed_mul(U256::MAX, U256::MAX), U256::MAX);
+    });
+}
+
+#[test]
+fn test_fixed_exp_matches_f64() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Network::fixed_exp(U256::zero()), Network::PERCENTAGE_FACTOR);
+
+        for i in 0..2000 {
+            let x = i as f64 * 0.04;
+            let expected = x.exp();
+            let result = fixed_as_f64(Network::fixed_exp(f64_as_fixed(x)));
+            let rel_err = ((result - expected) / expected).abs();
+            assert!(
+                rel_err < 1e-14,
+                "exp({}) = {}, expected {}",
+                x,
+                result,
+                expected
+            );
+        }
+
+        // Saturates past U256
+        assert_eq!(Network::fixed_exp(f64_as_fixed(1000.0)), U256::MAX);
+        assert_eq!(Network::fixed_exp_neg(f64_as_fixed(1000.0)), U256::zero());
+    });
+}
+
+#[test]
+fn test_fixed_exp_neg_matches_f64() {
+    new_test_ext().execute_with(|| {
+        for i in 0..2000 {
+            let x = i as f64 * 0.02;
+            let expected = (-x).exp();
+            let result = fixed_as_f64(Network::fixed_exp_neg(f64_as_fixed(x)));
+            assert!(
+                (result - expected).abs() < 1e-14,
+                "exp(-{}) = {}, expected {}",
+                x,
+                result,
+                expected
+            );
+        }
+    });
+}
+
+#[test]
+fn test_fixed_ln_matches_f64() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Network::fixed_ln(Network::PERCENTAGE_FACTOR), U256::zero());
+
+        for i in 1..5000 {
+            let x = 1.0 + i as f64 * 0.73;
+            let expected = x.ln();
+            let result = fixed_as_f64(Network::fixed_ln(f64_as_fixed(x)));
+            assert!(
+                (result - expected).abs() < 1e-14,
+                "ln({}) = {}, expected {}",
+                x,
+                result,
+                expected
+            );
+        }
+    });
+}
+
+#[test]
+fn test_fixed_pow_matches_f64() {
+    new_test_ext().execute_with(|| {
+        // Percentages, x <= 1.0
+        for i in 0..=100 {
+            for j in 0..=40 {
+                let x = i as f64 / 100.0;
+                let y = j as f64 * 0.25;
+                let expected = Network::pow(x, y);
+                let result = fixed_as_f64(Network::fixed_pow(f64_as_fixed(x), f64_as_fixed(y)));
+                assert!(
+                    (result - expected).abs() < 1e-14,
+                    "{}^{} = {}, expected {}",
+                    x,
+                    y,
+                    result,
+                    expected
+                );
+            }
+        }
+
+        // Balances, x > 1.0, as used in overwatch stake weights
+        for stake in [1.0, 100.0, 1_000.0, 1_000_000.0, 1_000_000_000.0] {
+            for y in [0.5, 0.75, 0.9, 1.0, 1.25] {
+                let stake_balance = (stake * 1e18) as u128;
+                let expected = Network::pow(stake_balance as f64, y);
+                let result = fixed_as_f64(Network::fixed_pow(
+                    U256::from(stake_balance) * Network::PERCENTAGE_FACTOR,
+                    f64_as_fixed(y),
+                ));
+                let rel_err = ((result - expected) / expected).abs();
+                assert!(
+                    rel_err < 1e-14,
+                    "{}^{} = {}, expected {}",
+                    stake_balance,
+                    y,
+                    result,
+                    expected
+                );
+            }
+        }
+    });
+}
+
+#[test]
+fn test_fixed_sigmoid_decreasing_matches_f64() {
+    new_test_ext().execute_with(|| {
+        let pf = Network::percentage_factor_as_u128();
+
+        for k in [0.01, 1.0, 5.0, 7.0, 20.0, 50.0, 200.0] {
+            for mid in [0.25, 0.5, 0.8] {
+                for i in 0..=100 {
+                    let x = i as f64 / 100.0;
+                    let expected = Network::sigmoid_decreasing(x, mid, k, 0.0, 1.0);
+                    let result = Network::get_percent_as_f64(Network::fixed_sigmoid_decreasing(
+                        Network::get_f64_as_percentage(x),
+                        Network::get_f64_as_percentage(mid),
+                        Network::get_f64_as_percentage(k),
+                        0,
+                        pf,
+                    ));
+                    assert!(
+                        (result - expected).abs() < 1e-14,
+                        "sigmoid({}, {}, {}) = {}, expected {}",
+                        x,
+                        mid,
+                        k,
+                        result,
+                        expected
+                    );
+
+                    let expected_v2 = Network::sigmoid_decreasing_v2(x, mid, k);
+                    let result_v2 =
+                        Network::get_percent_as_f64(Network::fixed_sigmoid_decreasing_v2(
+                            Network::get_f64_as_percentage(x),
+                            Network::get_f64_as_percentage(mid),
+                            Network::get_f64_as_percentage(k),
+                        ));
+                    assert!((result_v2 - expected_v2).abs() < 1e-14);
+                }
+            }
+        }
+    });
+}
+
+#[test]
+fn test_fixed_sigmoid_decreasing_bounds_and_monotonicity() {
+    new_test_ext().execute_with(|| {
+        let pf = Network::percentage_factor_as_u128();
+        let min = pf / 10;
+        let max = pf * 9 / 10;
+        let mid = pf / 2;
+        let k = 20 * pf;
+
+        let mut last = u128::MAX;
+        for i in 0..=100u128 {
+            let y = Network::fixed_sigmoid_decreasing(pf * i / 100, mid, k, min, max);
+            assert!(y >= min && y <= max);
+            assert!(y <= last);
+            last = y;
+        }
+
+        // Symmetric around mid
+        let y_left = Network::fixed_sigmoid_decreasing_v2(pf / 4, mid, 5 * pf);
+        let y_right = Network::fixed_sigmoid_decreasing_v2(pf * 3 / 4, mid, 5 * pf);
+        assert!((y_left + y_right).abs_diff(pf) <= 2);
+    });
+}
+
+#[test]
+fn test_fixed_concave_down_decreasing_matches_f64() {
+    new_test_ext().execute_with(|| {
+        let pf = Network::percentage_factor_as_u128();
+
+        for power in [0.5, 1.0, 2.0, 10.0] {
+            for i in 0..=100 {
+                let x = i as f64 / 100.0;
+                let expected = Network::concave_down_decreasing(x, 0.33, 1.0, power);
+                let result = Network::get_percent_as_f64(Network::fixed_concave_down_decreasing(
+                    Network::get_f64_as_percentage(x),
+                    Network::get_f64_as_percentage(0.33),
+                    pf,
+                    Network::get_f64_as_percentage(power),
+                ));
+                assert!(
+                    (result - expected).abs() < 1e-14,
+                    "concave({}, {}) = {}, expected {}",
+                    x,
+                    power,
+                    result,
+                    expected
+                );
+            }
+        }
+
+        // zero power -> should default to 1.0
+        assert_eq!(
+            Network::fixed_concave_down_decreasing(pf / 2, 0, pf, 0),
+            pf / 2
+        );
+    });
+}
+
+#[test]
+fn test_fixed_point_reward_curves_are_deterministic() {
+    new_test_ext().execute_with(|| {
+        let pf = Network::percentage_factor_as_u128();
+
+        // Start of the epoch pays out in full, end of the epoch pays the floor
+        assert_eq!(Network::get_attestor_reward_multiplier(0), pf);
+        assert_eq!(
+            Network::get_attestor_reward_multiplier(pf),
+            AttestorMinRewardFactor::<Test>::get()
+        );
+
+        let mut last_validator = u128::MAX;
+        let mut last_attestor = u128::MAX;
+        for i in 0..=100u128 {
+            let progress = pf * i / 100;
+            let validator = Network::get_validator_reward_multiplier(progress);
+            let attestor = Network::get_attestor_reward_multiplier(progress);
+            assert!(validator <= pf && validator <= last_validator);
+            assert!(attestor <= pf && attestor <= last_attestor);
+            // Same inputs always produce the same output
+            assert_eq!(
+                validator,
+                Network::get_validator_reward_multiplier(progress)
+            );
+            last_validator = validator;
+            last_attestor = attestor;
+        }
+    });
+}
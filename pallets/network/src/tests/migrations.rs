@@ -0,0 +1,314 @@
+use super::mock::*;
+use crate::migrations::v1::{bootnode_peer_id, MigrateV0ToV1};
+use crate::migrations::v2::MigrateV1ToV2;
+use crate::tests::test_utils::*;
+use crate::{
+    AccountSwapQueueIds, DefaultMaxVectorLength, MaxBootnodes, QueuedSwapCall, QueuedSwapItem,
+    SubnetBootnodes, SubnetBootnodesV2, SwapCallQueue,
+};
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+use frame_support::{assert_ok, BoundedVec};
+use sp_core::OpaquePeerId as PeerId;
+use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
+
+//
+//
+//
+//
+//
+//
+//
+// Migrations
+//
+//
+//
+//
+//
+//
+//
+
+fn bootnode(peer_id: &PeerId) -> BoundedVec<u8, DefaultMaxVectorLength> {
+    let mut bootnode = b"/ip4/127.0.0.1/tcp/30333/p2p/".to_vec();
+    bootnode.extend_from_slice(&peer_id.0);
+    BoundedVec::try_from(bootnode).unwrap()
+}
+
+fn malformed_bootnode() -> BoundedVec<u8, DefaultMaxVectorLength> {
+    BoundedVec::try_from(b"p2p/127.0.0.1/33130".to_vec()).unwrap()
+}
+
+#[test]
+fn test_bootnode_peer_id() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            bootnode_peer_id::<Test>(&bootnode(&ed25519_peer(1))),
+            Some(ed25519_peer(1))
+        );
+
+        let mut legacy = b"/dns/bootnode.example/tcp/30333/ipfs/".to_vec();
+        legacy.extend_from_slice(&peer(1).0);
+        assert_eq!(bootnode_peer_id::<Test>(&legacy), Some(peer(1)));
+
+        assert_eq!(bootnode_peer_id::<Test>(&malformed_bootnode()), None);
+        assert_eq!(
+            bootnode_peer_id::<Test>(b"/ip4/127.0.0.1/tcp/30333/p2p/"),
+            None
+        );
+        assert_eq!(
+            bootnode_peer_id::<Test>(b"/ip4/127.0.0.1/tcp/30333/p2p/not-a-peer-id"),
+            None
+        );
+    });
+}
+
+#[test]
+fn test_migrate_v0_to_v1_backfills_bootnodes_v2() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<Network>();
+
+        let bootnodes_1: BTreeSet<_> = BTreeSet::from([
+            bootnode(&ed25519_peer(1)),
+            bootnode(&ed25519_peer(2)),
+            malformed_bootnode(),
+        ]);
+        let bootnodes_2: BTreeSet<_> = BTreeSet::from([bootnode(&peer(3))]);
+        SubnetBootnodes::<Test>::insert(1, bootnodes_1.clone());
+        SubnetBootnodes::<Test>::insert(2, bootnodes_2.clone());
+
+        MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(Network::on_chain_storage_version(), StorageVersion::new(1));
+
+        let bootnodes_v2 = SubnetBootnodesV2::<Test>::get(1);
+        assert_eq!(bootnodes_v2.len(), 2);
+        assert_eq!(
+            bootnodes_v2.get(&ed25519_peer(1)),
+            Some(&bootnode(&ed25519_peer(1)))
+        );
+        assert_eq!(
+            bootnodes_v2.get(&ed25519_peer(2)),
+            Some(&bootnode(&ed25519_peer(2)))
+        );
+
+        let bootnodes_v2 = SubnetBootnodesV2::<Test>::get(2);
+        assert_eq!(bootnodes_v2.len(), 1);
+        assert_eq!(bootnodes_v2.get(&peer(3)), Some(&bootnode(&peer(3))));
+
+        // V1 is left as is
+        assert_eq!(SubnetBootnodes::<Test>::get(1), bootnodes_1);
+        assert_eq!(SubnetBootnodes::<Test>::get(2), bootnodes_2);
+    });
+}
+
+#[test]
+fn test_migrate_v0_to_v1_keeps_existing_bootnodes_v2_and_max_bootnodes() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<Network>();
+        MaxBootnodes::<Test>::put(2);
+
+        let existing = BoundedVec::try_from(b"existing".to_vec()).unwrap();
+        SubnetBootnodesV2::<Test>::insert(1, BTreeMap::from([(ed25519_peer(1), existing.clone())]));
+        SubnetBootnodes::<Test>::insert(
+            1,
+            BTreeSet::from([
+                bootnode(&ed25519_peer(1)),
+                bootnode(&ed25519_peer(2)),
+                bootnode(&ed25519_peer(3)),
+            ]),
+        );
+
+        MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        let bootnodes_v2 = SubnetBootnodesV2::<Test>::get(1);
+        assert_eq!(bootnodes_v2.len(), 2);
+        assert_eq!(bootnodes_v2.get(&ed25519_peer(1)), Some(&existing));
+        assert!(
+            bootnodes_v2.contains_key(&ed25519_peer(2))
+                || bootnodes_v2.contains_key(&ed25519_peer(3))
+        );
+    });
+}
+
+#[test]
+fn test_migrate_v0_to_v1_only_runs_once() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<Network>();
+        SubnetBootnodes::<Test>::insert(1, BTreeSet::from([bootnode(&ed25519_peer(1))]));
+
+        MigrateV0ToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(SubnetBootnodesV2::<Test>::get(1).len(), 1);
+
+        SubnetBootnodes::<Test>::insert(
+            1,
+            BTreeSet::from([bootnode(&ed25519_peer(1)), bootnode(&ed25519_peer(2))]),
+        );
+        MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(SubnetBootnodesV2::<Test>::get(1).len(), 1);
+        assert_eq!(Network::on_chain_storage_version(), StorageVersion::new(1));
+    });
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn test_migrate_subnet_bootnodes_to_v2_try_runtime() {
+    use crate::migrations::v1::MigrateSubnetBootnodesToV2;
+    use frame_support::traits::UncheckedOnRuntimeUpgrade;
+
+    new_test_ext().execute_with(|| {
+        SubnetBootnodes::<Test>::insert(
+            1,
+            BTreeSet::from([bootnode(&ed25519_peer(1)), malformed_bootnode()]),
+        );
+
+        let state = MigrateSubnetBootnodesToV2::<Test>::pre_upgrade().unwrap();
+        MigrateSubnetBootnodesToV2::<Test>::on_runtime_upgrade();
+        assert_ok!(MigrateSubnetBootnodesToV2::<Test>::post_upgrade(
+            state.clone()
+        ));
+
+        // Dropping a migrated bootnode fails the post-upgrade checks
+        SubnetBootnodesV2::<Test>::remove(1);
+        assert!(MigrateSubnetBootnodesToV2::<Test>::post_upgrade(state).is_err());
+    });
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn test_migrate_subnet_bootnodes_to_v2_try_runtime_max_bootnodes() {
+    use crate::migrations::v1::MigrateSubnetBootnodesToV2;
+    use frame_support::traits::UncheckedOnRuntimeUpgrade;
+
+    new_test_ext().execute_with(|| {
+        MaxBootnodes::<Test>::put(2);
+
+        let existing = BoundedVec::try_from(b"existing".to_vec()).unwrap();
+        SubnetBootnodesV2::<Test>::insert(1, BTreeMap::from([(ed25519_peer(1), existing.clone())]));
+        SubnetBootnodes::<Test>::insert(
+            1,
+            BTreeSet::from([
+                bootnode(&ed25519_peer(1)),
+                bootnode(&ed25519_peer(2)),
+                bootnode(&ed25519_peer(3)),
+            ]),
+        );
+
+        let state = MigrateSubnetBootnodesToV2::<Test>::pre_upgrade().unwrap();
+        MigrateSubnetBootnodesToV2::<Test>::on_runtime_upgrade();
+        assert_ok!(MigrateSubnetBootnodesToV2::<Test>::post_upgrade(
+            state.clone()
+        ));
+
+        // Dropping the truncated entry fails the post-upgrade checks
+        let bootnodes_v2 = SubnetBootnodesV2::<Test>::get(1);
+        SubnetBootnodesV2::<Test>::insert(1, BTreeMap::from([(ed25519_peer(1), existing.clone())]));
+        assert!(MigrateSubnetBootnodesToV2::<Test>::post_upgrade(state.clone()).is_err());
+
+        // So does replacing an existing entry
+        let mut replaced = bootnodes_v2.clone();
+        replaced.insert(ed25519_peer(1), bootnode(&ed25519_peer(1)));
+        SubnetBootnodesV2::<Test>::insert(1, replaced);
+        assert!(MigrateSubnetBootnodesToV2::<Test>::post_upgrade(state).is_err());
+    });
+}
+
+/// Queue item as written before `AccountSwapQueueIds`, the ID is left out of it
+fn insert_untracked_swap(id: u32, account_n: u32) {
+    SwapCallQueue::<Test>::insert(
+        id,
+        QueuedSwapItem {
+            id,
+            call: QueuedSwapCall::SwapToSubnetDelegateStake {
+                account_id: account(account_n),
+                to_subnet_id: 1,
+                balance: 1000,
+            },
+            queued_at_block: 0,
+            execute_after_blocks: 0,
+        },
+    );
+}
+
+#[test]
+fn test_migrate_v1_to_v2_backfills_account_swap_queue_ids() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(1).put::<Network>();
+
+        insert_untracked_swap(0, 1);
+        insert_untracked_swap(1, 1);
+        insert_untracked_swap(2, 2);
+        AccountSwapQueueIds::<Test>::insert(account(1), BTreeSet::from([1]));
+        AccountSwapQueueIds::<Test>::insert(account(3), BTreeSet::from([7]));
+
+        MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+        assert_eq!(Network::on_chain_storage_version(), StorageVersion::new(2));
+
+        assert_eq!(
+            AccountSwapQueueIds::<Test>::get(account(1)),
+            BTreeSet::from([0, 1])
+        );
+        assert_eq!(
+            AccountSwapQueueIds::<Test>::get(account(2)),
+            BTreeSet::from([2])
+        );
+        // Existing IDs are kept
+        assert_eq!(
+            AccountSwapQueueIds::<Test>::get(account(3)),
+            BTreeSet::from([7])
+        );
+
+        // Swaps queued before the upgrade can be cancelled
+        assert_ok!(Network::cancel_swap_queue_item(
+            RuntimeOrigin::signed(account(2)),
+            2,
+        ));
+        assert!(SwapCallQueue::<Test>::get(2).is_none());
+        assert!(AccountSwapQueueIds::<Test>::get(account(2)).is_empty());
+    });
+}
+
+#[test]
+fn test_migrate_v1_to_v2_only_runs_from_v1() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<Network>();
+        insert_untracked_swap(0, 1);
+
+        MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+        assert!(AccountSwapQueueIds::<Test>::get(account(1)).is_empty());
+        assert_eq!(Network::on_chain_storage_version(), StorageVersion::new(0));
+
+        // Both steps run in order from version 0
+        <(MigrateV0ToV1<Test>, MigrateV1ToV2<Test>) as OnRuntimeUpgrade>::on_runtime_upgrade();
+
+        assert_eq!(
+            AccountSwapQueueIds::<Test>::get(account(1)),
+            BTreeSet::from([0])
+        );
+        assert_eq!(Network::on_chain_storage_version(), StorageVersion::new(2));
+    });
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn test_migrate_account_swap_queue_ids_try_runtime() {
+    use crate::migrations::v2::MigrateAccountSwapQueueIds;
+    use frame_support::traits::UncheckedOnRuntimeUpgrade;
+
+    new_test_ext().execute_with(|| {
+        insert_untracked_swap(0, 1);
+        insert_untracked_swap(1, 2);
+        AccountSwapQueueIds::<Test>::insert(account(3), BTreeSet::from([7]));
+
+        let state = MigrateAccountSwapQueueIds::<Test>::pre_upgrade().unwrap();
+        MigrateAccountSwapQueueIds::<Test>::on_runtime_upgrade();
+        assert_ok!(MigrateAccountSwapQueueIds::<Test>::post_upgrade(
+            state.clone()
+        ));
+
+        // A queued swap missing from its account fails the post-upgrade checks
+        AccountSwapQueueIds::<Test>::remove(account(2));
+        assert!(MigrateAccountSwapQueueIds::<Test>::post_upgrade(state).is_err());
+    });
+}
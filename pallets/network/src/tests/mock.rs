@@ -41,7 +41,7 @@ frame_support::construct_runtime!(
     pub enum Test
     {
     System: system,
-    InsecureRandomnessCollectiveFlip: pallet_insecure_randomness_collective_flip,
+    RandomnessBeacon: pallet_randomness_beacon,
     Balances: pallet_balances,
     Network: pallet_network,
     Collective: pallet_collective::<Instance1>,
@@ -117,7 +117,18 @@ pub type Balance = u128;
 
 pub const EXISTENTIAL_DEPOSIT: u128 = 500;
 
-impl pallet_insecure_randomness_collective_flip::Config for Test {}
+pub const RANDOMNESS_ROUND_LENGTH: u32 = 10;
+
+impl pallet_randomness_beacon::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type CommitDeposit = ConstU128<1_000_000_000_000_000_000>;
+    type RevealReward = ConstU128<1_000_000_000_000_000>;
+    type MinReveals = ConstU32<2>;
+    type RoundLength = ConstU32<RANDOMNESS_ROUND_LENGTH>;
+    type MaxCommitsPerRound = ConstU32<64>;
+    type WeightInfo = ();
+}
 
 #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
 impl pallet_balances::Config for Test {
@@ -214,6 +225,8 @@ parameter_types! {
     pub MaximumHooksWeight: Weight = Perbill::from_percent(50) *
         BlockWeights::get().max_block;
     pub const DesignatedEpochSlots: u32 = 3;
+    pub const PeerIdProofDeposit: u128 = 1_000_000_000_000_000_000;
+    pub const ReportHooksWeight: bool = true;
 }
 
 impl Config for Test {
@@ -227,19 +240,52 @@ impl Config for Test {
     type EpochLength = EpochLength;
     type EpochsPerYear = EpochsPerYear;
     type InitialTxRateLimit = ConstU32<0>;
-    type Randomness = InsecureRandomnessCollectiveFlip;
+    type Randomness = RandomnessBeacon;
     type PalletId = NetworkPalletId;
     type TreasuryAccount = TreasuryAccount;
     type OverwatchEpochEmissions = OverwatchEpochEmissions;
     type MaximumHooksWeight = MaximumHooksWeight;
     type DesignatedEpochSlots = DesignatedEpochSlots;
+    type PeerIdProofDeposit = PeerIdProofDeposit;
+    type ReportHooksWeight = ReportHooksWeight;
+}
+
+/// Test externalities checking `Network::do_try_state` at the end of every `execute_with`
+pub fn new_test_ext() -> TestExternalities {
+    TestExternalities {
+        ext: frame_system::GenesisConfig::<Test>::default()
+            .build_storage()
+            .unwrap()
+            .into(),
+    }
+}
+
+pub struct TestExternalities {
+    ext: sp_io::TestExternalities,
 }
 
-pub fn new_test_ext() -> sp_io::TestExternalities {
-    frame_system::GenesisConfig::<Test>::default()
-        .build_storage()
-        .unwrap()
-        .into()
+impl TestExternalities {
+    pub fn execute_with<R>(&mut self, execute: impl FnOnce() -> R) -> R {
+        self.ext.execute_with(|| {
+            let result = execute();
+            Network::do_try_state().expect("network invariants broken");
+            result
+        })
+    }
+}
+
+impl core::ops::Deref for TestExternalities {
+    type Target = sp_io::TestExternalities;
+
+    fn deref(&self) -> &Self::Target {
+        &self.ext
+    }
+}
+
+impl core::ops::DerefMut for TestExternalities {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.ext
+    }
 }
 
 pub(crate) fn network_events() -> Vec<crate::Event<Test>> {
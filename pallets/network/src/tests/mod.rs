@@ -1,20 +1,27 @@
 mod admin;
+mod check_network_call;
+mod cleanup;
+mod consensus_archive;
 mod delegate_staking;
 mod era;
+mod genesis;
 mod identity;
 mod incentives_protocol;
 mod inflation;
 mod keys;
 mod math;
+mod migrations;
 pub mod mock;
 mod node_delegate_staking;
 mod on_initialize;
 mod overwatch_nodes;
 mod overwatch_nodes_commit_reveal;
 mod owner;
+mod peer_id;
 mod randomization;
 mod registration_queue;
 mod reputation;
+mod reward_history;
 mod rpc;
 mod slot;
 mod staking;
@@ -25,5 +32,7 @@ mod subnet;
 mod subnet_cost;
 mod subnet_node;
 mod test_utils;
+mod try_state;
 mod unbonding;
+mod weights;
 // mod emissions_stake_weight;
@@ -9,6 +9,7 @@ use crate::{
 };
 use frame_support::assert_ok;
 use frame_support::traits::OnInitialize;
+use frame_support::weights::Weight;
 use sp_std::collections::btree_map::BTreeMap;
 
 //
@@ -453,3 +454,22 @@ fn test_on_initialize_verify_overwatch_weights() {
 fn test_on_initialize_verify_unpause_queue() {
     new_test_ext().execute_with(|| {});
 }
+
+#[test]
+fn test_on_initialize_reports_consumed_weight() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let amount: u128 = 100000000000000000000;
+        let end = MinSubnetNodes::<Test>::get();
+
+        build_activated_subnet(subnet_name.clone(), 0, end, deposit_amount, amount);
+
+        // Epoch preliminaries run on the first block of each epoch
+        let block = (Network::get_current_epoch_as_u32() + 1) * EpochLength::get();
+        System::set_block_number(block);
+
+        let weight = Network::on_initialize(block);
+        assert_ne!(weight, Weight::zero());
+    });
+}
@@ -1,13 +1,13 @@
 use super::mock::*;
 use crate::tests::test_utils::*;
 use crate::{
-    Error, HotkeyOverwatchNodeId, HotkeyOwner, MinSubnetMinStake, MinSubnetNodes, OverwatchCommit,
-    OverwatchCommits, OverwatchNode, OverwatchNodeBlacklist, OverwatchNodeIdHotkey, OverwatchNodes,
-    OverwatchReveal, OverwatchReveals, SubnetData, SubnetName, SubnetState, SubnetsData,
-    TotalOverwatchNodeUids,
+    Error, Event, HotkeyOverwatchNodeId, MaxSubnets, MinSubnetMinStake, MinSubnetNodes,
+    OverwatchCommit, OverwatchCommitRejectionReason, OverwatchCommits, OverwatchNode,
+    OverwatchNodeBlacklist, OverwatchNodeIdHotkey, OverwatchNodes, OverwatchReveal,
+    OverwatchReveals, SubnetData, SubnetName, SubnetState, SubnetsData, TotalOverwatchNodeUids,
 };
 use frame_support::traits::Currency;
-use frame_support::{assert_err, assert_ok};
+use frame_support::{assert_err, assert_ok, BoundedVec};
 
 //
 //
@@ -57,7 +57,7 @@ fn test_do_commit_and_reveal_weights_success() {
         };
 
         OverwatchNodes::<Test>::insert(overwatch_node_id, overwatch_node);
-        HotkeyOwner::<Test>::insert(hotkey.clone(), coldkey.clone());
+        set_hotkey_owner(&coldkey, &hotkey);
         OverwatchNodeIdHotkey::<Test>::insert(current_uid, hotkey.clone());
 
         // Weight + salt
@@ -128,7 +128,7 @@ fn test_do_commit_and_reveal_weights_not_key_owner_error() {
         };
 
         OverwatchNodes::<Test>::insert(overwatch_node_id, overwatch_node);
-        HotkeyOwner::<Test>::insert(hotkey.clone(), coldkey.clone());
+        set_hotkey_owner(&coldkey, &hotkey);
         OverwatchNodeIdHotkey::<Test>::insert(current_uid, hotkey.clone());
 
         // Weight + salt
@@ -183,7 +183,7 @@ fn test_do_commit_and_reveal_weights_blacklisted_error() {
         };
 
         OverwatchNodes::<Test>::insert(overwatch_node_id, overwatch_node);
-        HotkeyOwner::<Test>::insert(hotkey.clone(), coldkey.clone());
+        set_hotkey_owner(&coldkey, &hotkey);
         OverwatchNodeIdHotkey::<Test>::insert(current_uid, hotkey.clone());
 
         // Weight + salt
@@ -240,7 +240,7 @@ fn test_do_commit_and_reveal_weights_commits_empty_error() {
         };
 
         OverwatchNodes::<Test>::insert(overwatch_node_id, overwatch_node);
-        HotkeyOwner::<Test>::insert(hotkey.clone(), coldkey.clone());
+        set_hotkey_owner(&coldkey, &hotkey);
         OverwatchNodeIdHotkey::<Test>::insert(current_uid, hotkey.clone());
 
         // Weight + salt
@@ -260,6 +260,167 @@ fn test_do_commit_and_reveal_weights_commits_empty_error() {
     });
 }
 
+#[test]
+fn test_perform_commit_overwatch_subnet_weights_duplicate_subnet_id_error() {
+    new_test_ext().execute_with(|| {
+        let overwatch_node_id = 1;
+        let overwatch_epoch = Network::get_current_overwatch_epoch_as_u32();
+
+        for subnet_id in 1..=2 {
+            let subnet_data = SubnetData {
+                id: subnet_id,
+                friendly_id: subnet_id,
+                name: "subnet_name".into(),
+                repo: "github".into(),
+                description: "description".into(),
+                misc: "misc".into(),
+                state: SubnetState::Active,
+                start_epoch: 0,
+            };
+            SubnetsData::<Test>::insert(subnet_id, subnet_data);
+        }
+
+        let commit_hash = make_commit(123456, b"secret-salt".to_vec());
+
+        // Duplicates aren't adjacent
+        assert_err!(
+            Network::perform_commit_overwatch_subnet_weights(
+                overwatch_node_id,
+                vec![
+                    OverwatchCommit {
+                        subnet_id: 1,
+                        weight: commit_hash
+                    },
+                    OverwatchCommit {
+                        subnet_id: 2,
+                        weight: commit_hash
+                    },
+                    OverwatchCommit {
+                        subnet_id: 1,
+                        weight: commit_hash
+                    },
+                ]
+            ),
+            Error::<Test>::DuplicateSubnetId
+        );
+
+        // Unsorted commits are accepted
+        assert_ok!(Network::perform_commit_overwatch_subnet_weights(
+            overwatch_node_id,
+            vec![
+                OverwatchCommit {
+                    subnet_id: 2,
+                    weight: commit_hash
+                },
+                OverwatchCommit {
+                    subnet_id: 1,
+                    weight: commit_hash
+                },
+            ]
+        ));
+        for subnet_id in 1..=2 {
+            assert_eq!(
+                OverwatchCommits::<Test>::get((overwatch_epoch, overwatch_node_id, subnet_id)),
+                Some(commit_hash)
+            );
+        }
+    });
+}
+
+#[test]
+fn test_perform_commit_overwatch_subnet_weights_rejected_subnet_ids() {
+    new_test_ext().execute_with(|| {
+        let overwatch_node_id = 1;
+        let overwatch_epoch = Network::get_current_overwatch_epoch_as_u32();
+        let active_subnet_id = 1;
+        let registered_subnet_id = 2;
+        let unknown_subnet_id = 3;
+
+        for (subnet_id, state) in [
+            (active_subnet_id, SubnetState::Active),
+            (registered_subnet_id, SubnetState::Registered),
+        ] {
+            let subnet_data = SubnetData {
+                id: subnet_id,
+                friendly_id: subnet_id,
+                name: "subnet_name".into(),
+                repo: "github".into(),
+                description: "description".into(),
+                misc: "misc".into(),
+                state,
+                start_epoch: 0,
+            };
+            SubnetsData::<Test>::insert(subnet_id, subnet_data);
+        }
+
+        let commit_hash = make_commit(123456, b"secret-salt".to_vec());
+
+        assert_ok!(Network::perform_commit_overwatch_subnet_weights(
+            overwatch_node_id,
+            vec![
+                OverwatchCommit {
+                    subnet_id: unknown_subnet_id,
+                    weight: commit_hash
+                },
+                OverwatchCommit {
+                    subnet_id: active_subnet_id,
+                    weight: commit_hash
+                },
+                OverwatchCommit {
+                    subnet_id: registered_subnet_id,
+                    weight: commit_hash
+                },
+            ]
+        ));
+
+        assert_eq!(
+            OverwatchCommits::<Test>::get((overwatch_epoch, overwatch_node_id, active_subnet_id)),
+            Some(commit_hash)
+        );
+        for subnet_id in [registered_subnet_id, unknown_subnet_id] {
+            assert_eq!(
+                OverwatchCommits::<Test>::get((overwatch_epoch, overwatch_node_id, subnet_id)),
+                None
+            );
+        }
+
+        assert!(network_events().contains(&Event::OverwatchCommitsRejected {
+            overwatch_node_id,
+            epoch: overwatch_epoch,
+            rejected: BoundedVec::truncate_from(vec![
+                (
+                    registered_subnet_id,
+                    OverwatchCommitRejectionReason::SubnetNotActive
+                ),
+                (
+                    unknown_subnet_id,
+                    OverwatchCommitRejectionReason::UnknownSubnetId
+                ),
+            ]),
+        }));
+    });
+}
+
+#[test]
+fn test_perform_commit_overwatch_subnet_weights_too_long_error() {
+    new_test_ext().execute_with(|| {
+        let overwatch_node_id = 1;
+        let commit_hash = make_commit(123456, b"secret-salt".to_vec());
+
+        let commits: Vec<OverwatchCommit<_>> = (1..=MaxSubnets::<Test>::get() + 1)
+            .map(|subnet_id| OverwatchCommit {
+                subnet_id,
+                weight: commit_hash,
+            })
+            .collect();
+
+        assert_err!(
+            Network::perform_commit_overwatch_subnet_weights(overwatch_node_id, commits),
+            Error::<Test>::OverwatchCommitsTooLong
+        );
+    });
+}
+
 #[test]
 fn test_do_commit_and_reveal_weights_already_committed_error() {
     new_test_ext().execute_with(|| {
@@ -292,7 +453,7 @@ fn test_do_commit_and_reveal_weights_already_committed_error() {
         };
 
         OverwatchNodes::<Test>::insert(overwatch_node_id, overwatch_node);
-        HotkeyOwner::<Test>::insert(hotkey.clone(), coldkey.clone());
+        set_hotkey_owner(&coldkey, &hotkey);
         OverwatchNodeIdHotkey::<Test>::insert(current_uid, hotkey.clone());
 
         // Weight + salt
@@ -23,6 +23,7 @@ use crate::{
     TargetNodeRegistrationsPerEpoch, ValidatorAbsentSubnetNodeReputationFactor,
     ValidatorNonConsensusSubnetNodeReputationFactor,
 };
+use crate::{ElectionMode, SubnetElectionMode, SubnetPeerIdProofRequired};
 use codec::Decode;
 use frame_support::{assert_err, assert_ok};
 use sp_runtime::traits::TrailingZeroInput;
@@ -1970,6 +1971,88 @@ fn test_owner_update_key_types() {
     });
 }
 
+#[test]
+fn test_owner_update_election_mode() {
+    new_test_ext().execute_with(|| {
+        let subnet_id = 1;
+        let original_owner = account(1);
+
+        SubnetOwner::<Test>::insert(subnet_id, &original_owner);
+
+        assert_eq!(
+            SubnetElectionMode::<Test>::get(subnet_id),
+            ElectionMode::Uniform
+        );
+
+        assert_err!(
+            Network::owner_update_election_mode(
+                RuntimeOrigin::signed(account(2)),
+                subnet_id,
+                ElectionMode::StakeWeighted
+            ),
+            Error::<Test>::NotSubnetOwner
+        );
+
+        assert_ok!(Network::owner_update_election_mode(
+            RuntimeOrigin::signed(original_owner.clone()),
+            subnet_id,
+            ElectionMode::StakeWeighted
+        ));
+
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SubnetElectionModeUpdate {
+                subnet_id: subnet_id,
+                owner: original_owner.clone(),
+                value: ElectionMode::StakeWeighted,
+            }
+        );
+
+        assert_eq!(
+            SubnetElectionMode::<Test>::get(subnet_id),
+            ElectionMode::StakeWeighted
+        );
+    });
+}
+
+#[test]
+fn test_owner_update_peer_id_proof_required() {
+    new_test_ext().execute_with(|| {
+        let subnet_id = 1;
+        let original_owner = account(1);
+
+        SubnetOwner::<Test>::insert(subnet_id, &original_owner);
+
+        assert!(!SubnetPeerIdProofRequired::<Test>::get(subnet_id));
+
+        assert_err!(
+            Network::owner_update_peer_id_proof_required(
+                RuntimeOrigin::signed(account(2)),
+                subnet_id,
+                true
+            ),
+            Error::<Test>::NotSubnetOwner
+        );
+
+        assert_ok!(Network::owner_update_peer_id_proof_required(
+            RuntimeOrigin::signed(original_owner.clone()),
+            subnet_id,
+            true
+        ));
+
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SubnetPeerIdProofRequiredUpdate {
+                subnet_id: subnet_id,
+                owner: original_owner.clone(),
+                value: true,
+            }
+        );
+
+        assert!(SubnetPeerIdProofRequired::<Test>::get(subnet_id));
+    });
+}
+
 #[test]
 fn test_owner_remove_subnet_node() {
     new_test_ext().execute_with(|| {
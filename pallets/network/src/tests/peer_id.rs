@@ -0,0 +1,1134 @@
+use super::mock::*;
+use crate::tests::test_utils::*;
+use crate::utilities::peer_id::PeerIdKey;
+use crate::utilities::peer_id_proof::PEER_ID_PROOF_VALIDITY_BLOCKS;
+use crate::Event;
+use crate::{
+    DefaultMaxVectorLength, Error, HotkeySubnetNodeId, KeyType, MaxSubnetNodes, MaxSubnets,
+    MinSubnetMinStake, PeerId, PeerIdProof, PeerIdProofPruneBlock, PeerIdProofs,
+    PeerIdProofsBySignedBlock, SubnetKeyTypes, SubnetName, SubnetNodesData,
+    SubnetPeerIdProofRequired, TotalActiveSubnets,
+};
+use frame_support::traits::{Currency, Get, Hooks, ReservableCurrency};
+use frame_support::weights::{Weight, WeightMeter};
+use frame_support::{assert_err, assert_ok, BoundedVec};
+use sp_core::{ecdsa, ed25519, Pair};
+use sp_std::collections::btree_set::BTreeSet;
+
+fn key_types(types: &[KeyType]) -> BTreeSet<KeyType> {
+    types.iter().cloned().collect()
+}
+
+/// Ed25519 peer ID of a known keypair
+fn ed25519_keyed_peer(seed: u8) -> (ed25519::Pair, PeerId) {
+    let pair = ed25519::Pair::from_seed(&[seed; 32]);
+    let mut multihash = vec![0x00, 0x24, 0x08, 0x01, 0x12, 0x20];
+    multihash.extend_from_slice(pair.public().as_ref());
+    (pair, PeerId(Network::encode_base58(&multihash)))
+}
+
+/// Secp256k1 peer ID of a known keypair
+fn secp256k1_keyed_peer(seed: u8) -> (ecdsa::Pair, PeerId) {
+    let pair = ecdsa::Pair::from_seed(&[seed; 32]);
+    let mut multihash = vec![0x00, 0x25, 0x08, 0x02, 0x12, 0x21];
+    multihash.extend_from_slice(pair.public().as_ref());
+    (pair, PeerId(Network::encode_base58(&multihash)))
+}
+
+fn ed25519_proof(
+    pair: &ed25519::Pair,
+    subnet_id: u32,
+    hotkey: &AccountId,
+    block: u32,
+) -> BoundedVec<u8, DefaultMaxVectorLength> {
+    let message = Network::peer_id_proof_message(subnet_id, hotkey, block);
+    pair.sign(&message).as_ref().to_vec().try_into().unwrap()
+}
+
+fn secp256k1_proof(
+    pair: &ecdsa::Pair,
+    subnet_id: u32,
+    hotkey: &AccountId,
+    block: u32,
+) -> BoundedVec<u8, DefaultMaxVectorLength> {
+    let message = Network::peer_id_proof_message(subnet_id, hotkey, block);
+    let message_hash = sp_io::hashing::sha2_256(&message);
+    pair.sign_prehashed(&message_hash)
+        .as_ref()
+        .to_vec()
+        .try_into()
+        .unwrap()
+}
+
+fn secp256k1_der_proof(
+    pair: &ecdsa::Pair,
+    subnet_id: u32,
+    hotkey: &AccountId,
+    block: u32,
+    high_s: bool,
+) -> BoundedVec<u8, DefaultMaxVectorLength> {
+    let signature = secp256k1_proof(pair, subnet_id, hotkey, block);
+    let mut signature = libsecp256k1::Signature::parse_standard_slice(&signature[..64]).unwrap();
+    if high_s {
+        signature.s = -signature.s;
+    }
+    signature
+        .serialize_der()
+        .as_ref()
+        .to_vec()
+        .try_into()
+        .unwrap()
+}
+
+fn peer_id_proof(hotkey: &AccountId, block: u32, depositor: &AccountId) -> PeerIdProof<AccountId> {
+    PeerIdProof {
+        hotkey: hotkey.clone(),
+        block,
+        depositor: depositor.clone(),
+        deposit: PeerIdProofDeposit::get(),
+    }
+}
+
+#[test]
+fn test_decode_peer_id() {
+    new_test_ext().execute_with(|| {
+        // Legacy base58btc multihashes
+        assert_eq!(
+            Network::decode_peer_id(&PeerId(
+                "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx5N".into()
+            )),
+            Some(PeerIdKey::Hashed)
+        );
+        assert_eq!(
+            Network::decode_peer_id(&PeerId(
+                "12D3KooWD3eckifWpRn9wQpMG9R9hX3sD158z7EqHWmweQAJU5SA".into()
+            )),
+            Some(PeerIdKey::Inline(KeyType::Ed25519))
+        );
+
+        // CIDv1 with the libp2p-key codec
+        assert_eq!(
+            Network::decode_peer_id(&PeerId(
+                "bafzbeie5745rpv2m6tjyuugywy4d5ewrqgqqhfnf445he3omzpjbx5xqxe".into()
+            )),
+            Some(PeerIdKey::Hashed)
+        );
+
+        assert_eq!(Network::decode_peer_id(&peer(1)), Some(PeerIdKey::Hashed));
+        assert_eq!(
+            Network::decode_peer_id(&ed25519_peer(1)),
+            Some(PeerIdKey::Inline(KeyType::Ed25519))
+        );
+        assert_eq!(
+            Network::decode_peer_id(&secp256k1_peer(1)),
+            Some(PeerIdKey::Inline(KeyType::Secp256k1))
+        );
+    })
+}
+
+#[test]
+fn test_decode_peer_id_invalid() {
+    new_test_ext().execute_with(|| {
+        // Too short
+        assert_eq!(Network::decode_peer_id(&PeerId("QmYyQSo1c1".into())), None);
+
+        // Not base58
+        assert_eq!(
+            Network::decode_peer_id(&PeerId(
+                "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx5l".into()
+            )),
+            None
+        );
+
+        // Unknown multibase prefix
+        assert_eq!(
+            Network::decode_peer_id(&PeerId(
+                "xafzbeie5745rpv2m6tjyuugywy4d5ewrqgqqhfnf445he3omzpjbx5xqxe".into()
+            )),
+            None
+        );
+
+        // Multihash digest shorter than its length prefix
+        let mut multihash = vec![0x12, 0x21];
+        multihash.extend_from_slice(&[1u8; 32]);
+        assert_eq!(
+            Network::decode_peer_id(&PeerId(Network::encode_base58(&multihash))),
+            None
+        );
+
+        // Ed25519 key of the wrong length
+        let mut multihash = vec![0x00, 0x23, 0x08, 0x01, 0x12, 0x1f];
+        multihash.extend_from_slice(&[1u8; 31]);
+        assert_eq!(
+            Network::decode_peer_id(&PeerId(Network::encode_base58(&multihash))),
+            None
+        );
+    })
+}
+
+#[test]
+fn test_register_subnet_node_peer_id_key_type_not_allowed() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+
+        let deposit_amount: u128 = 10000000000000000000000;
+        let amount: u128 = 1000000000000000000000;
+
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        let subnets = TotalActiveSubnets::<Test>::get() + 1;
+        let max_subnet_nodes = MaxSubnetNodes::<Test>::get();
+        let max_subnets = MaxSubnets::<Test>::get();
+        let end = 4;
+
+        build_activated_subnet(subnet_name.clone(), 0, end, deposit_amount, stake_amount);
+
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        let coldkey = get_coldkey(subnets, max_subnet_nodes, end + 1);
+        let hotkey = get_hotkey(subnets, max_subnet_nodes, max_subnets, end + 1);
+        let burn_amount = Network::calculate_burn_amount(subnet_id);
+        let _ = Balances::deposit_creating(&coldkey.clone(), deposit_amount + burn_amount);
+
+        SubnetKeyTypes::<Test>::insert(subnet_id, key_types(&[KeyType::Ed25519]));
+
+        assert_err!(
+            Network::register_subnet_node(
+                RuntimeOrigin::signed(coldkey.clone()),
+                subnet_id,
+                hotkey.clone(),
+                peer(1000),
+                ed25519_peer(1001),
+                ed25519_peer(1002),
+                None,
+                0,
+                amount,
+                None,
+                None,
+                u128::MAX
+            ),
+            Error::<Test>::PeerIdHashedKeyTypeNotAllowed
+        );
+
+        assert_err!(
+            Network::register_subnet_node(
+                RuntimeOrigin::signed(coldkey.clone()),
+                subnet_id,
+                hotkey.clone(),
+                ed25519_peer(1000),
+                ed25519_peer(1001),
+                secp256k1_peer(1002),
+                None,
+                0,
+                amount,
+                None,
+                None,
+                u128::MAX
+            ),
+            Error::<Test>::PeerIdSecp256k1KeyTypeNotAllowed
+        );
+
+        assert_err!(
+            Network::register_subnet_node(
+                RuntimeOrigin::signed(coldkey.clone()),
+                subnet_id,
+                hotkey.clone(),
+                PeerId("not-a-peer-id-not-a-peer-id-not-a-peer-id".into()),
+                ed25519_peer(1001),
+                ed25519_peer(1002),
+                None,
+                0,
+                amount,
+                None,
+                None,
+                u128::MAX
+            ),
+            Error::<Test>::InvalidPeerId
+        );
+
+        assert_ok!(Network::register_subnet_node(
+            RuntimeOrigin::signed(coldkey.clone()),
+            subnet_id,
+            hotkey.clone(),
+            ed25519_peer(1000),
+            ed25519_peer(1001),
+            ed25519_peer(1002),
+            None,
+            0,
+            amount,
+            None,
+            None,
+            u128::MAX
+        ));
+
+        let subnet_node_id = HotkeySubnetNodeId::<Test>::get(subnet_id, hotkey.clone()).unwrap();
+        let subnet_node = SubnetNodesData::<Test>::get(subnet_id, subnet_node_id);
+        assert_eq!(subnet_node.peer_id, ed25519_peer(1000));
+    })
+}
+
+#[test]
+fn test_register_subnet_node_any_peer_id_key_type() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+
+        let deposit_amount: u128 = 10000000000000000000000;
+        let amount: u128 = 1000000000000000000000;
+
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        let subnets = TotalActiveSubnets::<Test>::get() + 1;
+        let max_subnet_nodes = MaxSubnetNodes::<Test>::get();
+        let max_subnets = MaxSubnets::<Test>::get();
+        let end = 4;
+
+        build_activated_subnet(subnet_name.clone(), 0, end, deposit_amount, stake_amount);
+
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        let coldkey = get_coldkey(subnets, max_subnet_nodes, end + 1);
+        let hotkey = get_hotkey(subnets, max_subnet_nodes, max_subnets, end + 1);
+        let burn_amount = Network::calculate_burn_amount(subnet_id);
+        let _ = Balances::deposit_creating(&coldkey.clone(), deposit_amount + burn_amount);
+
+        // No key types accepts every decodable peer ID
+        SubnetKeyTypes::<Test>::remove(subnet_id);
+
+        assert_ok!(Network::register_subnet_node(
+            RuntimeOrigin::signed(coldkey.clone()),
+            subnet_id,
+            hotkey.clone(),
+            peer(1000),
+            ed25519_peer(1001),
+            secp256k1_peer(1002),
+            None,
+            0,
+            amount,
+            None,
+            None,
+            u128::MAX
+        ));
+    })
+}
+
+#[test]
+fn test_update_peer_id_key_type_not_allowed() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        let subnets = TotalActiveSubnets::<Test>::get() + 1;
+        let max_subnet_nodes = MaxSubnetNodes::<Test>::get();
+        let max_subnets = MaxSubnets::<Test>::get();
+        let end = 3;
+
+        let coldkey = get_coldkey(subnets, max_subnet_nodes, end);
+        let hotkey = get_hotkey(subnets, max_subnet_nodes, max_subnets, end);
+
+        build_activated_subnet(subnet_name.clone(), 0, end, deposit_amount, stake_amount);
+
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+        let subnet_node_id = HotkeySubnetNodeId::<Test>::get(subnet_id, hotkey.clone()).unwrap();
+
+        SubnetKeyTypes::<Test>::insert(subnet_id, key_types(&[KeyType::Rsa]));
+
+        assert_err!(
+            Network::update_peer_id(
+                RuntimeOrigin::signed(coldkey.clone()),
+                subnet_id,
+                subnet_node_id,
+                ed25519_peer(500)
+            ),
+            Error::<Test>::PeerIdEd25519KeyTypeNotAllowed
+        );
+
+        assert_err!(
+            Network::update_bootnode_peer_id(
+                RuntimeOrigin::signed(coldkey.clone()),
+                subnet_id,
+                subnet_node_id,
+                secp256k1_peer(501)
+            ),
+            Error::<Test>::PeerIdSecp256k1KeyTypeNotAllowed
+        );
+
+        assert_err!(
+            Network::update_client_peer_id(
+                RuntimeOrigin::signed(coldkey.clone()),
+                subnet_id,
+                subnet_node_id,
+                PeerId("not-a-peer-id-not-a-peer-id-not-a-peer-id".into())
+            ),
+            Error::<Test>::InvalidClientPeerId
+        );
+
+        // Hashed keys are accepted as RSA
+        assert_ok!(Network::update_peer_id(
+            RuntimeOrigin::signed(coldkey.clone()),
+            subnet_id,
+            subnet_node_id,
+            peer(500)
+        ));
+
+        SubnetKeyTypes::<Test>::insert(subnet_id, key_types(&[KeyType::Rsa, KeyType::Ed25519]));
+
+        assert_ok!(Network::update_peer_id(
+            RuntimeOrigin::signed(coldkey.clone()),
+            subnet_id,
+            subnet_node_id,
+            ed25519_peer(500)
+        ));
+
+        let subnet_node = SubnetNodesData::<Test>::get(subnet_id, subnet_node_id);
+        assert_eq!(subnet_node.peer_id, ed25519_peer(500));
+    })
+}
+
+#[test]
+fn test_prove_peer_id() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet(subnet_name.clone(), 0, 4, deposit_amount, stake_amount);
+
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+        SubnetPeerIdProofRequired::<Test>::insert(subnet_id, true);
+        let block = Network::get_current_block_as_u32();
+        let hotkey = account(1000);
+        let depositor = account(1001);
+        let _ = Balances::deposit_creating(&depositor, deposit_amount);
+
+        let (pair, peer_id) = ed25519_keyed_peer(1);
+
+        assert_ok!(Network::prove_peer_id(
+            RuntimeOrigin::signed(account(1001)),
+            subnet_id,
+            hotkey.clone(),
+            peer_id.clone(),
+            block,
+            ed25519_proof(&pair, subnet_id, &hotkey, block)
+        ));
+
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::PeerIdProven {
+                subnet_id,
+                hotkey: hotkey.clone(),
+                peer_id: peer_id.clone(),
+            }
+        );
+        assert_eq!(
+            PeerIdProofs::<Test>::get(subnet_id, &peer_id),
+            Some(peer_id_proof(&hotkey, block, &depositor))
+        );
+
+        // Recoverable signature
+        let (pair, peer_id) = secp256k1_keyed_peer(2);
+        let signature = secp256k1_proof(&pair, subnet_id, &hotkey, block);
+        assert_eq!(signature.len(), 65);
+
+        assert_ok!(Network::prove_peer_id(
+            RuntimeOrigin::signed(account(1001)),
+            subnet_id,
+            hotkey.clone(),
+            peer_id.clone(),
+            block,
+            signature.clone()
+        ));
+        assert_eq!(
+            PeerIdProofs::<Test>::get(subnet_id, &peer_id),
+            Some(peer_id_proof(&hotkey, block, &depositor))
+        );
+
+        // Compact signature
+        let (pair, peer_id) = secp256k1_keyed_peer(3);
+        let signature = secp256k1_proof(&pair, subnet_id, &hotkey, block);
+        let compact: BoundedVec<u8, DefaultMaxVectorLength> =
+            signature[..64].to_vec().try_into().unwrap();
+
+        assert_ok!(Network::prove_peer_id(
+            RuntimeOrigin::signed(account(1001)),
+            subnet_id,
+            hotkey.clone(),
+            peer_id.clone(),
+            block,
+            compact
+        ));
+        assert_eq!(
+            PeerIdProofs::<Test>::get(subnet_id, &peer_id),
+            Some(peer_id_proof(&hotkey, block, &depositor))
+        );
+
+        // DER signature, as produced by libp2p
+        let (pair, peer_id) = secp256k1_keyed_peer(4);
+        assert_ok!(Network::prove_peer_id(
+            RuntimeOrigin::signed(account(1001)),
+            subnet_id,
+            hotkey.clone(),
+            peer_id.clone(),
+            block,
+            secp256k1_der_proof(&pair, subnet_id, &hotkey, block, false)
+        ));
+        assert_eq!(
+            PeerIdProofs::<Test>::get(subnet_id, &peer_id),
+            Some(peer_id_proof(&hotkey, block, &depositor))
+        );
+
+        // DER signature with a high S value
+        let (pair, peer_id) = secp256k1_keyed_peer(5);
+        assert_ok!(Network::prove_peer_id(
+            RuntimeOrigin::signed(account(1001)),
+            subnet_id,
+            hotkey.clone(),
+            peer_id.clone(),
+            block,
+            secp256k1_der_proof(&pair, subnet_id, &hotkey, block, true)
+        ));
+        assert_eq!(
+            PeerIdProofs::<Test>::get(subnet_id, &peer_id),
+            Some(peer_id_proof(&hotkey, block, &depositor))
+        );
+
+        // A deposit is reserved for each proof
+        assert_eq!(
+            Balances::reserved_balance(&depositor),
+            5 * PeerIdProofDeposit::get()
+        );
+    })
+}
+
+#[test]
+fn test_prove_peer_id_der_invalid() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet(subnet_name.clone(), 0, 4, deposit_amount, stake_amount);
+
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+        SubnetPeerIdProofRequired::<Test>::insert(subnet_id, true);
+        let block = Network::get_current_block_as_u32();
+        let hotkey = account(1000);
+        let _ = Balances::deposit_creating(&account(1001), deposit_amount);
+
+        let (pair, peer_id) = secp256k1_keyed_peer(1);
+        let (other_pair, _) = secp256k1_keyed_peer(2);
+
+        // Signed by another key
+        assert_err!(
+            Network::prove_peer_id(
+                RuntimeOrigin::signed(account(1001)),
+                subnet_id,
+                hotkey.clone(),
+                peer_id.clone(),
+                block,
+                secp256k1_der_proof(&other_pair, subnet_id, &hotkey, block, false)
+            ),
+            Error::<Test>::InvalidPeerIdProof
+        );
+
+        // Truncated DER
+        let signature = secp256k1_der_proof(&pair, subnet_id, &hotkey, block, false);
+        let truncated: BoundedVec<u8, DefaultMaxVectorLength> = signature[..signature.len() - 1]
+            .to_vec()
+            .try_into()
+            .unwrap();
+        assert_err!(
+            Network::prove_peer_id(
+                RuntimeOrigin::signed(account(1001)),
+                subnet_id,
+                hotkey.clone(),
+                peer_id.clone(),
+                block,
+                truncated
+            ),
+            Error::<Test>::InvalidPeerIdProof
+        );
+
+        assert_eq!(PeerIdProofs::<Test>::get(subnet_id, &peer_id), None);
+    })
+}
+
+#[test]
+fn test_prove_peer_id_invalid() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet(subnet_name.clone(), 0, 4, deposit_amount, stake_amount);
+
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+        System::set_block_number(System::block_number() + PEER_ID_PROOF_VALIDITY_BLOCKS * 2);
+        let block = Network::get_current_block_as_u32();
+        let hotkey = account(1000);
+
+        let (pair, peer_id) = ed25519_keyed_peer(1);
+        let (other_pair, _) = ed25519_keyed_peer(2);
+
+        // Subnet doesn't require proofs
+        assert_err!(
+            Network::prove_peer_id(
+                RuntimeOrigin::signed(account(1001)),
+                subnet_id,
+                hotkey.clone(),
+                peer_id.clone(),
+                block,
+                ed25519_proof(&pair, subnet_id, &hotkey, block)
+            ),
+            Error::<Test>::PeerIdProofNotRequired
+        );
+
+        SubnetPeerIdProofRequired::<Test>::insert(subnet_id, true);
+
+        // Depositor can't reserve the deposit
+        assert_err!(
+            Network::prove_peer_id(
+                RuntimeOrigin::signed(account(1001)),
+                subnet_id,
+                hotkey.clone(),
+                peer_id.clone(),
+                block,
+                ed25519_proof(&pair, subnet_id, &hotkey, block)
+            ),
+            Error::<Test>::NotEnoughBalance
+        );
+
+        assert_err!(
+            Network::prove_peer_id(
+                RuntimeOrigin::signed(account(1001)),
+                subnet_id + 1,
+                hotkey.clone(),
+                peer_id.clone(),
+                block,
+                ed25519_proof(&pair, subnet_id + 1, &hotkey, block)
+            ),
+            Error::<Test>::InvalidSubnetId
+        );
+
+        // Signed by another key
+        assert_err!(
+            Network::prove_peer_id(
+                RuntimeOrigin::signed(account(1001)),
+                subnet_id,
+                hotkey.clone(),
+                peer_id.clone(),
+                block,
+                ed25519_proof(&other_pair, subnet_id, &hotkey, block)
+            ),
+            Error::<Test>::InvalidPeerIdProof
+        );
+
+        // Signed for another hotkey
+        assert_err!(
+            Network::prove_peer_id(
+                RuntimeOrigin::signed(account(1001)),
+                subnet_id,
+                hotkey.clone(),
+                peer_id.clone(),
+                block,
+                ed25519_proof(&pair, subnet_id, &account(1001), block)
+            ),
+            Error::<Test>::InvalidPeerIdProof
+        );
+
+        // Hashed keys can't be verified
+        assert_err!(
+            Network::prove_peer_id(
+                RuntimeOrigin::signed(account(1001)),
+                subnet_id,
+                hotkey.clone(),
+                peer(1),
+                block,
+                ed25519_proof(&pair, subnet_id, &hotkey, block)
+            ),
+            Error::<Test>::InvalidPeerIdProof
+        );
+
+        // Expired
+        let expired_block = block - PEER_ID_PROOF_VALIDITY_BLOCKS - 1;
+        assert_err!(
+            Network::prove_peer_id(
+                RuntimeOrigin::signed(account(1001)),
+                subnet_id,
+                hotkey.clone(),
+                peer_id.clone(),
+                expired_block,
+                ed25519_proof(&pair, subnet_id, &hotkey, expired_block)
+            ),
+            Error::<Test>::PeerIdProofExpired
+        );
+
+        // Future block
+        assert_err!(
+            Network::prove_peer_id(
+                RuntimeOrigin::signed(account(1001)),
+                subnet_id,
+                hotkey.clone(),
+                peer_id.clone(),
+                block + 1,
+                ed25519_proof(&pair, subnet_id, &hotkey, block + 1)
+            ),
+            Error::<Test>::PeerIdProofExpired
+        );
+
+        assert_eq!(PeerIdProofs::<Test>::get(subnet_id, &peer_id), None);
+    })
+}
+
+#[test]
+fn test_register_subnet_node_peer_id_proof_required() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+
+        let deposit_amount: u128 = 10000000000000000000000;
+        let amount: u128 = 1000000000000000000000;
+
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        let subnets = TotalActiveSubnets::<Test>::get() + 1;
+        let max_subnet_nodes = MaxSubnetNodes::<Test>::get();
+        let max_subnets = MaxSubnets::<Test>::get();
+        let end = 4;
+
+        build_activated_subnet(subnet_name.clone(), 0, end, deposit_amount, stake_amount);
+
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        let coldkey = get_coldkey(subnets, max_subnet_nodes, end + 1);
+        let hotkey = get_hotkey(subnets, max_subnet_nodes, max_subnets, end + 1);
+        let burn_amount = Network::calculate_burn_amount(subnet_id);
+        let _ = Balances::deposit_creating(&coldkey.clone(), deposit_amount + burn_amount);
+
+        SubnetKeyTypes::<Test>::insert(
+            subnet_id,
+            key_types(&[KeyType::Ed25519, KeyType::Secp256k1]),
+        );
+        SubnetPeerIdProofRequired::<Test>::insert(subnet_id, true);
+
+        let block = Network::get_current_block_as_u32();
+        let (peer_pair, peer_id) = ed25519_keyed_peer(1);
+        let (bootnode_pair, bootnode_peer_id) = ed25519_keyed_peer(2);
+        let (client_pair, client_peer_id) = secp256k1_keyed_peer(3);
+
+        assert_err!(
+            Network::register_subnet_node(
+                RuntimeOrigin::signed(coldkey.clone()),
+                subnet_id,
+                hotkey.clone(),
+                peer_id.clone(),
+                bootnode_peer_id.clone(),
+                client_peer_id.clone(),
+                None,
+                0,
+                amount,
+                None,
+                None,
+                u128::MAX
+            ),
+            Error::<Test>::PeerIdProofRequired
+        );
+
+        assert_ok!(Network::prove_peer_id(
+            RuntimeOrigin::signed(coldkey.clone()),
+            subnet_id,
+            hotkey.clone(),
+            peer_id.clone(),
+            block,
+            ed25519_proof(&peer_pair, subnet_id, &hotkey, block)
+        ));
+        assert_ok!(Network::prove_peer_id(
+            RuntimeOrigin::signed(coldkey.clone()),
+            subnet_id,
+            hotkey.clone(),
+            bootnode_peer_id.clone(),
+            block,
+            ed25519_proof(&bootnode_pair, subnet_id, &hotkey, block)
+        ));
+
+        // Client peer ID is still unproven
+        assert_err!(
+            Network::register_subnet_node(
+                RuntimeOrigin::signed(coldkey.clone()),
+                subnet_id,
+                hotkey.clone(),
+                peer_id.clone(),
+                bootnode_peer_id.clone(),
+                client_peer_id.clone(),
+                None,
+                0,
+                amount,
+                None,
+                None,
+                u128::MAX
+            ),
+            Error::<Test>::PeerIdProofRequired
+        );
+
+        assert_ok!(Network::prove_peer_id(
+            RuntimeOrigin::signed(coldkey.clone()),
+            subnet_id,
+            hotkey.clone(),
+            client_peer_id.clone(),
+            block,
+            secp256k1_proof(&client_pair, subnet_id, &hotkey, block)
+        ));
+
+        // Proofs expire
+        System::set_block_number(System::block_number() + PEER_ID_PROOF_VALIDITY_BLOCKS + 1);
+
+        assert_err!(
+            Network::register_subnet_node(
+                RuntimeOrigin::signed(coldkey.clone()),
+                subnet_id,
+                hotkey.clone(),
+                peer_id.clone(),
+                bootnode_peer_id.clone(),
+                client_peer_id.clone(),
+                None,
+                0,
+                amount,
+                None,
+                None,
+                u128::MAX
+            ),
+            Error::<Test>::PeerIdProofExpired
+        );
+
+        let block = Network::get_current_block_as_u32();
+        for (pair, peer_id) in [(&peer_pair, &peer_id), (&bootnode_pair, &bootnode_peer_id)] {
+            assert_ok!(Network::prove_peer_id(
+                RuntimeOrigin::signed(coldkey.clone()),
+                subnet_id,
+                hotkey.clone(),
+                peer_id.clone(),
+                block,
+                ed25519_proof(pair, subnet_id, &hotkey, block)
+            ));
+        }
+        assert_ok!(Network::prove_peer_id(
+            RuntimeOrigin::signed(coldkey.clone()),
+            subnet_id,
+            hotkey.clone(),
+            client_peer_id.clone(),
+            block,
+            secp256k1_proof(&client_pair, subnet_id, &hotkey, block)
+        ));
+
+        assert_ok!(Network::register_subnet_node(
+            RuntimeOrigin::signed(coldkey.clone()),
+            subnet_id,
+            hotkey.clone(),
+            peer_id.clone(),
+            bootnode_peer_id.clone(),
+            client_peer_id.clone(),
+            None,
+            0,
+            amount,
+            None,
+            None,
+            u128::MAX
+        ));
+
+        // Proofs are consumed
+        assert_eq!(PeerIdProofs::<Test>::get(subnet_id, &peer_id), None);
+        assert_eq!(
+            PeerIdProofs::<Test>::get(subnet_id, &bootnode_peer_id),
+            None
+        );
+        assert_eq!(PeerIdProofs::<Test>::get(subnet_id, &client_peer_id), None);
+    })
+}
+
+#[test]
+fn test_update_peer_id_peer_id_proof_required() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        let subnets = TotalActiveSubnets::<Test>::get() + 1;
+        let max_subnet_nodes = MaxSubnetNodes::<Test>::get();
+        let max_subnets = MaxSubnets::<Test>::get();
+        let end = 3;
+
+        let coldkey = get_coldkey(subnets, max_subnet_nodes, end);
+        let hotkey = get_hotkey(subnets, max_subnet_nodes, max_subnets, end);
+        let other_hotkey = get_hotkey(subnets, max_subnet_nodes, max_subnets, end - 1);
+
+        build_activated_subnet(subnet_name.clone(), 0, end, deposit_amount, stake_amount);
+
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+        let subnet_node_id = HotkeySubnetNodeId::<Test>::get(subnet_id, hotkey.clone()).unwrap();
+
+        SubnetKeyTypes::<Test>::insert(subnet_id, key_types(&[KeyType::Ed25519]));
+        SubnetPeerIdProofRequired::<Test>::insert(subnet_id, true);
+
+        let block = Network::get_current_block_as_u32();
+        let (pair, peer_id) = ed25519_keyed_peer(1);
+
+        assert_err!(
+            Network::update_peer_id(
+                RuntimeOrigin::signed(coldkey.clone()),
+                subnet_id,
+                subnet_node_id,
+                peer_id.clone()
+            ),
+            Error::<Test>::PeerIdProofRequired
+        );
+
+        // Proven for another hotkey
+        assert_ok!(Network::prove_peer_id(
+            RuntimeOrigin::signed(coldkey.clone()),
+            subnet_id,
+            other_hotkey.clone(),
+            peer_id.clone(),
+            block,
+            ed25519_proof(&pair, subnet_id, &other_hotkey, block)
+        ));
+
+        assert_err!(
+            Network::update_peer_id(
+                RuntimeOrigin::signed(coldkey.clone()),
+                subnet_id,
+                subnet_node_id,
+                peer_id.clone()
+            ),
+            Error::<Test>::PeerIdProofHotkeyMismatch
+        );
+
+        assert_ok!(Network::prove_peer_id(
+            RuntimeOrigin::signed(coldkey.clone()),
+            subnet_id,
+            hotkey.clone(),
+            peer_id.clone(),
+            block,
+            ed25519_proof(&pair, subnet_id, &hotkey, block)
+        ));
+
+        assert_ok!(Network::update_peer_id(
+            RuntimeOrigin::signed(coldkey.clone()),
+            subnet_id,
+            subnet_node_id,
+            peer_id.clone()
+        ));
+
+        let subnet_node = SubnetNodesData::<Test>::get(subnet_id, subnet_node_id);
+        assert_eq!(subnet_node.peer_id, peer_id);
+        assert_eq!(PeerIdProofs::<Test>::get(subnet_id, &peer_id), None);
+
+        // Bootnode and client peer IDs require proofs too
+        let (bootnode_pair, bootnode_peer_id) = ed25519_keyed_peer(2);
+
+        assert_err!(
+            Network::update_bootnode_peer_id(
+                RuntimeOrigin::signed(coldkey.clone()),
+                subnet_id,
+                subnet_node_id,
+                bootnode_peer_id.clone()
+            ),
+            Error::<Test>::PeerIdProofRequired
+        );
+
+        assert_ok!(Network::prove_peer_id(
+            RuntimeOrigin::signed(coldkey.clone()),
+            subnet_id,
+            hotkey.clone(),
+            bootnode_peer_id.clone(),
+            block,
+            ed25519_proof(&bootnode_pair, subnet_id, &hotkey, block)
+        ));
+
+        assert_ok!(Network::update_bootnode_peer_id(
+            RuntimeOrigin::signed(coldkey.clone()),
+            subnet_id,
+            subnet_node_id,
+            bootnode_peer_id.clone()
+        ));
+
+        let (_, client_peer_id) = ed25519_keyed_peer(3);
+
+        assert_err!(
+            Network::update_client_peer_id(
+                RuntimeOrigin::signed(coldkey.clone()),
+                subnet_id,
+                subnet_node_id,
+                client_peer_id.clone()
+            ),
+            Error::<Test>::PeerIdProofRequired
+        );
+    })
+}
+
+#[test]
+fn test_peer_id_proof_deposit() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        let subnets = TotalActiveSubnets::<Test>::get() + 1;
+        let max_subnet_nodes = MaxSubnetNodes::<Test>::get();
+        let max_subnets = MaxSubnets::<Test>::get();
+        let end = 3;
+
+        let coldkey = get_coldkey(subnets, max_subnet_nodes, end);
+        let hotkey = get_hotkey(subnets, max_subnet_nodes, max_subnets, end);
+
+        build_activated_subnet(subnet_name.clone(), 0, end, deposit_amount, stake_amount);
+
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+        let subnet_node_id = HotkeySubnetNodeId::<Test>::get(subnet_id, hotkey.clone()).unwrap();
+
+        SubnetKeyTypes::<Test>::insert(subnet_id, key_types(&[KeyType::Ed25519]));
+        SubnetPeerIdProofRequired::<Test>::insert(subnet_id, true);
+
+        let depositor = account(1001);
+        let _ = Balances::deposit_creating(&depositor, deposit_amount);
+        let deposit = PeerIdProofDeposit::get();
+
+        let block = Network::get_current_block_as_u32();
+        let (pair, peer_id) = ed25519_keyed_peer(1);
+
+        assert_ok!(Network::prove_peer_id(
+            RuntimeOrigin::signed(depositor.clone()),
+            subnet_id,
+            hotkey.clone(),
+            peer_id.clone(),
+            block,
+            ed25519_proof(&pair, subnet_id, &hotkey, block)
+        ));
+        assert_eq!(Balances::reserved_balance(&depositor), deposit);
+        assert_eq!(Balances::free_balance(&depositor), deposit_amount - deposit);
+
+        // Replacing the proof returns the deposit of the replaced one
+        assert_ok!(Network::prove_peer_id(
+            RuntimeOrigin::signed(coldkey.clone()),
+            subnet_id,
+            hotkey.clone(),
+            peer_id.clone(),
+            block,
+            ed25519_proof(&pair, subnet_id, &hotkey, block)
+        ));
+        assert_eq!(Balances::reserved_balance(&depositor), 0);
+        assert_eq!(Balances::free_balance(&depositor), deposit_amount);
+        assert_eq!(Balances::reserved_balance(&coldkey), deposit);
+
+        // Using the proof returns the deposit
+        assert_ok!(Network::update_peer_id(
+            RuntimeOrigin::signed(coldkey.clone()),
+            subnet_id,
+            subnet_node_id,
+            peer_id.clone()
+        ));
+        assert_eq!(Balances::reserved_balance(&coldkey), 0);
+        assert_eq!(PeerIdProofs::<Test>::get(subnet_id, &peer_id), None);
+        assert_eq!(
+            PeerIdProofsBySignedBlock::<Test>::get(block, (subnet_id, peer_id.clone())),
+            None
+        );
+    })
+}
+
+#[test]
+fn test_prune_expired_peer_id_proofs() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet(subnet_name.clone(), 0, 4, deposit_amount, stake_amount);
+
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+        SubnetPeerIdProofRequired::<Test>::insert(subnet_id, true);
+        let hotkey = account(1000);
+        let depositor = account(1001);
+        let _ = Balances::deposit_creating(&depositor, deposit_amount);
+
+        let expiring_block = Network::get_current_block_as_u32();
+        let (expiring_pair, expiring_peer_id) = ed25519_keyed_peer(1);
+        assert_ok!(Network::prove_peer_id(
+            RuntimeOrigin::signed(depositor.clone()),
+            subnet_id,
+            hotkey.clone(),
+            expiring_peer_id.clone(),
+            expiring_block,
+            ed25519_proof(&expiring_pair, subnet_id, &hotkey, expiring_block)
+        ));
+        assert_eq!(
+            PeerIdProofPruneBlock::<Test>::get(),
+            Some(expiring_block.saturating_sub(PEER_ID_PROOF_VALIDITY_BLOCKS))
+        );
+
+        System::set_block_number(System::block_number() + 10);
+        let block = Network::get_current_block_as_u32();
+        let (pair, peer_id) = ed25519_keyed_peer(2);
+        assert_ok!(Network::prove_peer_id(
+            RuntimeOrigin::signed(depositor.clone()),
+            subnet_id,
+            hotkey.clone(),
+            peer_id.clone(),
+            block,
+            ed25519_proof(&pair, subnet_id, &hotkey, block)
+        ));
+        assert_eq!(
+            Balances::reserved_balance(&depositor),
+            2 * PeerIdProofDeposit::get()
+        );
+
+        // Nothing expired yet
+        System::set_block_number(System::block_number() + PEER_ID_PROOF_VALIDITY_BLOCKS - 10);
+        Network::prune_expired_peer_id_proofs(&mut WeightMeter::new());
+        assert!(PeerIdProofs::<Test>::contains_key(
+            subnet_id,
+            &expiring_peer_id
+        ));
+        assert_eq!(PeerIdProofPruneBlock::<Test>::get(), Some(expiring_block));
+
+        // Only the first proof expired
+        System::set_block_number(System::block_number() + 1);
+        Network::prune_expired_peer_id_proofs(&mut WeightMeter::new());
+
+        assert_eq!(
+            PeerIdProofs::<Test>::get(subnet_id, &expiring_peer_id),
+            None
+        );
+        assert_eq!(
+            PeerIdProofsBySignedBlock::<Test>::get(
+                expiring_block,
+                (subnet_id, expiring_peer_id.clone())
+            ),
+            None
+        );
+        assert_eq!(
+            PeerIdProofs::<Test>::get(subnet_id, &peer_id),
+            Some(peer_id_proof(&hotkey, block, &depositor))
+        );
+        assert_eq!(
+            Balances::reserved_balance(&depositor),
+            PeerIdProofDeposit::get()
+        );
+        assert_eq!(
+            PeerIdProofPruneBlock::<Test>::get(),
+            Some(expiring_block + 1)
+        );
+
+        System::set_block_number(System::block_number() + 10);
+        Network::on_idle(System::block_number(), Weight::MAX);
+
+        assert_eq!(PeerIdProofs::<Test>::get(subnet_id, &peer_id), None);
+        assert_eq!(Balances::reserved_balance(&depositor), 0);
+        assert_eq!(PeerIdProofPruneBlock::<Test>::get(), Some(block + 1));
+    })
+}
@@ -1,5 +1,10 @@
 use super::mock::*;
-use frame_support::traits::OnInitialize;
+use super::test_utils::*;
+use crate::{SubnetElectedValidator, SubnetNodeElectionSlots, TotalSubnetElectableNodes};
+use codec::Encode;
+use frame_support::assert_ok;
+use frame_support::traits::{Currency, Get, OnInitialize, Randomness};
+use sp_core::H256;
 use sp_runtime::traits::Header;
 
 ///
@@ -24,7 +29,7 @@ pub fn setup_blocks(blocks: u32) {
     for i in 1..(blocks + 1) {
         System::reset_events();
         System::initialize(&i, &parent_hash, &Default::default());
-        InsecureRandomnessCollectiveFlip::on_initialize(i);
+        RandomnessBeacon::on_initialize(i);
 
         let header = System::finalize();
         parent_hash = header.hash();
@@ -57,3 +62,147 @@ fn test_random_number_is_deterministic_with_mocked_randomness() {
         assert_eq!(r1, r2); // StaticRandomness always returns same result
     });
 }
+
+/// Advances to `block`, running the beacon's `on_initialize` on every block
+///
+/// Every block is authored by `author`, which only changes the parent hash.
+fn run_to_block(block: u32, author: u8) {
+    while System::block_number() < block {
+        let next = System::block_number() + 1;
+        System::reset_events();
+        System::initialize(&next, &H256::repeat_byte(author), &Default::default());
+        RandomnessBeacon::on_initialize(next);
+    }
+}
+
+fn beacon_secret(n: u32) -> [u8; 32] {
+    sp_io::hashing::blake2_256(&n.to_le_bytes())
+}
+
+const BEACON_BALANCE: u128 = 1_000_000_000_000_000_000_000;
+
+/// Runs one commit-reveal round and elects a validator from 16 election slots
+///
+/// Returns the seed, the elected validator and the balance lost by the withholding account.
+///
+/// * `author` - Author of every block.
+/// * `committers` - Accounts committing, revealed in this order.
+/// * `withheld` - Committer that never reveals.
+fn elect_after_randomness_round(
+    author: u8,
+    committers: &[u32],
+    withheld: Option<u32>,
+) -> (H256, Option<u32>, u128) {
+    new_test_ext().execute_with(|| {
+        let subnet_id = 1;
+        let subnet_epoch = 1000;
+        let round_length = RANDOMNESS_ROUND_LENGTH;
+
+        SubnetNodeElectionSlots::<Test>::insert(subnet_id, (1..=16).collect::<Vec<u32>>());
+        TotalSubnetElectableNodes::<Test>::insert(subnet_id, 16);
+
+        for n in committers {
+            let _ = Balances::deposit_creating(&account(*n), BEACON_BALANCE);
+        }
+
+        run_to_block(round_length, author);
+        for n in committers {
+            let commitment = RandomnessBeacon::commitment_of(&account(*n), &beacon_secret(*n));
+            assert_ok!(RandomnessBeacon::commit(
+                RuntimeOrigin::signed(account(*n)),
+                commitment
+            ));
+        }
+
+        run_to_block(2 * round_length, author);
+        for n in committers {
+            if Some(*n) != withheld {
+                assert_ok!(RandomnessBeacon::reveal(
+                    RuntimeOrigin::signed(account(*n)),
+                    beacon_secret(*n)
+                ));
+            }
+        }
+
+        run_to_block(3 * round_length + 1, author);
+
+        Network::elect_validator(subnet_id, subnet_epoch, System::block_number());
+
+        let slashed = withheld.map_or(0, |n| BEACON_BALANCE - Balances::total_balance(&account(n)));
+
+        (
+            pallet_randomness_beacon::RandomSeed::<Test>::get(),
+            SubnetElectedValidator::<Test>::get(subnet_id, subnet_epoch),
+            slashed,
+        )
+    })
+}
+
+#[test]
+fn test_last_revealer_withholding_forfeits_deposit() {
+    let (seed, validator, slashed) = elect_after_randomness_round(0, &[1, 2, 3], None);
+    assert!(validator.is_some());
+    assert_ne!(seed, H256::zero());
+    assert_eq!(slashed, 0);
+
+    // The last revealer sees every other secret and can choose between two seeds by withholding
+    let (withheld_seed, _, withheld_slashed) = elect_after_randomness_round(0, &[1, 2, 3], Some(3));
+    assert_ne!(seed, withheld_seed);
+
+    // Choosing the other seed costs the deposit
+    assert_eq!(
+        withheld_slashed,
+        <Test as pallet_randomness_beacon::Config>::CommitDeposit::get()
+    );
+}
+
+#[test]
+fn test_block_author_cannot_bias_elected_validator() {
+    let (seed, validator, _) = elect_after_randomness_round(0, &[1, 2, 3], None);
+
+    // Different block hashes
+    for author in 1..=4 {
+        let (author_seed, author_validator, _) =
+            elect_after_randomness_round(author, &[1, 2, 3], None);
+        assert_eq!((seed, validator), (author_seed, author_validator));
+    }
+
+    // Different reveal order
+    let (reordered_seed, reordered_validator, _) =
+        elect_after_randomness_round(1, &[3, 1, 2], None);
+    assert_eq!((seed, validator), (reordered_seed, reordered_validator));
+}
+
+#[test]
+fn test_lone_committer_cannot_steer_elected_validator() {
+    let (seed, validator, _) = elect_after_randomness_round(0, &[1], None);
+    assert!(validator.is_some());
+
+    // Below `MinReveals` the committer's secret isn't mixed in, any secret elects the same node
+    for n in 2..=5 {
+        let (lone_seed, lone_validator, _) = elect_after_randomness_round(0, &[n], None);
+        assert_eq!((seed, validator), (lone_seed, lone_validator));
+    }
+
+    // The last revealer of a round at `MinReveals` can only fall back to that same seed
+    let (withheld_seed, withheld_validator, _) = elect_after_randomness_round(0, &[1, 2], Some(2));
+    assert_eq!((seed, validator), (withheld_seed, withheld_validator));
+}
+
+#[test]
+fn test_elected_validator_follows_beacon_seed() {
+    let (seed, _, _) = elect_after_randomness_round(0, &[1, 2, 3], None);
+    let (other_seed, _, _) = elect_after_randomness_round(0, &[1, 2, 4], None);
+
+    // A different set of revealed secrets is a different seed
+    assert_ne!(seed, other_seed);
+
+    new_test_ext().execute_with(|| {
+        let (random, _) = RandomnessBeacon::random(&(NetworkPalletId::get(), 7u32).encode());
+        let bytes: [u8; 4] = random.as_ref()[0..4].try_into().unwrap();
+        assert_eq!(
+            Network::generate_random_number(7),
+            u32::from_le_bytes(bytes)
+        );
+    });
+}
@@ -0,0 +1,433 @@
+use super::mock::*;
+use crate::tests::test_utils::*;
+use crate::{
+    AccountEpochRewards, AccountNodeDelegateSharesHistory, AccountRewardHistory,
+    AccountSubnetDelegateSharesHistory, AccountSubnetDelegateStakeShares, DelegatePoolEpochRewards,
+    MinSubnetMinStake, NodeDelegateRewardHistory, RewardHistoryDepth, RewardHistoryPruneEpoch,
+    SubnetConsensusSubmission, SubnetDelegateRewardHistory, SubnetName, SubnetNodeIdHotkey,
+    TotalSubnetDelegateStakeShares,
+};
+use frame_support::assert_ok;
+use frame_support::traits::Get;
+use frame_support::weights::WeightMeter;
+
+//
+//
+//
+//
+//
+//
+//
+// Reward history
+//
+//
+//
+//
+//
+//
+//
+
+/// Run one subnet epoch of consensus and distribute its rewards
+///
+/// Returns the validators hotkey and the epoch the rewards were distributed in
+fn run_emission_epoch(subnet_id: u32) -> (AccountIdOf<Test>, u32) {
+    set_block_to_subnet_slot_epoch(Network::get_current_epoch_as_u32(), subnet_id);
+    let subnet_epoch = Network::get_current_subnet_epoch_as_u32(subnet_id);
+    Network::elect_validator(subnet_id, subnet_epoch, System::block_number());
+
+    run_subnet_consensus_step(subnet_id, None, None);
+
+    let submission = SubnetConsensusSubmission::<Test>::get(subnet_id, subnet_epoch).unwrap();
+    let validator_hotkey =
+        SubnetNodeIdHotkey::<Test>::get(subnet_id, submission.validator_id).unwrap();
+
+    increase_epochs(1);
+    set_block_to_subnet_slot_epoch(Network::get_current_epoch_as_u32(), subnet_id);
+
+    let epoch = Network::get_current_epoch_as_u32();
+    Network::handle_subnet_emission_weights(epoch);
+
+    Network::emission_step(
+        &mut WeightMeter::new(),
+        System::block_number(),
+        epoch,
+        Network::get_current_subnet_epoch_as_u32(subnet_id),
+        subnet_id,
+    );
+
+    (validator_hotkey, epoch)
+}
+
+#[test]
+fn test_reward_history_disabled_by_default() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet(subnet_name.clone(), 0, 4, deposit_amount, stake_amount);
+
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        assert_eq!(RewardHistoryDepth::<Test>::get(), 0);
+
+        let (validator_hotkey, epoch) = run_emission_epoch(subnet_id);
+
+        assert_eq!(AccountRewardHistory::<Test>::iter().count(), 0);
+        assert_eq!(SubnetDelegateRewardHistory::<Test>::iter().count(), 0);
+        assert_eq!(
+            Network::get_account_rewards(validator_hotkey, 0, epoch),
+            vec![]
+        );
+    });
+}
+
+#[test]
+fn test_emission_step_records_reward_history() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet(subnet_name.clone(), 0, 4, deposit_amount, stake_amount);
+
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        assert_ok!(Network::do_set_reward_history_depth(100));
+
+        let (validator_hotkey, epoch) = run_emission_epoch(subnet_id);
+
+        let rewards = AccountRewardHistory::<Test>::get(epoch, &validator_hotkey);
+        assert!(rewards.node_stake_rewards > 0);
+
+        let pool = SubnetDelegateRewardHistory::<Test>::get(epoch, subnet_id).unwrap();
+        assert!(pool.rewards > 0);
+        assert!(pool.total_shares > 0);
+
+        let history = Network::get_account_rewards(validator_hotkey, 0, epoch);
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].epoch, epoch);
+        assert_eq!(history[0].node_stake_rewards, rewards.node_stake_rewards);
+    });
+}
+
+#[test]
+fn test_get_account_rewards() {
+    new_test_ext().execute_with(|| {
+        let account_id = account(1);
+        let subnet_id = 1;
+        let subnet_node_id = 2;
+        RewardHistoryDepth::<Test>::set(4);
+
+        for epoch in 1..10 {
+            AccountRewardHistory::<Test>::insert(
+                epoch,
+                &account_id,
+                AccountEpochRewards {
+                    node_stake_rewards: epoch as u128,
+                    subnet_owner_rewards: 0,
+                    overwatch_rewards: 1,
+                },
+            );
+        }
+        AccountRewardHistory::<Test>::remove(3, &account_id);
+
+        // Epochs without rewards are skipped
+        let history = Network::get_account_rewards(account_id.clone(), 2, 4);
+        assert_eq!(
+            history.iter().map(|info| info.epoch).collect::<Vec<u32>>(),
+            vec![2, 4]
+        );
+        assert_eq!(history[1].node_stake_rewards, 4);
+        assert_eq!(history[1].overwatch_rewards, 1);
+
+        // Capped at the history depth
+        let history = Network::get_account_rewards(account_id.clone(), 5, u32::MAX);
+        assert_eq!(
+            history.iter().map(|info| info.epoch).collect::<Vec<u32>>(),
+            vec![5, 6, 7, 8]
+        );
+
+        // Delegate stake rewards are the accounts part of each pool
+        AccountSubnetDelegateStakeShares::<Test>::insert(&account_id, subnet_id, 250);
+        TotalSubnetDelegateStakeShares::<Test>::insert(subnet_id, 1000);
+        crate::AccountNodeDelegateStakeShares::<Test>::insert(
+            (&account_id, subnet_id, subnet_node_id),
+            100,
+        );
+        SubnetDelegateRewardHistory::<Test>::insert(
+            5,
+            subnet_id,
+            DelegatePoolEpochRewards {
+                rewards: 4000,
+                total_shares: 1000,
+            },
+        );
+        NodeDelegateRewardHistory::<Test>::insert(
+            5,
+            (subnet_id, subnet_node_id),
+            DelegatePoolEpochRewards {
+                rewards: 3000,
+                total_shares: 300,
+            },
+        );
+
+        let history = Network::get_account_rewards(account_id.clone(), 5, 5);
+        assert_eq!(history[0].delegate_stake_rewards, 1000);
+        assert_eq!(history[0].node_delegate_stake_rewards, 1000);
+
+        // Disabled
+        RewardHistoryDepth::<Test>::set(0);
+        assert_eq!(Network::get_account_rewards(account_id, 0, 10), vec![]);
+    });
+}
+
+#[test]
+fn test_get_account_rewards_uses_shares_held_in_each_epoch() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet(subnet_name.clone(), 0, 4, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+        let subnet_node_id = 1;
+
+        let account_id = account(1000);
+        assert_ok!(Network::do_set_reward_history_depth(100));
+
+        let pool = DelegatePoolEpochRewards {
+            rewards: 1000,
+            total_shares: 1000,
+        };
+
+        // Epoch 1: 100 shares
+        let epoch_1 = Network::get_current_epoch_as_u32();
+        Network::increase_account_delegate_stake(&account_id, subnet_id, 100, 100);
+        Network::increase_account_node_delegate_stake_shares(
+            &account_id,
+            subnet_id,
+            subnet_node_id,
+            100,
+            100,
+        );
+        SubnetDelegateRewardHistory::<Test>::insert(epoch_1, subnet_id, pool.clone());
+        NodeDelegateRewardHistory::<Test>::insert(
+            epoch_1,
+            (subnet_id, subnet_node_id),
+            pool.clone(),
+        );
+
+        // Epoch 2: 400 shares before the rewards, 200 after them
+        increase_epochs(1);
+        let epoch_2 = Network::get_current_epoch_as_u32();
+        Network::increase_account_delegate_stake(&account_id, subnet_id, 300, 300);
+        SubnetDelegateRewardHistory::<Test>::insert(epoch_2, subnet_id, pool.clone());
+        Network::decrease_account_delegate_stake(&account_id, subnet_id, 200, 200);
+        Network::decrease_account_node_delegate_stake_shares(
+            &account_id,
+            subnet_id,
+            subnet_node_id,
+            100,
+            100,
+        );
+        NodeDelegateRewardHistory::<Test>::insert(
+            epoch_2,
+            (subnet_id, subnet_node_id),
+            pool.clone(),
+        );
+
+        // Epoch 3: 200 shares
+        increase_epochs(1);
+        let epoch_3 = Network::get_current_epoch_as_u32();
+        SubnetDelegateRewardHistory::<Test>::insert(epoch_3, subnet_id, pool.clone());
+
+        // Only the first change counting from an epoch is checkpointed
+        assert_eq!(
+            AccountSubnetDelegateSharesHistory::<Test>::get((epoch_1, &account_id, subnet_id)),
+            Some(0)
+        );
+        assert_eq!(
+            AccountSubnetDelegateSharesHistory::<Test>::get((epoch_2, &account_id, subnet_id)),
+            Some(100)
+        );
+        assert_eq!(
+            AccountSubnetDelegateSharesHistory::<Test>::get((epoch_3, &account_id, subnet_id)),
+            Some(400)
+        );
+        assert_eq!(
+            AccountNodeDelegateSharesHistory::<Test>::get((
+                epoch_2,
+                &account_id,
+                (subnet_id, subnet_node_id)
+            )),
+            Some(100)
+        );
+
+        let history = Network::get_account_rewards(account_id.clone(), epoch_1, epoch_3);
+        assert_eq!(
+            history
+                .iter()
+                .map(|info| (
+                    info.epoch,
+                    info.delegate_stake_rewards,
+                    info.node_delegate_stake_rewards
+                ))
+                .collect::<Vec<(u32, u128, u128)>>(),
+            vec![(epoch_1, 100, 100), (epoch_2, 400, 0), (epoch_3, 200, 0),]
+        );
+
+        // Querying part of the range gives the same rewards
+        let history = Network::get_account_rewards(account_id.clone(), epoch_2, epoch_2);
+        assert_eq!(history[0].delegate_stake_rewards, 400);
+    });
+}
+
+#[test]
+fn test_checkpoint_delegate_shares_weight() {
+    new_test_ext().execute_with(|| {
+        let account_id = account(1);
+        let subnet_id = 1;
+        let subnet_node_id = 1;
+        let db_weight = <Test as frame_system::Config>::DbWeight::get();
+
+        // Disabled, only the depth is read
+        RewardHistoryDepth::<Test>::put(0);
+        assert_eq!(
+            Network::increase_account_delegate_stake(&account_id, subnet_id, 100, 100),
+            db_weight.reads(1)
+        );
+        assert_eq!(
+            Network::increase_account_node_delegate_stake_shares(
+                &account_id,
+                subnet_id,
+                subnet_node_id,
+                100,
+                100
+            ),
+            db_weight.reads(1)
+        );
+
+        // First change of the epoch writes the checkpoint
+        RewardHistoryDepth::<Test>::put(10);
+        assert_eq!(
+            Network::decrease_account_delegate_stake(&account_id, subnet_id, 50, 50),
+            db_weight.reads_writes(4, 1)
+        );
+        assert_eq!(
+            Network::decrease_account_node_delegate_stake_shares(
+                &account_id,
+                subnet_id,
+                subnet_node_id,
+                50,
+                50
+            ),
+            db_weight.reads_writes(4, 1)
+        );
+
+        // Later changes only check for it
+        assert_eq!(
+            Network::decrease_account_delegate_stake(&account_id, subnet_id, 50, 50),
+            db_weight.reads(3)
+        );
+        assert_eq!(
+            Network::decrease_account_node_delegate_stake_shares(
+                &account_id,
+                subnet_id,
+                subnet_node_id,
+                50,
+                50
+            ),
+            db_weight.reads(3)
+        );
+    });
+}
+
+#[test]
+fn test_reward_history_cleanup() {
+    new_test_ext().execute_with(|| {
+        let account_id = account(1);
+        let subnet_id = 1;
+
+        for epoch in 0..20 {
+            AccountRewardHistory::<Test>::insert(
+                epoch,
+                &account_id,
+                AccountEpochRewards {
+                    node_stake_rewards: 1,
+                    ..AccountEpochRewards::default()
+                },
+            );
+            SubnetDelegateRewardHistory::<Test>::insert(
+                epoch,
+                subnet_id,
+                DelegatePoolEpochRewards::default(),
+            );
+            NodeDelegateRewardHistory::<Test>::insert(
+                epoch,
+                (subnet_id, 1),
+                DelegatePoolEpochRewards::default(),
+            );
+            AccountSubnetDelegateSharesHistory::<Test>::insert((epoch, &account_id, subnet_id), 1);
+            AccountNodeDelegateSharesHistory::<Test>::insert(
+                (epoch, &account_id, (subnet_id, 1)),
+                1,
+            );
+        }
+
+        // Not pruned until enabled
+        Network::enqueue_reward_history_cleanup(20);
+        drain_cleanup_queue();
+        assert_eq!(AccountRewardHistory::<Test>::iter().count(), 20);
+
+        RewardHistoryDepth::<Test>::set(5);
+        RewardHistoryPruneEpoch::<Test>::put(0);
+
+        // At most `MAX_REWARD_HISTORY_PRUNE_EPOCHS` epochs are queued at once
+        let max_epochs = Network::MAX_REWARD_HISTORY_PRUNE_EPOCHS;
+        Network::enqueue_reward_history_cleanup(20);
+        drain_cleanup_queue();
+        assert_eq!(RewardHistoryPruneEpoch::<Test>::get(), Some(max_epochs));
+        for epoch in 0..20 {
+            assert_eq!(
+                AccountRewardHistory::<Test>::contains_key(epoch, &account_id),
+                epoch >= max_epochs
+            );
+            assert_eq!(
+                SubnetDelegateRewardHistory::<Test>::contains_key(epoch, subnet_id),
+                epoch >= max_epochs
+            );
+            assert_eq!(
+                NodeDelegateRewardHistory::<Test>::contains_key(epoch, (subnet_id, 1)),
+                epoch >= max_epochs
+            );
+            assert_eq!(
+                AccountSubnetDelegateSharesHistory::<Test>::contains_key((
+                    epoch,
+                    &account_id,
+                    subnet_id
+                )),
+                epoch >= max_epochs
+            );
+        }
+
+        Network::enqueue_reward_history_cleanup(20);
+        drain_cleanup_queue();
+        assert_eq!(RewardHistoryPruneEpoch::<Test>::get(), Some(15));
+        assert_eq!(AccountRewardHistory::<Test>::iter().count(), 5);
+
+        // Disabling prunes everything and stops pruning
+        RewardHistoryDepth::<Test>::set(0);
+        Network::enqueue_reward_history_cleanup(20);
+        drain_cleanup_queue();
+        assert_eq!(RewardHistoryPruneEpoch::<Test>::get(), None);
+        assert_eq!(AccountRewardHistory::<Test>::iter().count(), 0);
+        assert_eq!(SubnetDelegateRewardHistory::<Test>::iter().count(), 0);
+        assert_eq!(NodeDelegateRewardHistory::<Test>::iter().count(), 0);
+        assert_eq!(
+            AccountSubnetDelegateSharesHistory::<Test>::iter().count(),
+            0
+        );
+        assert_eq!(AccountNodeDelegateSharesHistory::<Test>::iter().count(), 0);
+    });
+}
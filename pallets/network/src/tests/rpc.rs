@@ -4,10 +4,14 @@ use crate::Event;
 use crate::{
     MaxSubnetNodes, MaxSubnets, MinSubnetMinStake, SubnetElectedValidator, SubnetName,
     SubnetNodeClass, TotalActiveSubnets, PeerIdOverwatchNodeId, SubnetBootnodesV2,
-    DefaultMaxVectorLength,
+    DefaultMaxVectorLength, SubnetNodeFilter, ConsensusSubmissionArchive, ConsensusSubmissionData,
+    DistributionData, FinalSubnetEmissionWeights, SubnetNodeConsensusData, SubnetNodesData,
+    TotalNodeDelegateStakeBalance, TotalSubnetDelegateStakeBalance, TotalSubnetNodeUids,
+    TotalDelegateStake,
 };
+use crate::rpc_info::info::MAX_RPC_PAGE_SCAN;
 use frame_support::assert_ok;
-use frame_support::traits::Currency;
+use frame_support::traits::{Currency, Get};
 use sp_runtime::BoundedVec;
 use sp_std::collections::btree_map::BTreeMap;
 
@@ -407,3 +411,447 @@ fn test_get_node_delegate_stakes() {
         );
     })
 }
+
+#[test]
+fn test_subnet_info_json_round_trip() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "test-subnet".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet(subnet_name.clone(), 0, 4, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        let mut subnet_info = Network::get_subnet_info(subnet_id).unwrap();
+        // Account keyed maps are serialized as pairs since JSON objects only allow string keys
+        subnet_info.initial_coldkeys = Some(BTreeMap::from([(account(1), 1), (account(2), 2)]));
+
+        let json = serde_json::to_string(&subnet_info).unwrap();
+        let decoded: crate::SubnetInfo<AccountIdOf<Test>> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded, subnet_info);
+
+        // Amounts are decimal strings and text is a string
+        let value = serde_json::to_value(&subnet_info).unwrap();
+        assert_eq!(value["min_stake"], serde_json::json!(subnet_info.min_stake.to_string()));
+        assert_eq!(
+            value["total_subnet_delegate_stake_balance"],
+            serde_json::json!(subnet_info.total_subnet_delegate_stake_balance.to_string())
+        );
+        assert_eq!(value["name"], serde_json::json!("test-subnet"));
+    })
+}
+
+#[test]
+fn test_subnet_nodes_info_json_round_trip() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "test-subnet".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet(subnet_name.clone(), 0, 4, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        let subnet_nodes_info = Network::get_subnet_nodes_info(subnet_id);
+        assert!(subnet_nodes_info.len() > 0);
+
+        let json = serde_json::to_string(&subnet_nodes_info).unwrap();
+        let decoded: Vec<crate::SubnetNodeInfo<AccountIdOf<Test>>> =
+            serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded, subnet_nodes_info);
+
+        // Amounts are decimal strings and peer IDs are their base58 string
+        let info = &subnet_nodes_info[0];
+        let value = serde_json::to_value(info).unwrap();
+        assert_eq!(value["stake_balance"], serde_json::json!(info.stake_balance.to_string()));
+        assert_eq!(
+            value["peer_id"],
+            serde_json::json!(String::from_utf8(info.peer_id.0.clone()).unwrap())
+        );
+    })
+}
+
+#[test]
+fn test_get_subnet_nodes_info_paged() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "test-subnet".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet(subnet_name.clone(), 0, 12, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        let mut expected: Vec<u32> = Network::get_subnet_nodes_info(subnet_id)
+            .iter()
+            .map(|info| info.subnet_node_id)
+            .collect();
+        expected.sort();
+
+        let limit = 5;
+        let mut subnet_node_ids: Vec<u32> = Vec::new();
+        let mut start = 0;
+        loop {
+            let page = Network::get_subnet_nodes_info_paged(
+                subnet_id,
+                start,
+                limit,
+                SubnetNodeFilter::default(),
+            );
+            assert!(page.items.len() as u32 <= limit);
+            subnet_node_ids.extend(page.items.iter().map(|info| info.subnet_node_id));
+            match page.next_cursor {
+                Some(next_cursor) => start = next_cursor,
+                None => break,
+            }
+        }
+
+        assert_eq!(subnet_node_ids, expected);
+    })
+}
+
+#[test]
+fn test_get_subnet_nodes_info_paged_filters() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "test-subnet".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet(subnet_name.clone(), 0, 12, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        // Give one node a bootnode so both `has_bootnode` filters match something
+        let bootnode_node = Network::get_subnet_nodes_info(subnet_id)[0].clone();
+        let bootnode: BoundedVec<u8, DefaultMaxVectorLength> =
+            b"/ip4/127.0.0.1/tcp/31330".to_vec().try_into().unwrap();
+        assert_ok!(Network::update_bootnode(
+            RuntimeOrigin::signed(bootnode_node.coldkey.clone()),
+            subnet_id,
+            bootnode_node.subnet_node_id,
+            Some(bootnode)
+        ));
+
+        let all_nodes = Network::get_subnet_nodes_info(subnet_id);
+        let coldkey = all_nodes[0].coldkey.clone();
+
+        let filter = SubnetNodeFilter {
+            coldkey: Some(coldkey.clone()),
+            ..Default::default()
+        };
+        let page = Network::get_subnet_nodes_info_paged(subnet_id, 0, 256, filter);
+        assert_eq!(
+            page.items.len(),
+            all_nodes.iter().filter(|info| info.coldkey == coldkey).count()
+        );
+        assert!(page.items.iter().all(|info| info.coldkey == coldkey));
+
+        let filter = SubnetNodeFilter {
+            min_class: Some(SubnetNodeClass::Validator),
+            ..Default::default()
+        };
+        let page = Network::get_subnet_nodes_info_paged(subnet_id, 0, 256, filter);
+        assert_eq!(
+            page.items.len(),
+            all_nodes
+                .iter()
+                .filter(|info| info.classification.node_class >= SubnetNodeClass::Validator)
+                .count()
+        );
+
+        let filter = SubnetNodeFilter {
+            has_bootnode: Some(false),
+            ..Default::default()
+        };
+        let page = Network::get_subnet_nodes_info_paged(subnet_id, 0, 256, filter);
+        assert_eq!(page.items.len(), all_nodes.len() - 1);
+        assert!(page.items.iter().all(|info| info.bootnode.is_none()));
+
+        let filter = SubnetNodeFilter {
+            has_bootnode: Some(true),
+            ..Default::default()
+        };
+        let page = Network::get_subnet_nodes_info_paged(subnet_id, 0, 256, filter);
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].subnet_node_id, bootnode_node.subnet_node_id);
+
+        let filter = SubnetNodeFilter {
+            min_reputation: Some(u128::MAX),
+            ..Default::default()
+        };
+        let page = Network::get_subnet_nodes_info_paged(subnet_id, 0, 256, filter);
+        assert_eq!(page.items.len(), 0);
+        assert_eq!(page.next_cursor, None);
+    })
+}
+
+#[test]
+fn test_get_subnet_nodes_info_paged_scan_budget() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "test-subnet".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet(subnet_name.clone(), 0, 4, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        // A filter matching nothing visits at most `MAX_RPC_PAGE_SCAN` node IDs per page
+        let total_nodes = TotalSubnetNodeUids::<Test>::get(subnet_id);
+        TotalSubnetNodeUids::<Test>::insert(subnet_id, total_nodes + 2 * MAX_RPC_PAGE_SCAN);
+
+        let filter = SubnetNodeFilter {
+            min_reputation: Some(u128::MAX),
+            ..Default::default()
+        };
+
+        let page = Network::get_subnet_nodes_info_paged(subnet_id, 0, 256, filter.clone());
+        assert_eq!(page.items.len(), 0);
+        assert_eq!(page.next_cursor, Some(1 + MAX_RPC_PAGE_SCAN));
+
+        let page = Network::get_subnet_nodes_info_paged(
+            subnet_id,
+            page.next_cursor.unwrap(),
+            256,
+            filter.clone(),
+        );
+        assert_eq!(page.items.len(), 0);
+        assert_eq!(page.next_cursor, Some(1 + 2 * MAX_RPC_PAGE_SCAN));
+
+        let page = Network::get_subnet_nodes_info_paged(
+            subnet_id,
+            page.next_cursor.unwrap(),
+            256,
+            filter.clone(),
+        );
+        assert_eq!(page.items.len(), 0);
+        assert_eq!(page.next_cursor, None);
+
+        // Visiting each subnet ID costs a scan and the cursor resumes inside the subnet
+        let page = Network::get_all_subnet_nodes_info_paged((0, 0), 256, filter);
+        assert_eq!(page.items.len(), 0);
+        assert_eq!(
+            page.next_cursor,
+            Some((subnet_id, 1 + MAX_RPC_PAGE_SCAN - subnet_id))
+        );
+
+        TotalSubnetNodeUids::<Test>::insert(subnet_id, total_nodes);
+    })
+}
+
+#[test]
+fn test_get_all_subnet_nodes_info_paged() {
+    new_test_ext().execute_with(|| {
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet("subnet-name".into(), 0, 4, deposit_amount, stake_amount);
+        build_activated_subnet("subnet-name-2".into(), 0, 4, deposit_amount, stake_amount);
+
+        let expected = Network::get_all_subnet_nodes_info().len();
+
+        let limit = 3;
+        let mut subnet_node_keys: Vec<(u32, u32)> = Vec::new();
+        let mut start = (0, 0);
+        loop {
+            let page =
+                Network::get_all_subnet_nodes_info_paged(start, limit, SubnetNodeFilter::default());
+            assert!(page.items.len() as u32 <= limit);
+            subnet_node_keys.extend(
+                page.items
+                    .iter()
+                    .map(|info| (info.subnet_id, info.subnet_node_id)),
+            );
+            match page.next_cursor {
+                Some(next_cursor) => start = next_cursor,
+                None => break,
+            }
+        }
+
+        let mut sorted = subnet_node_keys.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted, subnet_node_keys);
+        assert_eq!(subnet_node_keys.len(), expected);
+    })
+}
+
+#[test]
+fn test_get_all_subnets_info_paged() {
+    new_test_ext().execute_with(|| {
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet("subnet-name".into(), 0, 4, deposit_amount, stake_amount);
+        build_activated_subnet("subnet-name-2".into(), 0, 4, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(Vec::from("subnet-name")).unwrap();
+        let subnet_id_2 = SubnetName::<Test>::get(Vec::from("subnet-name-2")).unwrap();
+
+        let page = Network::get_all_subnets_info_paged(0, 1);
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].id, subnet_id);
+        assert_eq!(page.next_cursor, Some(subnet_id + 1));
+
+        let page = Network::get_all_subnets_info_paged(page.next_cursor.unwrap(), 1);
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].id, subnet_id_2);
+        assert_eq!(page.next_cursor, None);
+    })
+}
+
+#[test]
+fn test_estimate_subnet_epoch_rewards() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "test-subnet".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet(subnet_name.clone(), 0, 4, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        // No emission weights for the current epoch
+        assert_eq!(Network::estimate_subnet_epoch_rewards(subnet_id), None);
+        assert_eq!(Network::estimate_delegate_apr(subnet_id), 0);
+
+        let validator_emissions: u128 = 1000000000000000000000;
+        let subnet_weight: u128 = 500000000000000000;
+        FinalSubnetEmissionWeights::<Test>::insert(
+            Network::get_current_epoch_as_u32(),
+            DistributionData {
+                validator_emissions,
+                weights: BTreeMap::from([(subnet_id, subnet_weight)]),
+            },
+        );
+
+        let (expected, _) =
+            Network::calculate_rewards(subnet_id, validator_emissions, subnet_weight);
+        let estimate = Network::estimate_subnet_epoch_rewards(subnet_id).unwrap();
+        assert_eq!(estimate, expected);
+        assert!(estimate.delegate_stake_rewards > 0);
+
+        let json = serde_json::to_string(&estimate).unwrap();
+        let decoded: crate::RewardsData = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, estimate);
+        assert!(json.contains(&format!(
+            "\"delegate_stake_rewards\":\"{}\"",
+            estimate.delegate_stake_rewards
+        )));
+
+        // Not in the emission weights
+        assert_eq!(Network::estimate_subnet_epoch_rewards(subnet_id + 1), None);
+    })
+}
+
+#[test]
+fn test_estimate_delegate_apr() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "test-subnet".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet(subnet_name.clone(), 0, 4, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        FinalSubnetEmissionWeights::<Test>::insert(
+            Network::get_current_epoch_as_u32(),
+            DistributionData {
+                validator_emissions: 1000000000000000000000,
+                weights: BTreeMap::from([(subnet_id, 1000000000000000000)]),
+            },
+        );
+
+        let total_balance = TotalSubnetDelegateStakeBalance::<Test>::get(subnet_id);
+        assert!(total_balance > 0);
+
+        let estimate = Network::estimate_subnet_epoch_rewards(subnet_id).unwrap();
+        let yearly_rewards =
+            estimate.delegate_stake_rewards * <Test as crate::Config>::EpochsPerYear::get() as u128;
+
+        assert_eq!(
+            Network::estimate_delegate_apr(subnet_id),
+            Network::percent_div(yearly_rewards, total_balance)
+        );
+
+        // Doubling the pool halves the APR
+        Network::do_increase_delegate_stake(subnet_id, total_balance);
+        assert_eq!(
+            Network::estimate_delegate_apr(subnet_id),
+            Network::percent_div(yearly_rewards, total_balance * 2)
+        );
+
+        // No delegate stake
+        TotalSubnetDelegateStakeBalance::<Test>::insert(subnet_id, 0);
+        TotalDelegateStake::<Test>::mutate(|n| *n -= total_balance * 2);
+        assert_eq!(Network::estimate_delegate_apr(subnet_id), 0);
+    })
+}
+
+#[test]
+fn test_estimate_node_delegate_apr() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "test-subnet".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet(subnet_name.clone(), 0, 4, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+        let subnet_node_id = 1;
+
+        FinalSubnetEmissionWeights::<Test>::insert(
+            Network::get_current_epoch_as_u32(),
+            DistributionData {
+                validator_emissions: 1000000000000000000000,
+                weights: BTreeMap::from([(subnet_id, 1000000000000000000)]),
+            },
+        );
+
+        let delegate_reward_rate: u128 = 100000000000000000;
+        SubnetNodesData::<Test>::mutate(subnet_id, subnet_node_id, |subnet_node| {
+            subnet_node.delegate_reward_rate = delegate_reward_rate;
+        });
+        let total_balance: u128 = 1000000000000000000000;
+        TotalNodeDelegateStakeBalance::<Test>::insert(subnet_id, subnet_node_id, total_balance);
+
+        // No consensus submission to score the node from
+        assert_eq!(Network::estimate_node_delegate_apr(subnet_id, subnet_node_id), 0);
+
+        let data = vec![
+            SubnetNodeConsensusData {
+                subnet_node_id,
+                score: 1000000000000000000,
+            },
+            SubnetNodeConsensusData {
+                subnet_node_id: 2,
+                score: 3000000000000000000,
+            },
+        ];
+        ConsensusSubmissionArchive::<Test>::insert(
+            subnet_id,
+            Network::get_current_subnet_epoch_as_u32(subnet_id),
+            ConsensusSubmissionData {
+                weight_sum: 4000000000000000000,
+                data_length: data.len() as u32,
+                data,
+                ..ConsensusSubmissionData::default()
+            },
+        );
+
+        let estimate = Network::estimate_subnet_epoch_rewards(subnet_id).unwrap();
+        // Node has 25% of the scores
+        let node_reward = Network::percent_mul(estimate.subnet_node_rewards, 250000000000000000);
+        let node_delegate_reward = Network::percent_mul(node_reward, delegate_reward_rate);
+        let yearly_rewards =
+            node_delegate_reward * <Test as crate::Config>::EpochsPerYear::get() as u128;
+
+        let apr = Network::estimate_node_delegate_apr(subnet_id, subnet_node_id);
+        assert!(apr > 0);
+        assert_eq!(apr, Network::percent_div(yearly_rewards, total_balance));
+
+        // Node not in the consensus data
+        assert_eq!(Network::estimate_node_delegate_apr(subnet_id, 3), 0);
+
+        // Node not sharing rewards with delegate stakers
+        SubnetNodesData::<Test>::mutate(subnet_id, subnet_node_id, |subnet_node| {
+            subnet_node.delegate_reward_rate = 0;
+        });
+        assert_eq!(Network::estimate_node_delegate_apr(subnet_id, subnet_node_id), 0);
+    })
+}
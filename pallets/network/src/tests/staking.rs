@@ -716,6 +716,7 @@ fn test_remove_stake_after_remove_subnet() {
         let _ = Balances::deposit_creating(&coldkey.clone(), deposit_amount);
 
         Network::do_remove_subnet(subnet_id, SubnetRemovalReason::MinSubnetDelegateStake);
+        drain_cleanup_queue();
 
         assert_eq!(SubnetsData::<Test>::contains_key(subnet_id), false);
         assert_eq!(
@@ -790,10 +791,8 @@ fn test_remove_stake_after_remove_subnet_twice() {
         Network::do_remove_subnet(subnet_id, SubnetRemovalReason::MinSubnetDelegateStake);
 
         assert_eq!(SubnetsData::<Test>::contains_key(subnet_id), false);
-        assert_eq!(
-            HotkeySubnetNodeId::<Test>::try_get(subnet_id, &hotkey),
-            Err(())
-        );
+        // Node data is still queued for cleanup, the node can unstake regardless
+        assert!(HotkeySubnetNodeId::<Test>::try_get(subnet_id, &hotkey).is_ok());
 
         let epoch_length = EpochLength::get();
         let min_required_unstake_epochs = StakeCooldownEpochs::<Test>::get();
@@ -1,16 +1,19 @@
 use super::mock::*;
 use crate::tests::test_utils::*;
+use crate::Error;
 use crate::Event;
 use crate::{
-    AccountNodeDelegateStakeShares, AccountSubnetDelegateStakeShares, DelegateStakeCooldownEpochs,
-    HotkeySubnetNodeId, MaxSubnetNodes, MaxSubnets, MinSubnetMinStake, NextSwapQueueId,
-    QueuedSwapCall, QueuedSwapItem, StakeUnbondingLedger, SubnetName, SwapCallQueue,
-    SwapQueueOrder, TotalNodeDelegateStakeBalance, TotalNodeDelegateStakeShares,
-    TotalSubnetDelegateStakeBalance, TotalSubnetDelegateStakeShares,
+    AccountNodeDelegateStakeShares, AccountSubnetDelegateStakeShares, AccountSwapQueueIds,
+    DelegateStakeCooldownEpochs, HotkeySubnetNodeId, MaxPendingSwapsPerAccount, MaxSubnetNodes,
+    MaxSubnets, MaxSwapQueueCallsPerBlock, MinSubnetMinStake, NextSwapQueueId,
+    NodeDelegateStakeCooldownEpochs, QueuedSwapCall, QueuedSwapItem, StakeUnbondingLedger,
+    SubnetName, SwapCallQueue, SwapQueueOrder, TotalNodeDelegateStakeBalance,
+    TotalNodeDelegateStakeShares, TotalSubnetDelegateStakeBalance, TotalSubnetDelegateStakeShares,
 };
-use frame_support::assert_ok;
 use frame_support::traits::Currency;
 use frame_support::weights::WeightMeter;
+use frame_support::BoundedVec;
+use frame_support::{assert_err, assert_ok};
 
 //
 //
@@ -32,7 +35,7 @@ fn insert_to_subnet_swap_call_queue(account_id: AccountIdOf<Test>, subnet_id: u3
     let id = NextSwapQueueId::<Test>::get();
 
     let call = QueuedSwapCall::SwapToSubnetDelegateStake {
-        account_id: account_id,
+        account_id: account_id.clone(),
         to_subnet_id: subnet_id,
         balance: balance,
     };
@@ -52,6 +55,10 @@ fn insert_to_subnet_swap_call_queue(account_id: AccountIdOf<Test>, subnet_id: u3
         let _ = queue.try_push(id); // Handle error if queue is full
     });
 
+    AccountSwapQueueIds::<Test>::mutate(&account_id, |ids| {
+        ids.insert(id);
+    });
+
     NextSwapQueueId::<Test>::mutate(|next_id| *next_id = next_id.saturating_add(1));
 }
 
@@ -64,7 +71,7 @@ fn insert_to_node_swap_call_queue(
     let id = NextSwapQueueId::<Test>::get();
 
     let call = QueuedSwapCall::SwapToNodeDelegateStake {
-        account_id: account_id,
+        account_id: account_id.clone(),
         to_subnet_id: subnet_id,
         to_subnet_node_id: subnet_node_id,
         balance: balance,
@@ -85,6 +92,10 @@ fn insert_to_node_swap_call_queue(
         let _ = queue.try_push(id); // Handle error if queue is full
     });
 
+    AccountSwapQueueIds::<Test>::mutate(&account_id, |ids| {
+        ids.insert(id);
+    });
+
     NextSwapQueueId::<Test>::mutate(|next_id| *next_id = next_id.saturating_add(1));
 }
 
@@ -647,3 +658,352 @@ fn test_execute_ready_swap_calls() {
         }
     });
 }
+
+#[test]
+fn test_cancel_swap_queue_item_subnet_delegate_stake() {
+    new_test_ext().execute_with(|| {
+        let deposit_amount: u128 = 10000000000000000000000;
+        let amount: u128 = 1000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        build_activated_subnet(subnet_name.clone(), 0, 0, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        let n_account = 255;
+
+        let id = NextSwapQueueId::<Test>::get();
+        insert_to_subnet_swap_call_queue(account(n_account), subnet_id, amount);
+        insert_to_subnet_swap_call_queue(account(n_account + 1), subnet_id, amount);
+
+        assert_eq!(SwapQueueOrder::<Test>::get().len(), 2);
+
+        let block = Network::get_current_block_as_u32();
+
+        assert_ok!(Network::cancel_swap_queue_item(
+            RuntimeOrigin::signed(account(n_account)),
+            id,
+        ));
+
+        assert_eq!(
+            *network_events().last().unwrap(),
+            Event::SwapCallQueueCancelled {
+                id,
+                account_id: account(n_account),
+                balance: amount,
+            }
+        );
+
+        // Removed from the queue, other items stay in order
+        assert!(SwapCallQueue::<Test>::get(id).is_none());
+        let queue = SwapQueueOrder::<Test>::get();
+        assert_eq!(queue.len(), 1);
+        assert_eq!(queue[0], id + 1);
+
+        // Balance moved into the unbonding ledger with the delegate stake cooldown
+        let claim_block = block + DelegateStakeCooldownEpochs::<Test>::get() * EpochLength::get();
+        let ledger = StakeUnbondingLedger::<Test>::get(account(n_account));
+        assert_eq!(ledger.len(), 1);
+        assert_eq!(ledger.get(&claim_block), Some(&amount));
+
+        // Cannot cancel twice
+        assert_err!(
+            Network::cancel_swap_queue_item(RuntimeOrigin::signed(account(n_account)), id),
+            Error::<Test>::SwapCallNotFound
+        );
+
+        // Claimable after the cooldown
+        System::set_block_number(claim_block);
+        let starting_balance = Balances::free_balance(&account(n_account));
+        assert_ok!(Network::claim_unbondings(RuntimeOrigin::signed(account(
+            n_account
+        ))));
+        assert_eq!(
+            Balances::free_balance(&account(n_account)),
+            starting_balance + amount
+        );
+        assert!(StakeUnbondingLedger::<Test>::get(account(n_account)).is_empty());
+    });
+}
+
+#[test]
+fn test_cancel_swap_queue_item_node_delegate_stake() {
+    new_test_ext().execute_with(|| {
+        let deposit_amount: u128 = 10000000000000000000000;
+        let amount: u128 = 1000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+        let max_subnets = MaxSubnets::<Test>::get();
+        let max_subnet_nodes = MaxSubnetNodes::<Test>::get();
+        let end = 4;
+
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        build_activated_subnet(subnet_name.clone(), 0, end, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        let hotkey = get_hotkey(subnet_id, max_subnet_nodes, max_subnets, end - 1);
+        let subnet_node_id = HotkeySubnetNodeId::<Test>::get(subnet_id, hotkey).unwrap();
+
+        let n_account = 255;
+
+        let id = NextSwapQueueId::<Test>::get();
+        insert_to_node_swap_call_queue(account(n_account), subnet_id, subnet_node_id, amount);
+
+        let block = Network::get_current_block_as_u32();
+
+        assert_ok!(Network::cancel_swap_queue_item(
+            RuntimeOrigin::signed(account(n_account)),
+            id,
+        ));
+
+        assert!(SwapCallQueue::<Test>::get(id).is_none());
+        assert!(SwapQueueOrder::<Test>::get().is_empty());
+
+        // Balance moved into the unbonding ledger with the node delegate stake cooldown
+        let claim_block =
+            block + NodeDelegateStakeCooldownEpochs::<Test>::get() * EpochLength::get();
+        let ledger = StakeUnbondingLedger::<Test>::get(account(n_account));
+        assert_eq!(ledger.get(&claim_block), Some(&amount));
+
+        // Nothing left to execute
+        System::set_block_number(System::block_number() + EpochLength::get() + 1);
+        let _ = Network::execute_ready_swap_calls(System::block_number(), &mut WeightMeter::new());
+        assert_eq!(
+            AccountNodeDelegateStakeShares::<Test>::get((
+                &account(n_account),
+                subnet_id,
+                subnet_node_id
+            )),
+            0
+        );
+    });
+}
+
+#[test]
+fn test_cancel_swap_queue_item_not_key_owner() {
+    new_test_ext().execute_with(|| {
+        let deposit_amount: u128 = 10000000000000000000000;
+        let amount: u128 = 1000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        build_activated_subnet(subnet_name.clone(), 0, 0, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        let n_account = 255;
+
+        let id = NextSwapQueueId::<Test>::get();
+        insert_to_subnet_swap_call_queue(account(n_account), subnet_id, amount);
+
+        assert_err!(
+            Network::cancel_swap_queue_item(RuntimeOrigin::signed(account(n_account + 1)), id),
+            Error::<Test>::NotKeyOwner
+        );
+
+        assert_err!(
+            Network::cancel_swap_queue_item(RuntimeOrigin::signed(account(n_account)), id + 1),
+            Error::<Test>::SwapCallNotFound
+        );
+
+        assert!(SwapCallQueue::<Test>::get(id).is_some());
+        assert_eq!(SwapQueueOrder::<Test>::get().len(), 1);
+        assert!(StakeUnbondingLedger::<Test>::get(account(n_account + 1)).is_empty());
+    });
+}
+
+#[test]
+fn test_queue_swap_queue_full() {
+    new_test_ext().execute_with(|| {
+        let deposit_amount: u128 = 10000000000000000000000;
+        let amount: u128 = 1000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        let from_subnet_name: Vec<u8> = "subnet-name".into();
+        build_activated_subnet(from_subnet_name.clone(), 0, 0, deposit_amount, stake_amount);
+        let from_subnet_id = SubnetName::<Test>::get(from_subnet_name.clone()).unwrap();
+
+        let to_subnet_name: Vec<u8> = "subnet-name-2".into();
+        build_activated_subnet(to_subnet_name.clone(), 0, 0, deposit_amount, stake_amount);
+        let to_subnet_id = SubnetName::<Test>::get(to_subnet_name.clone()).unwrap();
+
+        let n_account = 255;
+
+        let _ = Balances::deposit_creating(&account(n_account), amount + 500);
+
+        System::set_block_number(
+            System::block_number()
+                + DelegateStakeCooldownEpochs::<Test>::get() * EpochLength::get(),
+        );
+
+        assert_ok!(Network::add_to_delegate_stake(
+            RuntimeOrigin::signed(account(n_account)),
+            from_subnet_id,
+            amount,
+        ));
+
+        let delegate_shares =
+            AccountSubnetDelegateStakeShares::<Test>::get(account(n_account), from_subnet_id);
+
+        // Fill the queue
+        SwapQueueOrder::<Test>::put(BoundedVec::truncate_from(
+            (1000..2000).collect::<Vec<u32>>(),
+        ));
+
+        let next_id = NextSwapQueueId::<Test>::get();
+
+        assert_err!(
+            Network::swap_delegate_stake(
+                RuntimeOrigin::signed(account(n_account)),
+                from_subnet_id,
+                to_subnet_id,
+                delegate_shares,
+            ),
+            Error::<Test>::SwapQueueFull
+        );
+
+        // Nothing was removed or queued
+        assert_eq!(
+            AccountSubnetDelegateStakeShares::<Test>::get(account(n_account), from_subnet_id),
+            delegate_shares
+        );
+        assert!(SwapCallQueue::<Test>::get(next_id).is_none());
+        assert_eq!(NextSwapQueueId::<Test>::get(), next_id);
+        assert!(AccountSwapQueueIds::<Test>::get(account(n_account)).is_empty());
+    });
+}
+
+#[test]
+fn test_queue_swap_max_pending_swaps_per_account() {
+    new_test_ext().execute_with(|| {
+        let deposit_amount: u128 = 10000000000000000000000;
+        let amount: u128 = 1000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        let from_subnet_name: Vec<u8> = "subnet-name".into();
+        build_activated_subnet(from_subnet_name.clone(), 0, 0, deposit_amount, stake_amount);
+        let from_subnet_id = SubnetName::<Test>::get(from_subnet_name.clone()).unwrap();
+
+        let to_subnet_name: Vec<u8> = "subnet-name-2".into();
+        build_activated_subnet(to_subnet_name.clone(), 0, 0, deposit_amount, stake_amount);
+        let to_subnet_id = SubnetName::<Test>::get(to_subnet_name.clone()).unwrap();
+
+        let n_account = 255;
+
+        let _ = Balances::deposit_creating(&account(n_account), amount + 500);
+
+        System::set_block_number(
+            System::block_number()
+                + DelegateStakeCooldownEpochs::<Test>::get() * EpochLength::get(),
+        );
+
+        assert_ok!(Network::add_to_delegate_stake(
+            RuntimeOrigin::signed(account(n_account)),
+            from_subnet_id,
+            amount,
+        ));
+
+        MaxPendingSwapsPerAccount::<Test>::put(1);
+
+        let delegate_shares =
+            AccountSubnetDelegateStakeShares::<Test>::get(account(n_account), from_subnet_id);
+        let id = NextSwapQueueId::<Test>::get();
+
+        assert_ok!(Network::swap_delegate_stake(
+            RuntimeOrigin::signed(account(n_account)),
+            from_subnet_id,
+            to_subnet_id,
+            delegate_shares / 2,
+        ));
+
+        let account_queue_ids = AccountSwapQueueIds::<Test>::get(account(n_account));
+        assert_eq!(account_queue_ids.len(), 1);
+        assert!(account_queue_ids.contains(&id));
+
+        assert_err!(
+            Network::swap_delegate_stake(
+                RuntimeOrigin::signed(account(n_account)),
+                from_subnet_id,
+                to_subnet_id,
+                delegate_shares / 2,
+            ),
+            Error::<Test>::MaxPendingSwapsPerAccountReached
+        );
+
+        // Executing the swap frees up the account
+        System::set_block_number(System::block_number() + EpochLength::get() + 1);
+        let _ = Network::execute_ready_swap_calls(System::block_number(), &mut WeightMeter::new());
+        assert!(SwapCallQueue::<Test>::get(id).is_none());
+        assert!(AccountSwapQueueIds::<Test>::get(account(n_account)).is_empty());
+
+        assert_ok!(Network::swap_delegate_stake(
+            RuntimeOrigin::signed(account(n_account)),
+            from_subnet_id,
+            to_subnet_id,
+            delegate_shares / 2,
+        ));
+        assert_eq!(
+            AccountSwapQueueIds::<Test>::get(account(n_account)).len(),
+            1
+        );
+    });
+}
+
+#[test]
+fn test_get_account_swap_queue() {
+    new_test_ext().execute_with(|| {
+        let deposit_amount: u128 = 10000000000000000000000;
+        let amount: u128 = 1000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        build_activated_subnet(subnet_name.clone(), 0, 0, deposit_amount, stake_amount);
+        let subnet_id = SubnetName::<Test>::get(subnet_name.clone()).unwrap();
+
+        let n_account = 255;
+
+        assert!(Network::get_account_swap_queue(account(n_account)).is_empty());
+
+        MaxSwapQueueCallsPerBlock::<Test>::put(1);
+
+        let id = NextSwapQueueId::<Test>::get();
+        insert_to_subnet_swap_call_queue(account(n_account), subnet_id, amount);
+        insert_to_subnet_swap_call_queue(account(n_account + 1), subnet_id, amount);
+        insert_to_subnet_swap_call_queue(account(n_account), subnet_id, amount);
+
+        let block = Network::get_current_block_as_u32();
+
+        let queued_swaps = Network::get_account_swap_queue(account(n_account));
+        assert_eq!(queued_swaps.len(), 2);
+
+        assert_eq!(queued_swaps[0].id, id);
+        assert_eq!(queued_swaps[0].position, 0);
+        assert_eq!(
+            queued_swaps[0].expected_execution_block,
+            block + EpochLength::get()
+        );
+
+        // One call per block, the other accounts call is executed in between
+        assert_eq!(queued_swaps[1].id, id + 2);
+        assert_eq!(queued_swaps[1].position, 2);
+        assert_eq!(
+            queued_swaps[1].expected_execution_block,
+            block + EpochLength::get() + 2
+        );
+
+        // Executed in the expected blocks
+        let _ = Network::execute_ready_swap_calls(
+            block + EpochLength::get() - 1,
+            &mut WeightMeter::new(),
+        );
+        assert_eq!(SwapQueueOrder::<Test>::get().len(), 3);
+
+        for n in 0..3 {
+            let _ = Network::execute_ready_swap_calls(
+                block + EpochLength::get() + n,
+                &mut WeightMeter::new(),
+            );
+            assert_eq!(SwapQueueOrder::<Test>::get().len(), 2 - n as usize);
+        }
+
+        assert!(Network::get_account_swap_queue(account(n_account)).is_empty());
+    });
+}
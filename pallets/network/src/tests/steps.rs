@@ -150,10 +150,7 @@ fn test_do_epoch_preliminaries_remove_under_min_delegate_stake() {
         // ensure removal subnet has less than minimum dstake
         let min_subnet_delegate_stake =
             Network::get_min_subnet_delegate_stake_balance(remove_subnet_id);
-        TotalSubnetDelegateStakeBalance::<Test>::insert(
-            remove_subnet_id,
-            min_subnet_delegate_stake - 1,
-        );
+        set_delegate_stake(remove_subnet_id, min_subnet_delegate_stake - 1);
 
         let current_epoch = Network::get_current_epoch_as_u32();
         Network::do_epoch_preliminaries(
@@ -218,10 +215,7 @@ fn test_do_epoch_preliminaries_remove_under_min_delegate_stake_fail() {
         // ensure removal subnet has less than minimum dstake
         let min_subnet_delegate_stake =
             Network::get_min_subnet_delegate_stake_balance(remove_subnet_id);
-        TotalSubnetDelegateStakeBalance::<Test>::insert(
-            remove_subnet_id,
-            min_subnet_delegate_stake - 1,
-        );
+        set_delegate_stake(remove_subnet_id, min_subnet_delegate_stake - 1);
 
         let current_epoch = Network::get_current_epoch_as_u32();
         Network::do_epoch_preliminaries(
@@ -326,7 +320,7 @@ fn test_do_epoch_preliminaries_remove_max_subnets() {
 
             if s + 1 != max_subnets.saturating_add(1) {
                 // Force all non-last subnets to be > last subnet
-                TotalSubnetDelegateStakeBalance::<Test>::mutate(subnet_id, |mut n| *n += 1000);
+                Network::do_increase_delegate_stake(subnet_id, 1000);
             }
         }
 
@@ -411,11 +405,12 @@ fn test_do_epoch_preliminaries_remove_registered_min_nodes() {
         let max_subnets = MaxSubnets::<Test>::get();
         let end = MinSubnetNodes::<Test>::get();
 
+        // Register one node below the minimum
         let subnet_name: Vec<u8> = format!("subnet-name-remove").into();
         build_registered_subnet_new(
             subnet_name.clone(),
             0,
-            end,
+            end - 1,
             deposit_amount,
             amount,
             false,
@@ -430,8 +425,10 @@ fn test_do_epoch_preliminaries_remove_registered_min_nodes() {
             .saturating_add(subnet_registration_epochs);
         let max_enactment_epoch = max_registration_epoch.saturating_add(subnet_enactment_epochs);
 
-        // Force set node count below minimum
-        TotalActiveSubnetNodes::<Test>::insert(subnet_id, MinSubnetNodes::<Test>::get() - 1);
+        assert_eq!(
+            TotalActiveSubnetNodes::<Test>::get(subnet_id),
+            MinSubnetNodes::<Test>::get() - 1
+        );
 
         // push into enactment period
         set_epoch(max_enactment_epoch, 0);
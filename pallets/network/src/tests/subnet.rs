@@ -1978,7 +1978,7 @@ fn test_enactment_period_insufficient_nodes_removal() {
 
         // Set epoch inside enactment period
         let epoch = 5 + subnet_registration_epochs + 1;
-        set_active_nodes(min_subnet_nodes - 1, 0);
+        set_active_nodes(2, min_subnet_nodes - 1);
 
         // Run preliminaries - subnet should be removed due to insufficient nodes
         Network::do_epoch_preliminaries(&mut WeightMeter::new(), 0, epoch);
@@ -4961,7 +4961,7 @@ fn test_do_activate_subnet_node_subnet_active_node_queued() {
         };
 
         RegisteredSubnetNodesData::<Test>::insert(subnet_id, subnet_node_id, &subnet_node);
-        HotkeyOwner::<Test>::insert(hotkey.clone(), hotkey.clone());
+        set_hotkey_owner(&hotkey, &hotkey);
 
         // Starting values
         let initial_active_subnet_nodes = TotalActiveSubnetNodes::<Test>::get(subnet_id);
@@ -5040,7 +5040,7 @@ fn test_do_activate_subnet_node_failures() {
         };
 
         RegisteredSubnetNodesData::<Test>::insert(subnet_id, subnet_node_id, &subnet_node);
-        HotkeyOwner::<Test>::insert(hotkey.clone(), hotkey.clone());
+        set_hotkey_owner(&hotkey, &hotkey);
 
         // Starting values
         let initial_active_subnet_nodes = TotalActiveSubnetNodes::<Test>::get(subnet_id);
@@ -5140,7 +5140,7 @@ fn test_do_activate_subnet_node_registered_subnet() {
             non_unique: None,
         };
 
-        HotkeyOwner::<Test>::insert(hotkey.clone(), hotkey.clone());
+        set_hotkey_owner(&hotkey, &hotkey);
 
         // Starting values
         let initial_active_subnet_nodes = TotalActiveSubnetNodes::<Test>::get(subnet_id);
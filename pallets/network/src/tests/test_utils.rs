@@ -15,15 +15,17 @@ use crate::{
     SubnetNodeElectionSlots, SubnetNodeIdHotkey, SubnetNodeReputation, SubnetNodesData,
     SubnetOwner, SubnetRegistrationEpoch, SubnetRegistrationEpochs,
     SubnetRegistrationInitialColdkeys, SubnetReputation, SubnetSlot, SubnetState, SubnetsData,
-    TotalActiveNodes, TotalActiveSubnetNodes, TotalActiveSubnets, TotalOverwatchNodeUids,
-    TotalOverwatchNodes, TotalOverwatchStake, TotalStake, TotalSubnetDelegateStakeBalance,
-    TotalSubnetNodeUids, TotalSubnetNodes, TotalSubnetStake, TotalSubnetUids,
-    UniqueParamSubnetNodeId,
+    TotalActiveNodes, TotalActiveSubnetNodes, TotalActiveSubnets, TotalDelegateStake,
+    TotalOverwatchNodeUids, TotalOverwatchNodes, TotalOverwatchStake, TotalStake,
+    TotalSubnetDelegateStakeBalance, TotalSubnetNodeUids, TotalSubnetNodes, TotalSubnetStake,
+    TotalSubnetUids, UniqueParamSubnetNodeId,
 };
+use crate::{CleanupQueueHead, CleanupQueueTail};
 use fp_account::AccountId20;
 use frame_support::assert_ok;
 use frame_support::storage::bounded_vec::BoundedVec;
-use frame_support::traits::{Currency, ExistenceRequirement};
+use frame_support::traits::{Currency, ExistenceRequirement, Hooks};
+use frame_support::weights::Weight;
 use sp_core::keccak_256;
 use sp_core::OpaquePeerId as PeerId;
 use sp_core::H160;
@@ -121,10 +123,25 @@ pub fn get_overwatch_hotkey(
 
 // it is possible to use `use libp2p::PeerId;` with `PeerId::random()`
 // https://github.com/paritytech/substrate/blob/033d4e86cc7eff0066cd376b9375f815761d653c/frame/node-authorization/src/mock.rs#L90
+/// `Qm...` SHA-256 peer ID, as used by RSA keys
 pub fn peer(id: u32) -> PeerId {
-    // let peer_id = format!("12D3KooWD3eckifWpRn9wQpMG9R9hX3sD158z7EqHWmweQAJU5SA{id}");
-    let peer_id = format!("QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx5N{id}");
-    PeerId(peer_id.into())
+    let mut multihash = vec![0x12, 0x20];
+    multihash.extend_from_slice(&sp_core::blake2_256(&id.to_le_bytes()));
+    PeerId(Network::encode_base58(&multihash))
+}
+
+/// `12D3KooW...` Ed25519 peer ID
+pub fn ed25519_peer(id: u32) -> PeerId {
+    let mut multihash = vec![0x00, 0x24, 0x08, 0x01, 0x12, 0x20];
+    multihash.extend_from_slice(&sp_core::blake2_256(&id.to_le_bytes()));
+    PeerId(Network::encode_base58(&multihash))
+}
+
+/// `16Uiu2...` Secp256k1 peer ID
+pub fn secp256k1_peer(id: u32) -> PeerId {
+    let mut multihash = vec![0x00, 0x25, 0x08, 0x02, 0x12, 0x21, 0x02];
+    multihash.extend_from_slice(&sp_core::blake2_256(&id.to_le_bytes()));
+    PeerId(Network::encode_base58(&multihash))
 }
 
 pub fn get_min_stake_balance() -> u128 {
@@ -2108,6 +2125,13 @@ pub fn default_registration_subnet_data_with_onodes(
     add_subnet_data
 }
 
+/// Run `on_idle` until the `CleanupQueue` is empty
+pub fn drain_cleanup_queue() {
+    while CleanupQueueHead::<Test>::get() < CleanupQueueTail::<Test>::get() {
+        Network::on_idle(System::block_number(), Weight::MAX);
+    }
+}
+
 pub fn post_subnet_removal_ensures(
     subnet_id: u32,
     subnets: u32, // count
@@ -2116,6 +2140,8 @@ pub fn post_subnet_removal_ensures(
     start: u32,
     end: u32,
 ) {
+    drain_cleanup_queue();
+
     assert_eq!(SubnetsData::<Test>::try_get(subnet_id), Err(()));
     assert_eq!(SubnetName::<Test>::try_get(name), Err(()));
     assert_eq!(
@@ -2502,13 +2528,9 @@ pub fn insert_overwatch_node(coldkey_n: u32, hotkey_n: u32) -> u32 {
     };
 
     OverwatchNodes::<Test>::insert(current_uid, overwatch_node);
-    HotkeyOwner::<Test>::insert(hotkey.clone(), coldkey.clone());
+    set_hotkey_owner(&coldkey, &hotkey);
     OverwatchNodeIdHotkey::<Test>::insert(current_uid, hotkey.clone());
 
-    let mut hotkeys = ColdkeyHotkeys::<Test>::get(&coldkey.clone());
-    hotkeys.insert(hotkey.clone());
-    ColdkeyHotkeys::<Test>::insert(&coldkey.clone(), hotkeys);
-
     HotkeyOverwatchNodeId::<Test>::insert(&hotkey.clone(), current_uid);
 
     // let stake_balance = OverwatchMinStakeBalance::<Test>::get();
@@ -2518,6 +2540,14 @@ pub fn insert_overwatch_node(coldkey_n: u32, hotkey_n: u32) -> u32 {
     current_uid
 }
 
+/// Insert `hotkey` into both `HotkeyOwner` and the coldkey's `ColdkeyHotkeys`
+pub fn set_hotkey_owner(coldkey: &AccountIdOf<Test>, hotkey: &AccountIdOf<Test>) {
+    HotkeyOwner::<Test>::insert(hotkey, coldkey);
+    ColdkeyHotkeys::<Test>::mutate(coldkey, |hotkeys| {
+        hotkeys.insert(hotkey.clone());
+    });
+}
+
 pub fn set_overwatch_stake(hotkey_n: u32, amount: u128) {
     // -- increase account staking balance
     AccountOverwatchStake::<Test>::mutate(account(hotkey_n), |mut n| *n += amount);
@@ -2583,6 +2613,9 @@ pub fn insert_subnet_node(
     class: SubnetNodeClass,
     start_epoch: u32,
 ) {
+    if !SubnetNodesData::<Test>::contains_key(subnet_id, node_id) {
+        TotalActiveSubnetNodes::<Test>::mutate(subnet_id, |n: &mut u32| *n += 1);
+    }
     SubnetNodesData::<Test>::insert(
         subnet_id,
         node_id,
@@ -2615,9 +2648,11 @@ pub fn set_active_nodes(id: u32, count: u32) {
     TotalActiveSubnetNodes::<Test>::insert(id, count);
 }
 
-// Helper to set delegate stake balance
+// Helper to set delegate stake balance, `TotalDelegateStake` is kept in sync
 pub fn set_delegate_stake(id: u32, stake: u128) {
+    let prev_stake = TotalSubnetDelegateStakeBalance::<Test>::get(id);
     TotalSubnetDelegateStakeBalance::<Test>::insert(id, stake);
+    TotalDelegateStake::<Test>::mutate(|n| *n = *n - prev_stake + stake);
 }
 
 // Helper to set reputation
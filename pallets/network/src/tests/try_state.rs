@@ -0,0 +1,173 @@
+use super::mock::*;
+use crate::tests::test_utils::*;
+use crate::{
+    AccountOverwatchStake, AccountSubnetDelegateStakeShares, AssignedSlots, HotkeyOwner,
+    MinSubnetMinStake, SubnetNodeClass, SubnetState, TotalDelegateStake, TotalStake,
+    TotalSubnetElectableNodes, TotalSubnetStake,
+};
+use frame_support::assert_ok;
+use sp_std::collections::btree_set::BTreeSet;
+
+//
+//
+//
+//
+//
+//
+//
+// Try state
+//
+//
+//
+//
+//
+//
+//
+
+#[test]
+fn test_try_state_activated_subnet() {
+    new_test_ext().execute_with(|| {
+        let subnet_name: Vec<u8> = "subnet-name".into();
+        let deposit_amount: u128 = 10000000000000000000000;
+        let stake_amount: u128 = MinSubnetMinStake::<Test>::get();
+
+        build_activated_subnet(subnet_name, 0, 4, deposit_amount, stake_amount);
+
+        assert_ok!(Network::do_try_state());
+    });
+}
+
+#[test]
+fn test_try_state_stake() {
+    new_test_ext().execute_with(|| {
+        Network::increase_account_stake(&account(1), 1, 100);
+        Network::increase_account_stake(&account(2), 1, 100);
+        Network::increase_account_stake(&account(1), 2, 100);
+        assert_ok!(Network::do_try_state());
+
+        TotalSubnetStake::<Test>::insert(1, 199);
+        assert_eq!(
+            Network::do_try_state(),
+            Err("TotalSubnetStake doesn't match the sum of AccountSubnetStake".into())
+        );
+
+        TotalSubnetStake::<Test>::insert(1, 200);
+        TotalStake::<Test>::put(301);
+        assert_eq!(
+            Network::do_try_state(),
+            Err("TotalStake doesn't match the sum of AccountSubnetStake".into())
+        );
+
+        TotalStake::<Test>::put(300);
+    });
+}
+
+#[test]
+fn test_try_state_delegate_stake() {
+    new_test_ext().execute_with(|| {
+        let (result, _, _) = Network::handle_increase_account_delegate_stake(&account(1), 1, 1000);
+        assert_ok!(result);
+        // Rewards increase the balance without minting shares
+        Network::do_increase_delegate_stake(1, 1000);
+        assert_ok!(Network::do_try_state());
+
+        AccountSubnetDelegateStakeShares::<Test>::mutate(account(2), 1, |n| *n += 1_000_000);
+        assert_eq!(
+            Network::do_try_state(),
+            Err("AccountSubnetDelegateStakeShares exceed TotalSubnetDelegateStakeShares".into())
+        );
+
+        AccountSubnetDelegateStakeShares::<Test>::remove(account(2), 1);
+        TotalDelegateStake::<Test>::mutate(|n| *n += 1);
+        assert_eq!(
+            Network::do_try_state(),
+            Err(
+                "TotalDelegateStake doesn't match the sum of TotalSubnetDelegateStakeBalance"
+                    .into()
+            )
+        );
+
+        TotalDelegateStake::<Test>::mutate(|n| *n -= 1);
+    });
+}
+
+#[test]
+fn test_try_state_subnet_nodes() {
+    new_test_ext().execute_with(|| {
+        insert_subnet(1, SubnetState::Active, 0);
+        insert_subnet_node(1, 1, 1, 2, 1, SubnetNodeClass::Validator, 0);
+        assert!(Network::insert_node_into_election_slot(1, 1));
+        assert_ok!(Network::do_try_state());
+
+        set_active_nodes(1, 2);
+        assert_eq!(
+            Network::do_try_state(),
+            Err("TotalActiveSubnetNodes doesn't match the count of SubnetNodesData".into())
+        );
+
+        set_active_nodes(1, 1);
+        TotalSubnetElectableNodes::<Test>::insert(1, 2);
+        assert_eq!(
+            Network::do_try_state(),
+            Err(
+                "TotalSubnetElectableNodes doesn't match the length of SubnetNodeElectionSlots"
+                    .into()
+            )
+        );
+
+        TotalSubnetElectableNodes::<Test>::insert(1, 1);
+    });
+}
+
+#[test]
+fn test_try_state_overwatch_stake() {
+    new_test_ext().execute_with(|| {
+        set_overwatch_stake(1, 100);
+        set_overwatch_stake(2, 100);
+        assert_ok!(Network::do_try_state());
+
+        AccountOverwatchStake::<Test>::insert(account(2), 99);
+        assert_eq!(
+            Network::do_try_state(),
+            Err("TotalOverwatchStake doesn't match the sum of AccountOverwatchStake".into())
+        );
+
+        AccountOverwatchStake::<Test>::insert(account(2), 100);
+    });
+}
+
+#[test]
+fn test_try_state_slots() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Network::assign_subnet_slot(1));
+        assert_ok!(Network::assign_subnet_slot(2));
+        Network::free_slot_of_subnet(1);
+        assert_ok!(Network::do_try_state());
+
+        let assigned_slots = AssignedSlots::<Test>::get();
+        AssignedSlots::<Test>::put(BTreeSet::new());
+        assert_eq!(
+            Network::do_try_state(),
+            Err("AssignedSlots doesn't match SlotAssignment".into())
+        );
+
+        AssignedSlots::<Test>::put(assigned_slots);
+    });
+}
+
+#[test]
+fn test_try_state_keys() {
+    new_test_ext().execute_with(|| {
+        insert_overwatch_node(1, 2);
+        insert_overwatch_node(1, 3);
+        assert_ok!(Network::do_try_state());
+
+        HotkeyOwner::<Test>::insert(account(3), account(4));
+        assert_eq!(
+            Network::do_try_state(),
+            Err("HotkeyOwner hotkey missing from its coldkey's ColdkeyHotkeys".into())
+        );
+
+        HotkeyOwner::<Test>::insert(account(3), account(1));
+    });
+}
@@ -0,0 +1,32 @@
+use super::mock::*;
+use crate::Call;
+use codec::Decode;
+use frame_support::{
+    dispatch::GetDispatchInfo,
+    traits::{GetCallIndex, GetCallName},
+    weights::Weight,
+};
+
+#[test]
+fn test_every_call_has_non_zero_weight() {
+    new_test_ext().execute_with(|| {
+        let call_names = Call::<Test>::get_call_names();
+        let call_indices = Call::<Test>::get_call_indices();
+        assert_eq!(call_names.len(), call_indices.len());
+
+        for (name, call_index) in call_names.iter().zip(call_indices.iter()) {
+            // Zeroed arguments decode to the smallest instance of every call
+            let mut encoded = vec![*call_index];
+            encoded.extend(vec![0u8; 4096]);
+            let call = Call::<Test>::decode(&mut &encoded[..])
+                .unwrap_or_else(|_| panic!("call {} failed to decode", name));
+
+            assert_ne!(
+                call.get_dispatch_info().weight,
+                Weight::zero(),
+                "call {} declares zero weight",
+                name
+            );
+        }
+    });
+}
@@ -0,0 +1,182 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Invariants between the pallet's running totals and the entries they sum or count
+//!
+//! Checked by the `try_state` hook under `try-runtime` and after every test in the mock, see
+//! `tests::mock::new_test_ext`.
+
+use super::*;
+use sp_runtime::TryRuntimeError;
+
+impl<T: Config> Pallet<T> {
+    /// Check every invariant, returning the first one broken
+    pub fn do_try_state() -> Result<(), TryRuntimeError> {
+        Self::try_state_stake()?;
+        Self::try_state_delegate_stake()?;
+        Self::try_state_subnet_nodes()?;
+        Self::try_state_overwatch_stake()?;
+        Self::try_state_slots()?;
+        Self::try_state_keys()
+    }
+
+    /// `TotalSubnetStake` is the sum of each subnet's `AccountSubnetStake`, and `TotalStake` the
+    /// sum of all of them
+    ///
+    /// `AccountSubnetStake` outlives its subnet until withdrawn, so this includes removed subnets.
+    fn try_state_stake() -> Result<(), TryRuntimeError> {
+        let mut subnet_stakes: BTreeMap<u32, u128> = BTreeMap::new();
+        let mut total_stake: u128 = 0;
+        for (_, subnet_id, stake) in AccountSubnetStake::<T>::iter() {
+            subnet_stakes
+                .entry(subnet_id)
+                .or_insert(0)
+                .saturating_accrue(stake);
+            total_stake.saturating_accrue(stake);
+        }
+
+        ensure!(
+            TotalStake::<T>::get() == total_stake,
+            "TotalStake doesn't match the sum of AccountSubnetStake"
+        );
+
+        for (subnet_id, total_subnet_stake) in TotalSubnetStake::<T>::iter() {
+            ensure!(
+                subnet_stakes.remove(&subnet_id).unwrap_or(0) == total_subnet_stake,
+                "TotalSubnetStake doesn't match the sum of AccountSubnetStake"
+            );
+        }
+        ensure!(
+            subnet_stakes.values().all(|stake| *stake == 0),
+            "AccountSubnetStake in a subnet without TotalSubnetStake"
+        );
+
+        Ok(())
+    }
+
+    /// Accounts never own more than `TotalSubnetDelegateStakeShares`, and `TotalDelegateStake` is
+    /// the sum of `TotalSubnetDelegateStakeBalance`
+    ///
+    /// The shares aren't equal to the sum of `AccountSubnetDelegateStakeShares`, each subnet's
+    /// first deposit mints `MIN_LIQUIDITY` shares that no account owns.
+    fn try_state_delegate_stake() -> Result<(), TryRuntimeError> {
+        let mut subnet_shares: BTreeMap<u32, u128> = BTreeMap::new();
+        for (_, subnet_id, shares) in AccountSubnetDelegateStakeShares::<T>::iter() {
+            subnet_shares
+                .entry(subnet_id)
+                .or_insert(0)
+                .saturating_accrue(shares);
+        }
+
+        for (subnet_id, shares) in subnet_shares {
+            ensure!(
+                shares <= TotalSubnetDelegateStakeShares::<T>::get(subnet_id),
+                "AccountSubnetDelegateStakeShares exceed TotalSubnetDelegateStakeShares"
+            );
+        }
+
+        let total_delegate_stake = TotalSubnetDelegateStakeBalance::<T>::iter_values()
+            .fold(0u128, |total, balance| total.saturating_add(balance));
+        ensure!(
+            TotalDelegateStake::<T>::get() == total_delegate_stake,
+            "TotalDelegateStake doesn't match the sum of TotalSubnetDelegateStakeBalance"
+        );
+
+        Ok(())
+    }
+
+    /// `TotalActiveSubnetNodes` counts each subnet's `SubnetNodesData`, and
+    /// `TotalSubnetElectableNodes` its `SubnetNodeElectionSlots`
+    ///
+    /// Only subnets in `SubnetsData` are counted, the nodes of removed subnets are cleared over
+    /// several blocks in `on_idle`.
+    fn try_state_subnet_nodes() -> Result<(), TryRuntimeError> {
+        for subnet_id in SubnetsData::<T>::iter_keys() {
+            ensure!(
+                TotalActiveSubnetNodes::<T>::get(subnet_id) as usize
+                    == SubnetNodesData::<T>::iter_prefix(subnet_id).count(),
+                "TotalActiveSubnetNodes doesn't match the count of SubnetNodesData"
+            );
+        }
+
+        for (subnet_id, election_slots) in SubnetNodeElectionSlots::<T>::iter() {
+            ensure!(
+                TotalSubnetElectableNodes::<T>::get(subnet_id) as usize == election_slots.len(),
+                "TotalSubnetElectableNodes doesn't match the length of SubnetNodeElectionSlots"
+            );
+        }
+        for (subnet_id, electable_nodes) in TotalSubnetElectableNodes::<T>::iter() {
+            ensure!(
+                SubnetNodeElectionSlots::<T>::decode_len(subnet_id).unwrap_or(0)
+                    == electable_nodes as usize,
+                "TotalSubnetElectableNodes doesn't match the length of SubnetNodeElectionSlots"
+            );
+        }
+
+        Ok(())
+    }
+
+    /// `TotalOverwatchStake` is the sum of `AccountOverwatchStake`
+    fn try_state_overwatch_stake() -> Result<(), TryRuntimeError> {
+        let total_overwatch_stake = AccountOverwatchStake::<T>::iter_values()
+            .fold(0u128, |total, stake| total.saturating_add(stake));
+        ensure!(
+            TotalOverwatchStake::<T>::get() == total_overwatch_stake,
+            "TotalOverwatchStake doesn't match the sum of AccountOverwatchStake"
+        );
+
+        Ok(())
+    }
+
+    /// `AssignedSlots` holds exactly the slots in `SlotAssignment`, each pointing at a subnet whose
+    /// `SubnetSlot` points back
+    fn try_state_slots() -> Result<(), TryRuntimeError> {
+        let assigned_slots: BTreeSet<u32> = SlotAssignment::<T>::iter_keys().collect();
+        ensure!(
+            AssignedSlots::<T>::get() == assigned_slots,
+            "AssignedSlots doesn't match SlotAssignment"
+        );
+
+        for (slot, subnet_id) in SlotAssignment::<T>::iter() {
+            ensure!(
+                SubnetSlot::<T>::get(subnet_id) == Some(slot),
+                "SlotAssignment doesn't match SubnetSlot"
+            );
+        }
+
+        Ok(())
+    }
+
+    /// `HotkeyOwner` and `ColdkeyHotkeys` are inverses of each other
+    fn try_state_keys() -> Result<(), TryRuntimeError> {
+        for (hotkey, coldkey) in HotkeyOwner::<T>::iter() {
+            ensure!(
+                ColdkeyHotkeys::<T>::get(&coldkey).contains(&hotkey),
+                "HotkeyOwner hotkey missing from its coldkey's ColdkeyHotkeys"
+            );
+        }
+
+        for (coldkey, hotkeys) in ColdkeyHotkeys::<T>::iter() {
+            for hotkey in hotkeys {
+                ensure!(
+                    HotkeyOwner::<T>::try_get(&hotkey) == Ok(coldkey.clone()),
+                    "ColdkeyHotkeys hotkey owned by another coldkey in HotkeyOwner"
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
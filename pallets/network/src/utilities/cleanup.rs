@@ -0,0 +1,204 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::pallet_prelude::Weight;
+use sp_io::MultiRemovalResults;
+
+impl<T: Config> Pallet<T> {
+    /// Max keys removed by `process_cleanup_queue` per block
+    pub const CLEANUP_MAX_KEYS_PER_BLOCK: u32 = 512;
+
+    /// Push a prefix to the back of the `CleanupQueue`
+    pub fn enqueue_cleanup_task(task: CleanupTask) -> Weight {
+        let index = CleanupQueueTail::<T>::mutate(|tail| {
+            let index = *tail;
+            *tail = tail.saturating_add(1);
+            index
+        });
+        CleanupQueue::<T>::insert(index, task);
+
+        // Reads:
+        // - CleanupQueueTail
+        // Writes:
+        // - CleanupQueueTail
+        // - CleanupQueue
+        T::DbWeight::get().reads_writes(1, 2)
+    }
+
+    /// Clear up to `limit` keys of the prefix of `task`, continuing from `maybe_cursor`
+    pub fn clear_cleanup_task_prefix(
+        task: &CleanupTask,
+        limit: u32,
+        maybe_cursor: Option<&[u8]>,
+    ) -> MultiRemovalResults {
+        match *task {
+            CleanupTask::SubnetNodesData(subnet_id) => {
+                SubnetNodesData::<T>::clear_prefix(subnet_id, limit, maybe_cursor)
+            }
+            CleanupTask::RegisteredSubnetNodesData(subnet_id) => {
+                RegisteredSubnetNodesData::<T>::clear_prefix(subnet_id, limit, maybe_cursor)
+            }
+            CleanupTask::PeerIdSubnetNodeId(subnet_id) => {
+                PeerIdSubnetNodeId::<T>::clear_prefix(subnet_id, limit, maybe_cursor)
+            }
+            CleanupTask::BootnodePeerIdSubnetNodeId(subnet_id) => {
+                BootnodePeerIdSubnetNodeId::<T>::clear_prefix(subnet_id, limit, maybe_cursor)
+            }
+            CleanupTask::ClientPeerIdSubnetNodeId(subnet_id) => {
+                ClientPeerIdSubnetNodeId::<T>::clear_prefix(subnet_id, limit, maybe_cursor)
+            }
+            CleanupTask::BootnodeSubnetNodeId(subnet_id) => {
+                BootnodeSubnetNodeId::<T>::clear_prefix(subnet_id, limit, maybe_cursor)
+            }
+            CleanupTask::UniqueParamSubnetNodeId(subnet_id) => {
+                UniqueParamSubnetNodeId::<T>::clear_prefix(subnet_id, limit, maybe_cursor)
+            }
+            CleanupTask::HotkeySubnetNodeId(subnet_id) => {
+                HotkeySubnetNodeId::<T>::clear_prefix(subnet_id, limit, maybe_cursor)
+            }
+            CleanupTask::SubnetNodeIdHotkey(subnet_id) => {
+                SubnetNodeIdHotkey::<T>::clear_prefix(subnet_id, limit, maybe_cursor)
+            }
+            CleanupTask::SubnetNodeReputation(subnet_id) => {
+                SubnetNodeReputation::<T>::clear_prefix(subnet_id, limit, maybe_cursor)
+            }
+            CleanupTask::SubnetNodeConsecutiveIncludedEpochs(subnet_id) => {
+                SubnetNodeConsecutiveIncludedEpochs::<T>::clear_prefix(
+                    subnet_id,
+                    limit,
+                    maybe_cursor,
+                )
+            }
+            CleanupTask::SubnetElectedValidator(subnet_id) => {
+                SubnetElectedValidator::<T>::clear_prefix(subnet_id, limit, maybe_cursor)
+            }
+            CleanupTask::SubnetBackupValidator(subnet_id) => {
+                SubnetBackupValidator::<T>::clear_prefix(subnet_id, limit, maybe_cursor)
+            }
+            CleanupTask::NodeSlotIndex(subnet_id) => {
+                NodeSlotIndex::<T>::clear_prefix(subnet_id, limit, maybe_cursor)
+            }
+            CleanupTask::SubnetConsensusSubmission(subnet_id) => {
+                SubnetConsensusSubmission::<T>::clear_prefix(subnet_id, limit, maybe_cursor)
+            }
+            CleanupTask::ConsensusSubmissionArchive(subnet_id) => {
+                ConsensusSubmissionArchive::<T>::clear_prefix(subnet_id, limit, maybe_cursor)
+            }
+            CleanupTask::OverwatchCommits(overwatch_epoch) => {
+                OverwatchCommits::<T>::clear_prefix((overwatch_epoch,), limit, maybe_cursor)
+            }
+            CleanupTask::OverwatchReveals(overwatch_epoch) => {
+                OverwatchReveals::<T>::clear_prefix((overwatch_epoch,), limit, maybe_cursor)
+            }
+            CleanupTask::AccountRewardHistory(epoch) => {
+                AccountRewardHistory::<T>::clear_prefix(epoch, limit, maybe_cursor)
+            }
+            CleanupTask::SubnetDelegateRewardHistory(epoch) => {
+                SubnetDelegateRewardHistory::<T>::clear_prefix(epoch, limit, maybe_cursor)
+            }
+            CleanupTask::NodeDelegateRewardHistory(epoch) => {
+                NodeDelegateRewardHistory::<T>::clear_prefix(epoch, limit, maybe_cursor)
+            }
+            CleanupTask::AccountSubnetDelegateSharesHistory(epoch) => {
+                AccountSubnetDelegateSharesHistory::<T>::clear_prefix((epoch,), limit, maybe_cursor)
+            }
+            CleanupTask::AccountNodeDelegateSharesHistory(epoch) => {
+                AccountNodeDelegateSharesHistory::<T>::clear_prefix((epoch,), limit, maybe_cursor)
+            }
+        }
+    }
+
+    /// Clear queued prefixes within the remaining weight of `weight_meter`
+    ///
+    /// Tasks are processed in FIFO order. When a prefix can't be cleared entirely its cursor is
+    /// stored in `CleanupCursor` and clearing resumes from it in a later block.
+    pub fn process_cleanup_queue(weight_meter: &mut WeightMeter) {
+        let db_weight = T::DbWeight::get();
+
+        // CleanupQueueHead | CleanupQueueTail
+        if !weight_meter.can_consume(db_weight.reads(2)) {
+            return;
+        }
+        weight_meter.consume(db_weight.reads(2));
+
+        let start = CleanupQueueHead::<T>::get();
+        let tail = CleanupQueueTail::<T>::get();
+
+        let mut head = start;
+        let mut keys_left = Self::CLEANUP_MAX_KEYS_PER_BLOCK;
+
+        while head < tail && keys_left > 0 {
+            // Reads:
+            // - CleanupQueue
+            // - CleanupCursor
+            // Writes:
+            // - CleanupQueue | CleanupCursor
+            // - CleanupCursor
+            // - CleanupQueueHead
+            let overhead = db_weight.reads_writes(2, 3);
+            if !weight_meter.can_consume(overhead) {
+                break;
+            }
+            weight_meter.consume(db_weight.reads(2));
+
+            let task = match CleanupQueue::<T>::get(head) {
+                Some(task) => task,
+                None => {
+                    head = head.saturating_add(1);
+                    continue;
+                }
+            };
+
+            // Each removed key is one read and one write
+            let limit = weight_meter
+                .remaining()
+                .saturating_sub(db_weight.writes(3))
+                .checked_div_per_component(&db_weight.reads_writes(1, 1))
+                .unwrap_or(u64::MAX)
+                .min(keys_left as u64) as u32;
+            if limit == 0 {
+                break;
+            }
+
+            let cursor = CleanupCursor::<T>::get();
+            let result = Self::clear_cleanup_task_prefix(&task, limit, cursor.as_deref());
+            weight_meter.consume(db_weight.reads_writes(result.loops as u64, result.unique as u64));
+            keys_left = keys_left.saturating_sub(result.loops);
+
+            match result.maybe_cursor {
+                Some(next_cursor) => {
+                    CleanupCursor::<T>::put(next_cursor);
+                    weight_meter.consume(db_weight.writes(1));
+                    break;
+                }
+                None => {
+                    if cursor.is_some() {
+                        CleanupCursor::<T>::kill();
+                        weight_meter.consume(db_weight.writes(1));
+                    }
+                    CleanupQueue::<T>::remove(head);
+                    weight_meter.consume(db_weight.writes(1));
+                    head = head.saturating_add(1);
+                }
+            }
+        }
+
+        if head != start {
+            CleanupQueueHead::<T>::put(head);
+            weight_meter.consume(db_weight.writes(1));
+        }
+    }
+}
@@ -484,13 +484,69 @@ impl<T: Config> Pallet<T> {
             return;
         }
 
-        let idx = Self::get_random_number(block, slot_list.len() as u32) as usize;
+        let mut weights = Self::get_election_weights(subnet_id, &slot_list);
 
-        let subnet_node_id = slot_list.get(idx).cloned();
+        let idx = weights
+            .as_ref()
+            .and_then(|weights| {
+                Self::get_weighted_random_index(Self::generate_random_number_u128(block), weights)
+            })
+            // Uniform, or no node has any weight
+            .unwrap_or_else(|| Self::get_random_number(block, slot_list.len() as u32) as usize);
 
-        if let Some(node_id) = subnet_node_id {
-            // --- Insert validator for next epoch
-            SubnetElectedValidator::<T>::insert(subnet_id, subnet_epoch, node_id);
+        let Some(node_id) = slot_list.get(idx).cloned() else {
+            return;
+        };
+
+        // --- Insert validator for next epoch
+        SubnetElectedValidator::<T>::insert(subnet_id, subnet_epoch, node_id);
+
+        // --- Elect a backup validator from the remaining slots
+        let mut backup_slot_list = slot_list;
+        backup_slot_list.remove(idx);
+        if let Some(weights) = weights.as_mut() {
+            weights.remove(idx);
+        }
+
+        if backup_slot_list.is_empty() {
+            return;
+        }
+
+        let random_number = Self::generate_backup_random_number(block);
+        let backup_idx = weights
+            .as_ref()
+            .and_then(|weights| Self::get_weighted_random_index(random_number, weights))
+            .unwrap_or((random_number % backup_slot_list.len() as u128) as usize);
+
+        if let Some(backup_node_id) = backup_slot_list.get(backup_idx).cloned() {
+            if backup_node_id != node_id {
+                SubnetBackupValidator::<T>::insert(subnet_id, subnet_epoch, backup_node_id);
+            }
+        }
+    }
+
+    /// Election weight of each slot in `slot_list`, `None` if the subnet elects uniformly
+    ///
+    /// See `ElectionMode`
+    pub fn get_election_weights(subnet_id: u32, slot_list: &[u32]) -> Option<Vec<u128>> {
+        match SubnetElectionMode::<T>::get(subnet_id) {
+            ElectionMode::Uniform => None,
+            ElectionMode::StakeWeighted => Some(
+                slot_list
+                    .iter()
+                    .map(|subnet_node_id| {
+                        SubnetNodeIdHotkey::<T>::get(subnet_id, subnet_node_id)
+                            .map(|hotkey| AccountSubnetStake::<T>::get(hotkey, subnet_id))
+                            .unwrap_or(0)
+                    })
+                    .collect(),
+            ),
+            ElectionMode::ReputationWeighted => Some(
+                slot_list
+                    .iter()
+                    .map(|subnet_node_id| SubnetNodeReputation::<T>::get(subnet_id, subnet_node_id))
+                    .collect(),
+            ),
         }
     }
 
@@ -0,0 +1,222 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// Deterministic fixed-point math used by every consensus-critical curve.
+//
+// All values are unsigned and scaled by `PERCENTAGE_FACTOR` (1e18 as 1.0). Intermediate
+// results are carried in `U256` so that native and wasm execution always agree bit-for-bit.
+//
+// Error bounds against the f64 curves (see `tests/math.rs`):
+// - `fixed_exp`: relative error below 1e-14
+// - `fixed_exp_neg`, `fixed_ln`: absolute error below 1e-14
+// - `fixed_pow`: absolute error below 1e-14 when `x <= 1.0`, relative error below 1e-14 otherwise
+// - `fixed_sigmoid_decreasing`, `fixed_concave_down_decreasing`: absolute error below 1e-14
+
+use super::*;
+use sp_core::U256;
+
+impl<T: Config> Pallet<T> {
+    // ln(2) as 1e18
+    pub const FIXED_LN_2: U256 = U256([0x099e_8db0_3256_ce5d, 0x0, 0x0, 0x0]);
+
+    /// Upper bound on series terms, the series always converge well before this
+    const FIXED_MAX_SERIES_TERMS: u32 = 64;
+
+    /// `a * b` where both are 1e18 fixed-point, saturating at `U256::MAX`
+    pub fn fixed_mul(a: U256, b: U256) -> U256 {
+        match a.checked_mul(b) {
+            Some(v) => v / Self::PERCENTAGE_FACTOR,
+            None => {
+                // Scale the larger operand down first to avoid the intermediate overflow
+                let (big, small) = if a > b { (a, b) } else { (b, a) };
+                (big / Self::PERCENTAGE_FACTOR)
+                    .checked_mul(small)
+                    .unwrap_or(U256::MAX)
+            }
+        }
+    }
+
+    /// `a / b` where both are 1e18 fixed-point
+    ///
+    /// Returns zero when `b` is zero, matching `percent_div`
+    pub fn fixed_div(a: U256, b: U256) -> U256 {
+        if b.is_zero() {
+            return U256::zero();
+        }
+        match a.checked_mul(Self::PERCENTAGE_FACTOR) {
+            Some(v) => v / b,
+            None => (a / b).saturating_mul(Self::PERCENTAGE_FACTOR),
+        }
+    }
+
+    /// Natural logarithm of `x` for `x >= 1.0`
+    ///
+    /// `x` is reduced to `m * 2^k` with `m` in `[1, 2)`, then `ln(m)` is evaluated with the
+    /// `2 * atanh((m - 1) / (m + 1))` series which converges quickly on that interval.
+    ///
+    /// Values below 1.0 return zero, callers take `ln(1 / x)` instead.
+    pub fn fixed_ln(x: U256) -> U256 {
+        let one = Self::PERCENTAGE_FACTOR;
+        if x <= one {
+            return U256::zero();
+        }
+
+        // k = floor(log2(x / 1.0))
+        let k = (x / one).bits().saturating_sub(1);
+        let m = x >> k;
+
+        let z = Self::fixed_div(m - one, m + one);
+        let z_squared = Self::fixed_mul(z, z);
+
+        let mut term = z;
+        let mut sum = z;
+        for n in 1..Self::FIXED_MAX_SERIES_TERMS {
+            term = Self::fixed_mul(term, z_squared);
+            if term.is_zero() {
+                break;
+            }
+            sum = sum.saturating_add(term / U256::from(2 * n + 1));
+        }
+
+        Self::FIXED_LN_2
+            .saturating_mul(U256::from(k))
+            .saturating_add(sum.saturating_mul(U256::from(2)))
+    }
+
+    /// `e^x` for `x >= 0`, saturating at `U256::MAX`
+    ///
+    /// `x` is reduced to `k * ln(2) + r` with `r` in `[0, ln(2))`, `e^r` is evaluated with its
+    /// Taylor series and shifted back by `2^k`.
+    pub fn fixed_exp(x: U256) -> U256 {
+        let one = Self::PERCENTAGE_FACTOR;
+        if x.is_zero() {
+            return one;
+        }
+
+        let k = x / Self::FIXED_LN_2;
+        // Anything past 2^256 saturates regardless of `r`
+        if k >= U256::from(256) {
+            return U256::MAX;
+        }
+        let k = k.as_u32();
+        let r = x - Self::FIXED_LN_2 * U256::from(k);
+
+        let mut term = one;
+        let mut sum = one;
+        for n in 1..Self::FIXED_MAX_SERIES_TERMS {
+            term = Self::fixed_mul(term, r) / U256::from(n);
+            if term.is_zero() {
+                break;
+            }
+            sum = sum.saturating_add(term);
+        }
+
+        if k >= sum.leading_zeros() {
+            return U256::MAX;
+        }
+
+        sum << k as usize
+    }
+
+    /// `e^-x` for `x >= 0`
+    pub fn fixed_exp_neg(x: U256) -> U256 {
+        let exp = Self::fixed_exp(x);
+        if exp == U256::MAX {
+            return U256::zero();
+        }
+        Self::fixed_div(Self::PERCENTAGE_FACTOR, exp)
+    }
+
+    /// `x^y` for `x >= 0` and `y >= 0`
+    ///
+    /// Computed as `e^(y * ln(x))`, using `e^-(y * ln(1 / x))` when `x < 1.0`
+    pub fn fixed_pow(x: U256, y: U256) -> U256 {
+        let one = Self::PERCENTAGE_FACTOR;
+        if y.is_zero() {
+            return one;
+        }
+        if x.is_zero() {
+            return U256::zero();
+        }
+        if x == one || y == one {
+            return x;
+        }
+
+        if x > one {
+            Self::fixed_exp(Self::fixed_mul(y, Self::fixed_ln(x)))
+        } else {
+            let inverse = Self::fixed_div(one, x);
+            Self::fixed_exp_neg(Self::fixed_mul(y, Self::fixed_ln(inverse)))
+        }
+    }
+
+    /// `x^y` for 1e18 percentages, saturating at `u128::MAX`
+    pub fn percent_pow(x: u128, y: u128) -> u128 {
+        Self::fixed_pow(U256::from(x), U256::from(y))
+            .try_into()
+            .unwrap_or(u128::MAX)
+    }
+
+    /// Fixed-point version of `sigmoid_decreasing`
+    ///
+    /// # Parameters
+    /// - `x`: The input value to evaluate the sigmoid at, in `[0, 1e18]`.
+    /// - `mid`: The midpoint of the sigmoid. The curve is symmetric around this value.
+    /// - `k`: Controls the steepness of the sigmoid, as 1e18 (e.g. `20e18` for a steepness of 20).
+    /// - `min`: Minimum value of the output range.
+    /// - `max`: Maximum value of the output range.
+    ///
+    /// # Returns
+    /// - The sigmoid at `x` as 1e18, clamped to `[min, max]`.
+    pub fn fixed_sigmoid_decreasing(x: u128, mid: u128, k: u128, min: u128, max: u128) -> u128 {
+        let one = Self::PERCENTAGE_FACTOR;
+        let c = x.abs_diff(mid);
+        let d = Self::fixed_mul(U256::from(k), U256::from(c));
+        // e^-d in (0, 1], avoids overflowing on steep curves
+        let exp_neg = Self::fixed_exp_neg(d);
+
+        // symmetric sigmoid around mid
+        let sigmoid = if x > mid {
+            Self::fixed_div(exp_neg, one + exp_neg)
+        } else {
+            Self::fixed_div(one, one + exp_neg)
+        };
+
+        let sigmoid: u128 = sigmoid.try_into().unwrap_or(u128::MAX);
+        sigmoid.clamp(min, max.max(min))
+    }
+
+    /// Fixed-point version of `sigmoid_decreasing_v2`
+    pub fn fixed_sigmoid_decreasing_v2(x: u128, mid: u128, k: u128) -> u128 {
+        Self::fixed_sigmoid_decreasing(x, mid, k, 0, Self::percentage_factor_as_u128())
+    }
+
+    /// Fixed-point version of `concave_down_decreasing`
+    ///
+    /// # Parameters
+    /// - `x`: Input value in `[0, 1e18]`.
+    /// - `min`: Returned when `x = 1e18`.
+    /// - `max`: Returned when `x = 0`.
+    /// - `power`: Steepness of the curve as 1e18, zero falls back to 1e18.
+    pub fn fixed_concave_down_decreasing(x: u128, min: u128, max: u128, power: u128) -> u128 {
+        let one = Self::percentage_factor_as_u128();
+        let p = if power == 0 { one } else { power };
+
+        let curve = one.saturating_sub(Self::percent_pow(x.min(one), p));
+
+        min.saturating_add(Self::percent_mul(max.saturating_sub(min), curve))
+            .clamp(min, max.max(min))
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_system::RawOrigin;
+use strum::IntoEnumIterator;
+
+impl<T: Config> Pallet<T> {
+    /// Register, populate and activate a genesis subnet
+    ///
+    /// Goes through the same paths as `register_subnet`, `register_subnet_node`,
+    /// `add_to_delegate_stake` and `activate_subnet`, so a genesis config that couldn't be
+    /// reproduced with extrinsics panics instead of building inconsistent storage.
+    pub fn do_build_genesis_subnet(subnet: &GenesisSubnet<T::AccountId>) {
+        let mut initial_coldkeys: BTreeMap<T::AccountId, u32> = BTreeMap::new();
+        for node in &subnet.nodes {
+            *initial_coldkeys.entry(node.coldkey.clone()).or_insert(0) += 1;
+        }
+
+        let bootnodes: BTreeSet<BoundedVec<u8, DefaultMaxVectorLength>> = subnet
+            .bootnodes
+            .iter()
+            .map(|bootnode| {
+                BoundedVec::try_from(bootnode.clone())
+                    .expect("genesis subnet bootnode exceeds the max vector length")
+            })
+            .collect();
+
+        Self::do_register_subnet(
+            subnet.owner.clone(),
+            u128::MAX,
+            RegistrationSubnetData {
+                name: subnet.name.clone(),
+                repo: subnet.repo.clone(),
+                description: subnet.description.clone(),
+                misc: Vec::new(),
+                min_stake: subnet.min_stake,
+                max_stake: subnet.max_stake,
+                delegate_stake_percentage: subnet.delegate_stake_percentage,
+                initial_coldkeys,
+                key_types: KeyType::iter().collect(),
+                bootnodes,
+            },
+        )
+        .expect("genesis subnet failed to register");
+
+        let subnet_id = TotalSubnetUids::<T>::get();
+
+        for node in &subnet.nodes {
+            Self::do_register_subnet_node(
+                RawOrigin::Signed(node.coldkey.clone()).into(),
+                subnet_id,
+                node.hotkey.clone(),
+                node.peer_id.clone(),
+                node.bootnode_peer_id.clone(),
+                node.client_peer_id.clone(),
+                None,
+                0,
+                node.stake,
+                None,
+                None,
+                u128::MAX,
+            )
+            .expect("genesis subnet node failed to register");
+        }
+
+        for (account_id, amount) in &subnet.delegate_stakes {
+            Self::do_add_delegate_stake(
+                RawOrigin::Signed(account_id.clone()).into(),
+                subnet_id,
+                *amount,
+            )
+            .expect("genesis subnet delegate stake failed to add");
+        }
+
+        Self::do_activate_subnet(subnet_id).expect("genesis subnet failed to activate");
+
+        // `do_activate_subnet` removes subnets outside their registration and enactment periods
+        // instead of erroring
+        assert!(
+            SubnetsData::<T>::get(subnet_id).is_some_and(|data| data.state == SubnetState::Active),
+            "genesis subnet failed to activate"
+        );
+    }
+}
@@ -29,28 +29,18 @@ impl<T: Config> Pallet<T> {
         }
     }
 
-    // Loosely validates Node ID
-    pub fn validate_peer_id(peer_id: &PeerId) -> bool {
-        let peer_id_0 = &peer_id.0;
-        let len = peer_id_0.len();
-
-        // Length must be between 32 and 128 bytes
-        if len < 32 || len > 128 {
-            return false;
+    /// Points `HotkeyOwner` of every hotkey in `ColdkeyHotkeys` of `coldkey` at `coldkey`
+    ///
+    /// `ColdkeyHotkeys` is the list of record, this is used after it is moved to another
+    /// coldkey to keep `HotkeyOwner` its inverse.
+    pub fn set_hotkeys_owner(coldkey: &T::AccountId) {
+        for hotkey in ColdkeyHotkeys::<T>::get(coldkey) {
+            HotkeyOwner::<T>::insert(&hotkey, coldkey);
         }
+    }
 
-        let first = peer_id_0[0];
-        let second = peer_id_0[1];
-
-        match (first, second) {
-            // (ed25519, using the "identity" multihash) encoded as a raw base58btc multihash
-            // '1' → base58btc identity multihash (ed25519)
-            (49, _) => true,
-            // (sha256) encoded as a raw base58btc multihash - 'Qm' → SHA256 base58 multihash
-            (81, 109) => true,
-            // (sha256) encoded as a CID - 'f', 'b', 'z', 'm' → CID/base multibase prefixes
-            (102, _) | (98, _) | (122, _) | (109, _) => true,
-            _ => false,
-        }
+    /// Validates a libp2p peer ID, see `decode_peer_id`
+    pub fn validate_peer_id(peer_id: &PeerId) -> bool {
+        Self::decode_peer_id(peer_id).is_some()
     }
 }
@@ -71,6 +71,21 @@ impl<T: Config> Pallet<T> {
         1_000_000_000_000_000_000
     }
 
+    pub fn checked_mul_div(x: U256, y: U256, z: U256) -> Option<U256> {
+        if z.is_zero() {
+            return None;
+        }
+        x.checked_mul(y)?.checked_div(z)
+    }
+
+    //
+    // f64 helpers
+    //
+    // These are not deterministic across native and wasm and must not feed storage or balances.
+    // They are kept as the reference curves the fixed-point versions in `fixed_point.rs` are
+    // tested against.
+    //
+
     /// Get percentage as f64 (full 1e18 with decimals)
     pub fn percentage_factor_as_f64() -> f64 {
         1_000_000_000_000_000_000.0
@@ -90,13 +105,6 @@ impl<T: Config> Pallet<T> {
         pow(x, exp)
     }
 
-    pub fn checked_mul_div(x: U256, y: U256, z: U256) -> Option<U256> {
-        if z.is_zero() {
-            return None;
-        }
-        x.checked_mul(y)?.checked_div(z)
-    }
-
     /// Computes a symmetric, decreasing sigmoid curve scaled to a specified output range.
     ///
     /// # Parameters
@@ -1,12 +1,17 @@
 use super::*;
+pub mod cleanup;
 pub mod delegate_staking;
 pub mod era;
+pub mod fixed_point;
+pub mod genesis;
 pub mod identity;
 pub mod keys;
 pub mod math;
 pub mod misc;
 pub mod overwatch_node;
 pub mod owner;
+pub mod peer_id;
+pub mod peer_id_proof;
 pub mod randomness;
 pub mod reputation;
 pub mod slot;
@@ -14,7 +14,6 @@
 // limitations under the License.
 
 use super::*;
-use libm::{ceil, log};
 
 impl<T: Config> Pallet<T> {
     /// Owner pause subnet for up to max period
@@ -190,24 +189,17 @@ impl<T: Config> Pallet<T> {
     /// Get the required epochs to have a node removed based on not being in consensus data
     /// based on the `AbsentDecreaseReputationFactor`
     fn get_max_steps_for_node_removal(subnet_id: u32) -> u32 {
-        let one: f64 = Self::get_percent_as_f64(Self::percentage_factor_as_u128());
-
         // Based on network min max parameters
-        let min_min_reputation: f64 =
-            Self::get_percent_as_f64(MinMinSubnetNodeReputation::<T>::get());
-        let min_absent_factor: f64 = Self::get_percent_as_f64(MinNodeReputationFactor::<T>::get());
-
-        let r = one - min_absent_factor;
-        let n = ceil(log(min_min_reputation / one) / log(r)) as u32 + 1;
+        let n = Self::get_steps_to_reputation(
+            MinMinSubnetNodeReputation::<T>::get(),
+            MinNodeReputationFactor::<T>::get(),
+        );
 
         // Subnet parameters
-        let min_reputation: f64 =
-            Self::get_percent_as_f64(MinSubnetNodeReputation::<T>::get(subnet_id));
-        let absent_factor: f64 =
-            Self::get_percent_as_f64(AbsentDecreaseReputationFactor::<T>::get(subnet_id));
-
-        let r2 = one - absent_factor;
-        let n2 = ceil(log(min_reputation / one) / log(r2)) as u32 + 1;
+        let n2 = Self::get_steps_to_reputation(
+            MinSubnetNodeReputation::<T>::get(subnet_id),
+            AbsentDecreaseReputationFactor::<T>::get(subnet_id),
+        );
 
         // Redundantly check steps
         if n < n2 {
@@ -217,6 +209,28 @@ impl<T: Config> Pallet<T> {
         n2
     }
 
+    /// Number of consecutive `decrease_factor` decreases from a reputation of 1.0 until it is at
+    /// or below `min_reputation`, plus one
+    ///
+    /// Equivalent to `ceil(ln(min_reputation) / ln(1.0 - decrease_factor)) + 1`, computed by
+    /// repeatedly applying the decrease so the result is exact in 1e18 fixed-point.
+    fn get_steps_to_reputation(min_reputation: u128, decrease_factor: u128) -> u32 {
+        // Upper bound on steps, only reachable with a near-zero decrease factor
+        const MAX_STEPS: u32 = 10_000;
+
+        let percentage_factor = Self::percentage_factor_as_u128();
+        let retain_factor = percentage_factor.saturating_sub(decrease_factor);
+
+        let mut reputation = percentage_factor;
+        let mut steps: u32 = 0;
+        while reputation > min_reputation && steps < MAX_STEPS {
+            reputation = Self::percent_mul(reputation, retain_factor);
+            steps = steps.saturating_add(1);
+        }
+
+        steps.saturating_add(1)
+    }
+
     pub fn do_owner_revert_emergency_validator_set(
         origin: T::RuntimeOrigin,
         subnet_id: u32,
@@ -647,6 +661,52 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    pub fn do_owner_update_election_mode(
+        origin: T::RuntimeOrigin,
+        subnet_id: u32,
+        value: ElectionMode,
+    ) -> DispatchResult {
+        let coldkey: T::AccountId = ensure_signed(origin)?;
+
+        ensure!(
+            Self::is_subnet_owner(&coldkey, subnet_id).unwrap_or(false),
+            Error::<T>::NotSubnetOwner
+        );
+
+        SubnetElectionMode::<T>::insert(subnet_id, value);
+
+        Self::deposit_event(Event::SubnetElectionModeUpdate {
+            subnet_id: subnet_id,
+            owner: coldkey,
+            value: value,
+        });
+
+        Ok(())
+    }
+
+    pub fn do_owner_update_peer_id_proof_required(
+        origin: T::RuntimeOrigin,
+        subnet_id: u32,
+        value: bool,
+    ) -> DispatchResult {
+        let coldkey: T::AccountId = ensure_signed(origin)?;
+
+        ensure!(
+            Self::is_subnet_owner(&coldkey, subnet_id).unwrap_or(false),
+            Error::<T>::NotSubnetOwner
+        );
+
+        SubnetPeerIdProofRequired::<T>::insert(subnet_id, value);
+
+        Self::deposit_event(Event::SubnetPeerIdProofRequiredUpdate {
+            subnet_id: subnet_id,
+            owner: coldkey,
+            value: value,
+        });
+
+        Ok(())
+    }
+
     pub fn do_owner_update_min_max_stake(
         origin: T::RuntimeOrigin,
         subnet_id: u32,
@@ -0,0 +1,281 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE32_ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+/// Multicodec of the multihashes and CIDs a peer ID is encoded as
+const MULTIHASH_IDENTITY: u64 = 0x00;
+const MULTIHASH_SHA2_256: u64 = 0x12;
+const CID_V1: u64 = 0x01;
+const CID_LIBP2P_KEY: u64 = 0x72;
+
+/// Public keys up to 42 bytes encoded are inlined with the identity multihash
+const MAX_INLINE_KEY_LENGTH: usize = 42;
+
+/// Public key embedded in a libp2p peer ID
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum PeerIdKey {
+    /// Identity multihash of the protobuf encoded public key, i.e. Ed25519 and Secp256k1 keys
+    Inline(KeyType),
+    /// SHA-256 multihash of the protobuf encoded public key, i.e. RSA and ECDSA keys
+    Hashed,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Decode a libp2p peer ID into the public key it embeds
+    ///
+    /// Accepts the legacy base58btc multihash (`12D3KooW...`, `16Uiu2...`, `Qm...`) and CIDv1
+    /// with the `libp2p-key` codec encoded as base32 (`b`), base58btc (`z`) or base16 (`f`).
+    pub fn decode_peer_id(peer_id: &PeerId) -> Option<PeerIdKey> {
+        let multihash = Self::peer_id_multihash(peer_id)?;
+        match Self::decode_multihash(&multihash)? {
+            (MULTIHASH_IDENTITY, key) => {
+                Self::decode_public_key(key).map(|(key_type, _)| PeerIdKey::Inline(key_type))
+            }
+            _ => Some(PeerIdKey::Hashed),
+        }
+    }
+
+    /// Public key inlined in a peer ID and its key type
+    ///
+    /// Returns `None` for peer IDs that only embed a hash of the key, i.e. RSA and ECDSA keys.
+    pub fn peer_id_public_key(peer_id: &PeerId) -> Option<(KeyType, Vec<u8>)> {
+        let multihash = Self::peer_id_multihash(peer_id)?;
+        match Self::decode_multihash(&multihash)? {
+            (MULTIHASH_IDENTITY, key) => {
+                Self::decode_public_key(key).map(|(key_type, data)| (key_type, data.to_vec()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Ensure a peer ID decodes and its key type is accepted by the subnet
+    ///
+    /// Subnets without `SubnetKeyTypes` accept any key type. `invalid_error` is returned if the
+    /// peer ID can't be decoded.
+    pub fn ensure_peer_id_key_type(
+        subnet_id: u32,
+        peer_id: &PeerId,
+        invalid_error: Error<T>,
+    ) -> DispatchResult {
+        let key = Self::decode_peer_id(peer_id).ok_or(invalid_error)?;
+
+        let key_types = SubnetKeyTypes::<T>::get(subnet_id);
+        if key_types.is_empty() {
+            return Ok(());
+        }
+
+        match key {
+            PeerIdKey::Inline(key_type) => {
+                ensure!(
+                    key_types.contains(&key_type),
+                    match key_type {
+                        KeyType::Rsa => Error::<T>::PeerIdRsaKeyTypeNotAllowed,
+                        KeyType::Ed25519 => Error::<T>::PeerIdEd25519KeyTypeNotAllowed,
+                        KeyType::Secp256k1 => Error::<T>::PeerIdSecp256k1KeyTypeNotAllowed,
+                        KeyType::Ecdsa => Error::<T>::PeerIdEcdsaKeyTypeNotAllowed,
+                    }
+                );
+            }
+            PeerIdKey::Hashed => {
+                ensure!(
+                    key_types.contains(&KeyType::Rsa) || key_types.contains(&KeyType::Ecdsa),
+                    Error::<T>::PeerIdHashedKeyTypeNotAllowed
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Encode bytes as base58btc, e.g. a multihash as a legacy peer ID
+    pub fn encode_base58(input: &[u8]) -> Vec<u8> {
+        // Little endian base58 digits
+        let mut digits: Vec<u8> = Vec::new();
+        for &byte in input {
+            let mut carry = byte as u32;
+            for digit in digits.iter_mut() {
+                carry += (*digit as u32) << 8;
+                *digit = (carry % 58) as u8;
+                carry /= 58;
+            }
+            while carry > 0 {
+                digits.push((carry % 58) as u8);
+                carry /= 58;
+            }
+        }
+
+        let zeros = input.iter().take_while(|&&byte| byte == 0).count();
+        let mut output: Vec<u8> = sp_std::vec![b'1'; zeros];
+        output.extend(
+            digits
+                .iter()
+                .rev()
+                .map(|&digit| BASE58_ALPHABET[digit as usize]),
+        );
+        output
+    }
+
+    fn decode_base58(input: &[u8]) -> Option<Vec<u8>> {
+        // Little endian bytes
+        let mut bytes: Vec<u8> = Vec::new();
+        for &c in input {
+            let mut carry = BASE58_ALPHABET.iter().position(|&a| a == c)? as u32;
+            for byte in bytes.iter_mut() {
+                carry += (*byte as u32) * 58;
+                *byte = carry as u8;
+                carry >>= 8;
+            }
+            while carry > 0 {
+                bytes.push(carry as u8);
+                carry >>= 8;
+            }
+        }
+
+        // Each leading '1' is a leading zero byte
+        let zeros = input.iter().take_while(|&&c| c == b'1').count();
+        bytes.extend(sp_std::iter::repeat(0).take(zeros));
+        bytes.reverse();
+        Some(bytes)
+    }
+
+    /// RFC 4648 lowercase base32 without padding
+    fn decode_base32(input: &[u8]) -> Option<Vec<u8>> {
+        let mut bytes: Vec<u8> = Vec::new();
+        let mut buffer: u32 = 0;
+        let mut bits: u32 = 0;
+        for &c in input {
+            let value = BASE32_ALPHABET.iter().position(|&a| a == c)? as u32;
+            buffer = (buffer << 5) | value;
+            bits += 5;
+            if bits >= 8 {
+                bits -= 8;
+                bytes.push((buffer >> bits) as u8);
+                buffer &= (1 << bits) - 1;
+            }
+        }
+        Some(bytes)
+    }
+
+    fn decode_base16(input: &[u8]) -> Option<Vec<u8>> {
+        if input.len() % 2 != 0 {
+            return None;
+        }
+
+        let nibble = |c: u8| match c {
+            b'0'..=b'9' => Some(c - b'0'),
+            b'a'..=b'f' => Some(c - b'a' + 10),
+            _ => None,
+        };
+
+        input
+            .chunks(2)
+            .map(|pair| Some((nibble(pair[0])? << 4) | nibble(pair[1])?))
+            .collect()
+    }
+
+    /// Unsigned LEB128 varint as used by multiformats
+    fn read_varint(input: &mut &[u8]) -> Option<u64> {
+        let mut value: u64 = 0;
+        for shift in 0..9 {
+            let (&byte, rest) = input.split_first()?;
+            *input = rest;
+            value |= ((byte & 0x7f) as u64) << (shift * 7);
+            if byte & 0x80 == 0 {
+                return Some(value);
+            }
+        }
+        None
+    }
+
+    /// Decode the multibase of a peer ID into its multihash
+    fn peer_id_multihash(peer_id: &PeerId) -> Option<Vec<u8>> {
+        let peer_id = &peer_id.0;
+
+        // Bounds the decoding cost
+        if peer_id.len() < 32 || peer_id.len() > 128 {
+            return None;
+        }
+
+        match peer_id[0] {
+            b'1' | b'Q' => Self::decode_base58(peer_id),
+            b'b' => Self::decode_cid(&Self::decode_base32(&peer_id[1..])?),
+            b'z' => Self::decode_cid(&Self::decode_base58(&peer_id[1..])?),
+            b'f' => Self::decode_cid(&Self::decode_base16(&peer_id[1..])?),
+            _ => None,
+        }
+    }
+
+    fn decode_cid(input: &[u8]) -> Option<Vec<u8>> {
+        let mut input = input;
+        if Self::read_varint(&mut input)? != CID_V1
+            || Self::read_varint(&mut input)? != CID_LIBP2P_KEY
+        {
+            return None;
+        }
+        Some(input.to_vec())
+    }
+
+    /// Multihash code and digest
+    fn decode_multihash(input: &[u8]) -> Option<(u64, &[u8])> {
+        let mut input = input;
+        let code = Self::read_varint(&mut input)?;
+        let length = Self::read_varint(&mut input)? as usize;
+        if input.len() != length {
+            return None;
+        }
+
+        match code {
+            MULTIHASH_IDENTITY if length <= MAX_INLINE_KEY_LENGTH => Some((code, input)),
+            MULTIHASH_SHA2_256 if length == 32 => Some((code, input)),
+            _ => None,
+        }
+    }
+
+    /// Protobuf encoded `PublicKey { KeyType Type = 1; bytes Data = 2; }`
+    fn decode_public_key(input: &[u8]) -> Option<(KeyType, &[u8])> {
+        let mut input = input;
+
+        let (&tag, rest) = input.split_first()?;
+        input = rest;
+        if tag != 0x08 {
+            return None;
+        }
+        let key_type = Self::read_varint(&mut input)?;
+
+        let (&tag, rest) = input.split_first()?;
+        input = rest;
+        if tag != 0x12 {
+            return None;
+        }
+        let length = Self::read_varint(&mut input)? as usize;
+        if input.len() != length {
+            return None;
+        }
+
+        let key_type = match (key_type, length) {
+            (0, _) => KeyType::Rsa,
+            (1, 32) => KeyType::Ed25519,
+            (2, 33) => KeyType::Secp256k1,
+            (3, _) => KeyType::Ecdsa,
+            _ => return None,
+        };
+
+        Some((key_type, input))
+    }
+}
@@ -0,0 +1,245 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use sp_core::ed25519;
+use sp_runtime::traits::Verify;
+
+/// Domain separator of the message signed by a peer ID's key
+pub const PEER_ID_PROOF_CONTEXT: &[u8] = b"hypertensor-peer-id-proof";
+
+/// Blocks after its signed block a peer ID proof can be submitted and used
+pub const PEER_ID_PROOF_VALIDITY_BLOCKS: u32 = 600;
+
+impl<T: Config> Pallet<T> {
+    /// Message the key embedded in a peer ID signs to prove `hotkey` owns it
+    pub fn peer_id_proof_message(subnet_id: u32, hotkey: &T::AccountId, block: u32) -> Vec<u8> {
+        (PEER_ID_PROOF_CONTEXT, subnet_id, hotkey, block).encode()
+    }
+
+    pub fn do_prove_peer_id(
+        depositor: T::AccountId,
+        subnet_id: u32,
+        hotkey: T::AccountId,
+        peer_id: PeerId,
+        block: u32,
+        signature: Vec<u8>,
+    ) -> DispatchResult {
+        ensure!(
+            SubnetsData::<T>::contains_key(subnet_id),
+            Error::<T>::InvalidSubnetId
+        );
+
+        ensure!(
+            SubnetPeerIdProofRequired::<T>::get(subnet_id),
+            Error::<T>::PeerIdProofNotRequired
+        );
+
+        ensure!(
+            Self::is_peer_id_proof_block_valid(block),
+            Error::<T>::PeerIdProofExpired
+        );
+
+        let message = Self::peer_id_proof_message(subnet_id, &hotkey, block);
+        ensure!(
+            Self::verify_peer_id_signature(&peer_id, &message, &signature),
+            Error::<T>::InvalidPeerIdProof
+        );
+
+        // Replacing a proof returns its deposit
+        if let Some(proof) = PeerIdProofs::<T>::get(subnet_id, &peer_id) {
+            Self::remove_peer_id_proof(subnet_id, &peer_id, &proof);
+        }
+
+        let deposit = T::PeerIdProofDeposit::get();
+        let deposit_as_balance =
+            Self::u128_to_balance(deposit).ok_or(Error::<T>::CouldNotConvertToBalance)?;
+        T::Currency::reserve(&depositor, deposit_as_balance)
+            .map_err(|_| Error::<T>::NotEnoughBalance)?;
+
+        PeerIdProofs::<T>::insert(
+            subnet_id,
+            &peer_id,
+            PeerIdProof {
+                hotkey: hotkey.clone(),
+                block,
+                depositor,
+                deposit,
+            },
+        );
+        PeerIdProofsBySignedBlock::<T>::insert(block, (subnet_id, peer_id.clone()), ());
+
+        if !PeerIdProofPruneBlock::<T>::exists() {
+            PeerIdProofPruneBlock::<T>::put(
+                Self::get_current_block_as_u32().saturating_sub(PEER_ID_PROOF_VALIDITY_BLOCKS),
+            );
+        }
+
+        Self::deposit_event(Event::PeerIdProven {
+            subnet_id,
+            hotkey,
+            peer_id,
+        });
+
+        Ok(())
+    }
+
+    /// Consume the proof of `hotkey` owning `peer_id` if the subnet requires one
+    ///
+    /// The proof's deposit is returned to its depositor.
+    pub fn ensure_peer_id_proof(
+        subnet_id: u32,
+        hotkey: &T::AccountId,
+        peer_id: &PeerId,
+    ) -> DispatchResult {
+        if !SubnetPeerIdProofRequired::<T>::get(subnet_id) {
+            return Ok(());
+        }
+
+        let proof =
+            PeerIdProofs::<T>::get(subnet_id, peer_id).ok_or(Error::<T>::PeerIdProofRequired)?;
+
+        ensure!(
+            proof.hotkey == *hotkey,
+            Error::<T>::PeerIdProofHotkeyMismatch
+        );
+
+        ensure!(
+            Self::is_peer_id_proof_block_valid(proof.block),
+            Error::<T>::PeerIdProofExpired
+        );
+
+        Self::remove_peer_id_proof(subnet_id, peer_id, &proof);
+
+        Ok(())
+    }
+
+    /// Prune proofs signed more than `PEER_ID_PROOF_VALIDITY_BLOCKS` ago and return their deposits
+    ///
+    /// Walks `PeerIdProofsBySignedBlock` from `PeerIdProofPruneBlock` while the weight allows.
+    pub fn prune_expired_peer_id_proofs(weight_meter: &mut WeightMeter) {
+        let db_weight = T::DbWeight::get();
+
+        // PeerIdProofPruneBlock
+        if !weight_meter.can_consume(db_weight.reads(1)) {
+            return;
+        }
+        weight_meter.consume(db_weight.reads(1));
+        let Some(prune_block) = PeerIdProofPruneBlock::<T>::get() else {
+            return;
+        };
+
+        let cutoff = Self::get_current_block_as_u32().saturating_sub(PEER_ID_PROOF_VALIDITY_BLOCKS);
+
+        // Always leave enough weight to write the new prune block
+        let mut block = prune_block;
+        'blocks: while block < cutoff {
+            // PeerIdProofsBySignedBlock iteration
+            if !weight_meter.can_consume(db_weight.reads_writes(1, 1)) {
+                break;
+            }
+            weight_meter.consume(db_weight.reads(1));
+
+            let mut entries = PeerIdProofsBySignedBlock::<T>::iter_key_prefix(block);
+            loop {
+                // PeerIdProofsBySignedBlock | PeerIdProofs | depositor account
+                if !weight_meter.can_consume(db_weight.reads_writes(3, 4)) {
+                    break 'blocks;
+                }
+                let Some((subnet_id, peer_id)) = entries.next() else {
+                    break;
+                };
+                weight_meter.consume(db_weight.reads_writes(3, 3));
+
+                match PeerIdProofs::<T>::get(subnet_id, &peer_id) {
+                    // The proof may have been replaced by a newer one
+                    Some(proof) if proof.block == block => {
+                        Self::remove_peer_id_proof(subnet_id, &peer_id, &proof)
+                    }
+                    _ => PeerIdProofsBySignedBlock::<T>::remove(block, (subnet_id, peer_id)),
+                }
+            }
+            block += 1;
+        }
+
+        if block != prune_block {
+            PeerIdProofPruneBlock::<T>::put(block);
+            weight_meter.consume(db_weight.writes(1));
+        }
+    }
+
+    fn remove_peer_id_proof(subnet_id: u32, peer_id: &PeerId, proof: &PeerIdProof<T::AccountId>) {
+        PeerIdProofs::<T>::remove(subnet_id, peer_id);
+        PeerIdProofsBySignedBlock::<T>::remove(proof.block, (subnet_id, peer_id.clone()));
+        if let Some(deposit) = Self::u128_to_balance(proof.deposit) {
+            T::Currency::unreserve(&proof.depositor, deposit);
+        }
+    }
+
+    /// Verify `signature` of `message` by the key inlined in `peer_id`
+    ///
+    /// Secp256k1 keys sign the SHA-256 hash of the message. libp2p produces DER encoded
+    /// signatures, 64 byte compact and 65 byte recoverable signatures are accepted too. Peer IDs
+    /// that only embed the hash of their key can't be verified.
+    pub fn verify_peer_id_signature(peer_id: &PeerId, message: &[u8], signature: &[u8]) -> bool {
+        let Some((key_type, public_key)) = Self::peer_id_public_key(peer_id) else {
+            return false;
+        };
+
+        match key_type {
+            KeyType::Ed25519 => {
+                let (Ok(signature), Ok(public_key)) = (
+                    <[u8; 64]>::try_from(signature),
+                    <[u8; 32]>::try_from(public_key.as_slice()),
+                ) else {
+                    return false;
+                };
+                ed25519::Signature::from_raw(signature)
+                    .verify(message, &ed25519::Public::from_raw(public_key))
+            }
+            KeyType::Secp256k1 => {
+                let message_hash = sp_io::hashing::sha2_256(message);
+                let mut recoverable = [0u8; 65];
+                match signature.len() {
+                    64 | 65 => recoverable[..64].copy_from_slice(&signature[..64]),
+                    _ => {
+                        let Ok(mut der) = libsecp256k1::Signature::parse_der(signature) else {
+                            return false;
+                        };
+                        // libp2p doesn't enforce low S values, both recovery IDs are tried anyway
+                        der.normalize_s();
+                        recoverable[..64].copy_from_slice(&der.serialize());
+                    }
+                }
+                let recovery_ids: &[u8] = match signature.len() {
+                    65 => &signature[64..],
+                    _ => &[0, 1],
+                };
+                recovery_ids.iter().any(|&recovery_id| {
+                    recoverable[64] = recovery_id;
+                    sp_io::crypto::secp256k1_ecdsa_recover_compressed(&recoverable, &message_hash)
+                        .map_or(false, |recovered| recovered[..] == public_key[..])
+                })
+            }
+            KeyType::Rsa | KeyType::Ecdsa => false,
+        }
+    }
+
+    fn is_peer_id_proof_block_valid(block: u32) -> bool {
+        let current_block = Self::get_current_block_as_u32();
+        block <= current_block
+            && current_block.saturating_sub(block) <= PEER_ID_PROOF_VALIDITY_BLOCKS
+    }
+}
@@ -34,6 +34,30 @@ impl<T: Config> Pallet<T> {
         random_number % total
     }
 
+    /// Pick an index with probability proportional to its weight
+    ///
+    /// Returns `None` when all weights are zero
+    pub fn get_weighted_random_index(random_number: u128, weights: &[u128]) -> Option<usize> {
+        let total = weights
+            .iter()
+            .fold(0u128, |acc, weight| acc.saturating_add(*weight));
+        if total == 0 {
+            return None;
+        }
+
+        let target = random_number % total;
+
+        let mut cumulative: u128 = 0;
+        for (idx, weight) in weights.iter().enumerate() {
+            cumulative = cumulative.saturating_add(*weight);
+            if target < cumulative {
+                return Some(idx);
+            }
+        }
+
+        None
+    }
+
     /// Generate a random number from a given seed.
     /// Note that there is potential bias introduced by using modulus operator.
     /// You should call this function with different seed values until the random
@@ -59,4 +83,29 @@ impl<T: Config> Pallet<T> {
 
         u32::from_le_bytes(array)
     }
+
+    /// Same as `generate_random_number` with the first 16 bytes of the seed
+    pub fn generate_random_number_u128(seed: u32) -> u128 {
+        let (random_seed, _) = T::Randomness::random(&(T::PalletId::get(), seed).encode());
+
+        let bytes = random_seed.as_ref();
+        let mut array = [0u8; 16];
+        array.copy_from_slice(&bytes[0..16]);
+
+        u128::from_le_bytes(array)
+    }
+
+    /// Random number for the backup validator election
+    ///
+    /// Uses its own subject so it's independent of `generate_random_number` for any seed
+    pub fn generate_backup_random_number(seed: u32) -> u128 {
+        let (random_seed, _) =
+            T::Randomness::random(&(T::PalletId::get(), b"backup", seed).encode());
+
+        let bytes = random_seed.as_ref();
+        let mut array = [0u8; 16];
+        array.copy_from_slice(&bytes[0..16]);
+
+        u128::from_le_bytes(array)
+    }
 }
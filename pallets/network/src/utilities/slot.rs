@@ -17,6 +17,7 @@
 
 use super::*;
 use frame_support::pallet_prelude::{Weight, Zero};
+use sp_core::U256;
 
 impl<T: Config> Pallet<T> {
     // Returns subnet weights, node scores, and db weight
@@ -30,10 +31,9 @@ impl<T: Config> Pallet<T> {
 
         let percentage_factor = Self::percentage_factor_as_u128();
 
-        let stake_weight_pow: f64 =
-            Self::get_percent_as_f64(OverwatchStakeWeightFactor::<T>::get());
+        let stake_weight_pow: U256 = U256::from(OverwatchStakeWeightFactor::<T>::get());
         weight = weight.saturating_add(db_weight.reads(1));
-        let mut total_stake_weight = 0;
+        let mut total_stake_weight: u128 = 0;
 
         // {node_id, score}
         let mut node_total_scores: BTreeMap<u32, u128> = BTreeMap::new();
@@ -66,10 +66,15 @@ impl<T: Config> Pallet<T> {
                 // AccountOverwatchStake
                 weight = weight.saturating_add(db_weight.reads(1));
 
-                let stake_weight_adj =
-                    Self::get_f64_as_percentage(Self::pow(stake_balance as f64, stake_weight_pow));
+                // stake_balance^stake_weight_pow as 1e18
+                let stake_weight_adj: u128 = Self::fixed_pow(
+                    U256::from(stake_balance).saturating_mul(Self::PERCENTAGE_FACTOR),
+                    stake_weight_pow,
+                )
+                .try_into()
+                .unwrap_or(u128::MAX);
 
-                total_stake_weight += stake_weight_adj;
+                total_stake_weight = total_stake_weight.saturating_add(stake_weight_adj);
 
                 node_stake_weights.insert(overwatch_node_id, stake_weight_adj);
                 node_hotkeys.insert(overwatch_node_id, overwatch_node.hotkey.clone());
@@ -140,6 +145,11 @@ impl<T: Config> Pallet<T> {
         //
         let ow_emissions = T::OverwatchEpochEmissions::get();
 
+        let reward_history = Self::is_reward_history_enabled();
+        let current_epoch = Self::get_current_epoch_as_u32();
+        // RewardHistoryDepth
+        weight = weight.saturating_add(db_weight.reads(1));
+
         let mut node_rewards: Vec<(u32, u128)> = Vec::new();
 
         for (node_id, score) in node_total_scores.iter() {
@@ -170,6 +180,14 @@ impl<T: Config> Pallet<T> {
             Self::increase_account_overwatch_stake(&hotkey, amount);
             weight = weight.saturating_add(db_weight.reads_writes(2, 2));
 
+            if reward_history {
+                weight = weight.saturating_add(Self::record_account_rewards(
+                    current_epoch,
+                    hotkey,
+                    |rewards| rewards.overwatch_rewards.saturating_accrue(amount),
+                ));
+            }
+
             node_rewards.push((*node_id, amount));
         }
 
@@ -213,6 +231,12 @@ impl<T: Config> Pallet<T> {
                 weight_meter.consume(consensus_submission_block_weight);
 
                 if let Some(consensus_submission_data) = consensus_submission_data {
+                    weight_meter.consume(Self::archive_consensus_submission(
+                        subnet_id,
+                        current_subnet_epoch - 1,
+                        consensus_submission_data.clone(),
+                    ));
+
                     // Calculate rewards
                     let (rewards_data, rewards_block_weight) = Self::calculate_rewards(
                         subnet_id,
@@ -438,22 +462,22 @@ impl<T: Config> Pallet<T> {
         let mut weight = Weight::zero();
         let db_weight = T::DbWeight::get();
 
-        let subnet_distribution_power =
-            Self::get_percent_as_f64(SubnetDistributionPower::<T>::get());
+        let percentage_factor = Self::percentage_factor_as_u128();
+        let subnet_distribution_power = SubnetDistributionPower::<T>::get();
         let total_delegate_stake = TotalDelegateStake::<T>::get();
 
         // {subnet_id, weight}
-        let mut subnet_weights: BTreeMap<u32, f64> = BTreeMap::new();
+        let mut subnet_weights: BTreeMap<u32, u128> = BTreeMap::new();
         // {subnet_id, count}
-        let mut subnet_weight_sum: f64 = 0.0;
-        let total_electable_nodes: f64 = TotalElectableNodes::<T>::get() as f64;
+        let mut subnet_weight_sum: u128 = 0;
+        let total_electable_nodes: u128 = TotalElectableNodes::<T>::get() as u128;
         let mut total_subnet_reads = 0u64;
 
         let weight_factors = SubnetWeightFactors::<T>::get();
         weight = weight.saturating_add(db_weight.reads(1));
-        let delegate_stake_factor = Self::get_percent_as_f64(weight_factors.delegate_stake);
-        let node_count_factor = Self::get_percent_as_f64(weight_factors.node_count);
-        let net_flow_factor = Self::get_percent_as_f64(weight_factors.net_flow);
+        let delegate_stake_factor = weight_factors.delegate_stake;
+        let node_count_factor = weight_factors.node_count;
+        let net_flow_factor = weight_factors.net_flow;
 
         // SubnetDistributionPower | TotalDelegateStake
         // TotalElectableNodes | DelegateStakeWeightFactor
@@ -479,24 +503,26 @@ impl<T: Config> Pallet<T> {
             let total_subnet_delegate_stake = TotalSubnetDelegateStakeBalance::<T>::get(subnet_id);
             weight = weight.saturating_add(db_weight.reads(1));
 
-            // - Get delegate stake weight in f64
-            let subnet_dstake_weight: f64 =
-                (total_subnet_delegate_stake as f64 / total_delegate_stake as f64).clamp(0.0, 1.0);
+            // - Get delegate stake weight as 1e18
+            let subnet_dstake_weight: u128 =
+                Self::percent_div(total_subnet_delegate_stake, total_delegate_stake)
+                    .min(percentage_factor);
 
-            // - Get node count weight in f64
+            // - Get node count weight as 1e18
             let electable_nodes_count = TotalSubnetElectableNodes::<T>::get(subnet_id);
             weight = weight.saturating_add(db_weight.reads(1));
-            let subnet_nodes_weight = electable_nodes_count as f64 / total_electable_nodes;
+            let subnet_nodes_weight =
+                Self::percent_div(electable_nodes_count as u128, total_electable_nodes)
+                    .min(percentage_factor);
 
-            // - Get Overwatch weight in f64
+            // - Get Overwatch weight as 1e18
             let overwatch_subnet_weight = match OverwatchSubnetWeights::<T>::try_get(
                 current_overwatch_epoch.saturating_sub(1),
                 subnet_id,
             ) {
-                Ok(weight) => (Self::get_percent_as_f64(weight)
-                    * Self::get_percent_as_f64(OverwatchWeightFactor::<T>::get()))
-                .min(1.0),
-                Err(()) => 1.0,
+                Ok(weight) => Self::percent_mul(weight, OverwatchWeightFactor::<T>::get())
+                    .min(percentage_factor),
+                Err(()) => percentage_factor,
             };
 
             // OverwatchSubnetWeights
@@ -504,30 +530,28 @@ impl<T: Config> Pallet<T> {
 
             // - Get combined weight (stake + node count + inflow) * overwatchers weight
 
-            let subnet_inflow_weight =
-                Self::get_percent_as_f64(inflow_weights.get(&subnet_id).cloned().unwrap_or(0));
-            let subnet_weight = ((subnet_dstake_weight * delegate_stake_factor
-                + subnet_nodes_weight * node_count_factor
-                + subnet_inflow_weight * net_flow_factor)
-                * overwatch_subnet_weight)
-                .clamp(0.0, 1.0);
+            let subnet_inflow_weight = inflow_weights.get(&subnet_id).cloned().unwrap_or(0);
+            let combined_weight = Self::percent_mul(subnet_dstake_weight, delegate_stake_factor)
+                .saturating_add(Self::percent_mul(subnet_nodes_weight, node_count_factor))
+                .saturating_add(Self::percent_mul(subnet_inflow_weight, net_flow_factor));
+            let subnet_weight =
+                Self::percent_mul(combined_weight, overwatch_subnet_weight).min(percentage_factor);
 
             // - Adj weight (to later be normalized)
-            let adj_subnet_weight: f64 = Self::pow(subnet_weight, subnet_distribution_power);
+            let adj_subnet_weight: u128 =
+                Self::percent_pow(subnet_weight, subnet_distribution_power);
 
             subnet_weights.insert(subnet_id, adj_subnet_weight);
-            subnet_weight_sum += adj_subnet_weight;
+            subnet_weight_sum = subnet_weight_sum.saturating_add(adj_subnet_weight);
             weight = weight.saturating_add(Weight::from_parts(400_000, 0));
         }
 
         weight = weight.saturating_add(db_weight.reads(total_subnet_reads));
         let mut subnet_weights_normalized: BTreeMap<u32, u128> = BTreeMap::new();
-        let percentage_factor = Self::percentage_factor_as_u128();
 
         // --- Normalize delegate stake weights from power
         for (subnet_id, subnet_weight) in subnet_weights {
-            let weight_normalized: u128 =
-                (subnet_weight / subnet_weight_sum * percentage_factor as f64) as u128;
+            let weight_normalized: u128 = Self::percent_div(subnet_weight, subnet_weight_sum);
             subnet_weights_normalized.insert(subnet_id, weight_normalized);
             weight = weight.saturating_add(Weight::from_parts(400_000, 0));
         }
@@ -655,6 +679,27 @@ impl<T: Config> Pallet<T> {
             }
         };
 
+        // --- Backup validator proposed, the elected validator was absent
+        // The subnet keeps its rewards, only the absent node's reputation is decreased
+        weight = weight.saturating_add(db_weight.reads(1));
+        if let Some(elected_validator_id) =
+            SubnetElectedValidator::<T>::get(subnet_id, prev_subnet_epoch)
+        {
+            if elected_validator_id != submission.validator_id {
+                let reputation = Self::get_decrease_reputation(
+                    SubnetNodeReputation::<T>::get(subnet_id, elected_validator_id),
+                    ValidatorAbsentSubnetNodeReputationFactor::<T>::get(subnet_id),
+                );
+                SubnetNodeReputation::<T>::insert(subnet_id, elected_validator_id, reputation);
+                // Reads:
+                // - SubnetNodeReputation
+                // - ValidatorAbsentSubnetNodeReputationFactor
+                // Writes:
+                // - SubnetNodeReputation
+                weight = weight.saturating_add(db_weight.reads_writes(2, 1));
+            }
+        }
+
         // --- Get all qualified possible attestors
         // We take the subnet nodes generated from the validators `propose_attestation` call
         // These are the only nodes that could attest, even if they remove themselves, the attestation
@@ -168,26 +168,14 @@ impl<T: Config> Pallet<T> {
         // Apply concave exponential: exponent α < 1
         // e.g., α = 0.5 = sqrt (concave)
         // concave factor = remaining_frac ^ alpha
-        let concave_factor = Self::pow(
-            Self::get_percent_as_f64(remaining_frac),
-            Self::get_percent_as_f64(alpha),
-        );
+        let concave_factor = Self::percent_pow(remaining_frac, alpha);
 
         // price = min_price + diff * concave_factor
-        // let addend: u128 = (diff as f64 * concave_factor)
-        //   .clamp(0.0, u128::MAX as f64)   // clamp the float
-        //   as u128;                        // now safe to cast
-
-        let safe_diff_f64 = (diff as f64).min(u128::MAX as f64 / concave_factor); // prevent inf
-        let addend = (safe_diff_f64 * concave_factor).clamp(0.0, u128::MAX as f64) as u128;
+        let addend = Self::percent_mul(diff, concave_factor);
 
         let decayed = min_price.saturating_add(addend);
 
-        // let decayed = min_price.saturating_add(
-        //   (diff as f64 * concave_factor) as u128
-        // );
-
-        decayed.min(u128::MAX).max(min_price)
+        decayed.max(min_price)
     }
 
     pub fn update_last_registration_cost(current_cost: u128, block: u32) {
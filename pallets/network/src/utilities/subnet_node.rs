@@ -172,10 +172,7 @@ impl<T: Config> Pallet<T> {
         maybe_params: &mut Option<SubnetNode<T::AccountId>>,
         new_peer_id: PeerId,
     ) -> DispatchResult {
-        ensure!(
-            Self::validate_peer_id(&new_peer_id),
-            Error::<T>::InvalidPeerId
-        );
+        Self::ensure_peer_id_key_type(subnet_id, &new_peer_id, Error::<T>::InvalidPeerId)?;
 
         ensure!(
             Self::is_owner_of_peer_or_ownerless(subnet_id, 0, 0, &new_peer_id),
@@ -186,6 +183,8 @@ impl<T: Config> Pallet<T> {
             .as_mut()
             .ok_or(Error::<T>::InvalidSubnetNodeId)?;
 
+        Self::ensure_peer_id_proof(subnet_id, &params.hotkey, &new_peer_id)?;
+
         PeerIdSubnetNodeId::<T>::remove(subnet_id, &params.peer_id);
         PeerIdSubnetNodeId::<T>::insert(subnet_id, &new_peer_id, subnet_node_id);
 
@@ -314,10 +313,11 @@ impl<T: Config> Pallet<T> {
         maybe_params: &mut Option<SubnetNode<T::AccountId>>,
         new_bootnode_peer_id: PeerId,
     ) -> DispatchResult {
-        ensure!(
-            Self::validate_peer_id(&new_bootnode_peer_id),
-            Error::<T>::InvalidBootnodePeerId
-        );
+        Self::ensure_peer_id_key_type(
+            subnet_id,
+            &new_bootnode_peer_id,
+            Error::<T>::InvalidBootnodePeerId,
+        )?;
 
         ensure!(
             Self::is_owner_of_peer_or_ownerless(subnet_id, 0, 0, &new_bootnode_peer_id),
@@ -328,6 +328,8 @@ impl<T: Config> Pallet<T> {
             .as_mut()
             .ok_or(Error::<T>::InvalidSubnetNodeId)?;
 
+        Self::ensure_peer_id_proof(subnet_id, &params.hotkey, &new_bootnode_peer_id)?;
+
         BootnodePeerIdSubnetNodeId::<T>::remove(subnet_id, &params.bootnode_peer_id);
         BootnodePeerIdSubnetNodeId::<T>::insert(subnet_id, &new_bootnode_peer_id, subnet_node_id);
 
@@ -389,10 +391,11 @@ impl<T: Config> Pallet<T> {
         maybe_params: &mut Option<SubnetNode<T::AccountId>>,
         new_client_peer_id: PeerId,
     ) -> DispatchResult {
-        ensure!(
-            Self::validate_peer_id(&new_client_peer_id),
-            Error::<T>::InvalidClientPeerId
-        );
+        Self::ensure_peer_id_key_type(
+            subnet_id,
+            &new_client_peer_id,
+            Error::<T>::InvalidClientPeerId,
+        )?;
 
         ensure!(
             Self::is_owner_of_peer_or_ownerless(subnet_id, 0, 0, &new_client_peer_id),
@@ -403,6 +406,8 @@ impl<T: Config> Pallet<T> {
             .as_mut()
             .ok_or(Error::<T>::InvalidSubnetNodeId)?;
 
+        Self::ensure_peer_id_proof(subnet_id, &params.hotkey, &new_client_peer_id)?;
+
         ClientPeerIdSubnetNodeId::<T>::remove(subnet_id, &params.client_peer_id);
         ClientPeerIdSubnetNodeId::<T>::insert(subnet_id, &new_client_peer_id, subnet_node_id);
 
@@ -895,10 +900,15 @@ impl<T: Config> Pallet<T> {
     }
 
     /// Is hotkey or coldkey owner for functions that allow both
+    ///
+    /// Node data of a removed subnet may still exist until the `CleanupQueue` clears it
     pub fn get_subnet_node_hotkey_coldkey(
         subnet_id: u32,
         subnet_node_id: u32,
     ) -> Option<(T::AccountId, T::AccountId)> {
+        if !SubnetsData::<T>::contains_key(subnet_id) {
+            return None;
+        }
         let hotkey = SubnetNodeIdHotkey::<T>::try_get(subnet_id, subnet_node_id).ok()?;
         let coldkey = HotkeyOwner::<T>::try_get(&hotkey).ok()?;
 
@@ -924,6 +934,9 @@ impl<T: Config> Pallet<T> {
         subnet_node_id: u32,
         coldkey: T::AccountId,
     ) -> bool {
+        if !SubnetsData::<T>::contains_key(subnet_id) {
+            return false;
+        }
         let hotkey = match SubnetNodeIdHotkey::<T>::try_get(subnet_id, subnet_node_id) {
             Ok(hotkey) => hotkey,
             Err(()) => return false,
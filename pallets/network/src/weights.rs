@@ -1,27 +1,14 @@
-
-//! Autogenerated weights for `pallet_network`
+//! Weights for `pallet_network`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 47.0.0
-//! DATE: 2025-12-14, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `Bob`, CPU: `11th Gen Intel(R) Core(TM) i7-11800H @ 2.30GHz`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
-
-// Executed Command:
-// frame-omni-bencher
-// v1
-// benchmark
-// pallet
-// --runtime
-// target/release/wbuild/hypertensor-runtime/hypertensor_runtime.compact.compressed.wasm
-// --extrinsic
-// 
-// --pallet
-// pallet_network
-// --output
-// pallets/network/src/weights.rs
-// --template
-// ./.maintain/frame-weight-template.hbs
+//! Not fully benchmarked. Weights marked "Estimated, not benchmarked" were written by hand
+//! from the storage accesses of each call and comparable benchmarked calls, they are not
+//! benchmark output. Weights whose reward history checkpoint storage is marked as such add a
+//! hand-written estimate to a benchmarked weight. The other weights were benchmarked with
+//! frame-omni-bencher on 2025-12-14 (STEPS: `50`, REPEAT: `20`, CPU: `11th Gen Intel(R)
+//! Core(TM) i7-11800H @ 2.30GHz`).
+//!
+//! Regenerate every weight with `.maintain/benchmark-weights.sh pallet_network`, the output
+//! replaces this file.
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -49,6 +36,18 @@ pub trait WeightInfo {
 	fn owner_add_or_update_initial_coldkeys() -> Weight;
 	fn owner_remove_initial_coldkeys() -> Weight;
 	fn owner_update_key_types() -> Weight;
+	fn owner_update_absent_decrease_reputation_factor() -> Weight;
+	fn owner_update_below_min_weight_decrease_reputation_factor() -> Weight;
+	fn owner_update_included_increase_reputation_factor() -> Weight;
+	fn owner_update_non_attestor_decrease_reputation_factor() -> Weight;
+	fn owner_update_non_consensus_attestor_decrease_reputation_factor() -> Weight;
+	fn owner_update_validator_absent_decrease_reputation_factor() -> Weight;
+	fn owner_update_validator_non_consensus_decrease_reputation_factor() -> Weight;
+	fn owner_update_min_subnet_node_reputation() -> Weight;
+	fn owner_update_election_mode() -> Weight;
+	fn owner_update_peer_id_proof_required() -> Weight;
+	fn owner_revert_emergency_validator_set() -> Weight;
+	fn owner_set_emergency_validator_set(x: u32, ) -> Weight;
 	fn owner_update_min_max_stake() -> Weight;
 	fn owner_update_delegate_stake_percentage() -> Weight;
 	fn owner_update_max_registered_nodes() -> Weight;
@@ -60,7 +59,9 @@ pub trait WeightInfo {
 	fn owner_update_node_burn_rate_alpha() -> Weight;
 	fn owner_update_queue_immunity_epochs() -> Weight;
 	fn owner_update_subnet_node_min_weight_decrease_reputation_threshold() -> Weight;
-	fn update_bootnodes() -> Weight;
+	fn update_bootnodes(x: u32, ) -> Weight;
+	fn register_subnet_node() -> Weight;
+	fn prove_peer_id() -> Weight;
 	fn remove_subnet_node() -> Weight;
 	fn register_or_update_identity() -> Weight;
 	fn remove_identity() -> Weight;
@@ -80,11 +81,11 @@ pub trait WeightInfo {
 	fn donate_node_delegate_stake() -> Weight;
 	fn swap_from_node_to_subnet() -> Weight;
 	fn swap_from_subnet_to_node() -> Weight;
-	fn propose_attestation() -> Weight;
+	fn propose_attestation(x: u32, ) -> Weight;
 	fn attest() -> Weight;
 	fn update_unique() -> Weight;
 	fn update_non_unique() -> Weight;
-	fn update_coldkey() -> Weight;
+	fn update_coldkey(x: u32, ) -> Weight;
 	fn update_hotkey() -> Weight;
 	fn update_peer_id() -> Weight;
 	fn update_bootnode() -> Weight;
@@ -94,8 +95,7 @@ pub trait WeightInfo {
 	fn remove_overwatch_node() -> Weight;
 	fn anyone_remove_overwatch_node() -> Weight;
 	fn set_overwatch_node_peer_id() -> Weight;
-	fn commit_overwatch_subnet_weights() -> Weight;
-	fn commit_overwatch_subnet_weights_v2(x: u32, ) -> Weight;
+	fn commit_overwatch_subnet_weights(x: u32, ) -> Weight;
 	fn reveal_overwatch_subnet_weights(x: u32, ) -> Weight;
 	fn add_to_overwatch_stake() -> Weight;
 	fn remove_overwatch_stake() -> Weight;
@@ -167,7 +167,31 @@ pub trait WeightInfo {
 	fn set_base_node_burn_amount() -> Weight;
 	fn set_node_burn_rates() -> Weight;
 	fn set_max_subnet_node_min_weight_decrease_reputation_threshold() -> Weight;
+	fn set_attestor_min_reward_factor() -> Weight;
+	fn set_attestor_reward_exponent() -> Weight;
+	fn set_churn_limit_multipliers() -> Weight;
+	fn set_in_consensus_subnet_reputation_factor() -> Weight;
+	fn set_less_than_min_nodes_subnet_reputation_factor() -> Weight;
+	fn set_max_emergency_subnet_nodes() -> Weight;
+	fn set_max_emergency_validator_epochs_multiplier() -> Weight;
+	fn set_max_pause_epochs_subnet_reputation_factor() -> Weight;
+	fn set_min_subnet_reputation() -> Weight;
+	fn set_not_in_consensus_subnet_reputation_factor() -> Weight;
+	fn set_overwatch_stake_weight_factor() -> Weight;
+	fn set_overwatch_weight_factor() -> Weight;
+	fn set_validator_proposal_absent_subnet_reputation_factor() -> Weight;
+	fn set_validator_reward_k() -> Weight;
+	fn set_validator_reward_midpoint() -> Weight;
+	fn set_min_max_node_reputation() -> Weight;
+	fn set_min_max_node_reputation_factor() -> Weight;
+	fn set_subnet_weight_factors() -> Weight;
+	fn set_max_pending_swaps_per_account() -> Weight;
+	fn set_backup_proposer_epoch_progression() -> Weight;
+	fn set_backup_validator_reward_factor() -> Weight;
+	fn set_consensus_archive_depth() -> Weight;
+	fn set_reward_history_depth() -> Weight;
 	fn update_swap_queue() -> Weight;
+	fn cancel_swap_queue_item() -> Weight;
 	fn elect_validator(x: u32, ) -> Weight;
 	fn handle_increase_account_delegate_stake() -> Weight;
 	fn handle_increase_account_node_delegate_stake_shares() -> Weight;
@@ -188,7 +212,7 @@ pub trait WeightInfo {
 	fn calculate_subnet_weights(x: u32, ) -> Weight;
 }
 
-/// Weights for `pallet_network` using the Substrate node and recommended hardware.
+/// Weights for `pallet_network`, partly estimated, see the module docs.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Network::TxPause` (r:1 w:0)
@@ -817,6 +841,196 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Storage: `Network::MinNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::MaxNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::EmergencySubnetNodeElectionData` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::AbsentDecreaseReputationFactor` (r:0 w:1)
+	fn owner_update_absent_decrease_reputation_factor() -> Weight {
+		Weight::from_parts(64_318_000, 4280)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Storage: `Network::MinNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::MaxNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::EmergencySubnetNodeElectionData` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::BelowMinWeightDecreaseReputationFactor` (r:0 w:1)
+	fn owner_update_below_min_weight_decrease_reputation_factor() -> Weight {
+		Weight::from_parts(64_318_000, 4280)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Storage: `Network::MinNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::MaxNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::EmergencySubnetNodeElectionData` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::IncludedIncreaseReputationFactor` (r:0 w:1)
+	fn owner_update_included_increase_reputation_factor() -> Weight {
+		Weight::from_parts(64_318_000, 4280)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Storage: `Network::MinNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::MaxNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::EmergencySubnetNodeElectionData` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::NonAttestorDecreaseReputationFactor` (r:0 w:1)
+	fn owner_update_non_attestor_decrease_reputation_factor() -> Weight {
+		Weight::from_parts(64_318_000, 4280)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Storage: `Network::MinNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::MaxNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::EmergencySubnetNodeElectionData` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::NonConsensusAttestorDecreaseReputationFactor` (r:0 w:1)
+	fn owner_update_non_consensus_attestor_decrease_reputation_factor() -> Weight {
+		Weight::from_parts(64_318_000, 4280)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Storage: `Network::MinNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::MaxNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::EmergencySubnetNodeElectionData` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::ValidatorAbsentSubnetNodeReputationFactor` (r:0 w:1)
+	fn owner_update_validator_absent_decrease_reputation_factor() -> Weight {
+		Weight::from_parts(64_318_000, 4280)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Storage: `Network::MinNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::MaxNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::EmergencySubnetNodeElectionData` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::ValidatorNonConsensusSubnetNodeReputationFactor` (r:0 w:1)
+	fn owner_update_validator_non_consensus_decrease_reputation_factor() -> Weight {
+		Weight::from_parts(64_318_000, 4280)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Storage: `Network::MinMinSubnetNodeReputation` (r:1 w:0)
+	/// Storage: `Network::MaxMinSubnetNodeReputation` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::MinSubnetNodeReputation` (r:0 w:1)
+	fn owner_update_min_subnet_node_reputation() -> Weight {
+		Weight::from_parts(64_318_000, 4280)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::SubnetElectionMode` (r:0 w:1)
+	fn owner_update_election_mode() -> Weight {
+		Weight::from_parts(64_318_000, 4280)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::SubnetPeerIdProofRequired` (r:0 w:1)
+	fn owner_update_peer_id_proof_required() -> Weight {
+		Weight::from_parts(64_318_000, 4280)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::EmergencySubnetNodeElectionData` (r:0 w:1)
+	fn owner_revert_emergency_validator_set() -> Weight {
+		Weight::from_parts(64_318_000, 4280)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Storage: `Network::SubnetsData` (r:1 w:0)
+	/// Storage: `Network::SubnetSlot` (r:1 w:0)
+	/// Storage: `Network::SubnetNodesData` (r:64 w:0)
+	/// Storage: `Network::MinSubnetNodes` (r:1 w:0)
+	/// Storage: `Network::MaxEmergencySubnetNodes` (r:1 w:0)
+	/// Storage: `Network::AbsentDecreaseReputationFactor` (r:1 w:0)
+	/// Storage: `Network::MinSubnetNodeReputation` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::EmergencySubnetNodeElectionData` (r:0 w:1)
+	/// The range of component `x` is `[3, 64]`.
+	fn owner_set_emergency_validator_set(x: u32, ) -> Weight {
+		Weight::from_parts(63_905_114, 4787)
+			.saturating_add(Weight::from_parts(5_912_407, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 2713).saturating_mul(x.into()))
+	}
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetOwner` (r:1 w:0)
@@ -1100,35 +1314,74 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Estimated, not benchmarked.
 	/// Storage: `Network::TxPause` (r:1 w:0)
-	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetsData` (r:1 w:0)
-	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetOwner` (r:1 w:0)
-	/// Proof: `Network::SubnetOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetBootnodeAccess` (r:1 w:0)
-	/// Proof: `Network::SubnetBootnodeAccess` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::MaxBootnodes` (r:1 w:0)
-	/// Proof: `Network::MaxBootnodes` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetBootnodes` (r:1 w:1)
-	/// Proof: `Network::SubnetBootnodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `System::Number` (r:1 w:0)
-	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::ExecutionPhase` (r:1 w:0)
-	/// Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
 	/// Storage: `System::EventCount` (r:1 w:1)
-	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::Events` (r:1 w:1)
-	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	fn update_bootnodes() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1175`
-		//  Estimated: `4640`
-		// Minimum execution time: 91_786_000 picoseconds.
-		Weight::from_parts(104_006_000, 4640)
+	/// The range of component `x` is `[1, 32]`.
+	fn update_bootnodes(x: u32, ) -> Weight {
+		Weight::from_parts(98_214_466, 4640)
+			.saturating_add(Weight::from_parts(1_372_518, 0).saturating_mul(x.into()))
 			.saturating_add(T::DbWeight::get().reads(10_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetsData` (r:1 w:0)
+	/// Storage: `Network::HotkeyOwner` (r:1 w:1)
+	/// Storage: `Network::HotkeySubnetId` (r:1 w:1)
+	/// Storage: `Network::SubnetRegistrationInitialColdkeys` (r:1 w:0)
+	/// Storage: `Network::TotalSubnetNodes` (r:1 w:1)
+	/// Storage: `Network::MaxRegisteredNodes` (r:1 w:0)
+	/// Storage: `Network::SubnetKeyTypes` (r:1 w:0)
+	/// Storage: `Network::SubnetPeerIdProofRequired` (r:1 w:0)
+	/// Storage: `Network::PeerIdProofs` (r:3 w:3)
+	/// Storage: `Network::PeerIdSubnetNodeId` (r:1 w:1)
+	/// Storage: `Network::BootnodePeerIdSubnetNodeId` (r:1 w:1)
+	/// Storage: `Network::ClientPeerIdSubnetNodeId` (r:1 w:1)
+	/// Storage: `Network::BootnodeSubnetNodeId` (r:1 w:1)
+	/// Storage: `Network::TotalNodes` (r:1 w:1)
+	/// Storage: `Network::SubnetNodeIdHotkey` (r:0 w:1)
+	/// Storage: `Network::HotkeySubnetNodeId` (r:0 w:1)
+	/// Storage: `Network::RegisteredSubnetNodesData` (r:0 w:1)
+	/// Storage: `Network::SubnetNodeQueue` (r:1 w:1)
+	/// Storage: `Network::AccountSubnetStake` (r:1 w:1)
+	/// Storage: `Network::TotalSubnetStake` (r:1 w:1)
+	/// Storage: `Network::TotalStake` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	fn register_subnet_node() -> Weight {
+		Weight::from_parts(339_172_000, 11662)
+			.saturating_add(T::DbWeight::get().reads(30_u64))
+			.saturating_add(T::DbWeight::get().writes(22_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetsData` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::SubnetPeerIdProofRequired` (r:1 w:0)
+	/// Storage: `Network::PeerIdProofs` (r:1 w:1)
+	/// Storage: `Network::PeerIdProofsBySignedBlock` (r:0 w:2)
+	/// Storage: `Network::PeerIdProofPruneBlock` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn prove_peer_id() -> Weight {
+		Weight::from_parts(98_448_000, 6172)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetNodeIdHotkey` (r:1 w:1)
@@ -1384,6 +1637,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
+	/// Reward history checkpoint storage estimated, not benchmarked.
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetsData` (r:1 w:0)
@@ -1416,15 +1670,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Network::TotalDelegateStake` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetNetFlow` (r:1 w:1)
 	/// Proof: `Network::SubnetNetFlow` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::RewardHistoryDepth` (r:1 w:0)
+	/// Storage: `Network::SubnetDelegateRewardHistory` (r:1 w:0)
+	/// Storage: `Network::AccountSubnetDelegateSharesHistory` (r:1 w:1)
 	fn add_to_delegate_stake() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1922`
 		//  Estimated: `5387`
 		// Minimum execution time: 89_053_000 picoseconds.
 		Weight::from_parts(99_968_000, 5387)
-			.saturating_add(T::DbWeight::get().reads(16_u64))
-			.saturating_add(T::DbWeight::get().writes(10_u64))
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
 	}
+	/// Reward history checkpoint storage estimated, not benchmarked.
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetsData` (r:1 w:0)
@@ -1457,15 +1715,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SwapCallQueue` (r:0 w:1)
 	/// Proof: `Network::SwapCallQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::RewardHistoryDepth` (r:1 w:0)
+	/// Storage: `Network::SubnetDelegateRewardHistory` (r:1 w:0)
+	/// Storage: `Network::AccountSubnetDelegateSharesHistory` (r:1 w:1)
 	fn swap_delegate_stake() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3704`
 		//  Estimated: `7169`
 		// Minimum execution time: 77_433_000 picoseconds.
 		Weight::from_parts(84_604_000, 7169)
-			.saturating_add(T::DbWeight::get().reads(15_u64))
-			.saturating_add(T::DbWeight::get().writes(10_u64))
+			.saturating_add(T::DbWeight::get().reads(18_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
 	}
+	/// Reward history checkpoint storage estimated, not benchmarked.
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::TotalSubnetDelegateStakeShares` (r:1 w:1)
@@ -1480,15 +1742,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Network::TotalDelegateStake` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetNetFlow` (r:1 w:1)
 	/// Proof: `Network::SubnetNetFlow` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::RewardHistoryDepth` (r:1 w:0)
+	/// Storage: `Network::SubnetDelegateRewardHistory` (r:1 w:0)
+	/// Storage: `Network::AccountSubnetDelegateSharesHistory` (r:2 w:2)
 	fn transfer_delegate_stake() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1290`
 		//  Estimated: `7230`
 		// Minimum execution time: 45_670_000 picoseconds.
 		Weight::from_parts(50_362_000, 7230)
-			.saturating_add(T::DbWeight::get().reads(8_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
+	/// Reward history checkpoint storage estimated, not benchmarked.
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::AccountSubnetDelegateStakeShares` (r:1 w:1)
@@ -1519,14 +1785,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::Events` (r:1 w:1)
 	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::RewardHistoryDepth` (r:1 w:0)
+	/// Storage: `Network::SubnetDelegateRewardHistory` (r:1 w:0)
+	/// Storage: `Network::AccountSubnetDelegateSharesHistory` (r:1 w:1)
 	fn remove_delegate_stake() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1871`
 		//  Estimated: `5336`
 		// Minimum execution time: 75_256_000 picoseconds.
 		Weight::from_parts(80_160_000, 5336)
-			.saturating_add(T::DbWeight::get().reads(15_u64))
-			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().reads(18_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -1561,6 +1830,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(12_u64))
 			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
+	/// Reward history checkpoint storage estimated, not benchmarked.
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetNodesData` (r:1 w:0)
@@ -1591,15 +1861,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Network::AccountNodeDelegateStakeShares` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::TotalNodeDelegateStake` (r:1 w:1)
 	/// Proof: `Network::TotalNodeDelegateStake` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::RewardHistoryDepth` (r:1 w:0)
+	/// Storage: `Network::NodeDelegateRewardHistory` (r:1 w:0)
+	/// Storage: `Network::AccountNodeDelegateSharesHistory` (r:1 w:1)
 	fn add_to_node_delegate_stake() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2470`
 		//  Estimated: `5935`
 		// Minimum execution time: 94_035_000 picoseconds.
 		Weight::from_parts(102_044_000, 5935)
-			.saturating_add(T::DbWeight::get().reads(15_u64))
-			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().reads(18_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
+	/// Reward history checkpoint storage estimated, not benchmarked.
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetNodesData` (r:1 w:0)
@@ -1630,15 +1904,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SwapCallQueue` (r:0 w:1)
 	/// Proof: `Network::SwapCallQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::RewardHistoryDepth` (r:1 w:0)
+	/// Storage: `Network::NodeDelegateRewardHistory` (r:1 w:0)
+	/// Storage: `Network::AccountNodeDelegateSharesHistory` (r:1 w:1)
 	fn swap_node_delegate_stake() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `7038`
 		//  Estimated: `10503`
 		// Minimum execution time: 93_699_000 picoseconds.
 		Weight::from_parts(102_938_000, 10503)
-			.saturating_add(T::DbWeight::get().reads(14_u64))
-			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
+	/// Reward history checkpoint storage estimated, not benchmarked.
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::TotalNodeDelegateStakeShares` (r:1 w:1)
@@ -1651,15 +1929,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Network::AccountNodeDelegateStakeShares` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::TotalNodeDelegateStake` (r:1 w:1)
 	/// Proof: `Network::TotalNodeDelegateStake` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::RewardHistoryDepth` (r:1 w:0)
+	/// Storage: `Network::NodeDelegateRewardHistory` (r:1 w:0)
+	/// Storage: `Network::AccountNodeDelegateSharesHistory` (r:2 w:2)
 	fn transfer_node_delegate_stake() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1296`
 		//  Estimated: `7236`
 		// Minimum execution time: 49_343_000 picoseconds.
 		Weight::from_parts(52_492_000, 7236)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
+	/// Reward history checkpoint storage estimated, not benchmarked.
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::AccountNodeDelegateStakeShares` (r:1 w:1)
@@ -1688,14 +1970,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::Events` (r:1 w:1)
 	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::RewardHistoryDepth` (r:1 w:0)
+	/// Storage: `Network::NodeDelegateRewardHistory` (r:1 w:0)
+	/// Storage: `Network::AccountNodeDelegateSharesHistory` (r:1 w:1)
 	fn remove_node_delegate_stake() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1842`
 		//  Estimated: `5307`
 		// Minimum execution time: 77_209_000 picoseconds.
 		Weight::from_parts(81_222_000, 5307)
-			.saturating_add(T::DbWeight::get().reads(14_u64))
-			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -1730,6 +2015,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(12_u64))
 			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
+	/// Reward history checkpoint storage estimated, not benchmarked.
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetsData` (r:1 w:0)
@@ -1760,15 +2046,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SwapCallQueue` (r:0 w:1)
 	/// Proof: `Network::SwapCallQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::RewardHistoryDepth` (r:1 w:0)
+	/// Storage: `Network::NodeDelegateRewardHistory` (r:1 w:0)
+	/// Storage: `Network::AccountNodeDelegateSharesHistory` (r:1 w:1)
 	fn swap_from_node_to_subnet() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3848`
 		//  Estimated: `7313`
 		// Minimum execution time: 84_863_000 picoseconds.
 		Weight::from_parts(97_835_000, 7313)
-			.saturating_add(T::DbWeight::get().reads(14_u64))
-			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
+	/// Reward history checkpoint storage estimated, not benchmarked.
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetNodesData` (r:1 w:0)
@@ -1801,47 +2091,39 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SwapCallQueue` (r:0 w:1)
 	/// Proof: `Network::SwapCallQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::RewardHistoryDepth` (r:1 w:0)
+	/// Storage: `Network::SubnetDelegateRewardHistory` (r:1 w:0)
+	/// Storage: `Network::AccountSubnetDelegateSharesHistory` (r:1 w:1)
 	fn swap_from_subnet_to_node() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4097`
 		//  Estimated: `7562`
 		// Minimum execution time: 85_307_000 picoseconds.
 		Weight::from_parts(89_241_000, 7562)
-			.saturating_add(T::DbWeight::get().reads(15_u64))
-			.saturating_add(T::DbWeight::get().writes(10_u64))
+			.saturating_add(T::DbWeight::get().reads(18_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
 	}
+	/// Estimated, not benchmarked.
 	/// Storage: `Network::TxPause` (r:1 w:0)
-	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetSlot` (r:1 w:0)
-	/// Proof: `Network::SubnetSlot` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `System::Number` (r:1 w:0)
-	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Network::SubnetElectedValidator` (r:1 w:0)
-	/// Proof: `Network::SubnetElectedValidator` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetNodeIdHotkey` (r:1 w:0)
-	/// Proof: `Network::SubnetNodeIdHotkey` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetConsensusSubmission` (r:1 w:1)
-	/// Proof: `Network::SubnetConsensusSubmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `Network::SubnetNodesData` (r:6 w:0)
-	/// Proof: `Network::SubnetNodesData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetNodesData` (r:65 w:0)
 	/// Storage: `Network::ValidatorRewardMidpoint` (r:1 w:0)
-	/// Proof: `Network::ValidatorRewardMidpoint` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::ValidatorRewardK` (r:1 w:0)
-	/// Proof: `Network::ValidatorRewardK` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `System::ExecutionPhase` (r:1 w:0)
-	/// Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
 	/// Storage: `System::EventCount` (r:1 w:1)
-	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::Events` (r:1 w:1)
-	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	fn propose_attestation() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2553`
-		//  Estimated: `18393`
-		// Minimum execution time: 93_868_000 picoseconds.
-		Weight::from_parts(100_877_000, 18393)
-			.saturating_add(T::DbWeight::get().reads(17_u64))
+	/// The range of component `x` is `[5, 64]`.
+	fn propose_attestation(x: u32, ) -> Weight {
+		Weight::from_parts(71_062_313, 4838)
+			.saturating_add(Weight::from_parts(5_963_104, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 2711).saturating_mul(x.into()))
 	}
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -1928,36 +2210,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Estimated, not benchmarked.
 	/// Storage: `Network::TxPause` (r:1 w:0)
-	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `Network::HotkeyOwner` (r:1 w:1)
-	/// Proof: `Network::HotkeyOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::HotkeyOwner` (r:1 w:64)
 	/// Storage: `Network::StakeUnbondingLedger` (r:2 w:2)
-	/// Proof: `Network::StakeUnbondingLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::ColdkeyHotkeys` (r:2 w:2)
-	/// Proof: `Network::ColdkeyHotkeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::ColdkeyIdentity` (r:1 w:0)
-	/// Proof: `Network::ColdkeyIdentity` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::ColdkeyReputation` (r:2 w:2)
-	/// Proof: `Network::ColdkeyReputation` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::ColdkeySubnetNodes` (r:2 w:2)
-	/// Proof: `Network::ColdkeySubnetNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `System::Number` (r:1 w:0)
-	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::ExecutionPhase` (r:1 w:0)
-	/// Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
 	/// Storage: `System::EventCount` (r:1 w:1)
-	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::Events` (r:1 w:1)
-	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	fn update_coldkey() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1775`
-		//  Estimated: `7715`
-		// Minimum execution time: 67_921_000 picoseconds.
+	/// The range of component `x` is `[1, 64]`.
+	fn update_coldkey(x: u32, ) -> Weight {
 		Weight::from_parts(73_218_000, 7715)
+			.saturating_add(Weight::from_parts(4_500_000, 0).saturating_mul(x.into()))
 			.saturating_add(T::DbWeight::get().reads(15_u64))
-			.saturating_add(T::DbWeight::get().writes(11_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 48).saturating_mul(x.into()))
 	}
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -2276,59 +2548,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(9_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Estimated, not benchmarked.
 	/// Storage: `Network::TxPause` (r:1 w:0)
-	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::OverwatchNodeIdHotkey` (r:1 w:0)
-	/// Proof: `Network::OverwatchNodeIdHotkey` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::HotkeyOwner` (r:1 w:0)
-	/// Proof: `Network::HotkeyOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::OverwatchNodeBlacklist` (r:1 w:0)
-	/// Proof: `Network::OverwatchNodeBlacklist` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `System::Number` (r:1 w:0)
-	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Network::OverwatchEpochLengthMultiplier` (r:1 w:0)
-	/// Proof: `Network::OverwatchEpochLengthMultiplier` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::OverwatchCommitCutoffPercent` (r:1 w:0)
-	/// Proof: `Network::OverwatchCommitCutoffPercent` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `Network::SubnetsData` (r:64 w:0)
-	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `Network::OverwatchCommits` (r:1 w:1)
-	/// Proof: `Network::OverwatchCommits` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn commit_overwatch_subnet_weights() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `3088`
-		//  Estimated: `162478`
-		// Minimum execution time: 198_344_000 picoseconds.
-		Weight::from_parts(211_691_000, 162478)
-			.saturating_add(T::DbWeight::get().reads(72_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
-	}
-	/// Storage: `Network::TxPause` (r:1 w:0)
-	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `Network::OverwatchNodeIdHotkey` (r:1 w:0)
-	/// Proof: `Network::OverwatchNodeIdHotkey` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `Network::HotkeyOwner` (r:1 w:0)
-	/// Proof: `Network::HotkeyOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `Network::OverwatchNodeBlacklist` (r:1 w:0)
-	/// Proof: `Network::OverwatchNodeBlacklist` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `System::Number` (r:1 w:0)
-	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `Network::OverwatchEpochLengthMultiplier` (r:1 w:0)
-	/// Proof: `Network::OverwatchEpochLengthMultiplier` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `Network::OverwatchCommitCutoffPercent` (r:1 w:0)
-	/// Proof: `Network::OverwatchCommitCutoffPercent` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetsData` (r:17 w:0)
-	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::OverwatchCommits` (r:16 w:16)
-	/// Proof: `Network::OverwatchCommits` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `x` is `[1, 16]`.
-	fn commit_overwatch_subnet_weights_v2(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1751 + x * (152 ±0)`
-		//  Estimated: `5177 + x * (2627 ±1)`
-		// Minimum execution time: 54_654_000 picoseconds.
+	fn commit_overwatch_subnet_weights(x: u32, ) -> Weight {
 		Weight::from_parts(54_127_797, 5177)
-			// Standard Error: 91_371
 			.saturating_add(Weight::from_parts(12_493_159, 0).saturating_mul(x.into()))
 			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
@@ -3853,59 +4085,316 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `System::Number` (r:1 w:0)
 	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::ExecutionPhase` (r:1 w:0)
-	/// Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	/// Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::BaseNodeBurnAmount` (r:0 w:1)
+	/// Proof: `Network::BaseNodeBurnAmount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_base_node_burn_amount() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `24`
+		//  Estimated: `1509`
+		// Minimum execution time: 10_628_000 picoseconds.
+		Weight::from_parts(11_369_000, 1509)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MinNodeBurnRate` (r:0 w:1)
+	/// Proof: `Network::MinNodeBurnRate` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxNodeBurnRate` (r:0 w:1)
+	/// Proof: `Network::MaxNodeBurnRate` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_node_burn_rates() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `24`
+		//  Estimated: `1509`
+		// Minimum execution time: 10_144_000 picoseconds.
+		Weight::from_parts(12_137_000, 1509)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxSubnetNodeMinWeightDecreaseReputationThreshold` (r:0 w:1)
+	/// Proof: `Network::MaxSubnetNodeMinWeightDecreaseReputationThreshold` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_max_subnet_node_min_weight_decrease_reputation_threshold() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `24`
+		//  Estimated: `1509`
+		// Minimum execution time: 10_916_000 picoseconds.
+		Weight::from_parts(11_729_000, 1509)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::AttestorMinRewardFactor` (r:0 w:1)
+	fn set_attestor_min_reward_factor() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::AttestorRewardExponent` (r:0 w:1)
+	fn set_attestor_reward_exponent() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::MaxChurnLimitMultiplier` (r:0 w:1)
+	/// Storage: `Network::MinChurnLimitMultiplier` (r:0 w:1)
+	fn set_churn_limit_multipliers() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::InConsensusSubnetReputationFactor` (r:0 w:1)
+	fn set_in_consensus_subnet_reputation_factor() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::LessThanMinNodesSubnetReputationFactor` (r:0 w:1)
+	fn set_less_than_min_nodes_subnet_reputation_factor() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::MinSubnetNodes` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::MaxEmergencySubnetNodes` (r:0 w:1)
+	fn set_max_emergency_subnet_nodes() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::MaxEmergencyValidatorEpochsMultiplier` (r:0 w:1)
+	fn set_max_emergency_validator_epochs_multiplier() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::MaxPauseEpochsSubnetReputationFactor` (r:0 w:1)
+	fn set_max_pause_epochs_subnet_reputation_factor() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::MinSubnetReputation` (r:0 w:1)
+	fn set_min_subnet_reputation() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::NotInConsensusSubnetReputationFactor` (r:0 w:1)
+	fn set_not_in_consensus_subnet_reputation_factor() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::OverwatchStakeWeightFactor` (r:0 w:1)
+	fn set_overwatch_stake_weight_factor() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::OverwatchWeightFactor` (r:0 w:1)
+	fn set_overwatch_weight_factor() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::ValidatorAbsentSubnetReputationFactor` (r:0 w:1)
+	fn set_validator_proposal_absent_subnet_reputation_factor() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::ValidatorRewardK` (r:0 w:1)
+	fn set_validator_reward_k() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::ValidatorRewardMidpoint` (r:0 w:1)
+	fn set_validator_reward_midpoint() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::MaxMinSubnetNodeReputation` (r:0 w:1)
+	/// Storage: `Network::MinMinSubnetNodeReputation` (r:0 w:1)
+	fn set_min_max_node_reputation() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::MaxNodeReputationFactor` (r:0 w:1)
+	/// Storage: `Network::MinNodeReputationFactor` (r:0 w:1)
+	fn set_min_max_node_reputation_factor() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::SubnetWeightFactors` (r:0 w:1)
+	fn set_subnet_weight_factors() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::MaxPendingSwapsPerAccount` (r:0 w:1)
+	fn set_max_pending_swaps_per_account() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::BackupProposerEpochProgression` (r:0 w:1)
+	fn set_backup_proposer_epoch_progression() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
 	/// Storage: `System::EventCount` (r:1 w:1)
-	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::Events` (r:1 w:1)
-	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `Network::BaseNodeBurnAmount` (r:0 w:1)
-	/// Proof: `Network::BaseNodeBurnAmount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	fn set_base_node_burn_amount() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `24`
-		//  Estimated: `1509`
-		// Minimum execution time: 10_628_000 picoseconds.
-		Weight::from_parts(11_369_000, 1509)
+	/// Storage: `Network::BackupValidatorRewardFactor` (r:0 w:1)
+	fn set_backup_validator_reward_factor() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Estimated, not benchmarked.
 	/// Storage: `System::Number` (r:1 w:0)
-	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::ExecutionPhase` (r:1 w:0)
-	/// Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
 	/// Storage: `System::EventCount` (r:1 w:1)
-	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::Events` (r:1 w:1)
-	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `Network::MinNodeBurnRate` (r:0 w:1)
-	/// Proof: `Network::MinNodeBurnRate` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `Network::MaxNodeBurnRate` (r:0 w:1)
-	/// Proof: `Network::MaxNodeBurnRate` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	fn set_node_burn_rates() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `24`
-		//  Estimated: `1509`
-		// Minimum execution time: 10_144_000 picoseconds.
-		Weight::from_parts(12_137_000, 1509)
+	/// Storage: `Network::ConsensusArchiveDepth` (r:0 w:1)
+	fn set_consensus_archive_depth() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
 			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Estimated, not benchmarked.
 	/// Storage: `System::Number` (r:1 w:0)
-	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::ExecutionPhase` (r:1 w:0)
-	/// Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
 	/// Storage: `System::EventCount` (r:1 w:1)
-	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::Events` (r:1 w:1)
-	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `Network::MaxSubnetNodeMinWeightDecreaseReputationThreshold` (r:0 w:1)
-	/// Proof: `Network::MaxSubnetNodeMinWeightDecreaseReputationThreshold` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	fn set_max_subnet_node_min_weight_decrease_reputation_threshold() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `24`
-		//  Estimated: `1509`
-		// Minimum execution time: 10_916_000 picoseconds.
-		Weight::from_parts(11_729_000, 1509)
+	/// Storage: `Network::RewardHistoryDepth` (r:0 w:1)
+	fn set_reward_history_depth() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
@@ -3930,6 +4419,23 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SwapCallQueue` (r:1 w:1)
+	/// Storage: `Network::DelegateStakeCooldownEpochs` (r:1 w:0)
+	/// Storage: `Network::StakeUnbondingLedger` (r:1 w:1)
+	/// Storage: `Network::MaxUnbondings` (r:1 w:0)
+	/// Storage: `Network::SwapQueueOrder` (r:1 w:1)
+	/// Storage: `Network::AccountSwapQueueIds` (r:1 w:1)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	fn cancel_swap_queue_item() -> Weight {
+		Weight::from_parts(58_912_000, 6157)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
 	/// Storage: `System::Number` (r:1 w:0)
 	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Network::SubnetElectedValidator` (r:1 w:1)
@@ -3953,6 +4459,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 			.saturating_add(Weight::from_parts(0, 4).saturating_mul(x.into()))
 	}
+	/// Reward history checkpoint storage estimated, not benchmarked.
 	/// Storage: `Network::TotalSubnetDelegateStakeShares` (r:1 w:1)
 	/// Proof: `Network::TotalSubnetDelegateStakeShares` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::TotalSubnetDelegateStakeBalance` (r:1 w:1)
@@ -3963,15 +4470,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Network::TotalDelegateStake` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetNetFlow` (r:1 w:1)
 	/// Proof: `Network::SubnetNetFlow` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::RewardHistoryDepth` (r:1 w:0)
+	/// Storage: `Network::SubnetDelegateRewardHistory` (r:1 w:0)
+	/// Storage: `Network::AccountSubnetDelegateSharesHistory` (r:1 w:1)
 	fn handle_increase_account_delegate_stake() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `182`
 		//  Estimated: `3647`
 		// Minimum execution time: 14_559_000 picoseconds.
 		Weight::from_parts(15_367_000, 3647)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
+	/// Reward history checkpoint storage estimated, not benchmarked.
 	/// Storage: `Network::TotalNodeDelegateStakeShares` (r:1 w:1)
 	/// Proof: `Network::TotalNodeDelegateStakeShares` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::TotalNodeDelegateStakeBalance` (r:1 w:1)
@@ -3980,14 +4491,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Network::AccountNodeDelegateStakeShares` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::TotalNodeDelegateStake` (r:1 w:1)
 	/// Proof: `Network::TotalNodeDelegateStake` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::RewardHistoryDepth` (r:1 w:0)
+	/// Storage: `Network::NodeDelegateRewardHistory` (r:1 w:0)
+	/// Storage: `Network::AccountNodeDelegateSharesHistory` (r:1 w:1)
 	fn handle_increase_account_node_delegate_stake_shares() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `182`
 		//  Estimated: `3647`
 		// Minimum execution time: 15_767_000 picoseconds.
 		Weight::from_parts(16_511_000, 3647)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Network::SubnetsData` (r:1 w:1)
 	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -5484,6 +5998,196 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Storage: `Network::MinNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::MaxNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::EmergencySubnetNodeElectionData` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::AbsentDecreaseReputationFactor` (r:0 w:1)
+	fn owner_update_absent_decrease_reputation_factor() -> Weight {
+		Weight::from_parts(64_318_000, 4280)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Storage: `Network::MinNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::MaxNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::EmergencySubnetNodeElectionData` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::BelowMinWeightDecreaseReputationFactor` (r:0 w:1)
+	fn owner_update_below_min_weight_decrease_reputation_factor() -> Weight {
+		Weight::from_parts(64_318_000, 4280)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Storage: `Network::MinNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::MaxNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::EmergencySubnetNodeElectionData` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::IncludedIncreaseReputationFactor` (r:0 w:1)
+	fn owner_update_included_increase_reputation_factor() -> Weight {
+		Weight::from_parts(64_318_000, 4280)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Storage: `Network::MinNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::MaxNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::EmergencySubnetNodeElectionData` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::NonAttestorDecreaseReputationFactor` (r:0 w:1)
+	fn owner_update_non_attestor_decrease_reputation_factor() -> Weight {
+		Weight::from_parts(64_318_000, 4280)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Storage: `Network::MinNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::MaxNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::EmergencySubnetNodeElectionData` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::NonConsensusAttestorDecreaseReputationFactor` (r:0 w:1)
+	fn owner_update_non_consensus_attestor_decrease_reputation_factor() -> Weight {
+		Weight::from_parts(64_318_000, 4280)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Storage: `Network::MinNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::MaxNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::EmergencySubnetNodeElectionData` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::ValidatorAbsentSubnetNodeReputationFactor` (r:0 w:1)
+	fn owner_update_validator_absent_decrease_reputation_factor() -> Weight {
+		Weight::from_parts(64_318_000, 4280)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Storage: `Network::MinNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::MaxNodeReputationFactor` (r:1 w:0)
+	/// Storage: `Network::EmergencySubnetNodeElectionData` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::ValidatorNonConsensusSubnetNodeReputationFactor` (r:0 w:1)
+	fn owner_update_validator_non_consensus_decrease_reputation_factor() -> Weight {
+		Weight::from_parts(64_318_000, 4280)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Storage: `Network::MinMinSubnetNodeReputation` (r:1 w:0)
+	/// Storage: `Network::MaxMinSubnetNodeReputation` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::MinSubnetNodeReputation` (r:0 w:1)
+	fn owner_update_min_subnet_node_reputation() -> Weight {
+		Weight::from_parts(64_318_000, 4280)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::SubnetElectionMode` (r:0 w:1)
+	fn owner_update_election_mode() -> Weight {
+		Weight::from_parts(64_318_000, 4280)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::SubnetPeerIdProofRequired` (r:0 w:1)
+	fn owner_update_peer_id_proof_required() -> Weight {
+		Weight::from_parts(64_318_000, 4280)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::EmergencySubnetNodeElectionData` (r:0 w:1)
+	fn owner_revert_emergency_validator_set() -> Weight {
+		Weight::from_parts(64_318_000, 4280)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Storage: `Network::SubnetsData` (r:1 w:0)
+	/// Storage: `Network::SubnetSlot` (r:1 w:0)
+	/// Storage: `Network::SubnetNodesData` (r:64 w:0)
+	/// Storage: `Network::MinSubnetNodes` (r:1 w:0)
+	/// Storage: `Network::MaxEmergencySubnetNodes` (r:1 w:0)
+	/// Storage: `Network::AbsentDecreaseReputationFactor` (r:1 w:0)
+	/// Storage: `Network::MinSubnetNodeReputation` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::EmergencySubnetNodeElectionData` (r:0 w:1)
+	/// The range of component `x` is `[3, 64]`.
+	fn owner_set_emergency_validator_set(x: u32, ) -> Weight {
+		Weight::from_parts(63_905_114, 4787)
+			.saturating_add(Weight::from_parts(5_912_407, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 2713).saturating_mul(x.into()))
+	}
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetOwner` (r:1 w:0)
@@ -5767,35 +6471,74 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(7_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Estimated, not benchmarked.
 	/// Storage: `Network::TxPause` (r:1 w:0)
-	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetsData` (r:1 w:0)
-	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetOwner` (r:1 w:0)
-	/// Proof: `Network::SubnetOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetBootnodeAccess` (r:1 w:0)
-	/// Proof: `Network::SubnetBootnodeAccess` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::MaxBootnodes` (r:1 w:0)
-	/// Proof: `Network::MaxBootnodes` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetBootnodes` (r:1 w:1)
-	/// Proof: `Network::SubnetBootnodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `System::Number` (r:1 w:0)
-	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::ExecutionPhase` (r:1 w:0)
-	/// Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
 	/// Storage: `System::EventCount` (r:1 w:1)
-	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::Events` (r:1 w:1)
-	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	fn update_bootnodes() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1175`
-		//  Estimated: `4640`
-		// Minimum execution time: 91_786_000 picoseconds.
-		Weight::from_parts(104_006_000, 4640)
+	/// The range of component `x` is `[1, 32]`.
+	fn update_bootnodes(x: u32, ) -> Weight {
+		Weight::from_parts(98_214_466, 4640)
+			.saturating_add(Weight::from_parts(1_372_518, 0).saturating_mul(x.into()))
 			.saturating_add(RocksDbWeight::get().reads(10_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetsData` (r:1 w:0)
+	/// Storage: `Network::HotkeyOwner` (r:1 w:1)
+	/// Storage: `Network::HotkeySubnetId` (r:1 w:1)
+	/// Storage: `Network::SubnetRegistrationInitialColdkeys` (r:1 w:0)
+	/// Storage: `Network::TotalSubnetNodes` (r:1 w:1)
+	/// Storage: `Network::MaxRegisteredNodes` (r:1 w:0)
+	/// Storage: `Network::SubnetKeyTypes` (r:1 w:0)
+	/// Storage: `Network::SubnetPeerIdProofRequired` (r:1 w:0)
+	/// Storage: `Network::PeerIdProofs` (r:3 w:3)
+	/// Storage: `Network::PeerIdSubnetNodeId` (r:1 w:1)
+	/// Storage: `Network::BootnodePeerIdSubnetNodeId` (r:1 w:1)
+	/// Storage: `Network::ClientPeerIdSubnetNodeId` (r:1 w:1)
+	/// Storage: `Network::BootnodeSubnetNodeId` (r:1 w:1)
+	/// Storage: `Network::TotalNodes` (r:1 w:1)
+	/// Storage: `Network::SubnetNodeIdHotkey` (r:0 w:1)
+	/// Storage: `Network::HotkeySubnetNodeId` (r:0 w:1)
+	/// Storage: `Network::RegisteredSubnetNodesData` (r:0 w:1)
+	/// Storage: `Network::SubnetNodeQueue` (r:1 w:1)
+	/// Storage: `Network::AccountSubnetStake` (r:1 w:1)
+	/// Storage: `Network::TotalSubnetStake` (r:1 w:1)
+	/// Storage: `Network::TotalStake` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	fn register_subnet_node() -> Weight {
+		Weight::from_parts(339_172_000, 11662)
+			.saturating_add(RocksDbWeight::get().reads(30_u64))
+			.saturating_add(RocksDbWeight::get().writes(22_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SubnetsData` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::SubnetPeerIdProofRequired` (r:1 w:0)
+	/// Storage: `Network::PeerIdProofs` (r:1 w:1)
+	/// Storage: `Network::PeerIdProofsBySignedBlock` (r:0 w:2)
+	/// Storage: `Network::PeerIdProofPruneBlock` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn prove_peer_id() -> Weight {
+		Weight::from_parts(98_448_000, 6172)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetNodeIdHotkey` (r:1 w:1)
@@ -6089,8 +6832,8 @@ impl WeightInfo for () {
 		//  Estimated: `5387`
 		// Minimum execution time: 89_053_000 picoseconds.
 		Weight::from_parts(99_968_000, 5387)
-			.saturating_add(RocksDbWeight::get().reads(16_u64))
-			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
 	}
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -6130,8 +6873,8 @@ impl WeightInfo for () {
 		//  Estimated: `7169`
 		// Minimum execution time: 77_433_000 picoseconds.
 		Weight::from_parts(84_604_000, 7169)
-			.saturating_add(RocksDbWeight::get().reads(15_u64))
-			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			.saturating_add(RocksDbWeight::get().reads(18_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
 	}
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -6153,8 +6896,8 @@ impl WeightInfo for () {
 		//  Estimated: `7230`
 		// Minimum execution time: 45_670_000 picoseconds.
 		Weight::from_parts(50_362_000, 7230)
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -6192,8 +6935,8 @@ impl WeightInfo for () {
 		//  Estimated: `5336`
 		// Minimum execution time: 75_256_000 picoseconds.
 		Weight::from_parts(80_160_000, 5336)
-			.saturating_add(RocksDbWeight::get().reads(15_u64))
-			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().reads(18_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -6264,8 +7007,8 @@ impl WeightInfo for () {
 		//  Estimated: `5935`
 		// Minimum execution time: 94_035_000 picoseconds.
 		Weight::from_parts(102_044_000, 5935)
-			.saturating_add(RocksDbWeight::get().reads(15_u64))
-			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().reads(18_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -6303,8 +7046,8 @@ impl WeightInfo for () {
 		//  Estimated: `10503`
 		// Minimum execution time: 93_699_000 picoseconds.
 		Weight::from_parts(102_938_000, 10503)
-			.saturating_add(RocksDbWeight::get().reads(14_u64))
-			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -6324,8 +7067,8 @@ impl WeightInfo for () {
 		//  Estimated: `7236`
 		// Minimum execution time: 49_343_000 picoseconds.
 		Weight::from_parts(52_492_000, 7236)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -6361,8 +7104,8 @@ impl WeightInfo for () {
 		//  Estimated: `5307`
 		// Minimum execution time: 77_209_000 picoseconds.
 		Weight::from_parts(81_222_000, 5307)
-			.saturating_add(RocksDbWeight::get().reads(14_u64))
-			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -6433,8 +7176,8 @@ impl WeightInfo for () {
 		//  Estimated: `7313`
 		// Minimum execution time: 84_863_000 picoseconds.
 		Weight::from_parts(97_835_000, 7313)
-			.saturating_add(RocksDbWeight::get().reads(14_u64))
-			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -6474,41 +7217,30 @@ impl WeightInfo for () {
 		//  Estimated: `7562`
 		// Minimum execution time: 85_307_000 picoseconds.
 		Weight::from_parts(89_241_000, 7562)
-			.saturating_add(RocksDbWeight::get().reads(15_u64))
-			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			.saturating_add(RocksDbWeight::get().reads(18_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
 	}
+	/// Estimated, not benchmarked.
 	/// Storage: `Network::TxPause` (r:1 w:0)
-	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetSlot` (r:1 w:0)
-	/// Proof: `Network::SubnetSlot` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `System::Number` (r:1 w:0)
-	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Network::SubnetElectedValidator` (r:1 w:0)
-	/// Proof: `Network::SubnetElectedValidator` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetNodeIdHotkey` (r:1 w:0)
-	/// Proof: `Network::SubnetNodeIdHotkey` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetConsensusSubmission` (r:1 w:1)
-	/// Proof: `Network::SubnetConsensusSubmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `Network::SubnetNodesData` (r:6 w:0)
-	/// Proof: `Network::SubnetNodesData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetNodesData` (r:65 w:0)
 	/// Storage: `Network::ValidatorRewardMidpoint` (r:1 w:0)
-	/// Proof: `Network::ValidatorRewardMidpoint` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::ValidatorRewardK` (r:1 w:0)
-	/// Proof: `Network::ValidatorRewardK` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `System::ExecutionPhase` (r:1 w:0)
-	/// Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
 	/// Storage: `System::EventCount` (r:1 w:1)
-	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::Events` (r:1 w:1)
-	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	fn propose_attestation() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2553`
-		//  Estimated: `18393`
-		// Minimum execution time: 93_868_000 picoseconds.
-		Weight::from_parts(100_877_000, 18393)
-			.saturating_add(RocksDbWeight::get().reads(17_u64))
+	/// The range of component `x` is `[5, 64]`.
+	fn propose_attestation(x: u32, ) -> Weight {
+		Weight::from_parts(71_062_313, 4838)
+			.saturating_add(Weight::from_parts(5_963_104, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 2711).saturating_mul(x.into()))
 	}
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -6595,36 +7327,26 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Estimated, not benchmarked.
 	/// Storage: `Network::TxPause` (r:1 w:0)
-	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `Network::HotkeyOwner` (r:1 w:1)
-	/// Proof: `Network::HotkeyOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::HotkeyOwner` (r:1 w:64)
 	/// Storage: `Network::StakeUnbondingLedger` (r:2 w:2)
-	/// Proof: `Network::StakeUnbondingLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::ColdkeyHotkeys` (r:2 w:2)
-	/// Proof: `Network::ColdkeyHotkeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::ColdkeyIdentity` (r:1 w:0)
-	/// Proof: `Network::ColdkeyIdentity` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::ColdkeyReputation` (r:2 w:2)
-	/// Proof: `Network::ColdkeyReputation` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::ColdkeySubnetNodes` (r:2 w:2)
-	/// Proof: `Network::ColdkeySubnetNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `System::Number` (r:1 w:0)
-	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::ExecutionPhase` (r:1 w:0)
-	/// Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
 	/// Storage: `System::EventCount` (r:1 w:1)
-	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::Events` (r:1 w:1)
-	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	fn update_coldkey() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1775`
-		//  Estimated: `7715`
-		// Minimum execution time: 67_921_000 picoseconds.
+	/// The range of component `x` is `[1, 64]`.
+	fn update_coldkey(x: u32, ) -> Weight {
 		Weight::from_parts(73_218_000, 7715)
+			.saturating_add(Weight::from_parts(4_500_000, 0).saturating_mul(x.into()))
 			.saturating_add(RocksDbWeight::get().reads(15_u64))
-			.saturating_add(RocksDbWeight::get().writes(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 48).saturating_mul(x.into()))
 	}
 	/// Storage: `Network::TxPause` (r:1 w:0)
 	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -6943,59 +7665,19 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(9_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Estimated, not benchmarked.
 	/// Storage: `Network::TxPause` (r:1 w:0)
-	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `Network::OverwatchNodeIdHotkey` (r:1 w:0)
-	/// Proof: `Network::OverwatchNodeIdHotkey` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `Network::HotkeyOwner` (r:1 w:0)
-	/// Proof: `Network::HotkeyOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `Network::OverwatchNodeBlacklist` (r:1 w:0)
-	/// Proof: `Network::OverwatchNodeBlacklist` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `System::Number` (r:1 w:0)
-	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `Network::OverwatchEpochLengthMultiplier` (r:1 w:0)
-	/// Proof: `Network::OverwatchEpochLengthMultiplier` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `Network::OverwatchCommitCutoffPercent` (r:1 w:0)
-	/// Proof: `Network::OverwatchCommitCutoffPercent` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `Network::SubnetsData` (r:64 w:0)
-	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `Network::OverwatchCommits` (r:1 w:1)
-	/// Proof: `Network::OverwatchCommits` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn commit_overwatch_subnet_weights() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `3088`
-		//  Estimated: `162478`
-		// Minimum execution time: 198_344_000 picoseconds.
-		Weight::from_parts(211_691_000, 162478)
-			.saturating_add(RocksDbWeight::get().reads(72_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
-	}
-	/// Storage: `Network::TxPause` (r:1 w:0)
-	/// Proof: `Network::TxPause` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::OverwatchNodeIdHotkey` (r:1 w:0)
-	/// Proof: `Network::OverwatchNodeIdHotkey` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::HotkeyOwner` (r:1 w:0)
-	/// Proof: `Network::HotkeyOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::OverwatchNodeBlacklist` (r:1 w:0)
-	/// Proof: `Network::OverwatchNodeBlacklist` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `System::Number` (r:1 w:0)
-	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Network::OverwatchEpochLengthMultiplier` (r:1 w:0)
-	/// Proof: `Network::OverwatchEpochLengthMultiplier` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::OverwatchCommitCutoffPercent` (r:1 w:0)
-	/// Proof: `Network::OverwatchCommitCutoffPercent` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetsData` (r:17 w:0)
-	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::OverwatchCommits` (r:16 w:16)
-	/// Proof: `Network::OverwatchCommits` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `x` is `[1, 16]`.
-	fn commit_overwatch_subnet_weights_v2(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1751 + x * (152 ±0)`
-		//  Estimated: `5177 + x * (2627 ±1)`
-		// Minimum execution time: 54_654_000 picoseconds.
+	fn commit_overwatch_subnet_weights(x: u32, ) -> Weight {
 		Weight::from_parts(54_127_797, 5177)
-			// Standard Error: 91_371
 			.saturating_add(Weight::from_parts(12_493_159, 0).saturating_mul(x.into()))
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(x.into())))
@@ -8576,6 +9258,263 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::AttestorMinRewardFactor` (r:0 w:1)
+	fn set_attestor_min_reward_factor() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::AttestorRewardExponent` (r:0 w:1)
+	fn set_attestor_reward_exponent() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::MaxChurnLimitMultiplier` (r:0 w:1)
+	/// Storage: `Network::MinChurnLimitMultiplier` (r:0 w:1)
+	fn set_churn_limit_multipliers() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::InConsensusSubnetReputationFactor` (r:0 w:1)
+	fn set_in_consensus_subnet_reputation_factor() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::LessThanMinNodesSubnetReputationFactor` (r:0 w:1)
+	fn set_less_than_min_nodes_subnet_reputation_factor() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::MinSubnetNodes` (r:1 w:0)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::MaxEmergencySubnetNodes` (r:0 w:1)
+	fn set_max_emergency_subnet_nodes() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::MaxEmergencyValidatorEpochsMultiplier` (r:0 w:1)
+	fn set_max_emergency_validator_epochs_multiplier() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::MaxPauseEpochsSubnetReputationFactor` (r:0 w:1)
+	fn set_max_pause_epochs_subnet_reputation_factor() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::MinSubnetReputation` (r:0 w:1)
+	fn set_min_subnet_reputation() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::NotInConsensusSubnetReputationFactor` (r:0 w:1)
+	fn set_not_in_consensus_subnet_reputation_factor() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::OverwatchStakeWeightFactor` (r:0 w:1)
+	fn set_overwatch_stake_weight_factor() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::OverwatchWeightFactor` (r:0 w:1)
+	fn set_overwatch_weight_factor() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::ValidatorAbsentSubnetReputationFactor` (r:0 w:1)
+	fn set_validator_proposal_absent_subnet_reputation_factor() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::ValidatorRewardK` (r:0 w:1)
+	fn set_validator_reward_k() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::ValidatorRewardMidpoint` (r:0 w:1)
+	fn set_validator_reward_midpoint() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::MaxMinSubnetNodeReputation` (r:0 w:1)
+	/// Storage: `Network::MinMinSubnetNodeReputation` (r:0 w:1)
+	fn set_min_max_node_reputation() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::MaxNodeReputationFactor` (r:0 w:1)
+	/// Storage: `Network::MinNodeReputationFactor` (r:0 w:1)
+	fn set_min_max_node_reputation_factor() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::SubnetWeightFactors` (r:0 w:1)
+	fn set_subnet_weight_factors() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::MaxPendingSwapsPerAccount` (r:0 w:1)
+	fn set_max_pending_swaps_per_account() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::BackupProposerEpochProgression` (r:0 w:1)
+	fn set_backup_proposer_epoch_progression() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::BackupValidatorRewardFactor` (r:0 w:1)
+	fn set_backup_validator_reward_factor() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::ConsensusArchiveDepth` (r:0 w:1)
+	fn set_consensus_archive_depth() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Estimated, not benchmarked.
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Storage: `Network::RewardHistoryDepth` (r:0 w:1)
+	fn set_reward_history_depth() -> Weight {
+		Weight::from_parts(11_034_000, 1509)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 	/// Storage: `Network::SwapCallQueue` (r:1 w:1)
 	/// Proof: `Network::SwapCallQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetsData` (r:1 w:0)
@@ -8597,6 +9536,23 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Estimated, not benchmarked.
+	/// Storage: `Network::TxPause` (r:1 w:0)
+	/// Storage: `Network::SwapCallQueue` (r:1 w:1)
+	/// Storage: `Network::DelegateStakeCooldownEpochs` (r:1 w:0)
+	/// Storage: `Network::StakeUnbondingLedger` (r:1 w:1)
+	/// Storage: `Network::MaxUnbondings` (r:1 w:0)
+	/// Storage: `Network::SwapQueueOrder` (r:1 w:1)
+	/// Storage: `Network::AccountSwapQueueIds` (r:1 w:1)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Storage: `System::Events` (r:1 w:1)
+	fn cancel_swap_queue_item() -> Weight {
+		Weight::from_parts(58_912_000, 6157)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
 	/// Storage: `System::Number` (r:1 w:0)
 	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Network::SubnetElectedValidator` (r:1 w:1)
@@ -8636,8 +9592,8 @@ impl WeightInfo for () {
 		//  Estimated: `3647`
 		// Minimum execution time: 14_559_000 picoseconds.
 		Weight::from_parts(15_367_000, 3647)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `Network::TotalNodeDelegateStakeShares` (r:1 w:1)
 	/// Proof: `Network::TotalNodeDelegateStakeShares` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -8653,8 +9609,8 @@ impl WeightInfo for () {
 		//  Estimated: `3647`
 		// Minimum execution time: 15_767_000 picoseconds.
 		Weight::from_parts(16_511_000, 3647)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Network::SubnetsData` (r:1 w:1)
 	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
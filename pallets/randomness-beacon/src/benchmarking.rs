@@ -0,0 +1,83 @@
+//! Benchmarking setup for pallet-randomness-beacon
+
+// frame-omni-bencher v1 benchmark pallet --runtime target/release/wbuild/hypertensor-runtime/hypertensor_runtime.compact.compressed.wasm --extrinsic "" --pallet "pallet_randomness_beacon"
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as RandomnessBeacon;
+use frame_benchmarking::v2::*;
+use frame_support::traits::Currency;
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+    let who: T::AccountId = account(name, index, 0);
+    T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 2u32.into());
+    who
+}
+
+fn set_round<T: Config>(round: u32) {
+    frame_system::Pallet::<T>::set_block_number(
+        round.saturating_mul(T::RoundLength::get().max(1)).into(),
+    );
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn commit() {
+        set_round::<T>(1);
+        let caller = funded_account::<T>("caller", 0);
+        let secret: Secret = [1u8; 32];
+        let commitment = RandomnessBeacon::<T>::commitment_of(&caller, &secret);
+
+        #[extrinsic_call]
+        commit(RawOrigin::Signed(caller.clone()), commitment);
+
+        assert_eq!(Commitments::<T>::get(1, &caller), Some(commitment));
+    }
+
+    #[benchmark]
+    fn reveal() {
+        set_round::<T>(1);
+        let caller = funded_account::<T>("caller", 0);
+        let secret: Secret = [1u8; 32];
+        let commitment = RandomnessBeacon::<T>::commitment_of(&caller, &secret);
+        RandomnessBeacon::<T>::commit(RawOrigin::Signed(caller.clone()).into(), commitment)
+            .unwrap();
+        set_round::<T>(2);
+
+        #[extrinsic_call]
+        reveal(RawOrigin::Signed(caller.clone()), secret);
+
+        assert_eq!(RevealCount::<T>::get(1), 1);
+    }
+
+    #[benchmark]
+    fn finalize_round(s: Linear<0, { T::MaxCommitsPerRound::get() }>) {
+        set_round::<T>(1);
+        for i in 0..s {
+            let who = funded_account::<T>("committer", i);
+            let secret: Secret = [i as u8; 32];
+            let commitment = RandomnessBeacon::<T>::commitment_of(&who, &secret);
+            RandomnessBeacon::<T>::commit(RawOrigin::Signed(who).into(), commitment).unwrap();
+        }
+        set_round::<T>(3);
+
+        #[block]
+        {
+            RandomnessBeacon::<T>::finalize_round(1, frame_system::Pallet::<T>::block_number());
+        }
+
+        assert_eq!(CommitCount::<T>::get(1), 0);
+    }
+
+    impl_benchmark_test_suite!(
+        RandomnessBeacon,
+        crate::mock::new_test_ext(),
+        crate::mock::Test
+    );
+}
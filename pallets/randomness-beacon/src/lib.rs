@@ -0,0 +1,357 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Randomness Beacon Pallet
+//!
+//! Commit-reveal randomness beacon used as the network pallet's `Randomness`.
+//!
+//! ## Overview
+//!
+//! Blocks are grouped into rounds of `RoundLength` blocks. Any account can `commit` to
+//! `hash(account, secret)` in round `r` by reserving `CommitDeposit`, then `reveal` the secret in
+//! round `r + 1` to get the deposit back along with `RevealReward`. Revealed secrets are XOR'd
+//! together and on the first block of round `r + 2` they are hashed with the previous seed into
+//! the new random seed. Deposits of commitments that were never revealed are slashed.
+//!
+//! A round's reveals are only mixed into the seed if there are at least `MinReveals` of them,
+//! otherwise the seed is rotated with `hash(previous seed, round)` alone.
+//!
+//! ## Security
+//!
+//! The seed is unpredictable as long as one of a round's `MinReveals` or more revealers is honest
+//! and keeps their secret private until the reveal round. It is not a VRF, a participant can
+//! still influence it in the following ways:
+//!
+//! - The last revealer sees every other revealed secret and can choose between two seeds, with
+//!   or without its own reveal, by withholding. Withholding forfeits `CommitDeposit`, which must
+//!   be set well above the value of steering a single election.
+//! - A block author that also committed can leave reveals out of the blocks it authors, most
+//!   usefully the last block of the reveal round, and gets the same choice as the last revealer.
+//!   The censored committers lose their deposit, so reveals should be submitted early in the
+//!   reveal round. The block hash and the extrinsic order don't change the seed.
+//! - An account, or a set of accounts controlled by one party, providing all of a round's
+//!   reveals chooses the seed. `MinReveals` and `CommitDeposit` set how much capital that takes
+//!   and `RevealReward` pays honest accounts to participate.
+//! - Rounds with fewer than `MinReveals` reveals rotate the seed predictably, no participant can
+//!   steer it but anyone can compute it in advance.
+
+// We make sure this pallet uses `no_std` for compiling to Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// FRAME pallets require their own "mock runtimes" to be able to run unit tests. This module
+// contains a mock runtime specific for testing this pallet's functionality.
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
+use frame_support::{
+    traits::{Currency, Get, Randomness, ReservableCurrency},
+    weights::Weight,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+pub use pallet::*;
+use sp_runtime::traits::{Hash as HashT, SaturatedConversion};
+
+// All pallet logic is defined in its own module and must be annotated by the `pallet` attribute.
+#[frame_support::pallet]
+pub mod pallet {
+    // Import various useful types required by all FRAME pallets.
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// A revealed secret, XOR'd into the round accumulator
+    pub type Secret = [u8; 32];
+
+    // The `Pallet` struct serves as a placeholder to implement traits, methods and dispatchables
+    // (`Call`s) in this pallet.
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// The pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching runtime event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// Reserved on `commit`, returned on `reveal` and slashed if never revealed
+        #[pallet::constant]
+        type CommitDeposit: Get<BalanceOf<Self>>;
+
+        /// Minted to each account that reveals its secret
+        #[pallet::constant]
+        type RevealReward: Get<BalanceOf<Self>>;
+
+        /// Minimum reveals for a round's secrets to be mixed into the seed
+        ///
+        /// Below this the seed is rotated without the round's reveals so a few participants can't
+        /// choose it.
+        #[pallet::constant]
+        type MinReveals: Get<u32>;
+
+        /// Blocks per round
+        #[pallet::constant]
+        type RoundLength: Get<u32>;
+
+        /// Maximum commitments per round, bounds the work done when a round is finalized
+        #[pallet::constant]
+        type MaxCommitsPerRound: Get<u32>;
+
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    /// Unrevealed commitments of each round
+    #[pallet::storage]
+    pub type Commitments<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u32, // round
+        Blake2_128Concat,
+        T::AccountId,
+        T::Hash,
+        OptionQuery,
+    >;
+
+    /// Number of commitments made in each round
+    #[pallet::storage]
+    pub type CommitCount<T> = StorageMap<_, Twox64Concat, u32, u32, ValueQuery>;
+
+    /// XOR of the secrets revealed for each round
+    #[pallet::storage]
+    pub type RevealAccumulator<T> = StorageMap<_, Twox64Concat, u32, Secret, ValueQuery>;
+
+    /// Number of secrets revealed for each round
+    #[pallet::storage]
+    pub type RevealCount<T> = StorageMap<_, Twox64Concat, u32, u32, ValueQuery>;
+
+    /// Current random seed
+    #[pallet::storage]
+    pub type RandomSeed<T: Config> = StorageValue<_, T::Hash, ValueQuery>;
+
+    /// Block the current random seed was set in
+    #[pallet::storage]
+    pub type RandomSeedBlock<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// Events that functions in this pallet can emit.
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        Committed {
+            round: u32,
+            who: T::AccountId,
+            commitment: T::Hash,
+        },
+        Revealed {
+            round: u32,
+            who: T::AccountId,
+        },
+        CommitmentSlashed {
+            round: u32,
+            who: T::AccountId,
+        },
+        SeedUpdated {
+            round: u32,
+            seed: T::Hash,
+            reveals: u32,
+        },
+    }
+
+    /// Errors that can be returned by this pallet.
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Account already committed this round
+        AlreadyCommitted,
+        /// Maximum commitments for this round reached, see `MaxCommitsPerRound`
+        TooManyCommitments,
+        /// No commitment from the previous round to reveal
+        NoCommitment,
+        /// Secret doesn't match the commitment
+        InvalidReveal,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
+            let block: u32 = block_number.saturated_into();
+            let round_length = T::RoundLength::get().max(1);
+
+            if block % round_length != 0 {
+                return Weight::zero();
+            }
+
+            // Commitments of round `r` are revealed during round `r + 1`
+            let round = block / round_length;
+            if round < 2 {
+                return Weight::zero();
+            }
+
+            Self::finalize_round(round - 2, block_number)
+        }
+    }
+
+    /// The pallet's dispatchable functions ([`Call`]s).
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Commit to a secret for the current round
+        ///
+        /// # Arguments
+        ///
+        /// * `commitment` - `hash(account_id, secret)`, see `commitment_of`
+        ///
+        /// # Requirements
+        ///
+        /// * Reserves `CommitDeposit`, returned once the secret is revealed in the next round
+        ///
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::commit())]
+        pub fn commit(origin: OriginFor<T>, commitment: T::Hash) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let round = Self::current_round();
+
+            ensure!(
+                !Commitments::<T>::contains_key(round, &who),
+                Error::<T>::AlreadyCommitted
+            );
+            ensure!(
+                CommitCount::<T>::get(round) < T::MaxCommitsPerRound::get(),
+                Error::<T>::TooManyCommitments
+            );
+
+            T::Currency::reserve(&who, T::CommitDeposit::get())?;
+
+            Commitments::<T>::insert(round, &who, commitment);
+            CommitCount::<T>::mutate(round, |n| n.saturating_inc());
+
+            Self::deposit_event(Event::Committed {
+                round,
+                who,
+                commitment,
+            });
+
+            Ok(())
+        }
+
+        /// Reveal the secret committed to in the previous round
+        ///
+        /// Returns `CommitDeposit` and mints `RevealReward` to the caller.
+        ///
+        /// # Arguments
+        ///
+        /// * `secret` - Secret committed to in the previous round
+        ///
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::reveal())]
+        pub fn reveal(origin: OriginFor<T>, secret: Secret) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let round = Self::current_round()
+                .checked_sub(1)
+                .ok_or(Error::<T>::NoCommitment)?;
+
+            let commitment = Commitments::<T>::get(round, &who).ok_or(Error::<T>::NoCommitment)?;
+            ensure!(
+                Self::commitment_of(&who, &secret) == commitment,
+                Error::<T>::InvalidReveal
+            );
+
+            Commitments::<T>::remove(round, &who);
+            RevealAccumulator::<T>::mutate(round, |accumulator| {
+                for (a, s) in accumulator.iter_mut().zip(secret.iter()) {
+                    *a ^= s;
+                }
+            });
+            RevealCount::<T>::mutate(round, |n| n.saturating_inc());
+
+            T::Currency::unreserve(&who, T::CommitDeposit::get());
+            let _ = T::Currency::deposit_creating(&who, T::RevealReward::get());
+
+            Self::deposit_event(Event::Revealed { round, who });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        pub fn current_round() -> u32 {
+            let block: u32 = frame_system::Pallet::<T>::block_number().saturated_into();
+            block / T::RoundLength::get().max(1)
+        }
+
+        /// Commitment to `secret` for `who`
+        ///
+        /// The account is part of the preimage so a secret can't be copied by another account
+        pub fn commitment_of(who: &T::AccountId, secret: &Secret) -> T::Hash {
+            T::Hashing::hash_of(&(who, secret))
+        }
+
+        /// Slash unrevealed commitments of `round` and rotate the seed with its reveals
+        ///
+        /// The reveals are only used if there are at least `MinReveals` of them.
+        pub fn finalize_round(round: u32, block_number: BlockNumberFor<T>) -> Weight {
+            let mut slashed: u32 = 0;
+            for (who, _) in Commitments::<T>::drain_prefix(round) {
+                let _ = T::Currency::slash_reserved(&who, T::CommitDeposit::get());
+                Self::deposit_event(Event::CommitmentSlashed { round, who });
+                slashed += 1;
+            }
+
+            CommitCount::<T>::remove(round);
+            let accumulator = RevealAccumulator::<T>::take(round);
+            let reveals = RevealCount::<T>::take(round);
+
+            // Too few reveals to be mixed in, a lone committer could choose the seed otherwise
+            let seed = if reveals >= T::MinReveals::get() {
+                T::Hashing::hash_of(&(RandomSeed::<T>::get(), accumulator, round))
+            } else {
+                T::Hashing::hash_of(&(RandomSeed::<T>::get(), round))
+            };
+            RandomSeed::<T>::put(seed);
+            RandomSeedBlock::<T>::put(block_number);
+
+            Self::deposit_event(Event::SeedUpdated {
+                round,
+                seed,
+                reveals,
+            });
+
+            T::WeightInfo::finalize_round(slashed)
+        }
+    }
+
+    impl<T: Config> Randomness<T::Hash, BlockNumberFor<T>> for Pallet<T> {
+        /// Returns `hash(seed, subject)` and the block the seed was set in
+        ///
+        /// The output only changes once per round, callers must mix in their own subject to get
+        /// distinct values within a round.
+        fn random(subject: &[u8]) -> (T::Hash, BlockNumberFor<T>) {
+            (
+                T::Hashing::hash_of(&(RandomSeed::<T>::get(), subject)),
+                RandomSeedBlock::<T>::get(),
+            )
+        }
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as pallet_randomness_beacon;
+use frame_support::{derive_impl, parameter_types, traits::Hooks};
+use sp_core::H256;
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+    // The main runtime
+    #[runtime::runtime]
+    // Runtime Types to be generated
+    #[runtime::derive(
+        RuntimeEvent,
+        RuntimeCall,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeHoldReason,
+        RuntimeSlashReason,
+        RuntimeLockId,
+        RuntimeTask
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system::Pallet<Test>;
+
+    #[runtime::pallet_index(1)]
+    pub type Balances = pallet_balances::Pallet<Test>;
+
+    #[runtime::pallet_index(2)]
+    pub type RandomnessBeacon = pallet_randomness_beacon::Pallet<Test>;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+}
+
+pub const COMMIT_DEPOSIT: u64 = 100;
+pub const ROUND_LENGTH: u32 = 10;
+pub const MAX_COMMITS_PER_ROUND: u32 = 4;
+pub const REVEAL_REWARD: u64 = 10;
+pub const MIN_REVEALS: u32 = 2;
+
+parameter_types! {
+    pub const CommitDeposit: u64 = COMMIT_DEPOSIT;
+    pub const RoundLength: u32 = ROUND_LENGTH;
+    pub const MaxCommitsPerRound: u32 = MAX_COMMITS_PER_ROUND;
+    pub const RevealReward: u64 = REVEAL_REWARD;
+    pub const MinReveals: u32 = MIN_REVEALS;
+}
+
+impl pallet_randomness_beacon::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type CommitDeposit = CommitDeposit;
+    type RevealReward = RevealReward;
+    type MinReveals = MinReveals;
+    type RoundLength = RoundLength;
+    type MaxCommitsPerRound = MaxCommitsPerRound;
+    type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: (1..=10).map(|who| (who, 10_000)).collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    t.into()
+}
+
+/// Move to `block`, initializing each block with a parent hash chosen by `author`
+///
+/// Each `author` produces a different chain of block hashes for the same blocks
+pub fn run_to_block(block: u64, author: u8) {
+    while System::block_number() < block {
+        let next = System::block_number() + 1;
+        System::initialize(&next, &H256::repeat_byte(author), &Default::default());
+        RandomnessBeacon::on_initialize(next);
+    }
+}
@@ -0,0 +1,288 @@
+use crate::{
+    mock::*, CommitCount, Commitments, Error, Event, RandomSeed, RandomSeedBlock, RevealCount,
+    Secret,
+};
+use frame_support::traits::{Currency, Randomness, ReservableCurrency};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
+
+fn secret(who: u64) -> Secret {
+    sp_io::hashing::blake2_256(&who.to_le_bytes())
+}
+
+fn commit(who: u64) {
+    assert_ok!(RandomnessBeacon::commit(
+        RuntimeOrigin::signed(who),
+        RandomnessBeacon::commitment_of(&who, &secret(who)),
+    ));
+}
+
+fn reveal(who: u64) {
+    assert_ok!(RandomnessBeacon::reveal(
+        RuntimeOrigin::signed(who),
+        secret(who)
+    ));
+}
+
+/// Runs a full commit-reveal round and returns the resulting seed
+///
+/// * `author` - Author of every block, changes every block hash.
+/// * `reveal_order` - Order reveals are submitted in.
+/// * `censored` - Reveal delayed by one block, as if censored by the block author.
+fn seed_after_round(author: u8, reveal_order: &[u64], censored: Option<u64>) -> H256 {
+    new_test_ext().execute_with(|| {
+        let round_length = ROUND_LENGTH as u64;
+
+        run_to_block(round_length, author);
+        for who in reveal_order {
+            commit(*who);
+        }
+
+        run_to_block(2 * round_length, author);
+        for who in reveal_order {
+            if Some(*who) != censored {
+                reveal(*who);
+            }
+        }
+        if let Some(who) = censored {
+            run_to_block(2 * round_length + 1, author);
+            reveal(who);
+        }
+
+        run_to_block(3 * round_length, author);
+        assert_eq!(RandomSeedBlock::<Test>::get(), 3 * round_length);
+
+        RandomSeed::<Test>::get()
+    })
+}
+
+#[test]
+fn commit_reserves_deposit() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, 0);
+
+        commit(1);
+
+        assert_eq!(Balances::reserved_balance(1), COMMIT_DEPOSIT);
+        assert_eq!(CommitCount::<Test>::get(0), 1);
+        assert_eq!(
+            Commitments::<Test>::get(0, 1),
+            Some(RandomnessBeacon::commitment_of(&1, &secret(1)))
+        );
+        System::assert_last_event(
+            Event::Committed {
+                round: 0,
+                who: 1,
+                commitment: RandomnessBeacon::commitment_of(&1, &secret(1)),
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            RandomnessBeacon::commit(RuntimeOrigin::signed(1), H256::zero()),
+            Error::<Test>::AlreadyCommitted
+        );
+    });
+}
+
+#[test]
+fn commit_max_commits_per_round() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, 0);
+
+        for who in 1..=MAX_COMMITS_PER_ROUND as u64 {
+            commit(who);
+        }
+
+        assert_noop!(
+            RandomnessBeacon::commit(
+                RuntimeOrigin::signed(MAX_COMMITS_PER_ROUND as u64 + 1),
+                H256::zero()
+            ),
+            Error::<Test>::TooManyCommitments
+        );
+
+        // Next round is open again
+        run_to_block(ROUND_LENGTH as u64, 0);
+        commit(MAX_COMMITS_PER_ROUND as u64 + 1);
+    });
+}
+
+#[test]
+fn reveal_returns_deposit() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, 0);
+        commit(1);
+
+        // Can't reveal in the same round
+        assert_noop!(
+            RandomnessBeacon::reveal(RuntimeOrigin::signed(1), secret(1)),
+            Error::<Test>::NoCommitment
+        );
+
+        run_to_block(ROUND_LENGTH as u64, 0);
+
+        assert_noop!(
+            RandomnessBeacon::reveal(RuntimeOrigin::signed(1), secret(2)),
+            Error::<Test>::InvalidReveal
+        );
+        // Another account can't reveal a copied secret
+        assert_noop!(
+            RandomnessBeacon::reveal(RuntimeOrigin::signed(2), secret(1)),
+            Error::<Test>::NoCommitment
+        );
+
+        let free_balance = Balances::free_balance(1);
+        reveal(1);
+
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(
+            Balances::free_balance(1),
+            free_balance + COMMIT_DEPOSIT + REVEAL_REWARD
+        );
+        assert_eq!(RevealCount::<Test>::get(0), 1);
+        assert_eq!(Commitments::<Test>::get(0, 1), None);
+        System::assert_last_event(Event::Revealed { round: 0, who: 1 }.into());
+
+        // Can only reveal once
+        assert_noop!(
+            RandomnessBeacon::reveal(RuntimeOrigin::signed(1), secret(1)),
+            Error::<Test>::NoCommitment
+        );
+
+        // Too late to reveal in the round after
+        run_to_block(2 * ROUND_LENGTH as u64 + 1, 0);
+        assert_eq!(RevealCount::<Test>::get(0), 0);
+        assert_noop!(
+            RandomnessBeacon::reveal(RuntimeOrigin::signed(1), secret(1)),
+            Error::<Test>::NoCommitment
+        );
+    });
+}
+
+#[test]
+fn unrevealed_commitment_is_slashed() {
+    new_test_ext().execute_with(|| {
+        let round_length = ROUND_LENGTH as u64;
+        run_to_block(round_length, 0);
+        commit(1);
+        commit(2);
+
+        let balance = Balances::total_balance(&2);
+
+        run_to_block(2 * round_length, 0);
+        reveal(1);
+
+        run_to_block(3 * round_length, 0);
+
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(Balances::total_balance(&2), balance - COMMIT_DEPOSIT);
+        assert_eq!(Commitments::<Test>::iter_prefix(1).count(), 0);
+        assert_eq!(CommitCount::<Test>::get(1), 0);
+        assert!(System::events()
+            .iter()
+            .any(|record| record.event == Event::CommitmentSlashed { round: 1, who: 2 }.into()));
+        assert!(System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::RandomnessBeacon(Event::SeedUpdated {
+                round: 1,
+                reveals: 1,
+                ..
+            })
+        )));
+    });
+}
+
+#[test]
+fn seed_is_rotated_every_round() {
+    new_test_ext().execute_with(|| {
+        let round_length = ROUND_LENGTH as u64;
+
+        run_to_block(2 * round_length - 1, 0);
+        assert_eq!(RandomSeed::<Test>::get(), H256::zero());
+
+        run_to_block(2 * round_length, 0);
+        let seed = RandomSeed::<Test>::get();
+        assert_ne!(seed, H256::zero());
+
+        // No reveals, the seed still changes
+        run_to_block(3 * round_length, 0);
+        assert_ne!(RandomSeed::<Test>::get(), seed);
+    });
+}
+
+#[test]
+fn seed_depends_on_reveals() {
+    let seed = seed_after_round(0, &[1, 2, 3], None);
+
+    // A different secret set gives a different seed
+    assert_ne!(seed, seed_after_round(0, &[1, 2], None));
+    assert_ne!(seed, seed_after_round(0, &[1, 2, 4], None));
+}
+
+#[test]
+fn reveals_below_minimum_cannot_steer_seed() {
+    let seed = seed_after_round(0, &[1], None);
+
+    // A lone committer gets the same seed whatever its secret
+    for who in 2..=4 {
+        assert_eq!(seed, seed_after_round(0, &[who], None));
+    }
+    assert_eq!(seed, seed_after_round(0, &[], None));
+
+    // From `MinReveals` on the reveals are mixed in
+    assert_ne!(seed, seed_after_round(0, &[1, 2], None));
+}
+
+#[test]
+fn block_author_cannot_bias_seed() {
+    let seed = seed_after_round(0, &[1, 2, 3], None);
+
+    // Different authors, i.e. different block hashes
+    for author in 1..=4 {
+        assert_eq!(seed, seed_after_round(author, &[1, 2, 3], None));
+    }
+
+    // Different reveal order
+    assert_eq!(seed, seed_after_round(1, &[3, 1, 2], None));
+    assert_eq!(seed, seed_after_round(2, &[2, 3, 1], None));
+
+    // Reveal censored for a block
+    for who in 1..=3 {
+        assert_eq!(seed, seed_after_round(3, &[1, 2, 3], Some(who)));
+    }
+}
+
+#[test]
+fn random_is_independent_of_block_hash() {
+    let random = |author: u8| {
+        new_test_ext().execute_with(|| {
+            run_to_block(3 * ROUND_LENGTH as u64 + 5, author);
+            (
+                RandomnessBeacon::random(b"subject"),
+                RandomnessBeacon::random(b"other-subject"),
+            )
+        })
+    };
+
+    let ((output, known_since), (other_output, _)) = random(0);
+    assert_eq!(known_since, 3 * ROUND_LENGTH as u64);
+    assert_ne!(output, other_output);
+
+    for author in 1..=4 {
+        assert_eq!(random(author).0, (output, known_since));
+    }
+}
+
+#[test]
+fn reserved_balance_is_required() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, 0);
+
+        // Not enough free balance to reserve the deposit
+        let _ = Balances::reserve(&1, Balances::free_balance(1) - 1);
+        assert!(RandomnessBeacon::commit(RuntimeOrigin::signed(1), H256::zero()).is_err());
+        assert_eq!(CommitCount::<Test>::get(0), 0);
+    });
+}
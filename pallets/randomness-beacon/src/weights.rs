@@ -0,0 +1,79 @@
+//! Weights for pallet_randomness_beacon
+//!
+//! Not benchmarked. Every weight was written by hand from the storage accesses of each call,
+//! none of them is benchmark output. Replace this file with the output of
+//! `.maintain/benchmark-weights.sh pallet_randomness_beacon`.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_randomness_beacon.
+pub trait WeightInfo {
+	fn commit() -> Weight;
+	fn reveal() -> Weight;
+	fn finalize_round(s: u32, ) -> Weight;
+}
+
+/// Estimated weights for pallet_randomness_beacon, not benchmarked.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `RandomnessBeacon::Commitments` (r:1 w:1)
+	/// Storage: `RandomnessBeacon::CommitCount` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn commit() -> Weight {
+		Weight::from_parts(30_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `RandomnessBeacon::Commitments` (r:1 w:1)
+	/// Storage: `RandomnessBeacon::RevealAccumulator` (r:1 w:1)
+	/// Storage: `RandomnessBeacon::RevealCount` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn reveal() -> Weight {
+		Weight::from_parts(35_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `RandomnessBeacon::Commitments` (r:s w:s)
+	/// Storage: `System::Account` (r:s w:s)
+	/// Storage: `RandomnessBeacon::CommitCount` (r:0 w:1)
+	/// Storage: `RandomnessBeacon::RevealAccumulator` (r:1 w:1)
+	/// Storage: `RandomnessBeacon::RevealCount` (r:1 w:1)
+	/// Storage: `RandomnessBeacon::RandomSeed` (r:1 w:1)
+	/// Storage: `RandomnessBeacon::RandomSeedBlock` (r:0 w:1)
+	/// The range of component `s` is `[0, 256]`.
+	fn finalize_round(s: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 1517)
+			.saturating_add(Weight::from_parts(25_000_000, 2603).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(s.into())))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn commit() -> Weight {
+		Weight::from_parts(30_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn reveal() -> Weight {
+		Weight::from_parts(35_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn finalize_round(s: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 1517)
+			.saturating_add(Weight::from_parts(25_000_000, 2603).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(s.into())))
+	}
+}
@@ -1,13 +1,48 @@
 use core::marker::PhantomData;
 
+use frame_support::Blake2_128Concat;
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_support::storage::types::{StorageDoubleMap, ValueQuery};
+use frame_support::traits::{Currency, StorageInstance};
 use frame_system::RawOrigin;
 use pallet_evm::AddressMapping;
-use pallet_evm::PrecompileHandle;
-use precompile_utils::EvmResult;
-use precompile_utils::prelude::RuntimeHelper;
-use sp_core::{H256, U256};
-use sp_runtime::traits::{Dispatchable, StaticLookup, UniqueSaturatedInto};
+use pallet_evm::{PrecompileFailure, PrecompileHandle};
+use precompile_utils::prelude::*;
+use precompile_utils::{EvmResult, solidity};
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::{CheckedSub, Dispatchable, StaticLookup, UniqueSaturatedInto};
+
+pub const SELECTOR_LOG_TRANSFER: [u8; 32] = keccak256!("Transfer(address,address,uint256)");
+pub const SELECTOR_LOG_APPROVAL: [u8; 32] = keccak256!("Approval(address,address,uint256)");
+
+/// Matches the `tokenSymbol` and `tokenDecimals` chain spec properties
+const TOKEN_NAME: &str = "Tensor";
+const TOKEN_SYMBOL: &str = "TENSOR";
+const TOKEN_DECIMALS: u8 = 18;
+
+/// Storage prefix of the ERC-20 allowances
+pub struct ApprovesPrefix;
+
+impl StorageInstance for ApprovesPrefix {
+    const STORAGE_PREFIX: &'static str = "Approves";
+
+    fn pallet_prefix() -> &'static str {
+        "ERC20BalancePrecompile"
+    }
+}
+
+/// ERC-20 allowances as `owner => spender => amount`
+pub type ApprovesStorage<Balance> = StorageDoubleMap<
+    ApprovesPrefix,
+    Blake2_128Concat,
+    H160,
+    Blake2_128Concat,
+    H160,
+    Balance,
+    ValueQuery,
+>;
+
+type BalanceOf<R> = <R as pallet_balances::Config>::Balance;
 
 pub(crate) struct ERC20BalancePrecompile<R>(PhantomData<R>);
 
@@ -23,7 +58,7 @@ where
     <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
     <R as pallet_balances::Config>::Balance: TryFrom<U256>,
 {
-    pub const HASH_N: u64 = 2050;
+    pub const HASH_N: u64 = 2053;
 }
 
 #[precompile_utils::precompile]
@@ -39,6 +74,150 @@ where
     <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
     <R as pallet_balances::Config>::Balance: TryFrom<U256>,
 {
+    #[precompile::public("name()")]
+    #[precompile::view]
+    fn name(_handle: &mut impl PrecompileHandle) -> EvmResult<UnboundedBytes> {
+        Ok(TOKEN_NAME.into())
+    }
+
+    #[precompile::public("symbol()")]
+    #[precompile::view]
+    fn symbol(_handle: &mut impl PrecompileHandle) -> EvmResult<UnboundedBytes> {
+        Ok(TOKEN_SYMBOL.into())
+    }
+
+    #[precompile::public("decimals()")]
+    #[precompile::view]
+    fn decimals(_handle: &mut impl PrecompileHandle) -> EvmResult<u8> {
+        Ok(TOKEN_DECIMALS)
+    }
+
+    /// Sum of every account's `balanceOf`
+    #[precompile::public("totalSupply()")]
+    #[precompile::view]
+    fn total_supply(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+        handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
+        let total_issuance = pallet_balances::Pallet::<R>::total_issuance();
+
+        Ok(balance_to_u256::<R>(total_issuance))
+    }
+
+    /// Free and reserved balance, matching `totalSupply`. Only the free balance above the
+    /// existential deposit and locks can be transferred
+    #[precompile::public("balanceOf(address)")]
+    #[precompile::view]
+    fn balance_of(handle: &mut impl PrecompileHandle, owner: Address) -> EvmResult<U256> {
+        handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
+        let owner = R::AddressMapping::into_account_id(owner.into());
+        let balance = <pallet_balances::Pallet<R> as Currency<R::AccountId>>::total_balance(&owner);
+
+        Ok(balance_to_u256::<R>(balance))
+    }
+
+    #[precompile::public("allowance(address,address)")]
+    #[precompile::view]
+    fn allowance(
+        handle: &mut impl PrecompileHandle,
+        owner: Address,
+        spender: Address,
+    ) -> EvmResult<U256> {
+        handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
+        let amount = ApprovesStorage::<BalanceOf<R>>::get(H160::from(owner), H160::from(spender));
+
+        Ok(balance_to_u256::<R>(amount))
+    }
+
+    #[precompile::public("approve(address,uint256)")]
+    fn approve(
+        handle: &mut impl PrecompileHandle,
+        spender: Address,
+        value: U256,
+    ) -> EvmResult<bool> {
+        let owner = handle.context().caller;
+        let spender = H160::from(spender);
+        let amount = try_u256_to_balance::<R>(value)?;
+
+        handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
+        handle.record_cost(RuntimeHelper::<R>::db_write_gas_cost())?;
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_APPROVAL,
+            owner,
+            spender,
+            solidity::encode_event_data(value),
+        );
+        handle.record_log_costs(&[&event])?;
+
+        ApprovesStorage::<BalanceOf<R>>::insert(owner, spender, amount);
+        event.record(handle)?;
+
+        Ok(true)
+    }
+
+    #[precompile::public("transfer(address,uint256)")]
+    fn transfer_to(
+        handle: &mut impl PrecompileHandle,
+        to: Address,
+        value: U256,
+    ) -> EvmResult<bool> {
+        let from = handle.context().caller;
+        let to = H160::from(to);
+        let amount = try_u256_to_balance::<R>(value)?;
+
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_TRANSFER,
+            from,
+            to,
+            solidity::encode_event_data(value),
+        );
+        handle.record_log_costs(&[&event])?;
+
+        Self::dispatch_transfer(handle, from, to, amount)?;
+        event.record(handle)?;
+
+        Ok(true)
+    }
+
+    #[precompile::public("transferFrom(address,address,uint256)")]
+    fn transfer_from(
+        handle: &mut impl PrecompileHandle,
+        from: Address,
+        to: Address,
+        value: U256,
+    ) -> EvmResult<bool> {
+        let caller = handle.context().caller;
+        let from = H160::from(from);
+        let to = H160::from(to);
+        let amount = try_u256_to_balance::<R>(value)?;
+
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_TRANSFER,
+            from,
+            to,
+            solidity::encode_event_data(value),
+        );
+        handle.record_log_costs(&[&event])?;
+
+        // The owner does not need an allowance to move their own balance
+        if caller != from {
+            handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
+            handle.record_cost(RuntimeHelper::<R>::db_write_gas_cost())?;
+            ApprovesStorage::<BalanceOf<R>>::try_mutate(from, caller, |allowance| {
+                *allowance = allowance
+                    .checked_sub(&amount)
+                    .ok_or(revert("Insufficient allowance"))?;
+                Ok::<_, PrecompileFailure>(())
+            })?;
+        }
+
+        Self::dispatch_transfer(handle, from, to, amount)?;
+        event.record(handle)?;
+
+        Ok(true)
+    }
+
     #[precompile::public("transfer(bytes32)")]
     #[precompile::payable]
     fn transfer(handle: &mut impl PrecompileHandle, address: H256) -> EvmResult<()> {
@@ -67,3 +246,43 @@ where
         Ok(())
     }
 }
+
+impl<R> ERC20BalancePrecompile<R>
+where
+    R: frame_system::Config + pallet_balances::Config + pallet_evm::Config,
+    <R as frame_system::Config>::RuntimeCall: From<pallet_balances::Call<R>>
+        + GetDispatchInfo
+        + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+    <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
+{
+    fn dispatch_transfer(
+        handle: &mut impl PrecompileHandle,
+        from: H160,
+        to: H160,
+        amount: BalanceOf<R>,
+    ) -> EvmResult<()> {
+        let origin = R::AddressMapping::into_account_id(from);
+        let dest = R::AddressMapping::into_account_id(to);
+
+        let call = pallet_balances::Call::<R>::transfer_allow_death {
+            dest: dest.into(),
+            value: amount,
+        };
+
+        RuntimeHelper::<R>::try_dispatch(handle, RawOrigin::Signed(origin).into(), call, 0)?;
+
+        Ok(())
+    }
+}
+
+fn balance_to_u256<R: pallet_balances::Config>(balance: BalanceOf<R>) -> U256 {
+    U256::from(UniqueSaturatedInto::<u128>::unique_saturated_into(balance))
+}
+
+fn try_u256_to_balance<R: pallet_balances::Config>(value: U256) -> EvmResult<BalanceOf<R>>
+where
+    BalanceOf<R>: TryFrom<U256>,
+{
+    value.try_into().map_err(|_| revert("Value out of bounds"))
+}
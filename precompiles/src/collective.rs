@@ -0,0 +1,282 @@
+use crate::utils::{dispatch_error_to_revert, try_u256_to_u32, try_u256_to_u64, u256_topic};
+use codec::DecodeLimit;
+use core::marker::PhantomData;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use pallet_collective::{Instance1, WeightInfo};
+use pallet_evm::{AddressMapping, GasWeightMapping, PrecompileFailure, PrecompileHandle};
+use precompile_utils::{EvmResult, prelude::*, solidity};
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::Hash;
+use sp_runtime::{SaturatedConversion, Vec};
+
+pub const SELECTOR_LOG_PROPOSED: [u8; 32] = keccak256!("Proposed(address,uint256,bytes32,uint256)");
+pub const SELECTOR_LOG_VOTED: [u8; 32] = keccak256!("Voted(address,bytes32,bool)");
+pub const SELECTOR_LOG_CLOSED: [u8; 32] = keccak256!("Closed(bytes32)");
+
+/// The council collective
+type CouncilInstance = Instance1;
+
+/// Max nesting depth when decoding a SCALE encoded proposal
+const MAX_PROPOSAL_DECODE_DEPTH: u32 = 256;
+
+type CouncilProposal<R> = <R as pallet_collective::Config<CouncilInstance>>::Proposal;
+type CouncilWeightInfo<R> = <R as pallet_collective::Config<CouncilInstance>>::WeightInfo;
+type CouncilMaxMembers<R> = <R as pallet_collective::Config<CouncilInstance>>::MaxMembers;
+type CouncilMaxProposals<R> = <R as pallet_collective::Config<CouncilInstance>>::MaxProposals;
+
+pub(crate) struct CollectivePrecompile<R>(PhantomData<R>);
+
+impl<R> CollectivePrecompile<R>
+where
+    R: frame_system::Config<Hash = H256>
+        + pallet_evm::Config
+        + pallet_collective::Config<CouncilInstance>,
+    R::AccountId: From<[u8; 20]> + Into<[u8; 20]>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    pub const HASH_N: u64 = 2052;
+}
+
+#[precompile_utils::precompile]
+impl<R> CollectivePrecompile<R>
+where
+    R: frame_system::Config<Hash = H256>
+        + pallet_evm::Config
+        + pallet_collective::Config<CouncilInstance>,
+    R::AccountId: From<[u8; 20]> + Into<[u8; 20]>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+{
+    /// Proposes a SCALE encoded runtime call to the council, returns the proposal index
+    ///
+    /// The `propose` extrinsic executes a proposal with a threshold below 2 right away, that isn't
+    /// supported here so such thresholds revert instead of creating a motion with no ayes.
+    #[precompile::public("propose(uint256,bytes)")]
+    fn propose(
+        handle: &mut impl PrecompileHandle,
+        threshold: U256,
+        proposal: UnboundedBytes,
+    ) -> EvmResult<U256> {
+        let threshold = try_u256_to_u32(threshold)?;
+        if threshold < 2 {
+            return Err(revert("Threshold must be at least 2"));
+        }
+        let proposal_bytes = proposal.as_bytes();
+        let length_bound: u32 = proposal_bytes
+            .len()
+            .try_into()
+            .map_err(|_| revert("Proposal too long"))?;
+
+        handle.record_cost(weight_to_gas::<R>(
+            CouncilWeightInfo::<R>::propose_proposed(
+                length_bound,
+                CouncilMaxMembers::<R>::get(),
+                CouncilMaxProposals::<R>::get(),
+            ),
+        ))?;
+
+        let proposal = CouncilProposal::<R>::decode_with_depth_limit(
+            MAX_PROPOSAL_DECODE_DEPTH,
+            &mut &*proposal_bytes,
+        )
+        .map_err(|_| revert("Failed to decode proposal"))?;
+
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        ensure_member::<R>(&origin)?;
+
+        let proposal_hash = <R as frame_system::Config>::Hashing::hash_of(&proposal);
+        let proposal_index = pallet_collective::ProposalCount::<R, CouncilInstance>::get();
+
+        let event = log4(
+            handle.context().address,
+            SELECTOR_LOG_PROPOSED,
+            handle.context().caller,
+            u256_topic(proposal_index.into()),
+            proposal_hash,
+            solidity::encode_event_data(U256::from(threshold)),
+        );
+        handle.record_log_costs(&[&event])?;
+
+        pallet_collective::Pallet::<R, CouncilInstance>::do_propose_proposed(
+            origin,
+            threshold,
+            proposal.into(),
+            length_bound,
+        )
+        .map_err(dispatch_error_to_revert)?;
+        event.record(handle)?;
+
+        Ok(proposal_index.into())
+    }
+
+    #[precompile::public("vote(bytes32,uint256,bool)")]
+    fn vote(
+        handle: &mut impl PrecompileHandle,
+        proposal_hash: H256,
+        proposal_index: U256,
+        approve: bool,
+    ) -> EvmResult<()> {
+        let proposal_index = try_u256_to_u32(proposal_index)?;
+
+        handle.record_cost(weight_to_gas::<R>(CouncilWeightInfo::<R>::vote(
+            CouncilMaxMembers::<R>::get(),
+        )))?;
+
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        ensure_member::<R>(&origin)?;
+
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_VOTED,
+            handle.context().caller,
+            proposal_hash,
+            solidity::encode_event_data(approve),
+        );
+        handle.record_log_costs(&[&event])?;
+
+        pallet_collective::Pallet::<R, CouncilInstance>::do_vote(
+            origin,
+            proposal_hash,
+            proposal_index,
+            approve,
+        )
+        .map_err(dispatch_error_to_revert)?;
+        event.record(handle)?;
+
+        Ok(())
+    }
+
+    /// Closes a motion, executing the proposal if approved. Any account may close a motion
+    #[precompile::public("close(bytes32,uint256,uint256,uint256,uint256)")]
+    fn close(
+        handle: &mut impl PrecompileHandle,
+        proposal_hash: H256,
+        proposal_index: U256,
+        weight_bound_ref_time: U256,
+        weight_bound_proof_size: U256,
+        length_bound: U256,
+    ) -> EvmResult<()> {
+        let proposal_index = try_u256_to_u32(proposal_index)?;
+        let proposal_weight_bound = Weight::from_parts(
+            try_u256_to_u64(weight_bound_ref_time)?,
+            try_u256_to_u64(weight_bound_proof_size)?,
+        );
+        let length_bound = try_u256_to_u32(length_bound)?;
+
+        let max_members = CouncilMaxMembers::<R>::get();
+        let max_proposals = CouncilMaxProposals::<R>::get();
+        handle.record_cost(weight_to_gas::<R>(
+            CouncilWeightInfo::<R>::close_early_approved(length_bound, max_members, max_proposals)
+                .max(CouncilWeightInfo::<R>::close_early_disapproved(
+                    max_members,
+                    max_proposals,
+                ))
+                .max(CouncilWeightInfo::<R>::close_approved(
+                    length_bound,
+                    max_members,
+                    max_proposals,
+                ))
+                .max(CouncilWeightInfo::<R>::close_disapproved(
+                    max_members,
+                    max_proposals,
+                ))
+                .saturating_add(proposal_weight_bound),
+        ))?;
+
+        let event = log2(
+            handle.context().address,
+            SELECTOR_LOG_CLOSED,
+            proposal_hash,
+            vec![],
+        );
+        handle.record_log_costs(&[&event])?;
+
+        pallet_collective::Pallet::<R, CouncilInstance>::do_close(
+            proposal_hash,
+            proposal_index,
+            proposal_weight_bound,
+            length_bound,
+        )
+        .map_err(|e| dispatch_error_to_revert(e.error))?;
+        event.record(handle)?;
+
+        Ok(())
+    }
+
+    /// Returns the SCALE encoded call of an active proposal
+    #[precompile::public("proposalOf(bytes32)")]
+    #[precompile::view]
+    fn proposal_of(
+        handle: &mut impl PrecompileHandle,
+        proposal_hash: H256,
+    ) -> EvmResult<UnboundedBytes> {
+        handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
+        let proposal = pallet_collective::ProposalOf::<R, CouncilInstance>::get(proposal_hash)
+            .ok_or(revert("Proposal not found"))?;
+
+        Ok(codec::Encode::encode(&proposal).into())
+    }
+
+    #[precompile::public("members()")]
+    #[precompile::view]
+    fn members(handle: &mut impl PrecompileHandle) -> EvmResult<Vec<Address>> {
+        handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
+        let members = pallet_collective::Members::<R, CouncilInstance>::get();
+
+        Ok(members.into_iter().map(account_to_address::<R>).collect())
+    }
+
+    /// Returns `(index, threshold, ayes, nays, end)` of an active proposal
+    #[precompile::public("voting(bytes32)")]
+    #[precompile::view]
+    fn voting(
+        handle: &mut impl PrecompileHandle,
+        proposal_hash: H256,
+    ) -> EvmResult<(U256, U256, Vec<Address>, Vec<Address>, U256)> {
+        handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
+        let voting = pallet_collective::Voting::<R, CouncilInstance>::get(proposal_hash)
+            .ok_or(revert("Proposal not found"))?;
+
+        Ok((
+            voting.index().into(),
+            voting.threshold().into(),
+            voting
+                .ayes()
+                .iter()
+                .cloned()
+                .map(account_to_address::<R>)
+                .collect(),
+            voting
+                .nays()
+                .iter()
+                .cloned()
+                .map(account_to_address::<R>)
+                .collect(),
+            voting.end().saturated_into::<u64>().into(),
+        ))
+    }
+}
+
+/// The dispatchables check membership themselves, the `do_*` helpers do not
+fn ensure_member<R>(who: &R::AccountId) -> Result<(), PrecompileFailure>
+where
+    R: pallet_collective::Config<CouncilInstance>,
+{
+    if pallet_collective::Pallet::<R, CouncilInstance>::is_member(who) {
+        Ok(())
+    } else {
+        Err(revert("Not a council member"))
+    }
+}
+
+fn weight_to_gas<R: pallet_evm::Config>(weight: Weight) -> u64 {
+    <R as pallet_evm::Config>::GasWeightMapping::weight_to_gas(weight)
+}
+
+fn account_to_address<R>(account: R::AccountId) -> Address
+where
+    R: frame_system::Config,
+    R::AccountId: Into<[u8; 20]>,
+{
+    Address(H160::from(account.into()))
+}
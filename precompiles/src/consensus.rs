@@ -0,0 +1,270 @@
+use crate::utils::{try_u256_to_u32, try_u256_to_u128, u256_topic};
+use core::marker::PhantomData;
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_support::storage::bounded_vec::BoundedVec;
+use frame_system::RawOrigin;
+use pallet_evm::{AddressMapping, PrecompileFailure, PrecompileHandle};
+use pallet_network::{DefaultValidatorArgsLimit, GetMaxSubnetNodes, SubnetNodeConsensusData};
+use precompile_utils::solidity::codec::BoundedVec as SolidityBoundedVec;
+use precompile_utils::{EvmResult, prelude::*, solidity};
+use sp_core::U256;
+use sp_runtime::Vec;
+use sp_runtime::traits::{Dispatchable, StaticLookup};
+
+pub const SELECTOR_LOG_VALIDATOR_SUBMISSION: [u8; 32] =
+    keccak256!("ValidatorSubmission(uint256,address,uint256)");
+pub const SELECTOR_LOG_BACKUP_VALIDATOR_SUBMISSION: [u8; 32] =
+    keccak256!("BackupValidatorSubmission(uint256,address,uint256)");
+pub const SELECTOR_LOG_ATTESTATION: [u8; 32] = keccak256!("Attestation(uint256,address,uint256)");
+
+pub(crate) struct ConsensusPrecompile<R>(PhantomData<R>);
+
+impl<R> ConsensusPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_network::Config,
+    R::AccountId: From<[u8; 20]> + Into<[u8; 20]>,
+    <R as frame_system::Config>::RuntimeCall:
+        From<pallet_network::Call<R>> + GetDispatchInfo + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+    <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
+{
+    pub const HASH_N: u64 = 2051;
+}
+
+#[precompile_utils::precompile]
+impl<R> ConsensusPrecompile<R>
+where
+    R: frame_system::Config + pallet_evm::Config + pallet_network::Config,
+    R::AccountId: From<[u8; 20]> + Into<[u8; 20]>,
+    <R as frame_system::Config>::RuntimeCall:
+        From<pallet_network::Call<R>> + GetDispatchInfo + Dispatchable<PostInfo = PostDispatchInfo>,
+    <R as pallet_evm::Config>::AddressMapping: AddressMapping<R::AccountId>,
+    <<R as frame_system::Config>::Lookup as StaticLookup>::Source: From<R::AccountId>,
+{
+    #[precompile::public(
+        "proposeAttestation(uint256,(uint256,uint256)[],uint256,uint256,bytes,bytes)"
+    )]
+    fn propose_attestation(
+        handle: &mut impl PrecompileHandle,
+        subnet_id: U256,
+        data: SolidityBoundedVec<(U256, U256), GetMaxSubnetNodes<R>>,
+        prioritize_queue_node_id: U256,
+        remove_queue_node_id: U256,
+        args: BoundedBytes<DefaultValidatorArgsLimit>,
+        attest_data: BoundedBytes<DefaultValidatorArgsLimit>,
+    ) -> EvmResult<()> {
+        let subnet_id = try_u256_to_u32(subnet_id)?;
+        // `MaxSubnetNodes` is read to bound `data`
+        handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
+        let data: Vec<SubnetNodeConsensusData> = Vec::from(data)
+            .into_iter()
+            .map(|(subnet_node_id, score)| {
+                Ok::<_, PrecompileFailure>(SubnetNodeConsensusData {
+                    subnet_node_id: try_u256_to_u32(subnet_node_id)?,
+                    score: try_u256_to_u128(score)?,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        let prioritize_queue_node_id = try_u256_to_option_u32(prioritize_queue_node_id)?;
+        let remove_queue_node_id = try_u256_to_option_u32(remove_queue_node_id)?;
+        let args = bytes_to_option_bounded_vec(&args)?;
+        let attest_data = bytes_to_option_bounded_vec(&attest_data)?;
+
+        handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
+        let subnet_epoch = pallet_network::Pallet::<R>::get_current_subnet_epoch_as_u32(subnet_id);
+
+        // Both logs have the same size, the selector is picked once the call succeeds
+        let (address, caller) = (handle.context().address, handle.context().caller);
+        let log = |selector: [u8; 32]| {
+            log3(
+                address,
+                selector,
+                u256_topic(subnet_id.into()),
+                caller,
+                solidity::encode_event_data(U256::from(subnet_epoch)),
+            )
+        };
+        handle.record_log_costs(&[&log(SELECTOR_LOG_VALIDATOR_SUBMISSION)])?;
+        // `SubnetElectedValidator` and `SubnetNodeIdHotkey` are read to tell a backup submission
+        handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost().saturating_mul(2))?;
+
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let call = pallet_network::Call::<R>::propose_attestation {
+            subnet_id,
+            data,
+            prioritize_queue_node_id,
+            remove_queue_node_id,
+            args,
+            attest_data,
+        };
+
+        RuntimeHelper::<R>::try_dispatch(
+            handle,
+            RawOrigin::Signed(origin.clone()).into(),
+            call,
+            0,
+        )?;
+
+        // The dispatch succeeded, so the caller is the elected validator or its backup
+        let is_elected = pallet_network::SubnetElectedValidator::<R>::get(subnet_id, subnet_epoch)
+            .and_then(|validator_id| {
+                pallet_network::SubnetNodeIdHotkey::<R>::get(subnet_id, validator_id)
+            })
+            .is_some_and(|hotkey| hotkey == origin);
+        let event = if is_elected {
+            log(SELECTOR_LOG_VALIDATOR_SUBMISSION)
+        } else {
+            log(SELECTOR_LOG_BACKUP_VALIDATOR_SUBMISSION)
+        };
+        event.record(handle)?;
+
+        Ok(())
+    }
+
+    #[precompile::public("attest(uint256,bytes)")]
+    fn attest(
+        handle: &mut impl PrecompileHandle,
+        subnet_id: U256,
+        attest_data: BoundedBytes<DefaultValidatorArgsLimit>,
+    ) -> EvmResult<()> {
+        let subnet_id = try_u256_to_u32(subnet_id)?;
+        let attest_data = bytes_to_option_bounded_vec(&attest_data)?;
+
+        handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
+        let subnet_epoch = pallet_network::Pallet::<R>::get_current_subnet_epoch_as_u32(subnet_id);
+
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_ATTESTATION,
+            u256_topic(subnet_id.into()),
+            handle.context().caller,
+            solidity::encode_event_data(U256::from(subnet_epoch)),
+        );
+        handle.record_log_costs(&[&event])?;
+
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let call = pallet_network::Call::<R>::attest {
+            subnet_id,
+            attest_data,
+        };
+
+        RuntimeHelper::<R>::try_dispatch(
+            handle,
+            RawOrigin::Signed(origin.clone()).into(),
+            call,
+            0,
+        )?;
+        event.record(handle)?;
+
+        Ok(())
+    }
+
+    #[precompile::public("getElectedValidator(uint256,uint256)")]
+    #[precompile::view]
+    fn get_elected_validator(
+        handle: &mut impl PrecompileHandle,
+        subnet_id: U256,
+        subnet_epoch: U256,
+    ) -> EvmResult<U256> {
+        let subnet_id = try_u256_to_u32(subnet_id)?;
+        let subnet_epoch = try_u256_to_u32(subnet_epoch)?;
+
+        handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
+        let subnet_node_id =
+            pallet_network::SubnetElectedValidator::<R>::get(subnet_id, subnet_epoch)
+                .ok_or(revert("Elected validator not found"))?;
+
+        Ok(subnet_node_id.into())
+    }
+
+    #[precompile::public("getCurrentSubnetEpoch(uint256)")]
+    #[precompile::view]
+    fn get_current_subnet_epoch(
+        handle: &mut impl PrecompileHandle,
+        subnet_id: U256,
+    ) -> EvmResult<U256> {
+        let subnet_id = try_u256_to_u32(subnet_id)?;
+
+        handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
+        let subnet_epoch = pallet_network::Pallet::<R>::get_current_subnet_epoch_as_u32(subnet_id);
+
+        Ok(subnet_epoch.into())
+    }
+
+    /// Returns `(validatorId, submittedBlock, data, attestors, prioritizeQueueNodeId, removeQueueNodeId,
+    /// args)` where queue node IDs are `0` when unset
+    #[precompile::public("getConsensusData(uint256,uint256)")]
+    #[precompile::view]
+    fn get_consensus_data(
+        handle: &mut impl PrecompileHandle,
+        subnet_id: U256,
+        subnet_epoch: U256,
+    ) -> EvmResult<(
+        U256,
+        U256,
+        Vec<(U256, U256)>,
+        Vec<U256>,
+        U256,
+        U256,
+        UnboundedBytes,
+    )> {
+        let subnet_id = try_u256_to_u32(subnet_id)?;
+        let subnet_epoch = try_u256_to_u32(subnet_epoch)?;
+
+        handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
+        let consensus_data =
+            pallet_network::SubnetConsensusSubmission::<R>::get(subnet_id, subnet_epoch)
+                .ok_or(revert("Consensus data not found"))?;
+
+        let data: Vec<(U256, U256)> = consensus_data
+            .data
+            .iter()
+            .map(|entry| (entry.subnet_node_id.into(), entry.score.into()))
+            .collect();
+        let attestors: Vec<U256> = consensus_data
+            .attests
+            .keys()
+            .map(|subnet_node_id| (*subnet_node_id).into())
+            .collect();
+        let args: UnboundedBytes = consensus_data
+            .args
+            .map(|args| args.into_inner())
+            .unwrap_or_default()
+            .into();
+
+        Ok((
+            consensus_data.validator_id.into(),
+            consensus_data.block.into(),
+            data,
+            attestors,
+            consensus_data.prioritize_queue_node_id.unwrap_or(0).into(),
+            consensus_data.remove_queue_node_id.unwrap_or(0).into(),
+            args,
+        ))
+    }
+}
+
+/// Subnet node IDs start at 1, `0` is used for `None`
+fn try_u256_to_option_u32(value: U256) -> Result<Option<u32>, PrecompileFailure> {
+    if value.is_zero() {
+        Ok(None)
+    } else {
+        try_u256_to_u32(value).map(Some)
+    }
+}
+
+fn bytes_to_option_bounded_vec(
+    bytes: &BoundedBytes<DefaultValidatorArgsLimit>,
+) -> Result<Option<BoundedVec<u8, DefaultValidatorArgsLimit>>, PrecompileFailure> {
+    if bytes.as_bytes().is_empty() {
+        Ok(None)
+    } else {
+        let vec: BoundedVec<u8, DefaultValidatorArgsLimit> =
+            bytes
+                .as_bytes()
+                .to_vec()
+                .try_into()
+                .map_err(|_| revert("Bytes too long"))?;
+        Ok(Some(vec))
+    }
+}
@@ -14,14 +14,20 @@ use sp_core::{H160, H256, U256, crypto::ByteArray};
 use sp_runtime::traits::{Dispatchable, StaticLookup};
 
 use crate::balance::*;
+use crate::collective::*;
+use crate::consensus::*;
 use crate::overwatch_nodes::*;
 use crate::staking::*;
 use crate::subnet::*;
+use crate::utils::ensure_not_delegatecall;
 
 mod balance;
+mod collective;
+mod consensus;
 mod overwatch_nodes;
 mod staking;
 mod subnet;
+mod utils;
 
 pub struct FrontierPrecompiles<R>(PhantomData<R>);
 
@@ -30,7 +36,8 @@ where
     R: frame_system::Config<Hash = H256>
         + pallet_evm::Config
         + pallet_balances::Config
-        + pallet_network::Config,
+        + pallet_network::Config
+        + pallet_collective::Config<pallet_collective::Instance1>,
     R::AccountId: From<[u8; 20]> + Into<[u8; 20]>,
     <R as frame_system::Config>::RuntimeCall: From<pallet_network::Call<R>>
         + From<pallet_balances::Call<R>>
@@ -50,7 +57,8 @@ where
     R: frame_system::Config<Hash = H256>
         + pallet_evm::Config
         + pallet_balances::Config
-        + pallet_network::Config,
+        + pallet_network::Config
+        + pallet_collective::Config<pallet_collective::Instance1>,
     R::AccountId: From<[u8; 20]> + Into<[u8; 20]>,
     <R as frame_system::Config>::RuntimeCall: From<pallet_network::Call<R>>
         + From<pallet_balances::Call<R>>
@@ -63,7 +71,7 @@ where
     pub fn new() -> Self {
         Self(Default::default())
     }
-    pub fn used_addresses() -> [H160; 10] {
+    pub fn used_addresses() -> [H160; 13] {
         [
             hash(1),
             hash(2),
@@ -75,7 +83,9 @@ where
             hash(StakingPrecompile::<R>::HASH_N),
             hash(SubnetPrecompile::<R>::HASH_N),
             hash(OverwatchNodePrecompile::<R>::HASH_N),
-            // hash(ERC20BalancePrecompile::<R>::HASH_N),
+            hash(ConsensusPrecompile::<R>::HASH_N),
+            hash(CollectivePrecompile::<R>::HASH_N),
+            hash(ERC20BalancePrecompile::<R>::HASH_N),
         ]
     }
 }
@@ -84,7 +94,8 @@ where
     R: frame_system::Config<Hash = H256>
         + pallet_evm::Config
         + pallet_balances::Config
-        + pallet_network::Config,
+        + pallet_network::Config
+        + pallet_collective::Config<pallet_collective::Instance1>,
     R::AccountId: From<[u8; 20]> + Into<[u8; 20]>,
     <R as frame_system::Config>::RuntimeCall: From<pallet_network::Call<R>>
         + From<pallet_balances::Call<R>>
@@ -115,6 +126,18 @@ where
             a if a == hash(OverwatchNodePrecompile::<R>::HASH_N) => {
                 Some(OverwatchNodePrecompile::<R>::execute(handle))
             }
+            a if a == hash(ConsensusPrecompile::<R>::HASH_N) => Some(
+                ensure_not_delegatecall(handle)
+                    .and_then(|_| ConsensusPrecompile::<R>::execute(handle)),
+            ),
+            a if a == hash(CollectivePrecompile::<R>::HASH_N) => Some(
+                ensure_not_delegatecall(handle)
+                    .and_then(|_| CollectivePrecompile::<R>::execute(handle)),
+            ),
+            a if a == hash(ERC20BalancePrecompile::<R>::HASH_N) => Some(
+                ensure_not_delegatecall(handle)
+                    .and_then(|_| ERC20BalancePrecompile::<R>::execute(handle)),
+            ),
             _ => None,
         }
     }
@@ -1,12 +1,19 @@
+use crate::utils::{
+    try_u32_to_u256, try_u128_to_u256, try_u256_to_u32, try_u256_to_u128, u256_topic,
+};
 use core::marker::PhantomData;
 use fp_evm::Log;
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
 use frame_support::traits::ConstU32;
 use frame_system::RawOrigin;
-use pallet_evm::{AddressMapping, ExitError, PrecompileFailure, PrecompileHandle};
+use pallet_evm::{AddressMapping, PrecompileFailure, PrecompileHandle};
 use pallet_network::QueuedSwapCall;
 use pallet_network::{OverwatchCommit, OverwatchReveal};
-use precompile_utils::{EvmResult, prelude::*, solidity::Codec};
+use precompile_utils::{
+    EvmResult,
+    prelude::*,
+    solidity::{self, Codec},
+};
 use sp_core::Decode;
 use sp_core::Get;
 use sp_core::{H160, H256, OpaquePeerId, U256};
@@ -19,6 +26,21 @@ use sp_std::vec;
 pub type BalanceOf<Runtime, Instance = ()> =
     <Runtime as pallet_balances::Config<Instance>>::Balance;
 
+pub const SELECTOR_LOG_OVERWATCH_NODE_REGISTERED: [u8; 32] =
+    keccak256!("OverwatchNodeRegistered(address,address,uint256)");
+pub const SELECTOR_LOG_OVERWATCH_NODE_REMOVED: [u8; 32] =
+    keccak256!("OverwatchNodeRemoved(uint256,address)");
+pub const SELECTOR_LOG_OVERWATCH_NODE_PEER_ID_SET: [u8; 32] =
+    keccak256!("OverwatchNodePeerIdSet(uint256,uint256,string)");
+pub const SELECTOR_LOG_OVERWATCH_WEIGHTS_COMMITTED: [u8; 32] =
+    keccak256!("OverwatchWeightsCommitted(uint256,address,uint256[])");
+pub const SELECTOR_LOG_OVERWATCH_WEIGHTS_REVEALED: [u8; 32] =
+    keccak256!("OverwatchWeightsRevealed(uint256,address,uint256[],uint256[])");
+pub const SELECTOR_LOG_OVERWATCH_STAKE_ADDED: [u8; 32] =
+    keccak256!("OverwatchStakeAdded(uint256,address,address,uint256)");
+pub const SELECTOR_LOG_OVERWATCH_STAKE_REMOVED: [u8; 32] =
+    keccak256!("OverwatchStakeRemoved(address,address,uint256)");
+
 pub(crate) struct OverwatchNodePrecompile<R>(PhantomData<R>);
 
 impl<R> OverwatchNodePrecompile<R>
@@ -50,6 +72,15 @@ where
         hotkey: Address,
         stake_to_be_added: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_OVERWATCH_NODE_REGISTERED,
+            handle.context().caller,
+            H160::from(hotkey),
+            solidity::encode_event_data(stake_to_be_added),
+        );
+        handle.record_log_costs(&[&event])?;
+
         handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
         let stake_to_be_added = stake_to_be_added.unique_saturated_into();
         let hotkey = R::AddressMapping::into_account_id(hotkey.into());
@@ -66,6 +97,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -75,6 +107,15 @@ where
         handle: &mut impl PrecompileHandle,
         overwatch_node_id: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_OVERWATCH_NODE_REMOVED,
+            u256_topic(overwatch_node_id),
+            handle.context().caller,
+            vec![],
+        );
+        handle.record_log_costs(&[&event])?;
+
         handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
         let overwatch_node_id = try_u256_to_u32(overwatch_node_id)?;
 
@@ -87,6 +128,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -96,6 +138,15 @@ where
         handle: &mut impl PrecompileHandle,
         overwatch_node_id: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_OVERWATCH_NODE_REMOVED,
+            u256_topic(overwatch_node_id),
+            handle.context().caller,
+            vec![],
+        );
+        handle.record_log_costs(&[&event])?;
+
         handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
         let overwatch_node_id = try_u256_to_u32(overwatch_node_id)?;
 
@@ -108,6 +159,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -119,6 +171,15 @@ where
         overwatch_node_id: U256,
         peer_id: BoundedString<ConstU32<64>>,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_OVERWATCH_NODE_PEER_ID_SET,
+            u256_topic(subnet_id),
+            u256_topic(overwatch_node_id),
+            solidity::encode_event_data(peer_id.clone()),
+        );
+        handle.record_log_costs(&[&event])?;
+
         handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let overwatch_node_id = try_u256_to_u32(overwatch_node_id)?;
@@ -137,6 +198,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -192,6 +254,20 @@ where
         overwatch_node_id: U256,
         commits: Vec<(U256, H256)>,
     ) -> EvmResult {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_OVERWATCH_WEIGHTS_COMMITTED,
+            u256_topic(overwatch_node_id),
+            handle.context().caller,
+            solidity::encode_event_data(
+                commits
+                    .iter()
+                    .map(|(subnet_id, _)| *subnet_id)
+                    .collect::<Vec<U256>>(),
+            ),
+        );
+        handle.record_log_costs(&[&event])?;
+
         handle.record_cost(RuntimeHelper::<R>::db_write_gas_cost())?;
 
         let overwatch_node_id: u32 = try_u256_to_u32(overwatch_node_id)?;
@@ -218,6 +294,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -228,6 +305,24 @@ where
         overwatch_node_id: U256,
         reveals: Vec<(U256, U256, Vec<u8>)>,
     ) -> EvmResult {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_OVERWATCH_WEIGHTS_REVEALED,
+            u256_topic(overwatch_node_id),
+            handle.context().caller,
+            solidity::encode_event_data((
+                reveals
+                    .iter()
+                    .map(|(subnet_id, _, _)| *subnet_id)
+                    .collect::<Vec<U256>>(),
+                reveals
+                    .iter()
+                    .map(|(_, weight, _)| *weight)
+                    .collect::<Vec<U256>>(),
+            )),
+        );
+        handle.record_log_costs(&[&event])?;
+
         handle.record_cost(RuntimeHelper::<R>::db_write_gas_cost())?;
 
         let overwatch_node_id = try_u256_to_u32(overwatch_node_id)?;
@@ -256,6 +351,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -268,6 +364,16 @@ where
         hotkey: Address,
         stake_to_be_added: U256,
     ) -> EvmResult<()> {
+        let event = log4(
+            handle.context().address,
+            SELECTOR_LOG_OVERWATCH_STAKE_ADDED,
+            u256_topic(overwatch_node_id),
+            handle.context().caller,
+            H160::from(hotkey),
+            solidity::encode_event_data(stake_to_be_added),
+        );
+        handle.record_log_costs(&[&event])?;
+
         handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
         let overwatch_node_id = try_u256_to_u32(overwatch_node_id)?;
         let hotkey = R::AddressMapping::into_account_id(hotkey.into());
@@ -286,6 +392,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -296,6 +403,15 @@ where
         hotkey: Address,
         stake_to_be_removed: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_OVERWATCH_STAKE_REMOVED,
+            handle.context().caller,
+            H160::from(hotkey),
+            solidity::encode_event_data(stake_to_be_removed),
+        );
+        handle.record_log_costs(&[&event])?;
+
         handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
         let hotkey = R::AddressMapping::into_account_id(hotkey.into());
         let stake_to_be_removed = stake_to_be_removed.unique_saturated_into();
@@ -312,6 +428,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -631,27 +748,3 @@ where
         Ok(value)
     }
 }
-
-fn try_u256_to_u32(value: U256) -> Result<u32, PrecompileFailure> {
-    value.try_into().map_err(|_| PrecompileFailure::Error {
-        exit_status: ExitError::Other("u32 out of bounds".into()),
-    })
-}
-
-fn try_u256_to_u128(value: U256) -> Result<u128, PrecompileFailure> {
-    value.try_into().map_err(|_| PrecompileFailure::Error {
-        exit_status: ExitError::Other("u128 out of bounds".into()),
-    })
-}
-
-fn try_u32_to_u256(value: u32) -> Result<U256, PrecompileFailure> {
-    value.try_into().map_err(|_| PrecompileFailure::Error {
-        exit_status: ExitError::Other("u32 out of bounds".into()),
-    })
-}
-
-fn try_u128_to_u256(value: u128) -> Result<U256, PrecompileFailure> {
-    value.try_into().map_err(|_| PrecompileFailure::Error {
-        exit_status: ExitError::Other("u128 out of bounds".into()),
-    })
-}
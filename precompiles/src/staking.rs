@@ -1,12 +1,17 @@
+use crate::utils::{try_u256_to_u32, u256_topic};
 use core::marker::PhantomData;
 use fp_evm::Log;
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
 use frame_system::RawOrigin;
-use pallet_evm::{AddressMapping, ExitError, PrecompileFailure, PrecompileHandle};
+use pallet_evm::{AddressMapping, PrecompileFailure, PrecompileHandle};
 use pallet_network::QueuedSwapCall;
-use precompile_utils::{EvmResult, prelude::*, solidity::Codec};
+use precompile_utils::{
+    EvmResult,
+    prelude::*,
+    solidity::{self, Codec},
+};
 use sp_core::Decode;
-use sp_core::{H160, H256, U256};
+use sp_core::{H160, U256};
 use sp_runtime::traits::{Dispatchable, StaticLookup, UniqueSaturatedInto};
 use sp_std::vec;
 
@@ -14,6 +19,40 @@ use sp_std::vec;
 pub type BalanceOf<Runtime, Instance = ()> =
     <Runtime as pallet_balances::Config<Instance>>::Balance;
 
+pub const SELECTOR_LOG_STAKE_ADDED: [u8; 32] =
+    keccak256!("StakeAdded(uint256,address,address,uint256)");
+pub const SELECTOR_LOG_STAKE_REMOVED: [u8; 32] =
+    keccak256!("StakeRemoved(uint256,address,address,uint256)");
+pub const SELECTOR_LOG_UNBONDINGS_CLAIMED: [u8; 32] = keccak256!("UnbondingsClaimed(address)");
+pub const SELECTOR_LOG_SUBNET_DELEGATE_STAKE_ADDED: [u8; 32] =
+    keccak256!("SubnetDelegateStakeAdded(uint256,address,uint256)");
+pub const SELECTOR_LOG_SUBNET_DELEGATE_STAKE_SWAPPED: [u8; 32] =
+    keccak256!("SubnetDelegateStakeSwapped(uint256,uint256,address,uint256)");
+pub const SELECTOR_LOG_SUBNET_DELEGATE_STAKE_TRANSFERRED: [u8; 32] =
+    keccak256!("SubnetDelegateStakeTransferred(uint256,address,address,uint256)");
+pub const SELECTOR_LOG_SUBNET_DELEGATE_STAKE_REMOVED: [u8; 32] =
+    keccak256!("SubnetDelegateStakeRemoved(uint256,address,uint256)");
+pub const SELECTOR_LOG_SUBNET_DELEGATE_STAKE_INCREASED: [u8; 32] =
+    keccak256!("SubnetDelegateStakeIncreased(uint256,address,uint256)");
+pub const SELECTOR_LOG_DELEGATE_NODE_STAKE_ADDED: [u8; 32] =
+    keccak256!("DelegateNodeStakeAdded(uint256,uint256,address,uint256)");
+pub const SELECTOR_LOG_DELEGATE_NODE_STAKE_SWAPPED: [u8; 32] =
+    keccak256!("DelegateNodeStakeSwapped(uint256,uint256,address,uint256,uint256,uint256)");
+pub const SELECTOR_LOG_DELEGATE_NODE_STAKE_TRANSFERRED: [u8; 32] =
+    keccak256!("DelegateNodeStakeTransferred(uint256,address,address,uint256,uint256)");
+pub const SELECTOR_LOG_DELEGATE_NODE_STAKE_REMOVED: [u8; 32] =
+    keccak256!("DelegateNodeStakeRemoved(uint256,uint256,address,uint256)");
+pub const SELECTOR_LOG_DELEGATE_NODE_STAKE_INCREASED: [u8; 32] =
+    keccak256!("DelegateNodeStakeIncreased(uint256,uint256,address,uint256)");
+pub const SELECTOR_LOG_DELEGATE_NODE_TO_SUBNET_DELEGATE_STAKE_SWAPPED: [u8; 32] =
+    keccak256!("DelegateNodeToSubnetDelegateStakeSwapped(uint256,uint256,address,uint256,uint256)");
+pub const SELECTOR_LOG_SUBNET_DELEGATE_TO_NODE_DELEGATE_STAKE_SWAPPED: [u8; 32] =
+    keccak256!("SubnetDelegateToNodeDelegateStakeSwapped(uint256,uint256,address,uint256,uint256)");
+pub const SELECTOR_LOG_SWAP_CALL_QUEUE_UPDATED: [u8; 32] =
+    keccak256!("SwapCallQueueUpdated(uint256,address,uint256,uint256,uint256)");
+pub const SELECTOR_LOG_SWAP_CALL_QUEUE_CANCELLED: [u8; 32] =
+    keccak256!("SwapCallQueueCancelled(uint256,address,uint256)");
+
 pub(crate) struct StakingPrecompile<R>(PhantomData<R>);
 
 impl<R> StakingPrecompile<R>
@@ -47,6 +86,16 @@ where
         hotkey: Address,
         stake_to_be_added: U256,
     ) -> EvmResult<()> {
+        let event = log4(
+            handle.context().address,
+            SELECTOR_LOG_STAKE_ADDED,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            H160::from(hotkey),
+            solidity::encode_event_data(stake_to_be_added),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let stake_to_be_added = stake_to_be_added.unique_saturated_into();
         let hotkey = R::AddressMapping::into_account_id(hotkey.into());
         let subnet_id = try_u256_to_u32(subnet_id)?;
@@ -67,6 +116,7 @@ where
             0,
         )?;
         // RuntimeHelper::<R>::try_dispatch(handle, Some(origin.clone()).into(), call, 0)?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -79,6 +129,16 @@ where
         hotkey: Address,
         stake_to_be_removed: U256,
     ) -> EvmResult<()> {
+        let event = log4(
+            handle.context().address,
+            SELECTOR_LOG_STAKE_REMOVED,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            H160::from(hotkey),
+            solidity::encode_event_data(stake_to_be_removed),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let stake_to_be_removed = stake_to_be_removed.unique_saturated_into();
         let hotkey = R::AddressMapping::into_account_id(hotkey.into());
         let subnet_id = try_u256_to_u32(subnet_id)?;
@@ -96,6 +156,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -103,6 +164,14 @@ where
     #[precompile::public("claimUnbondings()")]
     #[precompile::payable]
     fn claim_unbondings(handle: &mut impl PrecompileHandle) -> EvmResult<()> {
+        let event = log2(
+            handle.context().address,
+            SELECTOR_LOG_UNBONDINGS_CLAIMED,
+            handle.context().caller,
+            vec![],
+        );
+        handle.record_log_costs(&[&event])?;
+
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
         let call = pallet_network::Call::<R>::claim_unbondings {};
 
@@ -112,6 +181,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -123,6 +193,15 @@ where
         subnet_id: U256,
         stake_to_be_added: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_DELEGATE_STAKE_ADDED,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(stake_to_be_added),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
 
         let subnet_id = try_u256_to_u32(subnet_id)?;
@@ -139,6 +218,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -151,6 +231,16 @@ where
         to_subnet_id: U256,
         delegate_stake_shares_to_swap: U256,
     ) -> EvmResult<()> {
+        let event = log4(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_DELEGATE_STAKE_SWAPPED,
+            u256_topic(from_subnet_id),
+            u256_topic(to_subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(delegate_stake_shares_to_swap),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let delegate_stake_shares_to_swap = delegate_stake_shares_to_swap.unique_saturated_into();
         let from_subnet_id = try_u256_to_u32(from_subnet_id)?;
         let to_subnet_id = try_u256_to_u32(to_subnet_id)?;
@@ -168,6 +258,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -180,6 +271,16 @@ where
         to_account_id: Address,
         delegate_stake_shares_to_transfer: U256,
     ) -> EvmResult<()> {
+        let event = log4(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_DELEGATE_STAKE_TRANSFERRED,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            H160::from(to_account_id),
+            solidity::encode_event_data(delegate_stake_shares_to_transfer),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let delegate_stake_shares_to_transfer =
             delegate_stake_shares_to_transfer.unique_saturated_into();
         let subnet_id = try_u256_to_u32(subnet_id)?;
@@ -198,6 +299,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -209,6 +311,15 @@ where
         subnet_id: U256,
         shares_to_be_removed: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_DELEGATE_STAKE_REMOVED,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(shares_to_be_removed),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let shares_to_be_removed = shares_to_be_removed.unique_saturated_into();
         let subnet_id = try_u256_to_u32(subnet_id)?;
 
@@ -224,6 +335,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -235,6 +347,15 @@ where
         subnet_id: U256,
         amount: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_DELEGATE_STAKE_INCREASED,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(amount),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let amount = amount.unique_saturated_into();
         let subnet_id = try_u256_to_u32(subnet_id)?;
 
@@ -247,6 +368,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -259,6 +381,16 @@ where
         subnet_node_id: U256,
         node_delegate_stake_to_be_added: U256,
     ) -> EvmResult<()> {
+        let event = log4(
+            handle.context().address,
+            SELECTOR_LOG_DELEGATE_NODE_STAKE_ADDED,
+            u256_topic(subnet_id),
+            u256_topic(subnet_node_id),
+            handle.context().caller,
+            solidity::encode_event_data(node_delegate_stake_to_be_added),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let node_delegate_stake_to_be_added =
             node_delegate_stake_to_be_added.unique_saturated_into();
         let subnet_id = try_u256_to_u32(subnet_id)?;
@@ -277,6 +409,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -291,6 +424,20 @@ where
         to_subnet_node_id: U256,
         node_delegate_stake_shares_to_swap: U256,
     ) -> EvmResult<()> {
+        let event = log4(
+            handle.context().address,
+            SELECTOR_LOG_DELEGATE_NODE_STAKE_SWAPPED,
+            u256_topic(from_subnet_id),
+            u256_topic(to_subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data((
+                from_subnet_node_id,
+                to_subnet_node_id,
+                node_delegate_stake_shares_to_swap,
+            )),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let node_delegate_stake_shares_to_swap =
             node_delegate_stake_shares_to_swap.unique_saturated_into();
         let from_subnet_id = try_u256_to_u32(from_subnet_id)?;
@@ -313,6 +460,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -326,6 +474,16 @@ where
         to_account_id: Address,
         node_delegate_stake_shares_to_transfer: U256,
     ) -> EvmResult<()> {
+        let event = log4(
+            handle.context().address,
+            SELECTOR_LOG_DELEGATE_NODE_STAKE_TRANSFERRED,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            H160::from(to_account_id),
+            solidity::encode_event_data((subnet_node_id, node_delegate_stake_shares_to_transfer)),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let node_delegate_stake_shares_to_transfer =
             node_delegate_stake_shares_to_transfer.unique_saturated_into();
         let subnet_id = try_u256_to_u32(subnet_id)?;
@@ -346,6 +504,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -358,6 +517,16 @@ where
         subnet_node_id: U256,
         node_delegate_stake_shares_to_be_removed: U256,
     ) -> EvmResult<()> {
+        let event = log4(
+            handle.context().address,
+            SELECTOR_LOG_DELEGATE_NODE_STAKE_REMOVED,
+            u256_topic(subnet_id),
+            u256_topic(subnet_node_id),
+            handle.context().caller,
+            solidity::encode_event_data(node_delegate_stake_shares_to_be_removed),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let node_delegate_stake_shares_to_be_removed =
             node_delegate_stake_shares_to_be_removed.unique_saturated_into();
         let subnet_id = try_u256_to_u32(subnet_id)?;
@@ -376,6 +545,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -388,6 +558,16 @@ where
         subnet_node_id: U256,
         amount: U256,
     ) -> EvmResult<()> {
+        let event = log4(
+            handle.context().address,
+            SELECTOR_LOG_DELEGATE_NODE_STAKE_INCREASED,
+            u256_topic(subnet_id),
+            u256_topic(subnet_node_id),
+            handle.context().caller,
+            solidity::encode_event_data(amount),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let amount = amount.unique_saturated_into();
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let subnet_node_id = try_u256_to_u32(subnet_node_id)?;
@@ -405,6 +585,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -418,6 +599,16 @@ where
         to_subnet_id: U256,
         node_delegate_stake_shares_to_swap: U256,
     ) -> EvmResult<()> {
+        let event = log4(
+            handle.context().address,
+            SELECTOR_LOG_DELEGATE_NODE_TO_SUBNET_DELEGATE_STAKE_SWAPPED,
+            u256_topic(from_subnet_id),
+            u256_topic(to_subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data((from_subnet_node_id, node_delegate_stake_shares_to_swap)),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let node_delegate_stake_shares_to_swap =
             node_delegate_stake_shares_to_swap.unique_saturated_into();
         let from_subnet_id = try_u256_to_u32(from_subnet_id)?;
@@ -438,6 +629,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -451,6 +643,16 @@ where
         to_subnet_node_id: U256,
         delegate_stake_shares_to_swap: U256,
     ) -> EvmResult<()> {
+        let event = log4(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_DELEGATE_TO_NODE_DELEGATE_STAKE_SWAPPED,
+            u256_topic(from_subnet_id),
+            u256_topic(to_subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data((to_subnet_node_id, delegate_stake_shares_to_swap)),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let delegate_stake_shares_to_swap = delegate_stake_shares_to_swap.unique_saturated_into();
         let from_subnet_id = try_u256_to_u32(from_subnet_id)?;
         let to_subnet_id = try_u256_to_u32(to_subnet_id)?;
@@ -470,6 +672,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -483,6 +686,15 @@ where
         to_subnet_id: U256,
         to_subnet_node_id: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SWAP_CALL_QUEUE_UPDATED,
+            u256_topic(id),
+            handle.context().caller,
+            solidity::encode_event_data((call_type, to_subnet_id, to_subnet_node_id)),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let id = try_u256_to_u32(id)?;
         let call_type = try_u256_to_u32(call_type)?;
         let to_subnet_id = try_u256_to_u32(to_subnet_id)?;
@@ -516,6 +728,42 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
+
+        Ok(())
+    }
+
+    #[precompile::public("cancelSwapQueue(uint256)")]
+    #[precompile::payable]
+    fn cancel_swap_queue_item(handle: &mut impl PrecompileHandle, id: U256) -> EvmResult<()> {
+        let queue_id = try_u256_to_u32(id)?;
+
+        // The queued call is removed on cancellation, read the returned balance beforehand
+        handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
+        let balance = pallet_network::SwapCallQueue::<R>::get(queue_id)
+            .map(|item| item.call.get_queue_balance())
+            .unwrap_or_default();
+
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SWAP_CALL_QUEUE_CANCELLED,
+            u256_topic(id),
+            handle.context().caller,
+            solidity::encode_event_data(U256::from(balance)),
+        );
+        handle.record_log_costs(&[&event])?;
+
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+
+        let call = pallet_network::Call::<R>::cancel_swap_queue_item { id: queue_id };
+
+        RuntimeHelper::<R>::try_dispatch(
+            handle,
+            RawOrigin::Signed(origin.clone()).into(),
+            call,
+            0,
+        )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -725,9 +973,3 @@ where
         Ok(balance)
     }
 }
-
-fn try_u256_to_u32(value: U256) -> Result<u32, PrecompileFailure> {
-    value.try_into().map_err(|_| PrecompileFailure::Error {
-        exit_status: ExitError::Other("u32 out of bounds".into()),
-    })
-}
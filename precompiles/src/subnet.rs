@@ -1,3 +1,4 @@
+use crate::utils::{try_u256_to_u32, u256_topic};
 use core::marker::PhantomData;
 use frame_support::traits::ConstU32;
 use frame_support::{
@@ -5,12 +6,12 @@ use frame_support::{
     storage::bounded_vec::BoundedVec,
 };
 use frame_system::RawOrigin;
-use pallet_evm::{AddressMapping, ExitError, PrecompileFailure, PrecompileHandle};
+use pallet_evm::{AddressMapping, PrecompileFailure, PrecompileHandle};
 use pallet_network::{
-    DefaultMaxSocialIdLength, DefaultMaxUrlLength, DefaultMaxVectorLength, KeyType,
+    DefaultMaxSocialIdLength, DefaultMaxUrlLength, DefaultMaxVectorLength, ElectionMode, KeyType,
 };
-use precompile_utils::{EvmResult, prelude::*};
-use sp_core::{H160, H256, OpaquePeerId, U256};
+use precompile_utils::{EvmResult, prelude::*, solidity};
+use sp_core::{H160, OpaquePeerId, U256};
 use sp_runtime::{
     Vec,
     traits::{Dispatchable, StaticLookup, UniqueSaturatedInto},
@@ -23,6 +24,105 @@ use sp_std::vec;
 pub type BalanceOf<Runtime, Instance = ()> =
     <Runtime as pallet_balances::Config<Instance>>::Balance;
 
+pub const SELECTOR_LOG_SUBNET_REGISTERED: [u8; 32] =
+    keccak256!("SubnetRegistered(uint256,address,string)");
+pub const SELECTOR_LOG_SUBNET_NODE_REGISTERED: [u8; 32] =
+    keccak256!("SubnetNodeRegistered(uint256,uint256,address,address)");
+pub const SELECTOR_LOG_SUBNET_ACTIVATED: [u8; 32] = keccak256!("SubnetActivated(uint256)");
+pub const SELECTOR_LOG_SUBNET_NODE_REMOVED: [u8; 32] =
+    keccak256!("SubnetNodeRemoved(uint256,uint256)");
+pub const SELECTOR_LOG_SUBNET_NODE_UPDATE_DELEGATE_REWARD_RATE: [u8; 32] =
+    keccak256!("SubnetNodeUpdateDelegateRewardRate(uint256,uint256,uint256)");
+pub const SELECTOR_LOG_SUBNET_NODE_UPDATE_UNIQUE: [u8; 32] =
+    keccak256!("SubnetNodeUpdateUnique(uint256,uint256,string)");
+pub const SELECTOR_LOG_SUBNET_NODE_UPDATE_NON_UNIQUE: [u8; 32] =
+    keccak256!("SubnetNodeUpdateNonUnique(uint256,uint256,string)");
+pub const SELECTOR_LOG_UPDATE_COLDKEY: [u8; 32] = keccak256!("UpdateColdkey(address,address)");
+pub const SELECTOR_LOG_UPDATE_HOTKEY: [u8; 32] = keccak256!("UpdateHotkey(address,address)");
+pub const SELECTOR_LOG_SUBNET_NODE_UPDATE_PEER_ID: [u8; 32] =
+    keccak256!("SubnetNodeUpdatePeerId(uint256,uint256,string)");
+pub const SELECTOR_LOG_SUBNET_NODE_UPDATE_BOOTNODE: [u8; 32] =
+    keccak256!("SubnetNodeUpdateBootnode(uint256,uint256,string)");
+pub const SELECTOR_LOG_SUBNET_NODE_UPDATE_BOOTNODE_PEER_ID: [u8; 32] =
+    keccak256!("SubnetNodeUpdateBootnodePeerId(uint256,uint256,string)");
+pub const SELECTOR_LOG_SUBNET_NODE_UPDATE_CLIENT_PEER_ID: [u8; 32] =
+    keccak256!("SubnetNodeUpdateClientPeerId(uint256,uint256,string)");
+pub const SELECTOR_LOG_IDENTITY_REGISTERED: [u8; 32] =
+    keccak256!("IdentityRegistered(address,address,string)");
+pub const SELECTOR_LOG_IDENTITY_REMOVED: [u8; 32] = keccak256!("IdentityRemoved(address)");
+pub const SELECTOR_LOG_SUBNET_PAUSED: [u8; 32] = keccak256!("SubnetPaused(uint256,address)");
+pub const SELECTOR_LOG_SUBNET_UNPAUSED: [u8; 32] = keccak256!("SubnetUnpaused(uint256,address)");
+pub const SELECTOR_LOG_SUBNET_FORKED: [u8; 32] =
+    keccak256!("SubnetForked(uint256,address,uint256[])");
+pub const SELECTOR_LOG_SUBNET_FORK_REVERT: [u8; 32] =
+    keccak256!("SubnetForkRevert(uint256,address)");
+pub const SELECTOR_LOG_SUBNET_DEACTIVATED: [u8; 32] =
+    keccak256!("SubnetDeactivated(uint256,address)");
+pub const SELECTOR_LOG_SUBNET_NAME_UPDATE: [u8; 32] =
+    keccak256!("SubnetNameUpdate(uint256,address,string)");
+pub const SELECTOR_LOG_SUBNET_REPO_UPDATE: [u8; 32] =
+    keccak256!("SubnetRepoUpdate(uint256,address,string)");
+pub const SELECTOR_LOG_SUBNET_DESCRIPTION_UPDATE: [u8; 32] =
+    keccak256!("SubnetDescriptionUpdate(uint256,address,string)");
+pub const SELECTOR_LOG_SUBNET_MISC_UPDATE: [u8; 32] =
+    keccak256!("SubnetMiscUpdate(uint256,address,string)");
+pub const SELECTOR_LOG_CHURN_LIMIT_UPDATE: [u8; 32] =
+    keccak256!("ChurnLimitUpdate(uint256,address,uint256)");
+pub const SELECTOR_LOG_REGISTRATION_QUEUE_EPOCHS_UPDATE: [u8; 32] =
+    keccak256!("RegistrationQueueEpochsUpdate(uint256,address,uint256)");
+pub const SELECTOR_LOG_IDLE_CLASSIFICATION_EPOCHS_UPDATE: [u8; 32] =
+    keccak256!("IdleClassificationEpochsUpdate(uint256,address,uint256)");
+pub const SELECTOR_LOG_INCLUDED_CLASSIFICATION_EPOCHS_UPDATE: [u8; 32] =
+    keccak256!("IncludedClassificationEpochsUpdate(uint256,address,uint256)");
+pub const SELECTOR_LOG_ADD_SUBNET_REGISTRATION_INITIAL_COLDKEYS: [u8; 32] =
+    keccak256!("AddSubnetRegistrationInitialColdkeys(uint256,address,(address,uint256)[])");
+pub const SELECTOR_LOG_REMOVE_SUBNET_REGISTRATION_INITIAL_COLDKEYS: [u8; 32] =
+    keccak256!("RemoveSubnetRegistrationInitialColdkeys(uint256,address,address[])");
+pub const SELECTOR_LOG_SUBNET_KEY_TYPES_UPDATE: [u8; 32] =
+    keccak256!("SubnetKeyTypesUpdate(uint256,address,uint256[])");
+pub const SELECTOR_LOG_SUBNET_MIN_MAX_STAKE_BALANCE_UPDATE: [u8; 32] =
+    keccak256!("SubnetMinMaxStakeBalanceUpdate(uint256,address,uint256,uint256)");
+pub const SELECTOR_LOG_SUBNET_DELEGATE_STAKE_REWARDS_PERCENTAGE_UPDATE: [u8; 32] =
+    keccak256!("SubnetDelegateStakeRewardsPercentageUpdate(uint256,address,uint256)");
+pub const SELECTOR_LOG_MAX_REGISTERED_NODES_UPDATE: [u8; 32] =
+    keccak256!("MaxRegisteredNodesUpdate(uint256,address,uint256)");
+pub const SELECTOR_LOG_TRANSFER_PENDING_SUBNET_OWNER: [u8; 32] =
+    keccak256!("TransferPendingSubnetOwner(uint256,address,address)");
+pub const SELECTOR_LOG_ACCEPT_PENDING_SUBNET_OWNER: [u8; 32] =
+    keccak256!("AcceptPendingSubnetOwner(uint256,address)");
+pub const SELECTOR_LOG_TARGET_NODE_REGISTRATIONS_PER_EPOCH_UPDATE: [u8; 32] =
+    keccak256!("TargetNodeRegistrationsPerEpochUpdate(uint256,address,uint256)");
+pub const SELECTOR_LOG_NODE_BURN_RATE_ALPHA_UPDATE: [u8; 32] =
+    keccak256!("NodeBurnRateAlphaUpdate(uint256,address,uint256)");
+pub const SELECTOR_LOG_QUEUE_IMMUNITY_EPOCHS_UPDATE: [u8; 32] =
+    keccak256!("QueueImmunityEpochsUpdate(uint256,address,uint256)");
+pub const SELECTOR_LOG_MIN_SUBNET_NODE_REPUTATION_UPDATE: [u8; 32] =
+    keccak256!("MinSubnetNodeReputationUpdate(uint256,address,uint256)");
+pub const SELECTOR_LOG_SUBNET_NODE_MIN_WEIGHT_DECREASE_REPUTATION_THRESHOLD_UPDATE: [u8; 32] =
+    keccak256!("SubnetNodeMinWeightDecreaseReputationThresholdUpdate(uint256,address,uint256)");
+pub const SELECTOR_LOG_ABSENT_DECREASE_REPUTATION_FACTOR_UPDATE: [u8; 32] =
+    keccak256!("AbsentDecreaseReputationFactorUpdate(uint256,address,uint256)");
+pub const SELECTOR_LOG_INCLUDED_INCREASE_REPUTATION_FACTOR_UPDATE: [u8; 32] =
+    keccak256!("IncludedIncreaseReputationFactorUpdate(uint256,address,uint256)");
+pub const SELECTOR_LOG_BELOW_MIN_WEIGHT_DECREASE_REPUTATION_FACTOR_UPDATE: [u8; 32] =
+    keccak256!("BelowMinWeightDecreaseReputationFactorUpdate(uint256,address,uint256)");
+pub const SELECTOR_LOG_NON_ATTESTOR_DECREASE_REPUTATION_FACTOR_UPDATE: [u8; 32] =
+    keccak256!("NonAttestorDecreaseReputationFactorUpdate(uint256,address,uint256)");
+pub const SELECTOR_LOG_NON_CONSENSUS_ATTESTOR_DECREASE_REPUTATION_FACTOR_UPDATE: [u8; 32] =
+    keccak256!("NonConsensusAttestorDecreaseReputationFactorUpdate(uint256,address,uint256)");
+pub const SELECTOR_LOG_VALIDATOR_ABSENT_SUBNET_NODE_REPUTATION_FACTOR_UPDATE: [u8; 32] =
+    keccak256!("ValidatorAbsentSubnetNodeReputationFactorUpdate(uint256,address,uint256)");
+pub const SELECTOR_LOG_VALIDATOR_NON_CONSENSUS_SUBNET_NODE_REPUTATION_FACTOR_UPDATE: [u8; 32] =
+    keccak256!("ValidatorNonConsensusSubnetNodeReputationFactorUpdate(uint256,address,uint256)");
+pub const SELECTOR_LOG_BOOTNODES_UPDATED: [u8; 32] =
+    keccak256!("BootnodesUpdated(uint256,string[],string[])");
+pub const SELECTOR_LOG_ADD_SUBNET_BOOTNODE_ACCESS: [u8; 32] =
+    keccak256!("AddSubnetBootnodeAccess(uint256,address,address)");
+pub const SELECTOR_LOG_REMOVE_SUBNET_BOOTNODE_ACCESS: [u8; 32] =
+    keccak256!("RemoveSubnetBootnodeAccess(uint256,address,address)");
+pub const SELECTOR_LOG_SUBNET_ELECTION_MODE_UPDATE: [u8; 32] =
+    keccak256!("SubnetElectionModeUpdate(uint256,address,uint256)");
+
 pub(crate) struct SubnetPrecompile<R>(PhantomData<R>);
 
 impl<R> SubnetPrecompile<R>
@@ -93,8 +193,10 @@ where
             })
             .collect::<Result<_, _>>()?;
 
+        let subnet_name: Vec<u8> = name.as_bytes().to_vec();
+
         let subnet_data = pallet_network::RegistrationSubnetData {
-            name: name.into(),
+            name: name.clone().into(),
             repo: repo.into(),
             description: description.into(),
             misc: misc.into(),
@@ -118,6 +220,21 @@ where
             0,
         )?;
 
+        // Subnet ID is assigned on registration
+        handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
+        let subnet_id = pallet_network::SubnetName::<R>::get(subnet_name)
+            .ok_or_else(|| revert("SubnetName not found for subnet"))?;
+
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_REGISTERED,
+            u256_topic(subnet_id.into()),
+            handle.context().caller,
+            solidity::encode_event_data(name),
+        );
+        handle.record_log_costs(&[&event])?;
+        event.record(handle)?;
+
         Ok(())
     }
 
@@ -135,6 +252,14 @@ where
     #[precompile::public("activateSubnet(uint256)")]
     #[precompile::payable]
     fn activate_subnet(handle: &mut impl PrecompileHandle, subnet_id: U256) -> EvmResult<()> {
+        let event = log2(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_ACTIVATED,
+            u256_topic(subnet_id),
+            vec![],
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
 
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
@@ -146,6 +271,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -218,7 +344,7 @@ where
 
         let call = pallet_network::Call::<R>::register_subnet_node {
             subnet_id,
-            hotkey,
+            hotkey: hotkey.clone(),
             peer_id,
             bootnode_peer_id,
             client_peer_id,
@@ -237,6 +363,22 @@ where
             0,
         )?;
 
+        // Subnet node ID is assigned on registration
+        handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
+        let subnet_node_id = pallet_network::HotkeySubnetNodeId::<R>::get(subnet_id, &hotkey)
+            .ok_or_else(|| revert("HotkeySubnetNodeId not found for hotkey"))?;
+
+        let event = log4(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_NODE_REGISTERED,
+            u256_topic(subnet_id.into()),
+            u256_topic(subnet_node_id.into()),
+            handle.context().caller,
+            solidity::encode_event_data(Address(H160::from(hotkey.into()))),
+        );
+        handle.record_log_costs(&[&event])?;
+        event.record(handle)?;
+
         Ok(())
     }
 
@@ -247,6 +389,15 @@ where
         subnet_id: U256,
         subnet_node_id: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_NODE_REMOVED,
+            u256_topic(subnet_id),
+            u256_topic(subnet_node_id),
+            vec![],
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let subnet_node_id = try_u256_to_u32(subnet_node_id)?;
 
@@ -262,6 +413,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -274,6 +426,15 @@ where
         subnet_node_id: U256,
         new_delegate_reward_rate: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_NODE_UPDATE_DELEGATE_REWARD_RATE,
+            u256_topic(subnet_id),
+            u256_topic(subnet_node_id),
+            solidity::encode_event_data(new_delegate_reward_rate),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let subnet_node_id = try_u256_to_u32(subnet_node_id)?;
         let new_delegate_reward_rate = new_delegate_reward_rate.unique_saturated_into();
@@ -291,6 +452,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -303,6 +465,15 @@ where
         subnet_node_id: U256,
         unique: BoundedString<ConstU32<1024>>,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_NODE_UPDATE_UNIQUE,
+            u256_topic(subnet_id),
+            u256_topic(subnet_node_id),
+            solidity::encode_event_data(unique.clone()),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let subnet_node_id = try_u256_to_u32(subnet_node_id)?;
         let unique: Option<BoundedVec<u8, DefaultMaxVectorLength>> =
@@ -321,6 +492,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -333,6 +505,15 @@ where
         subnet_node_id: U256,
         non_unique: BoundedString<ConstU32<1024>>,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_NODE_UPDATE_NON_UNIQUE,
+            u256_topic(subnet_id),
+            u256_topic(subnet_node_id),
+            solidity::encode_event_data(non_unique.clone()),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let subnet_node_id = try_u256_to_u32(subnet_node_id)?;
         let non_unique: Option<BoundedVec<u8, DefaultMaxVectorLength>> =
@@ -351,6 +532,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -362,13 +544,28 @@ where
         hotkey: Address,
         new_coldkey: Address,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_UPDATE_COLDKEY,
+            handle.context().caller,
+            H160::from(new_coldkey),
+            vec![],
+        );
+        handle.record_log_costs(&[&event])?;
+
         let hotkey = R::AddressMapping::into_account_id(hotkey.into());
         let new_coldkey = R::AddressMapping::into_account_id(new_coldkey.into());
 
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
+
+        // Witness of the hotkeys moved by the call
+        handle.record_cost(RuntimeHelper::<R>::db_read_gas_cost())?;
+        let hotkeys = pallet_network::ColdkeyHotkeys::<R>::decode_len(&origin).unwrap_or(0) as u32;
+
         let call = pallet_network::Call::<R>::update_coldkey {
             hotkey,
             new_coldkey,
+            hotkeys,
         };
 
         RuntimeHelper::<R>::try_dispatch(
@@ -377,6 +574,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -388,6 +586,15 @@ where
         old_hotkey: Address,
         new_hotkey: Address,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_UPDATE_HOTKEY,
+            H160::from(old_hotkey),
+            H160::from(new_hotkey),
+            vec![],
+        );
+        handle.record_log_costs(&[&event])?;
+
         let old_hotkey = R::AddressMapping::into_account_id(old_hotkey.into());
         let new_hotkey = R::AddressMapping::into_account_id(new_hotkey.into());
 
@@ -403,6 +610,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -415,6 +623,15 @@ where
         subnet_node_id: U256,
         new_peer_id: BoundedString<ConstU32<64>>,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_NODE_UPDATE_PEER_ID,
+            u256_topic(subnet_id),
+            u256_topic(subnet_node_id),
+            solidity::encode_event_data(new_peer_id.clone()),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let subnet_node_id = try_u256_to_u32(subnet_node_id)?;
         let new_peer_id = OpaquePeerId(new_peer_id.as_bytes().to_vec());
@@ -432,6 +649,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -444,6 +662,15 @@ where
         subnet_node_id: U256,
         new_bootnode: BoundedString<ConstU32<1024>>,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_NODE_UPDATE_BOOTNODE,
+            u256_topic(subnet_id),
+            u256_topic(subnet_node_id),
+            solidity::encode_event_data(new_bootnode.clone()),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let subnet_node_id = try_u256_to_u32(subnet_node_id)?;
         let new_bootnode: Option<BoundedVec<u8, DefaultMaxVectorLength>> =
@@ -462,6 +689,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -474,6 +702,15 @@ where
         subnet_node_id: U256,
         new_bootnode_peer_id: BoundedString<ConstU32<64>>,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_NODE_UPDATE_BOOTNODE_PEER_ID,
+            u256_topic(subnet_id),
+            u256_topic(subnet_node_id),
+            solidity::encode_event_data(new_bootnode_peer_id.clone()),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let subnet_node_id = try_u256_to_u32(subnet_node_id)?;
         let new_bootnode_peer_id = OpaquePeerId(new_bootnode_peer_id.as_bytes().to_vec());
@@ -491,6 +728,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -503,6 +741,15 @@ where
         subnet_node_id: U256,
         new_client_peer_id: BoundedString<ConstU32<64>>,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_NODE_UPDATE_CLIENT_PEER_ID,
+            u256_topic(subnet_id),
+            u256_topic(subnet_node_id),
+            solidity::encode_event_data(new_client_peer_id.clone()),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let subnet_node_id = try_u256_to_u32(subnet_node_id)?;
         let new_client_peer_id = OpaquePeerId(new_client_peer_id.as_bytes().to_vec());
@@ -520,6 +767,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -542,6 +790,15 @@ where
         description: BoundedString<ConstU32<1024>>,
         misc: BoundedString<ConstU32<1024>>,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_IDENTITY_REGISTERED,
+            handle.context().caller,
+            H160::from(hotkey),
+            solidity::encode_event_data(name.clone()),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
         let hotkey = R::AddressMapping::into_account_id(hotkey.into());
 
@@ -586,6 +843,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -593,6 +851,14 @@ where
     #[precompile::public("removeIdentity()")]
     #[precompile::payable]
     fn remove_identity(handle: &mut impl PrecompileHandle) -> EvmResult<()> {
+        let event = log2(
+            handle.context().address,
+            SELECTOR_LOG_IDENTITY_REMOVED,
+            handle.context().caller,
+            vec![],
+        );
+        handle.record_log_costs(&[&event])?;
+
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
 
         let call = pallet_network::Call::<R>::remove_identity {};
@@ -603,12 +869,22 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
 
     #[precompile::public("ownerPauseSubnet(uint256)")]
     fn owner_pause_subnet(handle: &mut impl PrecompileHandle, subnet_id: U256) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_PAUSED,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            vec![],
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
 
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
@@ -620,12 +896,22 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
 
     #[precompile::public("ownerUnpauseSubnet(uint256)")]
     fn owner_unpause_subnet(handle: &mut impl PrecompileHandle, subnet_id: U256) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_UNPAUSED,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            vec![],
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
 
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
@@ -637,6 +923,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -647,6 +934,15 @@ where
         subnet_id: U256,
         subnet_node_ids: Vec<U256>,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_FORKED,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(subnet_node_ids.clone()),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let subnet_node_ids = subnet_node_ids
             .into_iter()
@@ -665,6 +961,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -674,6 +971,15 @@ where
         handle: &mut impl PrecompileHandle,
         subnet_id: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_FORK_REVERT,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            vec![],
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
 
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
@@ -685,6 +991,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -694,6 +1001,15 @@ where
         handle: &mut impl PrecompileHandle,
         subnet_id: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_DEACTIVATED,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            vec![],
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
 
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
@@ -705,6 +1021,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -715,6 +1032,15 @@ where
         subnet_id: U256,
         value: BoundedString<ConstU32<256>>,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_NAME_UPDATE,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(value.clone()),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
 
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
@@ -729,6 +1055,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -739,6 +1066,15 @@ where
         subnet_id: U256,
         value: BoundedString<ConstU32<256>>,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_REPO_UPDATE,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(value.clone()),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
 
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
@@ -753,6 +1089,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -763,6 +1100,15 @@ where
         subnet_id: U256,
         value: BoundedString<ConstU32<256>>,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_DESCRIPTION_UPDATE,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(value.clone()),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
 
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
@@ -777,6 +1123,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -787,6 +1134,15 @@ where
         subnet_id: U256,
         value: BoundedString<ConstU32<256>>,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_MISC_UPDATE,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(value.clone()),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
 
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
@@ -801,6 +1157,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -811,6 +1168,15 @@ where
         subnet_id: U256,
         value: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_CHURN_LIMIT_UPDATE,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(value),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let value = try_u256_to_u32(value)?;
 
@@ -823,6 +1189,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -833,6 +1200,15 @@ where
         subnet_id: U256,
         value: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_REGISTRATION_QUEUE_EPOCHS_UPDATE,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(value),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let value = try_u256_to_u32(value)?;
 
@@ -846,6 +1222,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -856,6 +1233,15 @@ where
         subnet_id: U256,
         value: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_IDLE_CLASSIFICATION_EPOCHS_UPDATE,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(value),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let value = try_u256_to_u32(value)?;
 
@@ -869,6 +1255,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -879,6 +1266,15 @@ where
         subnet_id: U256,
         value: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_INCLUDED_CLASSIFICATION_EPOCHS_UPDATE,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(value),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let value = try_u256_to_u32(value)?;
 
@@ -894,6 +1290,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -904,6 +1301,15 @@ where
         subnet_id: U256,
         coldkeys: Vec<(Address, U256)>,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_ADD_SUBNET_REGISTRATION_INITIAL_COLDKEYS,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(coldkeys.clone()),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let coldkeys: BTreeMap<R::AccountId, u32> = coldkeys
             .into_iter()
@@ -927,6 +1333,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -937,6 +1344,15 @@ where
         subnet_id: U256,
         coldkeys: Vec<Address>,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_REMOVE_SUBNET_REGISTRATION_INITIAL_COLDKEYS,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(coldkeys.clone()),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let coldkeys: BTreeSet<R::AccountId> = coldkeys
             .into_iter()
@@ -955,6 +1371,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -965,6 +1382,15 @@ where
         subnet_id: U256,
         key_types: Vec<U256>,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_KEY_TYPES_UPDATE,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(key_types.clone()),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let key_types: BTreeSet<KeyType> = key_types
             .into_iter()
@@ -983,6 +1409,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -994,6 +1421,15 @@ where
         min: U256,
         max: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_MIN_MAX_STAKE_BALANCE_UPDATE,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data((min, max)),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let min: u128 = min.unique_saturated_into();
         let max: u128 = max.unique_saturated_into();
@@ -1011,6 +1447,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -1021,6 +1458,15 @@ where
         subnet_id: U256,
         value: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_DELEGATE_STAKE_REWARDS_PERCENTAGE_UPDATE,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(value),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let value: u128 = value.unique_saturated_into();
 
@@ -1034,6 +1480,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -1044,6 +1491,15 @@ where
         subnet_id: U256,
         value: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_MAX_REGISTERED_NODES_UPDATE,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(value),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let value = try_u256_to_u32(value)?;
 
@@ -1057,6 +1513,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -1067,6 +1524,16 @@ where
         subnet_id: U256,
         new_owner: Address,
     ) -> EvmResult<()> {
+        let event = log4(
+            handle.context().address,
+            SELECTOR_LOG_TRANSFER_PENDING_SUBNET_OWNER,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            H160::from(new_owner),
+            vec![],
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let new_owner = R::AddressMapping::into_account_id(new_owner.into());
 
@@ -1082,6 +1549,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -1091,6 +1559,15 @@ where
         handle: &mut impl PrecompileHandle,
         subnet_id: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_ACCEPT_PENDING_SUBNET_OWNER,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            vec![],
+        );
+        handle.record_log_costs(&[&event])?;
+
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
 
         let subnet_id = try_u256_to_u32(subnet_id)?;
@@ -1102,6 +1579,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -1112,6 +1590,15 @@ where
         subnet_id: U256,
         value: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_TARGET_NODE_REGISTRATIONS_PER_EPOCH_UPDATE,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(value),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let value = try_u256_to_u32(value)?;
 
@@ -1127,6 +1614,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -1137,6 +1625,15 @@ where
         subnet_id: U256,
         value: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_NODE_BURN_RATE_ALPHA_UPDATE,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(value),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let value: u128 = value.unique_saturated_into();
 
@@ -1150,6 +1647,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -1160,6 +1658,15 @@ where
         subnet_id: U256,
         value: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_QUEUE_IMMUNITY_EPOCHS_UPDATE,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(value),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let value = try_u256_to_u32(value)?;
 
@@ -1173,6 +1680,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -1183,6 +1691,15 @@ where
         subnet_id: U256,
         value: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_MIN_SUBNET_NODE_REPUTATION_UPDATE,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(value),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let value = value.unique_saturated_into();
 
@@ -1196,6 +1713,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -1208,6 +1726,15 @@ where
         subnet_id: U256,
         value: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_NODE_MIN_WEIGHT_DECREASE_REPUTATION_THRESHOLD_UPDATE,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(value),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let value = value.unique_saturated_into();
 
@@ -1223,6 +1750,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -1233,6 +1761,15 @@ where
         subnet_id: U256,
         value: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_ABSENT_DECREASE_REPUTATION_FACTOR_UPDATE,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(value),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let value = value.unique_saturated_into();
 
@@ -1248,6 +1785,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -1258,6 +1796,15 @@ where
         subnet_id: U256,
         value: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_INCLUDED_INCREASE_REPUTATION_FACTOR_UPDATE,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(value),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let value = value.unique_saturated_into();
 
@@ -1273,6 +1820,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -1283,6 +1831,15 @@ where
         subnet_id: U256,
         value: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_BELOW_MIN_WEIGHT_DECREASE_REPUTATION_FACTOR_UPDATE,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(value),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let value = value.unique_saturated_into();
 
@@ -1299,6 +1856,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -1309,6 +1867,15 @@ where
         subnet_id: U256,
         value: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_NON_ATTESTOR_DECREASE_REPUTATION_FACTOR_UPDATE,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(value),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let value = value.unique_saturated_into();
 
@@ -1325,6 +1892,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -1335,6 +1903,15 @@ where
         subnet_id: U256,
         value: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_NON_CONSENSUS_ATTESTOR_DECREASE_REPUTATION_FACTOR_UPDATE,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(value),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let value = value.unique_saturated_into();
 
@@ -1348,6 +1925,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -1358,6 +1936,15 @@ where
         subnet_id: U256,
         value: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_VALIDATOR_ABSENT_SUBNET_NODE_REPUTATION_FACTOR_UPDATE,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(value),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let value = value.unique_saturated_into();
 
@@ -1374,6 +1961,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -1386,6 +1974,15 @@ where
         subnet_id: U256,
         value: U256,
     ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_VALIDATOR_NON_CONSENSUS_SUBNET_NODE_REPUTATION_FACTOR_UPDATE,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(value),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let value = value.unique_saturated_into();
 
@@ -1399,6 +1996,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -1410,6 +2008,14 @@ where
         add: Vec<BoundedString<ConstU32<1024>>>,
         remove: Vec<BoundedString<ConstU32<1024>>>,
     ) -> EvmResult<()> {
+        let event = log2(
+            handle.context().address,
+            SELECTOR_LOG_BOOTNODES_UPDATED,
+            u256_topic(subnet_id),
+            solidity::encode_event_data((add.clone(), remove.clone())),
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
 
         let add: BTreeSet<BoundedVec<u8, DefaultMaxVectorLength>> = add
@@ -1443,6 +2049,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -1453,6 +2060,16 @@ where
         subnet_id: U256,
         new_account: Address,
     ) -> EvmResult<()> {
+        let event = log4(
+            handle.context().address,
+            SELECTOR_LOG_ADD_SUBNET_BOOTNODE_ACCESS,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            H160::from(new_account),
+            vec![],
+        );
+        handle.record_log_costs(&[&event])?;
+
         let origin = R::AddressMapping::into_account_id(handle.context().caller);
 
         let subnet_id = try_u256_to_u32(subnet_id)?;
@@ -1469,6 +2086,7 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -1479,6 +2097,16 @@ where
         subnet_id: U256,
         remove_account: Address,
     ) -> EvmResult<()> {
+        let event = log4(
+            handle.context().address,
+            SELECTOR_LOG_REMOVE_SUBNET_BOOTNODE_ACCESS,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            H160::from(remove_account),
+            vec![],
+        );
+        handle.record_log_costs(&[&event])?;
+
         let subnet_id = try_u256_to_u32(subnet_id)?;
         let remove_account = R::AddressMapping::into_account_id(remove_account.into());
 
@@ -1494,6 +2122,40 @@ where
             call,
             0,
         )?;
+        event.record(handle)?;
+
+        Ok(())
+    }
+
+    #[precompile::public("ownerUpdateElectionMode(uint256,uint256)")]
+    fn owner_update_election_mode(
+        handle: &mut impl PrecompileHandle,
+        subnet_id: U256,
+        value: U256,
+    ) -> EvmResult<()> {
+        let event = log3(
+            handle.context().address,
+            SELECTOR_LOG_SUBNET_ELECTION_MODE_UPDATE,
+            u256_topic(subnet_id),
+            handle.context().caller,
+            solidity::encode_event_data(value),
+        );
+        handle.record_log_costs(&[&event])?;
+
+        let subnet_id = try_u256_to_u32(subnet_id)?;
+        let value =
+            election_mode_from_u256(value).ok_or_else(|| revert("Invalid ElectionMode value"))?;
+
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let call = pallet_network::Call::<R>::owner_update_election_mode { subnet_id, value };
+
+        RuntimeHelper::<R>::try_dispatch(
+            handle,
+            RawOrigin::Signed(origin.clone()).into(),
+            call,
+            0,
+        )?;
+        event.record(handle)?;
 
         Ok(())
     }
@@ -2145,18 +2807,6 @@ where
     }
 }
 
-fn try_u256_to_u32(value: U256) -> Result<u32, PrecompileFailure> {
-    value.try_into().map_err(|_| PrecompileFailure::Error {
-        exit_status: ExitError::Other("u32 out of bounds".into()),
-    })
-}
-
-fn try_u32_to_u256(value: u32) -> Result<U256, PrecompileFailure> {
-    value.try_into().map_err(|_| PrecompileFailure::Error {
-        exit_status: ExitError::Other("u32 out of bounds".into()),
-    })
-}
-
 fn key_type_from_u256(val: U256) -> Option<KeyType> {
     match val.as_u32() {
         0 => Some(KeyType::Rsa),
@@ -2167,6 +2817,15 @@ fn key_type_from_u256(val: U256) -> Option<KeyType> {
     }
 }
 
+fn election_mode_from_u256(val: U256) -> Option<ElectionMode> {
+    match u32::try_from(val).ok()? {
+        0 => Some(ElectionMode::Uniform),
+        1 => Some(ElectionMode::StakeWeighted),
+        2 => Some(ElectionMode::ReputationWeighted),
+        _ => None,
+    }
+}
+
 fn bounded_string_to_option_bounded_vec<const N: u32, T>(
     s: &BoundedString<ConstU32<N>>,
 ) -> Result<Option<BoundedVec<u8, T>>, PrecompileFailure>
@@ -0,0 +1,63 @@
+//! Conversions, error mapping and call checks shared by the Hypertensor precompiles
+
+use pallet_evm::{ExitError, PrecompileFailure, PrecompileHandle};
+use precompile_utils::prelude::*;
+use sp_core::{H256, U256};
+use sp_runtime::{DispatchError, ModuleError};
+
+/// Topic of an indexed `uint256` event parameter
+pub(crate) fn u256_topic(value: U256) -> H256 {
+    H256::from(value.to_big_endian())
+}
+
+/// Reverts when reached through `DELEGATECALL` or `CALLCODE`, the calling contract would
+/// otherwise dispatch calls as `context().caller`
+pub(crate) fn ensure_not_delegatecall(
+    handle: &impl PrecompileHandle,
+) -> Result<(), PrecompileFailure> {
+    if handle.code_address() != handle.context().address {
+        return Err(revert("Cannot be called with DELEGATECALL or CALLCODE"));
+    }
+    Ok(())
+}
+
+/// Reverts with the pallet error name, or the `DispatchError` variant otherwise
+pub(crate) fn dispatch_error_to_revert(error: DispatchError) -> PrecompileFailure {
+    match error {
+        DispatchError::Module(ModuleError {
+            message: Some(message),
+            ..
+        }) => revert(message),
+        error => revert(<&'static str>::from(error)),
+    }
+}
+
+pub(crate) fn try_u256_to_u32(value: U256) -> Result<u32, PrecompileFailure> {
+    value.try_into().map_err(|_| PrecompileFailure::Error {
+        exit_status: ExitError::Other("u32 out of bounds".into()),
+    })
+}
+
+pub(crate) fn try_u256_to_u64(value: U256) -> Result<u64, PrecompileFailure> {
+    value.try_into().map_err(|_| PrecompileFailure::Error {
+        exit_status: ExitError::Other("u64 out of bounds".into()),
+    })
+}
+
+pub(crate) fn try_u256_to_u128(value: U256) -> Result<u128, PrecompileFailure> {
+    value.try_into().map_err(|_| PrecompileFailure::Error {
+        exit_status: ExitError::Other("u128 out of bounds".into()),
+    })
+}
+
+pub(crate) fn try_u32_to_u256(value: u32) -> Result<U256, PrecompileFailure> {
+    value.try_into().map_err(|_| PrecompileFailure::Error {
+        exit_status: ExitError::Other("u32 out of bounds".into()),
+    })
+}
+
+pub(crate) fn try_u128_to_u256(value: u128) -> Result<U256, PrecompileFailure> {
+    value.try_into().map_err(|_| PrecompileFailure::Error {
+        exit_status: ExitError::Other("u128 out of bounds".into()),
+    })
+}
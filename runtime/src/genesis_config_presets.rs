@@ -23,10 +23,10 @@ use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
 #[allow(unused_imports)]
 use sp_core::ecdsa;
-use sp_core::{crypto::Ss58Codec, OpaquePeerId, Pair, Public, H160, U256};
+use sp_core::{blake2_256, crypto::Ss58Codec, OpaquePeerId, Pair, Public, H160, U256};
 use sp_runtime::traits::{IdentifyAccount, Verify};
 // Frontier
-use crate::{AccountId, Balance, SS58Prefix, Signature};
+use crate::{AccountId, Balance, Network, SS58Prefix, Signature};
 use alloc::{format, vec, vec::Vec};
 use frame_support::build_struct_json_patch;
 use serde_json::Value;
@@ -39,9 +39,11 @@ pub fn get_from_seed<TPublic: Public>(seed: &str) -> <TPublic::Pair as Pair>::Pu
         .public()
 }
 
-fn peer(id: u8) -> OpaquePeerId {
-    let peer_id = format!("12D{id}KooWGFuUunX1AzAzjs3CgyqTXtPWX3AqRhJFbesGPGYHJQTP");
-    OpaquePeerId(peer_id.into())
+/// SHA-256 multihash peer ID, accepted by subnets allowing RSA keys
+fn peer(id: u32) -> OpaquePeerId {
+    let mut multihash = vec![0x12, 0x20];
+    multihash.extend_from_slice(&blake2_256(&id.to_le_bytes()));
+    OpaquePeerId(Network::encode_base58(&multihash))
 }
 
 #[allow(dead_code)]
@@ -85,10 +87,8 @@ fn testnet_genesis(
     initial_authorities: Vec<(AuraId, GrandpaId)>,
     chain_id: u64,
     enable_manual_seal: bool,
+    network: Value,
 ) -> serde_json::Value {
-    let subnet_name: Vec<u8> = "subnet-name".into();
-    let mut peer_index: u8 = 0;
-
     let evm_accounts = {
         let mut map = BTreeMap::new();
         map.insert(
@@ -145,16 +145,7 @@ fn testnet_genesis(
         "evmChainId": { "chainId": chain_id },
         "evm": { "accounts": evm_accounts },
         "manualSeal": { "enable": enable_manual_seal },
-        "network": {
-            "subnetName": subnet_name,
-            "subnetNodes": endowed_accounts.iter().cloned().map(|k| {
-                peer_index += 1;
-                (
-                    k,
-                    peer(peer_index),
-                )
-            }).collect::<Vec<_>>(),
-        },
+        "network": network,
     })
 }
 
@@ -165,10 +156,8 @@ fn ethereum_testnet_genesis(
     initial_authorities: Vec<(AuraId, GrandpaId)>,
     chain_id: u64,
     enable_manual_seal: bool,
+    network: Value,
 ) -> serde_json::Value {
-    let subnet_name: Vec<u8> = "subnet-name".into();
-    let mut peer_index: u8 = 0;
-
     let evm_accounts = {
         let mut map = BTreeMap::new();
         map.insert(
@@ -227,21 +216,12 @@ fn ethereum_testnet_genesis(
         "evmChainId": { "chainId": chain_id },
         "evm": { "accounts": evm_accounts },
         "manualSeal": { "enable": enable_manual_seal },
-        "network": {
-            "subnetName": subnet_name,
-            "subnetNodes": endowed_accounts.iter().cloned().map(|k| {
-                peer_index += 1;
-                (
-                    k,
-                    peer(peer_index),
-                )
-            }).collect::<Vec<_>>(),
-        },
+        "network": network,
     })
 }
 
 /// Return the development genesis config.
-pub fn development_config_genesis(enable_manual_seal: bool) -> Value {
+pub fn development_config_genesis(enable_manual_seal: bool, network: Value) -> Value {
     testnet_genesis(
         // Sudo account (Alith)
         AccountId::from(hex!("f24FF3a9CF04c71Dbc94D0b566f7A27B94566cac")),
@@ -263,10 +243,11 @@ pub fn development_config_genesis(enable_manual_seal: bool) -> Value {
         vec![authority_keys_from_seed("Alice")],
         SS58Prefix::get() as u64,
         enable_manual_seal,
+        network,
     )
 }
 
-pub fn ethereum_development_config_genesis(enable_manual_seal: bool) -> Value {
+pub fn ethereum_development_config_genesis(enable_manual_seal: bool, network: Value) -> Value {
     ethereum_testnet_genesis(
         // Sudo account (Alith)
         AccountId::from(hex!("f24FF3a9CF04c71Dbc94D0b566f7A27B94566cac")),
@@ -282,11 +263,12 @@ pub fn ethereum_development_config_genesis(enable_manual_seal: bool) -> Value {
         vec![authority_keys_from_seed("Alice")],
         SS58Prefix::get() as u64,
         enable_manual_seal,
+        network,
     )
 }
 
 /// Return the local genesis config preset.
-pub fn local_config_genesis() -> Value {
+pub fn local_config_genesis(network: Value) -> Value {
     testnet_genesis(
         // Sudo account (Alith)
         AccountId::from(hex!("f24FF3a9CF04c71Dbc94D0b566f7A27B94566cac")),
@@ -311,10 +293,11 @@ pub fn local_config_genesis() -> Value {
         ],
         42,
         false,
+        network,
     )
 }
 
-pub fn hoskinson_config_genesis() -> Value {
+pub fn hoskinson_config_genesis(network: Value) -> Value {
     testnet_genesis(
         // Sudo account (Alith)
         AccountId::from(hex!("f24FF3a9CF04c71Dbc94D0b566f7A27B94566cac")),
@@ -359,16 +342,152 @@ pub fn hoskinson_config_genesis() -> Value {
         ],
         42,
         false,
+        network,
     )
 }
 
+/// Preset for local development, a single authority chain with a subnet activated at genesis
+pub const LOCAL_PRESET: &str = "local";
+
+/// Preset for the EVM tests, with every network cooldown removed
+pub const EVM_TESTS_PRESET: &str = "evm-tests";
+
+/// Preset for benchmarking, with every network cooldown removed
+pub const BENCHMARK_PRESET: &str = "benchmark";
+
+/// Preset using the network pallet's default parameters
+///
+/// Has no sudo key, authorities or endowed accounts, they are added to the chain spec built from
+/// it, e.g. `build-spec --chain production`, before launch.
+pub const PRODUCTION_PRESET: &str = "production";
+
+/// Return the production genesis config preset.
+///
+/// Only sets what differs from the runtime's default genesis config, every network parameter
+/// keeps the pallet's default.
+pub fn production_config_genesis() -> Value {
+    serde_json::json!({
+        "evmChainId": { "chainId": SS58Prefix::get() as u64 },
+        "manualSeal": { "enable": false },
+        "network": {},
+    })
+}
+
+/// Network pallet parameters for local development
+///
+/// Subnets can activate as soon as their conditions are met, and overwatch nodes qualify without
+/// any history.
+fn local_network_genesis(subnets: Vec<Value>) -> Value {
+    serde_json::json!({
+        "minSubnetRegistrationEpochs": 0,
+        "overwatchEpochLengthMultiplier": 2,
+        "overwatchMinDiversificationRatio": 0,
+        "overwatchMinRepScore": 0,
+        "overwatchMinAvgAttestationRatio": 0,
+        "overwatchMinAge": 0,
+        "subnets": subnets,
+    })
+}
+
+/// Network pallet parameters for the EVM tests and benchmarks
+///
+/// On top of the local development parameters, overwatch epochs match subnet epochs, and every
+/// stake cooldown, update period and minimum delegate stake requirement is removed.
+fn testing_network_genesis() -> Value {
+    serde_json::json!({
+        "minSubnetRegistrationEpochs": 0,
+        "overwatchEpochLengthMultiplier": 1,
+        "overwatchMinDiversificationRatio": 0,
+        "overwatchMinRepScore": 0,
+        "overwatchMinAvgAttestationRatio": 0,
+        "overwatchMinAge": 0,
+        "delegateStakeCooldownEpochs": 0,
+        "nodeDelegateStakeCooldownEpochs": 0,
+        "stakeCooldownEpochs": 0,
+        "minActiveNodeStakeEpochs": 0,
+        "subnetDelegateStakeRewardsUpdatePeriod": 0,
+        "nodeRewardRateUpdatePeriod": 0,
+        "minSubnetDelegateStakeFactor": 0,
+        "maxMinDelegateStakeMultiplier": 1_000_000_000_000_000_000u128, // 100%
+        "subnetPauseCooldownEpochs": 0,
+    })
+}
+
+/// Subnet owned by Alith with a node for each development coldkey and hotkey pair
+fn local_subnet() -> Value {
+    let owner = AccountId::from(hex!("f24FF3a9CF04c71Dbc94D0b566f7A27B94566cac")); // Alith
+    let nodes = vec![
+        (
+            AccountId::from(hex!("f24FF3a9CF04c71Dbc94D0b566f7A27B94566cac")), // Alith
+            AccountId::from(hex!("317D7a5a2ba5787A99BE4693Eb340a10C71d680b")), // Alith hotkey
+        ),
+        (
+            AccountId::from(hex!("3Cd0A705a2DC65e5b1E1205896BaA2be8A07c6e0")), // Baltathar
+            AccountId::from(hex!("c30fE91DE91a3FA79E42Dfe7a01917d0D92D99D7")), // Baltathar hotkey
+        ),
+        (
+            AccountId::from(hex!("798d4Ba9baf0064Ec19eB4F0a1a45785ae9D6DFc")), // Charleth
+            AccountId::from(hex!("2f7703Ba9953d422294079A1CB32f5d2B60E38EB")), // Charleth hotkey
+        ),
+        (
+            AccountId::from(hex!("773539d4Ac0e786233D90A233654ccEE26a613D9")), // Dorothy
+            AccountId::from(hex!("294BFfC18b5321264f55c517Aca2963bEF9D29EA")), // Dorothy hotkey
+        ),
+        (
+            AccountId::from(hex!("Ff64d3F6efE2317EE2807d223a0Bdc4c0c49dfDB")), // Ethan
+            AccountId::from(hex!("919a696741e5bEe48538D43CB8A34a95261E62fc")), // Ethan hotkey
+        ),
+        (
+            AccountId::from(hex!("C0F0f4ab324C46e55D02D0033343B4Be8A55532d")), // Faith
+            AccountId::from(hex!("D4eb2503fA9F447CCa7b78D9a86F2fdbc964401e")), // Faith hotkey
+        ),
+    ];
+
+    serde_json::json!({
+        "name": b"subnet-name".to_vec(),
+        "repo": b"github.com/hypertensor/subnet-name".to_vec(),
+        "description": b"Local development subnet".to_vec(),
+        "owner": owner,
+        "minStake": 100 * UNITS,
+        "maxStake": 1_000 * UNITS,
+        "delegateStakePercentage": 100_000_000_000_000_000u128, // 10%
+        "bootnodes": vec![b"p2p/127.0.0.1/33130".to_vec()],
+        "nodes": nodes
+            .into_iter()
+            .zip(0u32..)
+            .map(|((coldkey, hotkey), n)| serde_json::json!({
+                "coldkey": coldkey,
+                "hotkey": hotkey,
+                "peerId": peer(n * 3),
+                "bootnodePeerId": peer(n * 3 + 1),
+                "clientPeerId": peer(n * 3 + 2),
+                "stake": 100 * UNITS,
+            }))
+            .collect::<Vec<_>>(),
+        // Well above the minimum delegate stake of 0.1% of the network issuance
+        "delegateStakes": vec![(owner, 100_000 * UNITS)],
+    })
+}
+
 /// Provides the JSON representation of predefined genesis config for given `id`.
 pub fn get_preset(id: &PresetId) -> Option<Vec<u8>> {
     let patch = match id.as_ref() {
-        "ETHEREUM_DEV_RUNTIME_PRESET" => ethereum_development_config_genesis(true),
-        sp_genesis_builder::DEV_RUNTIME_PRESET => development_config_genesis(false),
-        sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET => local_config_genesis(),
-        "HOSKINSON_RUNTIME_PRESET" => hoskinson_config_genesis(),
+        "ETHEREUM_DEV_RUNTIME_PRESET" => {
+            ethereum_development_config_genesis(true, local_network_genesis(vec![]))
+        }
+        sp_genesis_builder::DEV_RUNTIME_PRESET => {
+            development_config_genesis(false, local_network_genesis(vec![]))
+        }
+        sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET => {
+            local_config_genesis(local_network_genesis(vec![]))
+        }
+        "HOSKINSON_RUNTIME_PRESET" => hoskinson_config_genesis(local_network_genesis(vec![])),
+        LOCAL_PRESET => {
+            development_config_genesis(false, local_network_genesis(vec![local_subnet()]))
+        }
+        EVM_TESTS_PRESET => ethereum_development_config_genesis(true, testing_network_genesis()),
+        BENCHMARK_PRESET => development_config_genesis(false, testing_network_genesis()),
+        PRODUCTION_PRESET => production_config_genesis(),
         _ => return None,
     };
     Some(
@@ -385,5 +504,9 @@ pub fn preset_names() -> Vec<PresetId> {
         PresetId::from(sp_genesis_builder::DEV_RUNTIME_PRESET),
         PresetId::from(sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET),
         PresetId::from("HOSKINSON_RUNTIME_PRESET"),
+        PresetId::from(LOCAL_PRESET),
+        PresetId::from(EVM_TESTS_PRESET),
+        PresetId::from(BENCHMARK_PRESET),
+        PresetId::from(PRODUCTION_PRESET),
     ]
 }
@@ -73,6 +73,7 @@ pub use frame_system::Call as SystemCall;
 pub use frame_system::{EnsureRoot, EnsureRootWithSuccess, EnsureWithSuccess};
 
 pub use pallet_balances::Call as BalancesCall;
+pub use pallet_network::CheckNetworkCall;
 pub use pallet_timestamp::Call as TimestampCall;
 use pallet_transaction_payment::Multiplier;
 
@@ -131,6 +132,7 @@ pub type SignedExtra = (
     frame_system::CheckEra<Runtime>,
     frame_system::CheckNonce<Runtime>,
     frame_system::CheckWeight<Runtime>,
+    pallet_network::CheckNetworkCall<Runtime>,
     pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
     cumulus_primitives_storage_weight_reclaim::StorageWeightReclaim<Runtime>,
 );
@@ -150,7 +152,10 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+    pallet_network::migrations::v1::MigrateV0ToV1<Runtime>,
+    pallet_network::migrations::v2::MigrateV1ToV2<Runtime>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -184,6 +189,8 @@ pub const OVERWATCH_EPOCH_EMISSIONS: u128 = OVERWATCH_YEARLY_EMISSIONS / (EPOCHS
 pub const AUTHOR_YEARLY_EMISSIONS: u128 = 1_000 * TENSOR;
 pub const AUTHOR_BLOCK_EMISSIONS: u128 = AUTHOR_YEARLY_EMISSIONS / (YEAR as u128);
 
+pub const RANDOMNESS_YEARLY_EMISSIONS: u128 = 1_000 * TENSOR;
+
 /// Opaque types. These are used by the CLI to instantiate machinery that don't need to know
 /// the specifics of the runtime. They can then be made to be agnostic over specific formats
 /// of data like extrinsics, allowing for them to continue syncing the network through upgrades
@@ -213,10 +220,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: Cow::Borrowed("hypertensor-node"),
     impl_name: Cow::Borrowed("hypertensor-node"),
     authoring_version: 1,
-    spec_version: 1,
+    spec_version: 2,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
     system_version: 1,
 };
 
@@ -366,6 +373,29 @@ impl pallet_atomic_swap::Config for Runtime {
 
 impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
 
+parameter_types! {
+    // Withholding a reveal to pick between two seeds forfeits this
+    pub const RandomnessCommitDeposit: Balance = 1_000 * TENSOR;
+    // Seeds rotate a few times per epoch so every election uses a fresh seed
+    pub const RandomnessRoundLength: u32 = BLOCKS_PER_EPOCH / 3;
+    pub const RandomnessMaxCommitsPerRound: u32 = 256;
+    // Yearly emissions if every round is full
+    pub const RandomnessRevealReward: Balance =
+        RANDOMNESS_YEARLY_EMISSIONS / ((YEAR / (BLOCKS_PER_EPOCH / 3)) as u128 * 256);
+    pub const RandomnessMinReveals: u32 = 8;
+}
+
+impl pallet_randomness_beacon::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type CommitDeposit = RandomnessCommitDeposit;
+    type RevealReward = RandomnessRevealReward;
+    type MinReveals = RandomnessMinReveals;
+    type RoundLength = RandomnessRoundLength;
+    type MaxCommitsPerRound = RandomnessMaxCommitsPerRound;
+    type WeightInfo = pallet_randomness_beacon::weights::SubstrateWeight<Runtime>;
+}
+
 impl pallet_utility::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
@@ -669,6 +699,9 @@ parameter_types! {
     pub MaximumHooksWeight: Weight = Perbill::from_percent(50) *
         BlockWeights::get().max_block;
     pub const DesignatedEpochSlots: u32 = 3;
+    pub const PeerIdProofDeposit: u128 = TENSOR / 10;
+    // The EVM test suite runs its transactions in blocks the network hooks would otherwise fill
+    pub const ReportHooksWeight: bool = !cfg!(feature = "evm-tests");
 }
 
 impl pallet_network::Config for Runtime {
@@ -683,11 +716,13 @@ impl pallet_network::Config for Runtime {
     type EpochsPerYear = EpochsPerYear;
     type InitialTxRateLimit = InitialTxRateLimit;
     type PalletId = NetworkPalletId;
-    type Randomness = InsecureRandomnessCollectiveFlip;
+    type Randomness = RandomnessBeacon;
     type TreasuryAccount = TreasuryAccount;
     type OverwatchEpochEmissions = OverwatchEpochEmissions;
     type MaximumHooksWeight = MaximumHooksWeight;
     type DesignatedEpochSlots = DesignatedEpochSlots;
+    type PeerIdProofDeposit = PeerIdProofDeposit;
+    type ReportHooksWeight = ReportHooksWeight;
 }
 
 impl pallet_evm_chain_id::Config for Runtime {}
@@ -909,6 +944,10 @@ mod runtime {
     #[runtime::pallet_index(22)]
     pub type Collective = pallet_collective::Pallet<Runtime, Instance1>;
 
+    // Declared before `Network` so the seed is rotated before the same block's elections
+    #[runtime::pallet_index(25)]
+    pub type RandomnessBeacon = pallet_randomness_beacon;
+
     #[runtime::pallet_index(23)]
     pub type Network = pallet_network;
 
@@ -1011,6 +1050,7 @@ mod benches {
         [pallet_collective, Collective]
         [pallet_network, Network]
         [pallet_author_subsidy, AuthorSubsidy]
+        [pallet_randomness_beacon, RandomnessBeacon]
         // [pallet_treasury, Treasury]
     );
 }
@@ -1432,6 +1472,7 @@ impl_runtime_apis! {
         }
     }
 
+    #[api_version(5)]
     impl network_custom_rpc_runtime_api::NetworkRuntimeApi<Block> for Runtime {
         fn get_subnet_info(subnet_id: u32) -> Vec<u8> {
             let result = Network::get_subnet_info(subnet_id);
@@ -1492,6 +1533,95 @@ impl_runtime_apis! {
             let result = Network::get_validators_and_attestors(subnet_id);
             result.encode()
         }
+        fn get_account_swap_queue(account_id: AccountId) -> Vec<u8> {
+            let result = Network::get_account_swap_queue(account_id);
+            result.encode()
+        }
+        fn get_consensus_submission(subnet_id: u32, subnet_epoch: u32) -> Option<pallet_network::ConsensusSubmissionInfo<AccountId>> {
+            Network::get_consensus_submission(subnet_id, subnet_epoch)
+        }
+        fn get_consensus_history(subnet_id: u32, from: u32, to: u32) -> Vec<pallet_network::ConsensusSubmissionInfo<AccountId>> {
+            Network::get_consensus_history(subnet_id, from, to)
+        }
+        fn get_subnet_info_v2(subnet_id: u32) -> Option<pallet_network::SubnetInfo<AccountId>> {
+            Network::get_subnet_info(subnet_id)
+        }
+        fn get_all_subnets_info_v2() -> Vec<pallet_network::SubnetInfo<AccountId>> {
+            Network::get_all_subnets_info()
+        }
+        fn get_subnet_node_info_v2(subnet_id: u32, subnet_node_id: u32) -> Option<pallet_network::SubnetNodeInfo<AccountId>> {
+            Network::get_subnet_node_info(subnet_id, subnet_node_id)
+        }
+        fn get_subnet_nodes_info_v2(subnet_id: u32) -> Vec<pallet_network::SubnetNodeInfo<AccountId>> {
+            Network::get_subnet_nodes_info(subnet_id)
+        }
+        fn get_all_subnet_nodes_info_v2() -> Vec<pallet_network::SubnetNodeInfo<AccountId>> {
+            Network::get_all_subnet_nodes_info()
+        }
+        fn get_bootnodes_v2(subnet_id: u32) -> pallet_network::AllSubnetBootnodes {
+            Network::get_bootnodes(subnet_id)
+        }
+        fn get_coldkey_subnet_nodes_info_v2(coldkey: AccountId) -> Vec<pallet_network::SubnetNodeInfo<AccountId>> {
+            Network::get_coldkey_subnet_nodes_info(coldkey)
+        }
+        fn get_coldkey_stakes_v2(coldkey: AccountId) -> Vec<pallet_network::SubnetNodeStakeInfo<AccountId>> {
+            Network::get_coldkey_stakes(coldkey)
+        }
+        fn get_delegate_stakes_v2(account_id: AccountId) -> Vec<pallet_network::DelegateStakeInfo> {
+            Network::get_delegate_stakes(account_id)
+        }
+        fn get_node_delegate_stakes_v2(account_id: AccountId) -> Vec<pallet_network::NodeDelegateStakeInfo> {
+            Network::get_node_delegate_stakes(account_id)
+        }
+        fn get_overwatch_commits_for_epoch_and_node_v2(epoch: u32, overwatch_node_id: u32) -> Vec<(u32, Hash)> {
+            Network::get_overwatch_commits_for_epoch_and_node(epoch, overwatch_node_id)
+        }
+        fn get_overwatch_reveals_for_epoch_and_node_v2(epoch: u32, overwatch_node_id: u32) -> Vec<(u32, u128)> {
+            Network::get_overwatch_reveals_for_epoch_and_node(epoch, overwatch_node_id)
+        }
+        fn get_elected_validator_info_v2(subnet_id: u32, subnet_epoch: u32) -> Option<pallet_network::SubnetNodeInfo<AccountId>> {
+            Network::get_elected_validator_info(subnet_id, subnet_epoch)
+        }
+        fn get_validators_and_attestors_v2(subnet_id: u32) -> Vec<pallet_network::SubnetNodeInfo<AccountId>> {
+            Network::get_validators_and_attestors(subnet_id)
+        }
+        fn get_account_swap_queue_v2(account_id: AccountId) -> Vec<pallet_network::QueuedSwapInfo<AccountId>> {
+            Network::get_account_swap_queue(account_id)
+        }
+        fn get_all_subnets_info_paged(start: u32, limit: u32) -> pallet_network::RpcPage<pallet_network::SubnetInfo<AccountId>, u32> {
+            Network::get_all_subnets_info_paged(start, limit)
+        }
+        fn get_subnet_nodes_info_paged(
+            subnet_id: u32,
+            start: u32,
+            limit: u32,
+            filter: pallet_network::SubnetNodeFilter<AccountId>,
+        ) -> pallet_network::RpcPage<pallet_network::SubnetNodeInfo<AccountId>, u32> {
+            Network::get_subnet_nodes_info_paged(subnet_id, start, limit, filter)
+        }
+        fn get_all_subnet_nodes_info_paged(
+            start: (u32, u32),
+            limit: u32,
+            filter: pallet_network::SubnetNodeFilter<AccountId>,
+        ) -> pallet_network::RpcPage<pallet_network::SubnetNodeInfo<AccountId>, (u32, u32)> {
+            Network::get_all_subnet_nodes_info_paged(start, limit, filter)
+        }
+        fn estimate_subnet_epoch_rewards(subnet_id: u32) -> Option<pallet_network::RewardsData> {
+            Network::estimate_subnet_epoch_rewards(subnet_id)
+        }
+        fn estimate_delegate_apr(subnet_id: u32) -> u128 {
+            Network::estimate_delegate_apr(subnet_id)
+        }
+        fn estimate_node_delegate_apr(subnet_id: u32, subnet_node_id: u32) -> u128 {
+            Network::estimate_node_delegate_apr(subnet_id, subnet_node_id)
+        }
+        fn get_account_rewards(
+            account_id: AccountId,
+            from_epoch: u32,
+            to_epoch: u32,
+        ) -> Vec<pallet_network::AccountRewardsInfo> {
+            Network::get_account_rewards(account_id, from_epoch, to_epoch)
+        }
     }
 
     #[cfg(feature = "runtime-benchmarks")]